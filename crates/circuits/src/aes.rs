@@ -0,0 +1,642 @@
+// Copyright 2025 Irreducible Inc.
+
+//! AES-128 and AES-256 block encryption, as specified in [FIPS 197].
+//!
+//! Every row of the trace attests to the encryption of one 16-byte block, so a trace with
+//! `1 << log_size` rows encrypts that many blocks in ECB or CTR mode. Each row may use a different
+//! key.
+//!
+//! AES operates on bytes as elements of the Rijndael field, [`AESTowerField8b`], whereas the
+//! constraint system is defined over the canonical binary tower. Bytes are represented as elements
+//! of [`BinaryField8b`] under the field isomorphism between the two, see [`aes_byte_to_tower`] and
+//! [`tower_to_aes_byte`]. The isomorphism is additive, so XORs are field additions, and it
+//! commutes with multiplication and inversion.
+//!
+//! SubBytes is arithmetized by committing to the bits of the field inverse of every byte, which
+//! the S-box affine transformation maps linearly to the S-box output. ShiftRows, MixColumns,
+//! AddRoundKey and the linear part of the key schedule are folded into a single linear
+//! combination per byte and round.
+//!
+//! [FIPS 197]: <https://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.197-upd1.pdf>
+
+use std::array;
+
+use anyhow::{ensure, Result};
+use binius_core::oracle::OracleId;
+use binius_field::{
+	packed::set_packed_slice, AESTowerField8b, BinaryField1b, BinaryField8b, ExtensionField, Field,
+	TowerField,
+};
+use binius_math::ArithExpr;
+
+use crate::{
+	builder::{types::F, ConstraintSystemBuilder},
+	transparent,
+};
+
+type B1 = BinaryField1b;
+type B8 = BinaryField8b;
+
+/// Number of bytes in an AES block.
+pub const BLOCK_SIZE: usize = 16;
+
+/// Converts an AES byte into its representation in the canonical binary tower.
+pub fn aes_byte_to_tower(byte: u8) -> BinaryField8b {
+	BinaryField8b::from(AESTowerField8b::new(byte))
+}
+
+/// Converts a byte represented in the canonical binary tower back into an AES byte.
+pub fn tower_to_aes_byte(x: BinaryField8b) -> u8 {
+	AESTowerField8b::from(x).val()
+}
+
+/// The round keys of an expanded AES key.
+///
+/// Round keys are not materialized as oracles, they are kept as affine combinations of the key
+/// and key schedule S-box outputs and folded into the AddRoundKey linear combinations.
+#[derive(Debug, Clone)]
+pub struct AesRoundKeys {
+	round_keys: Vec<[AffineByte; BLOCK_SIZE]>,
+}
+
+impl AesRoundKeys {
+	/// The number of AES rounds, 10 for AES-128 and 14 for AES-256.
+	pub fn n_rounds(&self) -> usize {
+		self.round_keys.len() - 1
+	}
+}
+
+/// Expands a 16-byte (AES-128) or 32-byte (AES-256) key into round keys.
+pub fn aes_key_expansion(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	log_size: usize,
+	key: &[OracleId],
+) -> Result<AesRoundKeys> {
+	ensure!(
+		key.len() == 16 || key.len() == 32,
+		"AES key must be 16 or 32 bytes, got {}",
+		key.len()
+	);
+
+	builder.push_namespace(name);
+
+	let n_key_words = key.len() / 4;
+	let n_rounds = n_key_words + 6;
+
+	let mut words = key
+		.chunks_exact(4)
+		.map(|word| array::from_fn::<_, 4, _>(|j| AffineByte::from_oracle(word[j])))
+		.collect::<Vec<_>>();
+	let mut round_const = AESTowerField8b::ONE;
+	for i in n_key_words..4 * (n_rounds + 1) {
+		let mut temp = words[i - 1].clone();
+		if i % n_key_words == 0 {
+			temp.rotate_left(1);
+			temp = sub_word(builder, format!("sub_word[{i}]"), log_size, &temp)?;
+			temp[0] = temp[0].add(&AffineByte::constant(round_const.into()));
+			round_const *= AESTowerField8b::new(2);
+		} else if n_key_words > 6 && i % n_key_words == 4 {
+			temp = sub_word(builder, format!("sub_word[{i}]"), log_size, &temp)?;
+		}
+		let word = array::from_fn(|j| words[i - n_key_words][j].add(&temp[j]));
+		words.push(word);
+	}
+
+	builder.pop_namespace();
+
+	let round_keys = words
+		.chunks_exact(4)
+		.map(|round_words| array::from_fn(|i| round_words[i / 4][i % 4].clone()))
+		.collect();
+	Ok(AesRoundKeys { round_keys })
+}
+
+/// Encrypts one block per row with the expanded key.
+///
+/// Returns the ciphertext bytes.
+pub fn aes_encrypt(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	log_size: usize,
+	round_keys: &AesRoundKeys,
+	plaintext: [OracleId; BLOCK_SIZE],
+) -> Result<[OracleId; BLOCK_SIZE]> {
+	builder.push_namespace(name);
+	let output = cipher(builder, log_size, round_keys, plaintext.map(AffineByte::from_oracle))?;
+	let ciphertext = materialize_block(builder, "ciphertext", log_size, &output)?;
+	builder.pop_namespace();
+	Ok(ciphertext)
+}
+
+/// Encrypts one block per row in electronic codebook (ECB) mode.
+///
+/// The key is either 16 bytes for AES-128 or 32 bytes for AES-256. Returns the ciphertext bytes.
+pub fn aes_ecb_encrypt(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	log_size: usize,
+	key: &[OracleId],
+	plaintext: [OracleId; BLOCK_SIZE],
+) -> Result<[OracleId; BLOCK_SIZE]> {
+	builder.push_namespace(name);
+	let round_keys = aes_key_expansion(builder, "key_expansion", log_size, key)?;
+	let ciphertext = aes_encrypt(builder, "encrypt", log_size, &round_keys, plaintext)?;
+	builder.pop_namespace();
+	Ok(ciphertext)
+}
+
+/// Encrypts one block per row in counter (CTR) mode.
+///
+/// The counter block of row `z` is the 12-byte `nonce` followed by the big-endian 32-bit counter
+/// `initial_counter + z`, which wraps around on overflow. The counter blocks are public, so they
+/// are transparent and need not be committed. The key is either 16 bytes for AES-128 or 32 bytes
+/// for AES-256. Returns the ciphertext bytes.
+pub fn aes_ctr_encrypt(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	log_size: usize,
+	key: &[OracleId],
+	nonce: [u8; 12],
+	initial_counter: u32,
+	plaintext: [OracleId; BLOCK_SIZE],
+) -> Result<[OracleId; BLOCK_SIZE]> {
+	builder.push_namespace(name);
+
+	let round_keys = aes_key_expansion(builder, "key_expansion", log_size, key)?;
+
+	let counters = (0..1u32 << log_size)
+		.map(|z| initial_counter.wrapping_add(z).to_be_bytes())
+		.collect::<Vec<_>>();
+	let counter_block: [AffineByte; BLOCK_SIZE] = array::try_from_fn(|i| {
+		if i < 12 {
+			return Ok(AffineByte::constant(aes_byte_to_tower(nonce[i])));
+		}
+
+		let values = counters
+			.iter()
+			.map(|counter| aes_byte_to_tower(counter[i - 12]))
+			.collect::<Vec<_>>();
+		if values.iter().all(|&value| value == values[0]) {
+			Ok(AffineByte::constant(values[0]))
+		} else {
+			transparent::make_transparent(builder, format!("counter[{}]", i - 12), &values)
+				.map(AffineByte::from_oracle)
+		}
+	})?;
+
+	let keystream = cipher(builder, log_size, &round_keys, counter_block)?;
+	let output: [AffineByte; BLOCK_SIZE] =
+		array::from_fn(|i| keystream[i].add(&AffineByte::from_oracle(plaintext[i])));
+	let ciphertext = materialize_block(builder, "ciphertext", log_size, &output)?;
+
+	builder.pop_namespace();
+	Ok(ciphertext)
+}
+
+/// The AES cipher, returning the output of the last round without materializing it.
+fn cipher(
+	builder: &mut ConstraintSystemBuilder,
+	log_size: usize,
+	round_keys: &AesRoundKeys,
+	input: [AffineByte; BLOCK_SIZE],
+) -> Result<[AffineByte; BLOCK_SIZE]> {
+	let mut state: [AffineByte; BLOCK_SIZE] =
+		array::from_fn(|i| input[i].add(&round_keys.round_keys[0][i]));
+
+	let n_rounds = round_keys.n_rounds();
+	for round in 1..=n_rounds {
+		builder.push_namespace(format!("round[{round}]"));
+
+		let state_in = materialize_block(builder, "state_in", log_size, &state)?;
+		let sub_bytes: [OracleId; BLOCK_SIZE] = array::try_from_fn(|i| {
+			sub_byte(builder, format!("s_box[{i}]"), log_size, state_in[i])
+		})?;
+
+		// State bytes are in column-major order, byte i is in row i % 4 and column i / 4.
+		let shift_rows: [OracleId; BLOCK_SIZE] = array::from_fn(|i| {
+			let (col, row) = (i / 4, i % 4);
+			sub_bytes[4 * ((col + row) % 4) + row]
+		});
+
+		state = if round < n_rounds {
+			array::from_fn(|i| {
+				let (col, row) = (i / 4, i % 4);
+				MIX_COLUMNS_ROW.iter().enumerate().fold(
+					AffineByte::default(),
+					|acc, (k, &coeff)| {
+						let byte = AffineByte::from_oracle(shift_rows[4 * col + (row + k) % 4]);
+						acc.add(&byte.scale(aes_byte_to_tower(coeff)))
+					},
+				)
+			})
+		} else {
+			shift_rows.map(AffineByte::from_oracle)
+		};
+		for (byte, round_key_byte) in state.iter_mut().zip(&round_keys.round_keys[round]) {
+			*byte = byte.add(round_key_byte);
+		}
+
+		builder.pop_namespace();
+	}
+
+	Ok(state)
+}
+
+fn sub_word(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	log_size: usize,
+	word: &[AffineByte; 4],
+) -> Result<[AffineByte; 4]> {
+	builder.push_namespace(name);
+	let sub_word = array::try_from_fn(|j| {
+		let input = word[j].materialize(builder, format!("input[{j}]"), log_size)?;
+		sub_byte(builder, format!("s_box[{j}]"), log_size, input).map(AffineByte::from_oracle)
+	});
+	builder.pop_namespace();
+	sub_word
+}
+
+/// The AES S-box.
+///
+/// The prover commits to the bits of the inverse of the input, where zero maps to zero, and the
+/// output is the affine transformation of the inverse.
+pub(crate) fn sub_byte(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	log_size: usize,
+	input: OracleId,
+) -> Result<OracleId> {
+	builder.push_namespace(name);
+
+	let inv_bits: [OracleId; 8] =
+		builder.add_committed_multiple("inv_bits", log_size, B1::TOWER_LEVEL);
+
+	let inv = builder.add_linear_combination(
+		"inv",
+		log_size,
+		(0..8).map(|b| {
+			let basis =
+				<B8 as ExtensionField<B1>>::basis(b).expect("index is less than extension degree");
+			(inv_bits[b], basis.into())
+		}),
+	)?;
+
+	let sbox_linear = sbox_linear_coeffs();
+	let output = builder.add_linear_combination_with_offset(
+		"output",
+		log_size,
+		aes_byte_to_tower(SBOX_CONST).into(),
+		(0..8).map(|b| (inv_bits[b], sbox_linear[b].into())),
+	)?;
+
+	builder.fill_witness(inv_bits.into_iter().chain([inv, output]), move |witness, _| {
+		let input = witness.get::<B8>(input)?.as_slice::<B8>();
+
+		let mut inv_bits_witness: [_; 8] = inv_bits.map(|id| witness.new_column::<B1>(id));
+		let inv_bits = inv_bits_witness.each_mut().map(|bit| bit.packed());
+
+		let mut inv = witness.new_column::<B8>(inv);
+		let inv = inv.as_mut_slice::<B8>();
+
+		let mut output = witness.new_column::<B8>(output);
+		let output = output.as_mut_slice::<B8>();
+
+		for z in 0..1 << log_size {
+			inv[z] = input[z].invert().unwrap_or(B8::ZERO);
+			output[z] = aes_byte_to_tower(s_box(tower_to_aes_byte(input[z])));
+			for (b, bit) in ExtensionField::<B1>::iter_bases(&inv[z]).enumerate() {
+				set_packed_slice(inv_bits[b], z, bit);
+			}
+		}
+		Ok(())
+	})?;
+
+	builder.assert_zero("s_box", [input, inv], s_box_expr()?);
+	builder.pop_namespace();
+	Ok(output)
+}
+
+fn s_box_expr() -> Result<ArithExpr<F>> {
+	let x = ArithExpr::Var(0);
+	let inv = ArithExpr::Var(1);
+
+	// x * inv == 1
+	let non_zero_case = x.clone() * inv.clone() - ArithExpr::one();
+
+	// x == 0 AND inv == 0, using that inv * beta is outside of the 8-bit subfield unless zero
+	let beta = <F as ExtensionField<B1>>::basis(1 << 3)?;
+	let zero_case = x + inv * ArithExpr::Const(beta);
+
+	// (x * inv == 1) OR (x == 0 AND inv == 0)
+	Ok(non_zero_case * zero_case)
+}
+
+/// Constant of the S-box affine transformation.
+const SBOX_CONST: u8 = 0x63;
+
+/// The first row of the circulant MixColumns matrix.
+const MIX_COLUMNS_ROW: [u8; 4] = [0x02, 0x03, 0x01, 0x01];
+
+/// The linear part of the S-box affine transformation on AES bytes.
+const fn sbox_linear(x: u8) -> u8 {
+	x ^ x.rotate_left(1) ^ x.rotate_left(2) ^ x.rotate_left(3) ^ x.rotate_left(4)
+}
+
+/// The images of the tower basis elements under the linear part of the S-box affine
+/// transformation, in the tower representation.
+fn sbox_linear_coeffs() -> [B8; 8] {
+	array::from_fn(|b| {
+		let basis =
+			<B8 as ExtensionField<B1>>::basis(b).expect("index is less than extension degree");
+		aes_byte_to_tower(sbox_linear(tower_to_aes_byte(basis)))
+	})
+}
+
+fn s_box(x: u8) -> u8 {
+	let inv = AESTowerField8b::new(x)
+		.invert()
+		.unwrap_or(AESTowerField8b::ZERO)
+		.val();
+	sbox_linear(inv) ^ SBOX_CONST
+}
+
+fn materialize_block(
+	builder: &mut ConstraintSystemBuilder,
+	name: &str,
+	log_size: usize,
+	block: &[AffineByte; BLOCK_SIZE],
+) -> Result<[OracleId; BLOCK_SIZE]> {
+	array::try_from_fn(|i| block[i].materialize(builder, format!("{name}[{i}]"), log_size))
+}
+
+/// An affine combination of byte-valued oracles with coefficients in the 8-bit tower field.
+///
+/// The linear layers of AES and Grøstl are accumulated symbolically so that each of them is
+/// materialized as a single linear combination oracle, instead of a deep chain of them.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct AffineByte {
+	terms: Vec<(OracleId, B8)>,
+	offset: B8,
+}
+
+impl AffineByte {
+	pub(crate) fn from_oracle(id: OracleId) -> Self {
+		Self {
+			terms: vec![(id, B8::ONE)],
+			offset: B8::ZERO,
+		}
+	}
+
+	pub(crate) fn constant(offset: B8) -> Self {
+		Self {
+			terms: Vec::new(),
+			offset,
+		}
+	}
+
+	pub(crate) fn add(&self, other: &Self) -> Self {
+		let mut terms = self.terms.clone();
+		for &(id, coeff) in &other.terms {
+			match terms.iter().position(|&(term_id, _)| term_id == id) {
+				Some(index) => {
+					terms[index].1 += coeff;
+					if terms[index].1 == B8::ZERO {
+						terms.swap_remove(index);
+					}
+				}
+				None => terms.push((id, coeff)),
+			}
+		}
+		Self {
+			terms,
+			offset: self.offset + other.offset,
+		}
+	}
+
+	pub(crate) fn scale(&self, coeff: B8) -> Self {
+		Self {
+			terms: self
+				.terms
+				.iter()
+				.map(|&(id, term_coeff)| (id, term_coeff * coeff))
+				.filter(|&(_, term_coeff)| term_coeff != B8::ZERO)
+				.collect(),
+			offset: self.offset * coeff,
+		}
+	}
+
+	/// Returns an oracle for the affine combination, adding a linear combination oracle unless it
+	/// is a single oracle.
+	pub(crate) fn materialize(
+		&self,
+		builder: &mut ConstraintSystemBuilder,
+		name: impl ToString,
+		log_size: usize,
+	) -> Result<OracleId> {
+		if let [(id, coeff)] = self.terms[..] {
+			if coeff == B8::ONE && self.offset == B8::ZERO {
+				return Ok(id);
+			}
+		}
+
+		let id = builder.add_linear_combination_with_offset(
+			name,
+			log_size,
+			self.offset.into(),
+			self.terms
+				.iter()
+				.map(|&(term_id, coeff)| (term_id, coeff.into())),
+		)?;
+
+		let (terms, offset) = (self.terms.clone(), self.offset);
+		builder.fill_witness([id], move |witness, _| {
+			let terms = terms
+				.iter()
+				.map(|&(term_id, coeff)| Ok((witness.get::<B8>(term_id)?, coeff)))
+				.collect::<Result<Vec<_>>>()?;
+
+			let mut output = witness.new_column_with_default::<B8>(id, offset);
+			let output = output.as_mut_slice::<B8>();
+			for (values, coeff) in terms {
+				for (out, &value) in output.iter_mut().zip(values.as_slice::<B8>()) {
+					*out += value * coeff;
+				}
+			}
+			Ok(())
+		})?;
+
+		Ok(id)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use aes::{
+		cipher::{BlockEncrypt, KeyInit, KeyIvInit, StreamCipher},
+		Aes128, Aes256,
+	};
+	use binius_core::constraint_system::validate::validate_witness;
+	use rand::{rngs::StdRng, Rng, SeedableRng};
+
+	use super::*;
+	use crate::{builder::test_utils::test_circuit, unconstrained::unconstrained};
+
+	/// Commits to byte columns with the given values, one entry per row.
+	fn committed_bytes<const N: usize>(
+		builder: &mut ConstraintSystemBuilder,
+		name: &str,
+		log_size: usize,
+		values: &[[u8; N]],
+	) -> [OracleId; N] {
+		let ids = builder.add_committed_multiple(name, log_size, B8::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			for (i, &id) in ids.iter().enumerate() {
+				let mut column = witness.new_column::<B8>(id);
+				for (out, value) in column.as_mut_slice::<B8>().iter_mut().zip(values) {
+					*out = aes_byte_to_tower(value[i]);
+				}
+			}
+		}
+		ids
+	}
+
+	fn read_bytes(
+		builder: &mut ConstraintSystemBuilder,
+		ids: [OracleId; BLOCK_SIZE],
+	) -> Vec<[u8; BLOCK_SIZE]> {
+		let witness = builder.witness().unwrap();
+		let columns = ids.map(|id| witness.get::<B8>(id).unwrap().as_slice::<B8>());
+		(0..columns[0].len())
+			.map(|z| array::from_fn(|i| tower_to_aes_byte(columns[i][z])))
+			.collect()
+	}
+
+	fn random_blocks<const N: usize>(rng: &mut StdRng, log_size: usize) -> Vec<[u8; N]> {
+		(0..1 << log_size)
+			.map(|_| array::from_fn(|_| rng.gen()))
+			.collect()
+	}
+
+	#[test]
+	fn test_s_box() {
+		const S_BOX_HEAD: [u8; 8] = [0x63, 0x7c, 0x77, 0x7b, 0xf2, 0x6b, 0x6f, 0xc5];
+		for (x, &expected) in S_BOX_HEAD.iter().enumerate() {
+			assert_eq!(s_box(x as u8), expected);
+		}
+		assert_eq!(s_box(0xff), 0x16);
+	}
+
+	#[test]
+	fn test_aes128_ecb() {
+		let log_size = 6;
+		let mut rng = StdRng::seed_from_u64(0);
+		let keys = random_blocks::<16>(&mut rng, log_size);
+		let plaintexts = random_blocks::<BLOCK_SIZE>(&mut rng, log_size);
+
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let key = committed_bytes(&mut builder, "key", log_size, &keys);
+		let plaintext = committed_bytes(&mut builder, "plaintext", log_size, &plaintexts);
+		let ciphertext = aes_ecb_encrypt(&mut builder, "aes", log_size, &key, plaintext).unwrap();
+
+		for ((key, plaintext), ciphertext) in keys
+			.iter()
+			.zip(&plaintexts)
+			.zip(read_bytes(&mut builder, ciphertext))
+		{
+			let mut expected = (*plaintext).into();
+			Aes128::new(key.into()).encrypt_block(&mut expected);
+			assert_eq!(ciphertext, <[u8; BLOCK_SIZE]>::from(expected));
+		}
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+
+	#[test]
+	fn test_aes256_ecb() {
+		let log_size = 6;
+		let mut rng = StdRng::seed_from_u64(0);
+		let keys = random_blocks::<32>(&mut rng, log_size);
+		let plaintexts = random_blocks::<BLOCK_SIZE>(&mut rng, log_size);
+
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let key = committed_bytes(&mut builder, "key", log_size, &keys);
+		let plaintext = committed_bytes(&mut builder, "plaintext", log_size, &plaintexts);
+		let ciphertext = aes_ecb_encrypt(&mut builder, "aes", log_size, &key, plaintext).unwrap();
+
+		for ((key, plaintext), ciphertext) in keys
+			.iter()
+			.zip(&plaintexts)
+			.zip(read_bytes(&mut builder, ciphertext))
+		{
+			let mut expected = (*plaintext).into();
+			Aes256::new(key.into()).encrypt_block(&mut expected);
+			assert_eq!(ciphertext, <[u8; BLOCK_SIZE]>::from(expected));
+		}
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+
+	#[test]
+	fn test_aes128_ctr() {
+		type Aes128Ctr = ctr::Ctr32BE<Aes128>;
+
+		let log_size = 6;
+		let mut rng = StdRng::seed_from_u64(0);
+		let key_bytes: [u8; 16] = rng.gen();
+		let nonce: [u8; 12] = rng.gen();
+		// Start close to the maximum to exercise the counter wrapping around
+		let initial_counter = u32::MAX - 5;
+		let plaintexts = random_blocks::<BLOCK_SIZE>(&mut rng, log_size);
+
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let key = committed_bytes(&mut builder, "key", log_size, &vec![key_bytes; 1 << log_size]);
+		let plaintext = committed_bytes(&mut builder, "plaintext", log_size, &plaintexts);
+		let ciphertext =
+			aes_ctr_encrypt(&mut builder, "aes", log_size, &key, nonce, initial_counter, plaintext)
+				.unwrap();
+
+		let mut iv = [0u8; 16];
+		iv[..12].copy_from_slice(&nonce);
+		iv[12..].copy_from_slice(&initial_counter.to_be_bytes());
+		let mut expected = plaintexts.concat();
+		Aes128Ctr::new(&key_bytes.into(), &iv.into()).apply_keystream(&mut expected);
+		assert_eq!(read_bytes(&mut builder, ciphertext).concat(), expected);
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+
+	#[test]
+	fn test_aes256_ctr_circuit() {
+		test_circuit(|builder| {
+			let log_size = 7;
+			let key: [OracleId; 32] = array::from_fn(|i| {
+				unconstrained::<B8>(builder, format!("key[{i}]"), log_size).unwrap()
+			});
+			let plaintext: [OracleId; BLOCK_SIZE] = array::from_fn(|i| {
+				unconstrained::<B8>(builder, format!("plaintext[{i}]"), log_size).unwrap()
+			});
+			let _ciphertext =
+				aes_ctr_encrypt(builder, "aes", log_size, &key, [0; 12], 0, plaintext)?;
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_invalid_key_length() {
+		let mut builder = ConstraintSystemBuilder::new();
+		let key = builder.add_committed_multiple::<24>("key", 6, B8::TOWER_LEVEL);
+		assert!(aes_key_expansion(&mut builder, "key_expansion", 6, &key).is_err());
+	}
+}
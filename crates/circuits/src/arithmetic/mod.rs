@@ -2,6 +2,7 @@
 
 pub mod mul;
 pub mod u32;
+pub mod u64;
 
 /// Whether to allow or disallow arithmetic overflow
 #[derive(Debug, Clone, Copy)]
@@ -78,7 +78,9 @@ where
 		})
 		.collect::<Vec<_>>();
 
-	if let Some(witness) = builder.witness() {
+	let bits = (xin_bits.clone(), yin_bits.clone(), cout_bits.clone());
+	builder.fill_witness(cout_bits.clone(), move |witness, _| {
+		let (xin_bits, yin_bits, cout_bits) = &bits;
 		let xin_columns = xin_bits
 			.iter()
 			.map(|&id| witness.get::<BinaryField1b>(id).map(|x| x.packed()))
@@ -106,7 +108,8 @@ where
 			.collect::<Vec<_>>();
 
 		numbers_to_columns(&result, &mut cout_columns_u8);
-	}
+		Ok(())
+	})?;
 
 	// Handling special case when $x == 0$ $y == 0$ $c == 2^{2 \cdot n} -1$
 	builder.assert_zero(
@@ -30,9 +30,9 @@ pub fn packed(
 	input: OracleId,
 ) -> Result<OracleId, anyhow::Error> {
 	let packed = builder.add_packed(name, input, 5)?;
-	if let Some(witness) = builder.witness() {
-		witness.set(packed, witness.get::<B1>(input)?.repacked::<B32>())?;
-	}
+	builder.fill_witness([packed], move |witness, _| {
+		witness.set(packed, witness.get::<B1>(input)?.repacked::<B32>())
+	})?;
 	Ok(packed)
 }
 
@@ -98,7 +98,7 @@ pub fn add(
 	let cin = builder.add_shifted("cin", cout, 1, 5, ShiftVariant::LogicalLeft)?;
 	let zout = builder.add_committed("zout", log_rows, B1::TOWER_LEVEL);
 
-	if let Some(witness) = builder.witness() {
+	builder.fill_witness([zout, cout, cin], move |witness, _| {
 		(
 			witness.get::<B1>(xin)?.as_slice::<u32>(),
 			witness.get::<B1>(yin)?.as_slice::<u32>(),
@@ -113,7 +113,8 @@ pub fn add(
 				*cin = (*xin) ^ (*yin) ^ (*zout);
 				*cout = ((carry as u32) << 31) | (*cin >> 1);
 			});
-	}
+		Ok(())
+	})?;
 
 	builder.assert_zero(
 		"sum",
@@ -154,7 +155,7 @@ pub fn sub(
 	let cin = builder.add_shifted("cin", cout, 1, 5, ShiftVariant::LogicalLeft)?;
 	let xout = builder.add_committed("xin", log_rows, B1::TOWER_LEVEL);
 
-	if let Some(witness) = builder.witness() {
+	builder.fill_witness([xout, cout, cin], move |witness, _| {
 		(
 			witness.get::<B1>(zin)?.as_slice::<u32>(),
 			witness.get::<B1>(yin)?.as_slice::<u32>(),
@@ -169,7 +170,8 @@ pub fn sub(
 				*cin = (*xin) ^ (*yin) ^ (*zout);
 				*cout = ((carry as u32) << 31) | (*cin >> 1);
 			});
-	}
+		Ok(())
+	})?;
 
 	builder.assert_zero(
 		"sum",
@@ -223,14 +225,15 @@ pub fn shl(
 	}
 
 	let shifted = builder.add_shifted(name, input, offset, 5, ShiftVariant::LogicalLeft)?;
-	if let Some(witness) = builder.witness() {
+	builder.fill_witness([shifted], move |witness, _| {
 		(
 			witness.new_column::<B1>(shifted).as_mut_slice::<u32>(),
 			witness.get::<B1>(input)?.as_slice::<u32>(),
 		)
 			.into_par_iter()
 			.for_each(|(shifted, input)| *shifted = *input << offset);
-	}
+		Ok(())
+	})?;
 
 	Ok(shifted)
 }
@@ -246,14 +249,15 @@ pub fn shr(
 	}
 
 	let shifted = builder.add_shifted(name, input, offset, 5, ShiftVariant::LogicalRight)?;
-	if let Some(witness) = builder.witness() {
+	builder.fill_witness([shifted], move |witness, _| {
 		(
 			witness.new_column::<B1>(shifted).as_mut_slice::<u32>(),
 			witness.get::<B1>(input)?.as_slice::<u32>(),
 		)
 			.into_par_iter()
 			.for_each(|(shifted, input)| *shifted = *input >> offset);
-	}
+		Ok(())
+	})?;
 
 	Ok(shifted)
 }
@@ -271,7 +275,7 @@ pub fn select_bit(
 	let query = binius_core::polynomial::test_utils::decompose_index_to_hypercube_point(5, index);
 	let bits = builder.add_projected(name, input, query, ProjectionVariant::FirstVars)?;
 
-	if let Some(witness) = builder.witness() {
+	builder.fill_witness([bits], move |witness, _| {
 		let mut bits = witness.new_column::<B1>(bits);
 		let bits = bits.packed();
 		let input = witness.get::<B1>(input)?.as_slice::<u32>();
@@ -282,7 +286,8 @@ pub fn select_bit(
 			};
 			set_packed_slice(bits, i, value);
 		});
-	}
+		Ok(())
+	})?;
 
 	Ok(bits)
 }
@@ -296,20 +301,21 @@ pub fn constant(
 	builder.push_namespace(name);
 	// This would not need to be committed if we had `builder.add_unpacked(..)`
 	let output = builder.add_committed("output", log_count + 5, B1::TOWER_LEVEL);
-	if let Some(witness) = builder.witness() {
+	builder.fill_witness([output], move |witness, _| {
 		witness.new_column::<B1>(output).as_mut_slice().fill(value);
-	}
+		Ok(())
+	})?;
 
 	let output_packed = builder.add_packed("output_packed", output, 5)?;
 	let transparent = builder.add_transparent(
 		"transparent",
 		binius_core::transparent::constant::Constant::new(log_count, B32::new(value)),
 	)?;
-	if let Some(witness) = builder.witness() {
+	builder.fill_witness([output_packed, transparent], move |witness, _| {
 		let packed = witness.get::<B1>(output)?.repacked::<B32>();
 		witness.set(output_packed, packed)?;
-		witness.set(transparent, packed)?;
-	}
+		witness.set(transparent, packed)
+	})?;
 	builder.assert_zero(
 		"unpack",
 		[output_packed, transparent],
@@ -354,13 +360,14 @@ pub fn u32const_repeating(
 		log_size - PackedType::<U, B1>::LOG_WIDTH,
 	)?;
 
-	if let Some(witness) = builder.witness() {
+	builder.fill_witness([transparent_id, repeating_id], move |witness, _| {
 		let mut transparent_witness = witness.new_column::<B1>(transparent_id);
 		transparent_witness.as_mut_slice::<u32>().fill(x);
 
 		let mut repeating_witness = witness.new_column::<B1>(repeating_id);
 		repeating_witness.as_mut_slice::<u32>().fill(x);
-	}
+		Ok(())
+	})?;
 
 	Ok(repeating_id)
 }
@@ -375,13 +382,14 @@ mod tests {
 	fn test_mul_const() {
 		test_circuit(|builder| {
 			let a = builder.add_committed("a", 5, BinaryField1b::TOWER_LEVEL);
-			if let Some(witness) = builder.witness() {
+			builder.fill_witness([a], move |witness, _| {
 				witness
 					.new_column::<BinaryField1b>(a)
 					.as_mut_slice::<u32>()
 					.iter_mut()
 					.for_each(|v| *v = 0b01000000_00000000_00000000_00000000u32);
-			}
+				Ok(())
+			})?;
 			let _c = arithmetic::u32::mul_const(builder, "mul3", a, 3, arithmetic::Flags::Checked)?;
 			Ok(vec![])
 		})
@@ -0,0 +1,520 @@
+// Copyright 2025 Irreducible Inc.
+
+//! 64-bit unsigned integer arithmetic.
+//!
+//! Integers are represented as in [`super::u32`], as bit columns where every 64 consecutive rows
+//! hold the little-endian bits of one integer.
+
+use binius_core::oracle::{OracleId, ProjectionVariant, ShiftVariant};
+use binius_field::{
+	packed::set_packed_slice, BinaryField128b, BinaryField1b, BinaryField64b, Field, TowerField,
+};
+use binius_macros::arith_expr;
+use binius_maybe_rayon::prelude::*;
+use binius_utils::checked_arithmetics::checked_log_2;
+
+use super::mul::mul as exp_mul;
+use crate::builder::ConstraintSystemBuilder;
+
+type B1 = BinaryField1b;
+type B64 = BinaryField64b;
+
+pub const LOG_U64_BITS: usize = checked_log_2(64);
+
+pub fn packed(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	input: OracleId,
+) -> Result<OracleId, anyhow::Error> {
+	let packed = builder.add_packed(name, input, LOG_U64_BITS)?;
+	builder.fill_witness([packed], move |witness, _| {
+		witness.set(packed, witness.get::<B1>(input)?.repacked::<B64>())
+	})?;
+	Ok(packed)
+}
+
+pub fn add(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+	flags: super::Flags,
+) -> Result<OracleId, anyhow::Error> {
+	builder.push_namespace(name);
+	let (zout, cout) = add_with_cout(builder, xin, yin)?;
+
+	// Overflow checking
+	if matches!(flags, super::Flags::Checked) {
+		let last_cout = select_bit(builder, "last_cout", cout, 63)?;
+		builder.assert_zero(
+			"overflow",
+			[last_cout],
+			arith_expr!([last_cout] = last_cout).convert_field(),
+		);
+	}
+
+	builder.pop_namespace();
+	Ok(zout)
+}
+
+/// Adds two integers modulo $2^{64}$, returning the sum and the carry-out bit of every addition.
+///
+/// The carry-out column has one row per integer.
+pub fn add_with_carry(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+) -> Result<(OracleId, OracleId), anyhow::Error> {
+	builder.push_namespace(name);
+	let (zout, cout) = add_with_cout(builder, xin, yin)?;
+	let carry = select_bit(builder, "carry", cout, 63)?;
+	builder.pop_namespace();
+	Ok((zout, carry))
+}
+
+fn add_with_cout(
+	builder: &mut ConstraintSystemBuilder,
+	xin: OracleId,
+	yin: OracleId,
+) -> Result<(OracleId, OracleId), anyhow::Error> {
+	let log_rows = builder.log_rows([xin, yin])?;
+	let cout = builder.add_committed("cout", log_rows, B1::TOWER_LEVEL);
+	let cin = builder.add_shifted("cin", cout, 1, LOG_U64_BITS, ShiftVariant::LogicalLeft)?;
+	let zout = builder.add_committed("zout", log_rows, B1::TOWER_LEVEL);
+
+	builder.fill_witness([zout, cout, cin], move |witness, _| {
+		(
+			witness.get::<B1>(xin)?.as_slice::<u64>(),
+			witness.get::<B1>(yin)?.as_slice::<u64>(),
+			witness.new_column::<B1>(zout).as_mut_slice::<u64>(),
+			witness.new_column::<B1>(cout).as_mut_slice::<u64>(),
+			witness.new_column::<B1>(cin).as_mut_slice::<u64>(),
+		)
+			.into_par_iter()
+			.for_each(|(xin, yin, zout, cout, cin)| {
+				let carry;
+				(*zout, carry) = (*xin).overflowing_add(*yin);
+				*cin = (*xin) ^ (*yin) ^ (*zout);
+				*cout = ((carry as u64) << 63) | (*cin >> 1);
+			});
+		Ok(())
+	})?;
+
+	builder.assert_zero(
+		"sum",
+		[xin, yin, cin, zout],
+		arith_expr!([xin, yin, cin, zout] = xin + yin + cin - zout).convert_field(),
+	);
+
+	builder.assert_zero(
+		"carry",
+		[xin, yin, cin, cout],
+		arith_expr!([xin, yin, cin, cout] = (xin + cin) * (yin + cin) + cin - cout).convert_field(),
+	);
+
+	Ok((zout, cout))
+}
+
+pub fn sub(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	zin: OracleId,
+	yin: OracleId,
+	flags: super::Flags,
+) -> Result<OracleId, anyhow::Error> {
+	builder.push_namespace(name);
+	let (xout, cout) = sub_with_cout(builder, zin, yin)?;
+
+	// Underflow checking
+	if matches!(flags, super::Flags::Checked) {
+		let last_cout = select_bit(builder, "last_cout", cout, 63)?;
+		builder.assert_zero(
+			"underflow",
+			[last_cout],
+			arith_expr!([last_cout] = last_cout).convert_field(),
+		);
+	}
+
+	builder.pop_namespace();
+	Ok(xout)
+}
+
+/// Subtracts `yin` from `zin` modulo $2^{64}$, returning the difference and the borrow-out bit of
+/// every subtraction.
+///
+/// The borrow-out column has one row per integer.
+pub fn sub_with_borrow(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	zin: OracleId,
+	yin: OracleId,
+) -> Result<(OracleId, OracleId), anyhow::Error> {
+	builder.push_namespace(name);
+	let (xout, cout) = sub_with_cout(builder, zin, yin)?;
+	let borrow = select_bit(builder, "borrow", cout, 63)?;
+	builder.pop_namespace();
+	Ok((xout, borrow))
+}
+
+fn sub_with_cout(
+	builder: &mut ConstraintSystemBuilder,
+	zin: OracleId,
+	yin: OracleId,
+) -> Result<(OracleId, OracleId), anyhow::Error> {
+	let log_rows = builder.log_rows([zin, yin])?;
+	let cout = builder.add_committed("cout", log_rows, B1::TOWER_LEVEL);
+	let cin = builder.add_shifted("cin", cout, 1, LOG_U64_BITS, ShiftVariant::LogicalLeft)?;
+	let xout = builder.add_committed("diff", log_rows, B1::TOWER_LEVEL);
+
+	builder.fill_witness([xout, cout, cin], move |witness, _| {
+		(
+			witness.get::<B1>(zin)?.as_slice::<u64>(),
+			witness.get::<B1>(yin)?.as_slice::<u64>(),
+			witness.new_column::<B1>(xout).as_mut_slice::<u64>(),
+			witness.new_column::<B1>(cout).as_mut_slice::<u64>(),
+			witness.new_column::<B1>(cin).as_mut_slice::<u64>(),
+		)
+			.into_par_iter()
+			.for_each(|(zin, yin, diff, cout, cin)| {
+				let carry;
+				(*diff, carry) = (*zin).overflowing_sub(*yin);
+				*cin = (*diff) ^ (*yin) ^ (*zin);
+				*cout = ((carry as u64) << 63) | (*cin >> 1);
+			});
+		Ok(())
+	})?;
+
+	builder.assert_zero(
+		"sum",
+		[xout, yin, cin, zin],
+		arith_expr!([xout, yin, cin, zin] = xout + yin + cin - zin).convert_field(),
+	);
+
+	builder.assert_zero(
+		"carry",
+		[xout, yin, cin, cout],
+		arith_expr!([xout, yin, cin, cout] = (xout + cin) * (yin + cin) + cin - cout)
+			.convert_field(),
+	);
+
+	Ok((xout, cout))
+}
+
+/// Unsigned comparison, returning a bit column that is one where `xin < yin`.
+pub fn lt(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+) -> Result<OracleId, anyhow::Error> {
+	let (_, borrow) = sub_with_borrow(builder, name, xin, yin)?;
+	Ok(borrow)
+}
+
+/// Unsigned comparison, returning a bit column that is one where `xin <= yin`.
+pub fn le(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+) -> Result<OracleId, anyhow::Error> {
+	builder.push_namespace(name);
+	let gt = lt(builder, "gt", yin, xin)?;
+	let log_rows = builder.log_rows([gt])?;
+	let le = builder.add_linear_combination_with_offset(
+		"le",
+		log_rows,
+		Field::ONE,
+		[(gt, Field::ONE)],
+	)?;
+	builder.fill_witness([le], move |witness, _| {
+		(
+			witness.new_column::<B1>(le).as_mut_slice::<u8>(),
+			witness.get::<B1>(gt)?.as_slice::<u8>(),
+		)
+			.into_par_iter()
+			.for_each(|(le, gt)| *le = !*gt);
+		Ok(())
+	})?;
+	builder.pop_namespace();
+	Ok(le)
+}
+
+/// Full multiplication, returning the low and high 64-bit halves of the 128-bit products.
+///
+/// The product is proven with the exponentiation-based [`super::mul::mul`] over the bits of the
+/// operands, whose result bits are then tied to the returned integer columns.
+pub fn mul(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+) -> Result<(OracleId, OracleId), anyhow::Error> {
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([xin, yin])?;
+
+	let xin_bits = (0..64)
+		.map(|i| select_bit(builder, format!("xin_bit{i}"), xin, i))
+		.collect::<Result<Vec<_>, _>>()?;
+	let yin_bits = (0..64)
+		.map(|i| select_bit(builder, format!("yin_bit{i}"), yin, i))
+		.collect::<Result<Vec<_>, _>>()?;
+	let product_bits = exp_mul::<BinaryField128b>(builder, "mul", xin_bits, yin_bits)?;
+
+	let lo = builder.add_committed("lo", log_rows, B1::TOWER_LEVEL);
+	let hi = builder.add_committed("hi", log_rows, B1::TOWER_LEVEL);
+
+	builder.fill_witness([lo, hi], move |witness, _| {
+		(
+			witness.get::<B1>(xin)?.as_slice::<u64>(),
+			witness.get::<B1>(yin)?.as_slice::<u64>(),
+			witness.new_column::<B1>(lo).as_mut_slice::<u64>(),
+			witness.new_column::<B1>(hi).as_mut_slice::<u64>(),
+		)
+			.into_par_iter()
+			.for_each(|(xin, yin, lo, hi)| {
+				let product = (*xin as u128) * (*yin as u128);
+				*lo = product as u64;
+				*hi = (product >> 64) as u64;
+			});
+		Ok(())
+	})?;
+
+	for (i, &product_bit) in product_bits.iter().enumerate() {
+		let (half, index) = if i < 64 { (lo, i) } else { (hi, i - 64) };
+		let bit = select_bit(builder, format!("product_bit{i}"), half, index)?;
+		builder.assert_zero(
+			format!("product_bit{i}"),
+			[bit, product_bit],
+			arith_expr!([x, y] = x - y).convert_field(),
+		);
+	}
+
+	builder.pop_namespace();
+	Ok((lo, hi))
+}
+
+pub fn shl(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	input: OracleId,
+	offset: usize,
+) -> Result<OracleId, anyhow::Error> {
+	shift(builder, name, input, offset, ShiftVariant::LogicalLeft, move |x| x << offset)
+}
+
+pub fn shr(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	input: OracleId,
+	offset: usize,
+) -> Result<OracleId, anyhow::Error> {
+	shift(builder, name, input, offset, ShiftVariant::LogicalRight, move |x| x >> offset)
+}
+
+pub fn rotl(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	input: OracleId,
+	offset: usize,
+) -> Result<OracleId, anyhow::Error> {
+	shift(builder, name, input, offset % 64, ShiftVariant::CircularLeft, move |x| {
+		x.rotate_left(offset as u32)
+	})
+}
+
+pub fn rotr(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	input: OracleId,
+	offset: usize,
+) -> Result<OracleId, anyhow::Error> {
+	rotl(builder, name, input, (64 - offset % 64) % 64)
+}
+
+fn shift(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	input: OracleId,
+	offset: usize,
+	variant: ShiftVariant,
+	op: impl Fn(u64) -> u64 + Sync + 'static,
+) -> Result<OracleId, anyhow::Error> {
+	if offset == 0 {
+		return Ok(input);
+	}
+
+	let shifted = builder.add_shifted(name, input, offset, LOG_U64_BITS, variant)?;
+	builder.fill_witness([shifted], move |witness, _| {
+		(
+			witness.new_column::<B1>(shifted).as_mut_slice::<u64>(),
+			witness.get::<B1>(input)?.as_slice::<u64>(),
+		)
+			.into_par_iter()
+			.for_each(|(shifted, input)| *shifted = op(*input));
+		Ok(())
+	})?;
+
+	Ok(shifted)
+}
+
+pub fn select_bit(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	input: OracleId,
+	index: usize,
+) -> Result<OracleId, anyhow::Error> {
+	let log_rows = builder.log_rows([input])?;
+	anyhow::ensure!(log_rows >= 6, "Polynomial must have n_vars >= 6. Got {log_rows}");
+	anyhow::ensure!(index < 64, "Only index values between 0 and 64 are allowed. Got {index}");
+
+	let query = binius_core::polynomial::test_utils::decompose_index_to_hypercube_point(
+		LOG_U64_BITS,
+		index,
+	);
+	let bits = builder.add_projected(name, input, query, ProjectionVariant::FirstVars)?;
+
+	builder.fill_witness([bits], move |witness, _| {
+		let mut bits = witness.new_column::<B1>(bits);
+		let bits = bits.packed();
+		let input = witness.get::<B1>(input)?.as_slice::<u64>();
+		input.iter().enumerate().for_each(|(i, &val)| {
+			let value = match (val >> index) & 1 {
+				0 => B1::ZERO,
+				_ => B1::ONE,
+			};
+			set_packed_slice(bits, i, value);
+		});
+		Ok(())
+	})?;
+
+	Ok(bits)
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_core::{
+		constraint_system, fiat_shamir::HasherChallenger, tower::CanonicalTowerFamily,
+	};
+	use binius_field::BinaryField1b;
+	use binius_hal::make_portable_backend;
+	use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+	use binius_math::DefaultEvaluationDomainFactory;
+
+	use crate::{
+		arithmetic,
+		builder::{test_utils::test_circuit, types::U, ConstraintSystemBuilder},
+		unconstrained::unconstrained,
+	};
+
+	#[test]
+	fn test_add_sub_with_carry() {
+		test_circuit(|builder| {
+			let log_size = 12;
+			let a = unconstrained::<BinaryField1b>(builder, "a", log_size)?;
+			let b = unconstrained::<BinaryField1b>(builder, "b", log_size)?;
+			let (sum, carry) = arithmetic::u64::add_with_carry(builder, "add", a, b)?;
+			let (diff, borrow) = arithmetic::u64::sub_with_borrow(builder, "sub", a, b)?;
+			let lt = arithmetic::u64::lt(builder, "lt", a, b)?;
+			let le = arithmetic::u64::le(builder, "le", a, b)?;
+
+			if let Some(witness) = builder.witness() {
+				let a = witness.get::<BinaryField1b>(a)?.as_slice::<u64>();
+				let b = witness.get::<BinaryField1b>(b)?.as_slice::<u64>();
+				let sum = witness.get::<BinaryField1b>(sum)?.as_slice::<u64>();
+				let diff = witness.get::<BinaryField1b>(diff)?.as_slice::<u64>();
+				let bit = |id, i: usize| -> Result<bool, anyhow::Error> {
+					let bytes = witness.get::<BinaryField1b>(id)?.as_slice::<u8>();
+					Ok(bytes[i / 8] >> (i % 8) & 1 == 1)
+				};
+				for i in 0..a.len() {
+					assert_eq!((sum[i], bit(carry, i)?), a[i].overflowing_add(b[i]));
+					assert_eq!((diff[i], bit(borrow, i)?), a[i].overflowing_sub(b[i]));
+					assert_eq!(bit(lt, i)?, a[i] < b[i]);
+					assert_eq!(bit(le, i)?, a[i] <= b[i]);
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_shifts_and_rotations() {
+		test_circuit(|builder| {
+			let log_size = 10;
+			let a = unconstrained::<BinaryField1b>(builder, "a", log_size)?;
+			let shl = arithmetic::u64::shl(builder, "shl", a, 13)?;
+			let shr = arithmetic::u64::shr(builder, "shr", a, 7)?;
+			let rotl = arithmetic::u64::rotl(builder, "rotl", a, 44)?;
+			let rotr = arithmetic::u64::rotr(builder, "rotr", a, 3)?;
+
+			if let Some(witness) = builder.witness() {
+				let values = [shl, shr, rotl, rotr]
+					.map(|id| witness.get::<BinaryField1b>(id).unwrap().as_slice::<u64>());
+				for (i, &a) in witness
+					.get::<BinaryField1b>(a)?
+					.as_slice::<u64>()
+					.iter()
+					.enumerate()
+				{
+					assert_eq!(values[0][i], a << 13);
+					assert_eq!(values[1][i], a >> 7);
+					assert_eq!(values[2][i], a.rotate_left(44));
+					assert_eq!(values[3][i], a.rotate_right(3));
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_mul() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+
+		let log_size = 13;
+		let a = unconstrained::<BinaryField1b>(&mut builder, "a", log_size).unwrap();
+		let b = unconstrained::<BinaryField1b>(&mut builder, "b", log_size).unwrap();
+		let (lo, hi) = arithmetic::u64::mul(&mut builder, "mul", a, b).unwrap();
+
+		let witness = builder.witness().unwrap();
+		let [a, b, lo, hi] =
+			[a, b, lo, hi].map(|id| witness.get::<BinaryField1b>(id).unwrap().as_slice::<u64>());
+		for i in 0..a.len() {
+			let product = (a[i] as u128) * (b[i] as u128);
+			assert_eq!((lo[i], hi[i]), (product as u64, (product >> 64) as u64));
+		}
+
+		let witness = builder
+			.take_witness()
+			.expect("builder created with witness");
+
+		let constraint_system = builder.build().unwrap();
+
+		let domain_factory = DefaultEvaluationDomainFactory::default();
+		let backend = make_portable_backend();
+
+		let proof = constraint_system::prove::<
+			U,
+			CanonicalTowerFamily,
+			_,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+			_,
+		>(&constraint_system, 1, 10, &[], witness, &domain_factory, &backend)
+		.unwrap();
+
+		constraint_system::verify::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+		>(&constraint_system, 1, 10, &[], proof)
+		.unwrap();
+	}
+}
@@ -16,7 +16,7 @@ pub fn and(
 	builder.push_namespace(name);
 	let log_rows = builder.log_rows([xin, yin])?;
 	let zout = builder.add_committed("zout", log_rows, BinaryField1b::TOWER_LEVEL);
-	if let Some(witness) = builder.witness() {
+	builder.fill_witness([zout], move |witness, _| {
 		(
 			witness.get::<BinaryField1b>(xin)?.as_slice::<u32>(),
 			witness.get::<BinaryField1b>(yin)?.as_slice::<u32>(),
@@ -28,7 +28,8 @@ pub fn and(
 			.for_each(|(xin, yin, zout)| {
 				*zout = (*xin) & (*yin);
 			});
-	}
+		Ok(())
+	})?;
 	builder.assert_zero(
 		"bitwise_and",
 		[xin, yin, zout],
@@ -48,7 +49,7 @@ pub fn xor(
 	let log_rows = builder.log_rows([xin, yin])?;
 	let zout =
 		builder.add_linear_combination("zout", log_rows, [(xin, Field::ONE), (yin, Field::ONE)])?;
-	if let Some(witness) = builder.witness() {
+	builder.fill_witness([zout], move |witness, _| {
 		(
 			witness.get::<BinaryField1b>(xin)?.as_slice::<u32>(),
 			witness.get::<BinaryField1b>(yin)?.as_slice::<u32>(),
@@ -60,7 +61,8 @@ pub fn xor(
 			.for_each(|(xin, yin, zout)| {
 				*zout = (*xin) ^ (*yin);
 			});
-	}
+		Ok(())
+	})?;
 	builder.pop_namespace();
 	Ok(zout)
 }
@@ -74,7 +76,7 @@ pub fn or(
 	builder.push_namespace(name);
 	let log_rows = builder.log_rows([xin, yin])?;
 	let zout = builder.add_committed("zout", log_rows, BinaryField1b::TOWER_LEVEL);
-	if let Some(witness) = builder.witness() {
+	builder.fill_witness([zout], move |witness, _| {
 		(
 			witness.get::<BinaryField1b>(xin)?.as_slice::<u32>(),
 			witness.get::<BinaryField1b>(yin)?.as_slice::<u32>(),
@@ -86,7 +88,8 @@ pub fn or(
 			.for_each(|(xin, yin, zout)| {
 				*zout = (*xin) | (*yin);
 			});
-	}
+		Ok(())
+	})?;
 	builder.assert_zero(
 		"bitwise_or",
 		[xin, yin, zout],
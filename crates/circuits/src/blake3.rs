@@ -47,7 +47,7 @@ fn xor_rotate_right(
 		ShiftVariant::CircularLeft,
 	)?;
 
-	if let Some(witness) = builder.witness() {
+	builder.fill_witness([xor, rotate], move |witness, _| {
 		let a_value = witness.get::<F1>(a)?.as_slice::<u32>();
 		let b_value = witness.get::<F1>(b)?.as_slice::<u32>();
 
@@ -63,7 +63,8 @@ fn xor_rotate_right(
 		for (idx, v) in rotate_value.iter_mut().enumerate() {
 			*v = xor_value[idx].rotate_right(rotate_right_offset);
 		}
-	}
+		Ok(())
+	})?;
 
 	builder.pop_namespace();
 
@@ -24,6 +24,7 @@ use binius_utils::bail;
 use crate::builder::{
 	types::{F, U},
 	witness,
+	witness_program::{WitnessFill, WitnessInputs, WitnessProgram},
 };
 
 #[derive(Default)]
@@ -34,7 +35,10 @@ pub struct ConstraintSystemBuilder<'arena> {
 	flushes: Vec<Flush>,
 	exponents: Vec<Exp<F>>,
 	step_down_dedup: HashMap<(usize, usize), OracleId>,
+	shared_oracles: HashMap<String, OracleId>,
 	witness: Option<witness::Builder<'arena>>,
+	witness_inputs: WitnessInputs,
+	witness_fills: Vec<(Vec<OracleId>, WitnessFill)>,
 	next_channel_id: ChannelId,
 	namespace_path: Vec<String>,
 }
@@ -75,18 +79,53 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 		})
 	}
 
+	/// Builds the constraint system together with the witness program of the fills registered
+	/// with [`Self::fill_witness`].
+	pub fn build_with_witness_program(
+		mut self,
+	) -> Result<(ConstraintSystem<F>, WitnessProgram), anyhow::Error> {
+		let oracles = self.oracles.borrow().clone();
+		let witness_fills = std::mem::take(&mut self.witness_fills);
+		let prover_filled = self.exponents.iter().map(|exp| exp.exp_result_id).collect();
+		let constraint_system = self.build()?;
+		Ok((constraint_system, WitnessProgram::new(oracles, witness_fills, prover_filled)))
+	}
+
 	pub const fn witness(&mut self) -> Option<&mut witness::Builder<'arena>> {
 		self.witness.as_mut()
 	}
 
+	/// The inputs passed to the witness fills run by [`Self::fill_witness`].
+	pub fn witness_inputs(&mut self) -> &mut WitnessInputs {
+		&mut self.witness_inputs
+	}
+
+	/// Registers `fill` as the witness generation of `oracle_ids` in the witness program.
+	///
+	/// If the builder has a witness, `fill` is also run right away with the builder's witness
+	/// inputs, so that gadgets defining their witness this way work with both.
+	pub fn fill_witness(
+		&mut self,
+		oracle_ids: impl IntoIterator<Item = OracleId>,
+		fill: impl for<'a> Fn(&witness::Builder<'a>, &WitnessInputs) -> anyhow::Result<()> + 'static,
+	) -> anyhow::Result<()> {
+		if let Some(witness) = &self.witness {
+			fill(witness, &self.witness_inputs)?;
+		}
+		self.witness_fills
+			.push((oracle_ids.into_iter().collect(), Box::new(fill)));
+		Ok(())
+	}
+
 	pub fn take_witness(
 		&mut self,
 	) -> Result<MultilinearExtensionIndex<'arena, U, F>, anyhow::Error> {
+		let prover_filled = self.exponents.iter().map(|exp| exp.exp_result_id).collect();
 		Option::take(&mut self.witness)
 			.ok_or_else(|| {
 				anyhow!("Witness is missing. Are you in verifier mode, or have you already extraced the witness?")
 			})?
-			.build()
+			.build(&prover_filled)
 	}
 
 	pub fn flush(
@@ -124,9 +163,10 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 				step_down.clone(),
 			)?;
 
-			if let Some(witness) = self.witness() {
+			self.fill_witness([selector], move |witness, _| {
 				step_down.populate(witness.new_column::<BinaryField1b>(selector).packed());
-			}
+				Ok(())
+			})?;
 
 			self.step_down_dedup.insert((n_vars, count), selector);
 			selector
@@ -386,7 +426,7 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 			.zero_padded(id, n_vars)
 	}
 
-	fn scoped_name(&self, name: impl ToString) -> String {
+	pub(crate) fn scoped_name(&self, name: impl ToString) -> String {
 		let name = name.to_string();
 		if self.namespace_path.is_empty() {
 			name
@@ -428,6 +468,30 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 		self.namespace_path.pop();
 	}
 
+	/// Returns the oracle added under `key` by an earlier call, or adds it with `add`.
+	///
+	/// This lets gadgets share an oracle, such as a lookup table, across the whole circuit instead
+	/// of adding one copy per gadget instance. The oracle is added outside of any namespace. Only
+	/// the oracle itself is shared: lookups into it still add their own channels and columns.
+	pub fn shared_oracle(
+		&mut self,
+		key: impl ToString,
+		add: impl FnOnce(&mut Self) -> anyhow::Result<OracleId>,
+	) -> anyhow::Result<OracleId> {
+		let key = key.to_string();
+		if let Some(&id) = self.shared_oracles.get(&key) {
+			return Ok(id);
+		}
+
+		let namespace_path = std::mem::take(&mut self.namespace_path);
+		let id = add(self);
+		self.namespace_path = namespace_path;
+
+		let id = id?;
+		self.shared_oracles.insert(key, id);
+		Ok(id)
+	}
+
 	/// Returns the number of rows shared by a set of columns.
 	///
 	/// Fails if no columns are provided, or not all columns have the same number of rows.
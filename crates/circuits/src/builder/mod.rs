@@ -4,5 +4,6 @@ pub mod constraint_system;
 pub mod test_utils;
 pub mod types;
 pub mod witness;
+pub mod witness_program;
 
 pub use constraint_system::ConstraintSystemBuilder;
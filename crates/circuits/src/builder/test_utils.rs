@@ -2,14 +2,15 @@
 
 use binius_core::constraint_system::{channel::Boundary, validate::validate_witness};
 
-use super::{types::F, ConstraintSystemBuilder};
+use super::{types::F, witness_program::WitnessInputs, ConstraintSystemBuilder};
 
 pub fn test_circuit(
 	build_circuit: fn(&mut ConstraintSystemBuilder) -> Result<Vec<Boundary<F>>, anyhow::Error>,
 ) -> Result<(), anyhow::Error> {
 	let mut verifier_builder = ConstraintSystemBuilder::new();
 	let verifier_boundaries = build_circuit(&mut verifier_builder)?;
-	let verifier_constraint_system = verifier_builder.build()?;
+	let (verifier_constraint_system, witness_program) =
+		verifier_builder.build_with_witness_program()?;
 
 	let allocator = bumpalo::Bump::new();
 	let mut prover_builder = ConstraintSystemBuilder::new_with_witness(&allocator);
@@ -19,5 +20,8 @@ pub fn test_circuit(
 
 	assert_eq!(verifier_boundaries, prover_boundaries);
 	validate_witness(&verifier_constraint_system, &verifier_boundaries, &prover_witness)?;
+
+	let program_witness = witness_program.run(&allocator, &WitnessInputs::new())?;
+	validate_witness(&verifier_constraint_system, &verifier_boundaries, &program_witness)?;
 	Ok(())
 }
@@ -1,10 +1,10 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{cell::RefCell, marker::PhantomData, rc::Rc};
+use std::{cell::RefCell, collections::HashSet, marker::PhantomData, rc::Rc};
 
-use anyhow::{anyhow, Error};
+use anyhow::{anyhow, ensure, Error};
 use binius_core::{
-	oracle::{MultilinearOracleSet, OracleId},
+	oracle::{MultilinearOracleSet, MultilinearPolyVariant, OracleId},
 	witness::{MultilinearExtensionIndex, MultilinearWitness},
 };
 use binius_field::{
@@ -118,6 +118,11 @@ impl<'arena> Builder<'arena> {
 		})
 	}
 
+	/// Whether the witness of `id` has been set.
+	pub fn contains(&self, id: OracleId) -> bool {
+		matches!(self.entries.borrow().get(id), Some(Some(_)))
+	}
+
 	pub fn set<FS: TowerField>(
 		&self,
 		id: OracleId,
@@ -144,7 +149,31 @@ impl<'arena> Builder<'arena> {
 		Ok(())
 	}
 
-	pub fn build(self) -> Result<MultilinearExtensionIndex<'arena, U, F>, Error> {
+	/// Builds the witness index.
+	///
+	/// Fails if a committed oracle is left without a witness, naming every such oracle, except for
+	/// the oracles in `prover_filled`, whose witnesses the prover computes itself.
+	pub fn build(
+		self,
+		prover_filled: &HashSet<OracleId>,
+	) -> Result<MultilinearExtensionIndex<'arena, U, F>, Error> {
+		let unfilled = self
+			.oracles
+			.borrow()
+			.iter()
+			.filter(|oracle| {
+				matches!(oracle.variant, MultilinearPolyVariant::Committed)
+					&& !prover_filled.contains(&oracle.id)
+					&& !self.contains(oracle.id)
+			})
+			.map(|oracle| oracle.label())
+			.collect::<Vec<_>>();
+		ensure!(
+			unfilled.is_empty(),
+			"Witness is missing for the committed oracles {}",
+			unfilled.join(", ")
+		);
+
 		let mut result = MultilinearExtensionIndex::new();
 		let entries = Rc::into_inner(self.entries)
 			.ok_or_else(|| anyhow!("Failed to build. There are still entries refs. Make sure there are no pending column insertions."))?
@@ -0,0 +1,198 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{
+	any::Any,
+	cell::RefCell,
+	collections::{HashMap, HashSet},
+	rc::Rc,
+};
+
+use anyhow::{anyhow, ensure, Error};
+use binius_core::{
+	oracle::{MultilinearOracleSet, OracleId},
+	witness::MultilinearExtensionIndex,
+};
+
+use super::{
+	types::{F, U},
+	witness,
+};
+
+/// Fills the witness of some oracles from the witness of others and the inputs of the circuit.
+pub type WitnessFill =
+	Box<dyn for<'arena> Fn(&witness::Builder<'arena>, &WitnessInputs) -> Result<(), Error>>;
+
+/// Named inputs of a witness program, such as the private values of committed columns.
+#[derive(Default)]
+pub struct WitnessInputs {
+	inputs: HashMap<String, Box<dyn Any>>,
+}
+
+impl WitnessInputs {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	pub fn insert<T: Any>(&mut self, name: impl ToString, value: T) {
+		self.inputs.insert(name.to_string(), Box::new(value));
+	}
+
+	pub fn get<T: Any>(&self, name: &str) -> Result<&T, Error> {
+		self.inputs
+			.get(name)
+			.ok_or_else(|| anyhow!("Witness input {name} is missing"))?
+			.downcast_ref()
+			.ok_or_else(|| anyhow!("Witness input {name} has a different type"))
+	}
+}
+
+/// The witness generation of a circuit, separated from its constraint system.
+///
+/// A witness program is the sequence of fills registered with
+/// [`ConstraintSystemBuilder::fill_witness`](super::ConstraintSystemBuilder::fill_witness), each
+/// keyed by the oracles it fills, in the order of their registration. It is obtained once from
+/// [`ConstraintSystemBuilder::build_with_witness_program`](super::ConstraintSystemBuilder::build_with_witness_program)
+/// together with the constraint system, and can then be run for any number of inputs.
+pub struct WitnessProgram {
+	oracles: MultilinearOracleSet<F>,
+	fills: Vec<(Vec<OracleId>, WitnessFill)>,
+	/// Committed oracles whose witness the prover computes itself, such as exponentiation results.
+	prover_filled: HashSet<OracleId>,
+}
+
+impl WitnessProgram {
+	pub(super) fn new(
+		oracles: MultilinearOracleSet<F>,
+		fills: Vec<(Vec<OracleId>, WitnessFill)>,
+		prover_filled: HashSet<OracleId>,
+	) -> Self {
+		Self {
+			oracles,
+			fills,
+			prover_filled,
+		}
+	}
+
+	/// The oracles filled by the program, in the order they are filled.
+	pub fn oracle_ids(&self) -> impl Iterator<Item = OracleId> + '_ {
+		self.fills.iter().flat_map(|(ids, _)| ids.iter().copied())
+	}
+
+	/// Runs the fills in order and returns the witness of the circuit.
+	///
+	/// Fails if a fill fails, or if a committed oracle is left without a witness, naming every
+	/// such oracle.
+	pub fn run<'arena>(
+		&self,
+		allocator: &'arena bumpalo::Bump,
+		inputs: &WitnessInputs,
+	) -> Result<MultilinearExtensionIndex<'arena, U, F>, Error> {
+		let witness = witness::Builder::new(allocator, Rc::new(RefCell::new(self.oracles.clone())));
+		for (ids, fill) in &self.fills {
+			fill(&witness, inputs)?;
+			for &id in ids {
+				ensure!(
+					witness.contains(id),
+					"Witness fill did not fill {}",
+					self.oracles.label(id)
+				);
+			}
+		}
+
+		witness.build(&self.prover_filled)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_core::constraint_system::validate::validate_witness;
+	use binius_field::{BinaryField1b, BinaryField32b, TowerField};
+
+	use super::*;
+	use crate::{arithmetic, builder::ConstraintSystemBuilder, u32fib, unconstrained::input};
+
+	#[test]
+	fn test_run_with_inputs() {
+		let mut builder = ConstraintSystemBuilder::new();
+		let x = input::<BinaryField1b>(&mut builder, "x", 10).unwrap();
+		let y = input::<BinaryField1b>(&mut builder, "y", 10).unwrap();
+		let _z =
+			arithmetic::u32::add(&mut builder, "z", x, y, arithmetic::Flags::Unchecked).unwrap();
+		let (constraint_system, program) = builder.build_with_witness_program().unwrap();
+
+		for seed in 0..2 {
+			let bits = |value: u32| {
+				(0..32)
+					.map(|i| BinaryField1b::from((value >> i) as u8 & 1))
+					.collect::<Vec<_>>()
+			};
+			let mut inputs = WitnessInputs::new();
+			inputs.insert("x", (0..32).flat_map(|i| bits(i * 7 + seed)).collect::<Vec<_>>());
+			inputs.insert("y", (0..32).flat_map(|i| bits(u32::MAX - i)).collect::<Vec<_>>());
+
+			let allocator = bumpalo::Bump::new();
+			let witness = program.run(&allocator, &inputs).unwrap();
+			validate_witness(&constraint_system, &[], &witness).unwrap();
+		}
+	}
+
+	#[test]
+	fn test_missing_input() {
+		let mut builder = ConstraintSystemBuilder::new();
+		input::<BinaryField32b>(&mut builder, "x", 4).unwrap();
+		let (_, program) = builder.build_with_witness_program().unwrap();
+
+		let allocator = bumpalo::Bump::new();
+		assert!(program.run(&allocator, &WitnessInputs::new()).is_err());
+
+		let mut inputs = WitnessInputs::new();
+		inputs.insert("x", vec![0u32; 16]);
+		assert!(program.run(&allocator, &inputs).is_err());
+	}
+
+	#[test]
+	fn test_namespaced_input() {
+		let mut builder = ConstraintSystemBuilder::new();
+		builder.push_namespace("circuit");
+		input::<BinaryField32b>(&mut builder, "x", 4).unwrap();
+		builder.pop_namespace();
+		let (_, program) = builder.build_with_witness_program().unwrap();
+
+		let allocator = bumpalo::Bump::new();
+		let mut inputs = WitnessInputs::new();
+		inputs.insert("x", vec![BinaryField32b::new(1); 16]);
+		assert!(program.run(&allocator, &inputs).is_err());
+
+		inputs.insert("circuit::x", vec![BinaryField32b::new(1); 16]);
+		program.run(&allocator, &inputs).unwrap();
+	}
+
+	#[test]
+	fn test_unfilled_committed_oracles() {
+		let mut builder = ConstraintSystemBuilder::new();
+		builder.add_committed("a", 4, BinaryField32b::TOWER_LEVEL);
+		builder.add_committed("b", 4, BinaryField32b::TOWER_LEVEL);
+		let (_, program) = builder.build_with_witness_program().unwrap();
+
+		let allocator = bumpalo::Bump::new();
+		let err = program
+			.run(&allocator, &WitnessInputs::new())
+			.err()
+			.unwrap()
+			.to_string();
+		assert!(err.contains("Committed: a"), "{err}");
+		assert!(err.contains("Committed: b"), "{err}");
+	}
+
+	#[test]
+	fn test_u32fib() {
+		let mut builder = ConstraintSystemBuilder::new();
+		u32fib::u32fib(&mut builder, "u32fib", 12).unwrap();
+		let (constraint_system, program) = builder.build_with_witness_program().unwrap();
+		assert!(program.oracle_ids().count() > 0);
+
+		let allocator = bumpalo::Bump::new();
+		let witness = program.run(&allocator, &WitnessInputs::new()).unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+}
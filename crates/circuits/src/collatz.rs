@@ -73,13 +73,15 @@ impl Collatz {
 	) -> Result<(), anyhow::Error> {
 		let log_1b_rows = 5 + binius_utils::checked_arithmetics::log2_ceil_usize(count);
 		let even = builder.add_committed("even", log_1b_rows, BinaryField1b::TOWER_LEVEL);
-		if let Some(witness) = builder.witness() {
-			debug_assert_eq!(count, self.evens.len());
+		let evens = self.evens.clone();
+		builder.fill_witness([even], move |witness, _| {
+			debug_assert_eq!(count, evens.len());
 			witness
 				.new_column::<BinaryField1b>(even)
 				.as_mut_slice::<u32>()[..count]
-				.copy_from_slice(&self.evens);
-		}
+				.copy_from_slice(&evens);
+			Ok(())
+		})?;
 
 		// Passing Checked flag here makes sure the number is actually even
 		let half = arithmetic::u32::half(builder, "half", even, arithmetic::Flags::Checked)?;
@@ -103,13 +105,15 @@ impl Collatz {
 		let log_1b_rows = 5 + log_32b_rows;
 
 		let odd = builder.add_committed("odd", log_1b_rows, BinaryField1b::TOWER_LEVEL);
-		if let Some(witness) = builder.witness() {
-			debug_assert_eq!(count, self.odds.len());
+		let odds = self.odds.clone();
+		builder.fill_witness([odd], move |witness, _| {
+			debug_assert_eq!(count, odds.len());
 			witness
 				.new_column::<BinaryField1b>(odd)
 				.as_mut_slice::<u32>()[..count]
-				.copy_from_slice(&self.odds);
-		}
+				.copy_from_slice(&odds);
+			Ok(())
+		})?;
 
 		// Ensure the number is odd
 		ensure_odd(builder, odd, count)?;
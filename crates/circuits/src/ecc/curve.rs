@@ -0,0 +1,450 @@
+// Copyright 2025 Irreducible Inc.
+
+use alloy_primitives::U256;
+use anyhow::{ensure, Result};
+use binius_core::{
+	oracle::{OracleId, ProjectionVariant, ShiftVariant},
+	polynomial::test_utils::decompose_index_to_hypercube_point,
+};
+use binius_field::{BinaryField1b, BinaryField8b, Field, TowerField};
+use binius_math::ArithExpr;
+
+use super::field::{
+	read_element, FieldElement, ModularArithmetic, ELEMENT_BYTES, LOG_ELEMENT_BITS,
+};
+use crate::{
+	builder::{types::F, ConstraintSystemBuilder},
+	transparent,
+};
+
+type B1 = BinaryField1b;
+type B8 = BinaryField8b;
+
+/// A point given by a fixed number of coordinates.
+pub trait Coordinates: Clone {
+	type Element: Clone;
+
+	fn coordinates(&self) -> Vec<Self::Element>;
+
+	fn from_coordinates(coordinates: &[Self::Element]) -> Self;
+}
+
+/// Group operations of an elliptic curve over byte-sliced field elements.
+///
+/// The formulas used by implementations are complete, so points never need special-casing of the
+/// identity or of doubling inside scalar multiplication.
+pub trait Curve {
+	type Point: Coordinates<Element = FieldElement>;
+
+	/// A point outside the circuit, whose coordinates are the canonical residues the circuit
+	/// assigns to the corresponding [`Self::Point`].
+	type NativePoint: Coordinates<Element = U256>;
+
+	/// The point with the given affine coordinates on every row.
+	fn constant(
+		builder: &mut ConstraintSystemBuilder,
+		arith: &mut ModularArithmetic,
+		name: impl ToString,
+		affine: (U256, U256),
+	) -> Result<Self::Point>;
+
+	/// The neutral element on every row.
+	fn identity(
+		builder: &mut ConstraintSystemBuilder,
+		arith: &mut ModularArithmetic,
+		name: impl ToString,
+	) -> Result<Self::Point>;
+
+	fn add(
+		builder: &mut ConstraintSystemBuilder,
+		arith: &mut ModularArithmetic,
+		name: impl ToString,
+		p: &Self::Point,
+		q: &Self::Point,
+	) -> Result<Self::Point>;
+
+	fn double(
+		builder: &mut ConstraintSystemBuilder,
+		arith: &mut ModularArithmetic,
+		name: impl ToString,
+		p: &Self::Point,
+	) -> Result<Self::Point>;
+
+	/// Selects `if_one` on rows where `bit` is set and `if_zero` elsewhere.
+	fn select(
+		builder: &mut ConstraintSystemBuilder,
+		arith: &mut ModularArithmetic,
+		name: impl ToString,
+		bit: OracleId,
+		if_one: &Self::Point,
+		if_zero: &Self::Point,
+	) -> Result<Self::Point>;
+
+	/// The counterpart of [`Self::constant`] outside the circuit.
+	fn native_constant(affine: (U256, U256)) -> Self::NativePoint;
+
+	/// The counterpart of [`Self::identity`] outside the circuit.
+	fn native_identity() -> Self::NativePoint;
+
+	/// The counterpart of [`Self::add`] outside the circuit.
+	fn native_add(p: &Self::NativePoint, q: &Self::NativePoint) -> Self::NativePoint;
+
+	/// The counterpart of [`Self::double`] outside the circuit.
+	fn native_double(p: &Self::NativePoint) -> Self::NativePoint;
+}
+
+/// Computes `scalar * point` with a fixed window of `window` bits.
+///
+/// The scalar is given by its bit columns, least significant first, as produced by
+/// [`ModularArithmetic::bits`]. The gadget precomputes the `2^window` multiples of `point`, then
+/// processes the scalar from the most significant window down, doubling `window` times and adding
+/// the multiple picked by the window's bits.
+pub fn scalar_mul<C: Curve>(
+	builder: &mut ConstraintSystemBuilder,
+	arith: &mut ModularArithmetic,
+	name: impl ToString,
+	point: &C::Point,
+	scalar_bits: &[OracleId],
+	window: usize,
+) -> Result<C::Point> {
+	ensure!(window > 0, "window must be non-empty");
+	builder.push_namespace(name);
+
+	let mut table = vec![C::identity(builder, arith, "multiple[0]")?, point.clone()];
+	for i in 2..1 << window {
+		let multiple = if i % 2 == 0 {
+			C::double(builder, arith, format!("multiple[{i}]"), &table[i / 2])?
+		} else {
+			C::add(builder, arith, format!("multiple[{i}]"), &table[i - 1], point)?
+		};
+		table.push(multiple);
+	}
+
+	let mut result: Option<C::Point> = None;
+	for (i, window_bits) in scalar_bits.chunks(window).enumerate().rev() {
+		builder.push_namespace(format!("window[{i}]"));
+		let multiple = select_multiple::<C>(builder, arith, &table, window_bits)?;
+		result = Some(match result {
+			None => multiple,
+			Some(mut acc) => {
+				for j in 0..window {
+					acc = C::double(builder, arith, format!("double[{j}]"), &acc)?;
+				}
+				C::add(builder, arith, "add", &acc, &multiple)?
+			}
+		});
+		builder.pop_namespace();
+	}
+
+	let result = match result {
+		Some(result) => result,
+		None => C::identity(builder, arith, "identity")?,
+	};
+	builder.pop_namespace();
+	Ok(result)
+}
+
+/// Picks `table[index]`, where `index` is given by little-endian bits, using a tree of selects.
+fn select_multiple<C: Curve>(
+	builder: &mut ConstraintSystemBuilder,
+	arith: &mut ModularArithmetic,
+	table: &[C::Point],
+	bits: &[OracleId],
+) -> Result<C::Point> {
+	let mut candidates = table[..1 << bits.len()].to_vec();
+	for (level, &bit) in bits.iter().enumerate() {
+		candidates = candidates
+			.chunks(2)
+			.enumerate()
+			.map(|(i, pair)| {
+				C::select(builder, arith, format!("select[{level}][{i}]"), bit, &pair[1], &pair[0])
+			})
+			.collect::<Result<_>>()?;
+	}
+	Ok(candidates.swap_remove(0))
+}
+
+/// Computes `u * p + v * q` with Shamir's trick, laying out one double-and-add step per row.
+///
+/// The scalars are given as field elements. Rather than instantiating all 256 steps, a single
+/// step is instantiated over `2^8` times as many rows using [`ModularArithmetic::bit_steps`]: row
+/// `i * 2^log_size + j` performs the step for bit `i` of the scalars in row `j`. Every step
+/// doubles the accumulator output by the step for the next higher bit, read through a shifted
+/// column, and adds one of `0`, `p`, `q` or `p + q`. The accumulators are committed, with
+/// witnesses computed by the native curve formulas, and the result is the output of the step for
+/// bit 0.
+///
+/// Both scalars must be below 2^256 as integers, and their bits are constrained to match them.
+#[allow(clippy::too_many_arguments)]
+pub fn double_scalar_mul<C: Curve>(
+	builder: &mut ConstraintSystemBuilder,
+	arith: &mut ModularArithmetic,
+	name: impl ToString,
+	p: &C::Point,
+	u: &FieldElement,
+	q: &C::Point,
+	v: &FieldElement,
+) -> Result<C::Point> {
+	builder.push_namespace(name);
+	let log_size = arith.log_size();
+	let step_log_size = log_size + LOG_ELEMENT_BITS;
+	let n_rows = 1 << log_size;
+	let n_bits = 1 << LOG_ELEMENT_BITS;
+
+	let p_plus_q = C::add(builder, arith, "p + q", p, q)?;
+
+	// The inputs of the native computation of the accumulators
+	let inputs = (p.coordinates(), q.coordinates(), *u, *v);
+
+	let u_bits = scalar_bits(builder, "u bits", u, log_size)?;
+	let v_bits = scalar_bits(builder, "v bits", v, log_size)?;
+
+	let steps = arith.bit_steps(builder)?;
+	let p = repeat_point::<C>(builder, "repeated p", p, log_size)?;
+	let q = repeat_point::<C>(builder, "repeated q", q, log_size)?;
+	let p_plus_q = repeat_point::<C>(builder, "repeated p + q", &p_plus_q, log_size)?;
+	let identity = C::identity(builder, steps, "identity")?;
+	let with_p = C::select(builder, steps, "v ? p + q : p", v_bits, &p_plus_q, &p)?;
+	let without_p = C::select(builder, steps, "v ? q : 0", v_bits, &q, &identity)?;
+	let addend = C::select(builder, steps, "addend", u_bits, &with_p, &without_p)?;
+
+	let accumulator_coordinates = (0..C::native_identity().coordinates().len())
+		.map(|k| {
+			builder.add_committed_multiple::<ELEMENT_BYTES>(
+				format!("accumulator[{k}]"),
+				step_log_size,
+				B8::TOWER_LEVEL,
+			)
+		})
+		.collect::<Vec<_>>();
+	// The accumulator before every step, computed outside the circuit
+	builder.fill_witness(accumulator_coordinates.as_flattened().to_vec(), {
+		let accumulator_coordinates = accumulator_coordinates.clone();
+		move |witness, _| {
+			let (p, q, u, v) = &inputs;
+			let read_point = |coordinates: &[FieldElement]| -> Result<Vec<C::NativePoint>> {
+				let coordinates = coordinates
+					.iter()
+					.map(|element| read_element(witness, element, log_size))
+					.collect::<Result<Vec<_>>>()?;
+				Ok((0..n_rows)
+					.map(|row| {
+						let row_coordinates = coordinates
+							.iter()
+							.map(|values| U256::from(values[row]))
+							.collect::<Vec<_>>();
+						C::NativePoint::from_coordinates(&row_coordinates)
+					})
+					.collect())
+			};
+			let (p, q) = (read_point(p)?, read_point(q)?);
+			let u = read_element(witness, u, log_size)?;
+			let v = read_element(witness, v, log_size)?;
+
+			let identity = C::native_identity();
+			let mut accumulators = vec![identity.clone(); n_bits << log_size];
+			for row in 0..n_rows {
+				let p_plus_q = C::native_add(&p[row], &q[row]);
+				let mut accumulator = identity.clone();
+				for i in (0..n_bits).rev() {
+					accumulators[i * n_rows + row] = accumulator.clone();
+					let doubled = C::native_double(&accumulator);
+					accumulator = match (u[row].bit(i), v[row].bit(i)) {
+						(false, false) => C::native_add(&doubled, &identity),
+						(true, false) => C::native_add(&doubled, &p[row]),
+						(false, true) => C::native_add(&doubled, &q[row]),
+						(true, true) => C::native_add(&doubled, &p_plus_q),
+					};
+				}
+			}
+
+			for (k, element) in accumulator_coordinates.iter().enumerate() {
+				let mut columns = element.map(|byte| witness.new_column::<B8>(byte));
+				for (row, accumulator) in accumulators.iter().enumerate() {
+					let value = accumulator.coordinates()[k];
+					for (i, column) in columns.iter_mut().enumerate() {
+						column.as_mut_slice::<u8>()[row] = value.byte(i);
+					}
+				}
+			}
+			Ok(())
+		}
+	})?;
+	let accumulator = C::Point::from_coordinates(&accumulator_coordinates);
+
+	let doubled = C::double(builder, steps, "2 * accumulator", &accumulator)?;
+	let output = C::add(builder, steps, "2 * accumulator + addend", &doubled, &addend)?;
+
+	// The first step, for the most significant bit, starts from the identity, and every other
+	// step from the output of the step for the next higher bit.
+	let first_step =
+		transparent::step_up(builder, "first step", step_log_size, (n_bits - 1) * n_rows)?;
+	let identity_coordinates = C::native_identity().coordinates();
+	for (k, (accumulator, output)) in accumulator_coordinates
+		.iter()
+		.zip(output.coordinates())
+		.enumerate()
+	{
+		for i in 0..ELEMENT_BYTES {
+			let next_output = builder.add_shifted(
+				format!("next output[{k}][{i}]"),
+				output[i],
+				n_rows,
+				step_log_size,
+				ShiftVariant::LogicalRight,
+			)?;
+			let output_byte = output[i];
+			builder.fill_witness([next_output], move |witness, _| {
+				let output = witness.get::<B8>(output_byte)?.as_slice::<u8>();
+				let mut next_output_column = witness.new_column::<B8>(next_output);
+				let next_output = next_output_column.as_mut_slice::<u8>();
+				let n_step_rows = n_rows << LOG_ELEMENT_BITS;
+				next_output[..n_step_rows - n_rows].copy_from_slice(&output[n_rows..n_step_rows]);
+				Ok(())
+			})?;
+
+			let identity_byte = F::from(B8::new(identity_coordinates[k].byte(i)));
+			builder.assert_zero(
+				format!("chain[{k}][{i}]"),
+				[accumulator[i], next_output, first_step],
+				ArithExpr::Var(0)
+					- ArithExpr::Var(1)
+					- ArithExpr::Var(2) * ArithExpr::Const(identity_byte),
+			);
+		}
+	}
+
+	let result = output
+		.coordinates()
+		.iter()
+		.enumerate()
+		.map(|(k, element)| {
+			element
+				.iter()
+				.enumerate()
+				.try_fold([0; ELEMENT_BYTES], |mut result, (i, &byte)| {
+					result[i] = builder.add_projected(
+						format!("result[{k}][{i}]"),
+						byte,
+						vec![F::ZERO; LOG_ELEMENT_BITS],
+						ProjectionVariant::LastVars,
+					)?;
+					let result_byte = result[i];
+					builder.fill_witness([result_byte], move |witness, _| {
+						let output = witness.get::<B8>(byte)?.as_slice::<u8>();
+						witness.new_column::<B8>(result_byte).as_mut_slice::<u8>()[..n_rows]
+							.copy_from_slice(&output[..n_rows]);
+						Ok(())
+					})?;
+					Ok::<_, anyhow::Error>(result)
+				})
+		})
+		.collect::<Result<Vec<_>>>()?;
+
+	builder.pop_namespace();
+	Ok(C::Point::from_coordinates(&result))
+}
+
+/// Commits the bits of `scalar` in the layout of [`double_scalar_mul`], with bit `i` of row `j`
+/// in row `i * 2^log_size + j`.
+fn scalar_bits(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	scalar: &FieldElement,
+	log_size: usize,
+) -> Result<OracleId> {
+	builder.push_namespace(name);
+	let n_rows = 1 << log_size;
+	let bits = builder.add_committed("bits", log_size + LOG_ELEMENT_BITS, B1::TOWER_LEVEL);
+
+	let scalar_bytes = *scalar;
+	builder.fill_witness([bits], move |witness, _| {
+		let values = read_element(witness, &scalar_bytes, log_size)?;
+		let mut column = witness.new_column::<B1>(bits);
+		let packed = column.as_mut_slice::<u8>();
+		for (row, value) in values.iter().enumerate() {
+			for i in 0..1 << LOG_ELEMENT_BITS {
+				let index = i * n_rows + row;
+				packed[index / 8] |= (value.bit(i) as u8) << (index % 8);
+			}
+		}
+		Ok(())
+	})?;
+
+	// Bit i of every row is the projection onto step i, and every byte is the sum of its bits.
+	for (byte_index, &byte) in scalar.iter().enumerate() {
+		let mut byte_bits = Vec::with_capacity(8);
+		for j in 0..8 {
+			let i = 8 * byte_index + j;
+			let bit = builder.add_projected(
+				format!("bit[{i}]"),
+				bits,
+				decompose_index_to_hypercube_point(LOG_ELEMENT_BITS, i),
+				ProjectionVariant::LastVars,
+			)?;
+			builder.fill_witness([bit], move |witness, _| {
+				let bits = witness.get::<B1>(bits)?.as_slice::<u8>();
+				let mut column = witness.new_column::<B1>(bit);
+				let packed = column.as_mut_slice::<u8>();
+				for row in 0..n_rows {
+					let index = i * n_rows + row;
+					packed[row / 8] |= ((bits[index / 8] >> (index % 8)) & 1) << (row % 8);
+				}
+				Ok(())
+			})?;
+			byte_bits.push(bit);
+		}
+
+		let expr = (0..8).fold(ArithExpr::Var(0), |expr, j| {
+			expr - ArithExpr::Var(j + 1) * ArithExpr::Const(F::from(B8::new(1 << j)))
+		});
+		builder.assert_zero(
+			format!("decomposition[{byte_index}]"),
+			std::iter::once(byte).chain(byte_bits),
+			expr,
+		);
+	}
+
+	builder.pop_namespace();
+	Ok(bits)
+}
+
+/// Repeats a point once per bit of a field element, in the layout of [`double_scalar_mul`].
+fn repeat_point<C: Curve>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	point: &C::Point,
+	log_size: usize,
+) -> Result<C::Point> {
+	builder.push_namespace(name);
+	let n_rows = 1 << log_size;
+	let coordinates = point
+		.coordinates()
+		.iter()
+		.enumerate()
+		.map(|(k, element)| {
+			element
+				.iter()
+				.enumerate()
+				.try_fold([0; ELEMENT_BYTES], |mut repeated, (i, &byte)| {
+					repeated[i] = builder.add_repeating(
+						format!("repeated[{k}][{i}]"),
+						byte,
+						LOG_ELEMENT_BITS,
+					)?;
+					let repeated_byte = repeated[i];
+					builder.fill_witness([repeated_byte], move |witness, _| {
+						let values = &witness.get::<B8>(byte)?.as_slice::<u8>()[..n_rows];
+						let mut column = witness.new_column::<B8>(repeated_byte);
+						for chunk in column.as_mut_slice::<u8>().chunks_exact_mut(n_rows) {
+							chunk.copy_from_slice(values);
+						}
+						Ok(())
+					})?;
+					Ok::<_, anyhow::Error>(repeated)
+				})
+		})
+		.collect::<Result<Vec<_>>>()?;
+	builder.pop_namespace();
+	Ok(C::Point::from_coordinates(&coordinates))
+}
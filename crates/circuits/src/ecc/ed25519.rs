@@ -0,0 +1,562 @@
+// Copyright 2025 Irreducible Inc.
+
+use alloy_primitives::{uint, U256};
+use anyhow::Result;
+use binius_core::oracle::OracleId;
+
+use super::{
+	curve::{double_scalar_mul, Coordinates, Curve},
+	field::{FieldArithmetic, FieldElement, FieldOps, ModularArithmetic, NativeField, PrimeField},
+	AffinePoint,
+};
+use crate::builder::ConstraintSystemBuilder;
+
+/// The base field of ed25519, with modulus `2^255 - 19`.
+pub const ED25519_P: PrimeField =
+	PrimeField::new(uint!(0x7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFED_U256));
+
+/// The scalar field of ed25519, i.e. the order `L` of its base point.
+pub const ED25519_L: PrimeField =
+	PrimeField::new(uint!(0x1000000000000000000000000000000014DEF9DEA2F79CD65812631A5CF5D3ED_U256));
+
+/// The affine coordinates of the ed25519 base point.
+pub const ED25519_BASE_POINT: (U256, U256) = (
+	uint!(0x216936D3CD6E53FEC0A4E231FDD6DC5C692CC7609525A7B2C9562D608F25D51A_U256),
+	uint!(0x6666666666666666666666666666666666666666666666666666666666666658_U256),
+);
+
+/// The coefficient `d` of the curve equation `-x^2 + y^2 = 1 + d x^2 y^2`.
+pub const ED25519_D: U256 =
+	uint!(0x52036CEE2B6FFE738CC740797779E89800700A4D4141D8AB75EB4DCA135978A3_U256);
+
+/// `2 * d mod p`.
+const D2: U256 = uint!(0x2406D9DC56DFFCE7198E80F2EEF3D13000E0149A8283B156EBD69B9426B2F159_U256);
+
+/// An ed25519 point in extended twisted Edwards coordinates `(X : Y : Z : T)`, with `x = X / Z`,
+/// `y = Y / Z` and `x * y = T / Z`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExtendedPoint<E = FieldElement> {
+	pub x: E,
+	pub y: E,
+	pub z: E,
+	pub t: E,
+}
+
+impl<E: Clone> Coordinates for ExtendedPoint<E> {
+	type Element = E;
+
+	fn coordinates(&self) -> Vec<E> {
+		vec![
+			self.x.clone(),
+			self.y.clone(),
+			self.z.clone(),
+			self.t.clone(),
+		]
+	}
+
+	fn from_coordinates(coordinates: &[E]) -> Self {
+		let [x, y, z, t] = coordinates else {
+			panic!("an extended point has four coordinates");
+		};
+		Self {
+			x: x.clone(),
+			y: y.clone(),
+			z: z.clone(),
+			t: t.clone(),
+		}
+	}
+}
+
+/// The ed25519 curve, using the formulas of Hisil, Wong, Carter and Dawson for `a = -1`
+/// (<https://eprint.iacr.org/2008/522>), which are complete since `d` is not a square.
+pub struct Ed25519;
+
+impl Curve for Ed25519 {
+	type Point = ExtendedPoint;
+	type NativePoint = ExtendedPoint<U256>;
+
+	fn constant(
+		builder: &mut ConstraintSystemBuilder,
+		arith: &mut ModularArithmetic,
+		name: impl ToString,
+		affine: (U256, U256),
+	) -> Result<ExtendedPoint> {
+		builder.push_namespace(name);
+		let (x, y) = affine;
+		let point = ExtendedPoint {
+			x: arith.constant(builder, "x", x)?,
+			y: arith.constant(builder, "y", y)?,
+			z: arith.constant(builder, "z", U256::from(1))?,
+			t: arith.constant(builder, "t", x.mul_mod(y, ED25519_P.modulus))?,
+		};
+		builder.pop_namespace();
+		Ok(point)
+	}
+
+	fn identity(
+		builder: &mut ConstraintSystemBuilder,
+		arith: &mut ModularArithmetic,
+		name: impl ToString,
+	) -> Result<ExtendedPoint> {
+		Self::constant(builder, arith, name, (U256::ZERO, U256::from(1)))
+	}
+
+	fn add(
+		builder: &mut ConstraintSystemBuilder,
+		arith: &mut ModularArithmetic,
+		name: impl ToString,
+		p: &ExtendedPoint,
+		q: &ExtendedPoint,
+	) -> Result<ExtendedPoint> {
+		builder.push_namespace(name);
+		let mut f = FieldOps {
+			builder,
+			arith,
+			field: ED25519_P,
+		};
+		let sum = add(&mut f, p, q)?;
+		builder.pop_namespace();
+		Ok(sum)
+	}
+
+	fn double(
+		builder: &mut ConstraintSystemBuilder,
+		arith: &mut ModularArithmetic,
+		name: impl ToString,
+		p: &ExtendedPoint,
+	) -> Result<ExtendedPoint> {
+		builder.push_namespace(name);
+		let mut f = FieldOps {
+			builder,
+			arith,
+			field: ED25519_P,
+		};
+		let double = double(&mut f, p)?;
+		builder.pop_namespace();
+		Ok(double)
+	}
+
+	fn select(
+		builder: &mut ConstraintSystemBuilder,
+		arith: &mut ModularArithmetic,
+		name: impl ToString,
+		bit: OracleId,
+		if_one: &ExtendedPoint,
+		if_zero: &ExtendedPoint,
+	) -> Result<ExtendedPoint> {
+		builder.push_namespace(name);
+		let point = ExtendedPoint {
+			x: arith.select(builder, "x", bit, &if_one.x, &if_zero.x)?,
+			y: arith.select(builder, "y", bit, &if_one.y, &if_zero.y)?,
+			z: arith.select(builder, "z", bit, &if_one.z, &if_zero.z)?,
+			t: arith.select(builder, "t", bit, &if_one.t, &if_zero.t)?,
+		};
+		builder.pop_namespace();
+		Ok(point)
+	}
+
+	fn native_constant(affine: (U256, U256)) -> ExtendedPoint<U256> {
+		let (x, y) = affine;
+		ExtendedPoint {
+			x,
+			y,
+			z: U256::from(1),
+			t: x.mul_mod(y, ED25519_P.modulus),
+		}
+	}
+
+	fn native_identity() -> ExtendedPoint<U256> {
+		Self::native_constant((U256::ZERO, U256::from(1)))
+	}
+
+	fn native_add(p: &ExtendedPoint<U256>, q: &ExtendedPoint<U256>) -> ExtendedPoint<U256> {
+		add(&mut NativeField(ED25519_P), p, q).expect("native arithmetic is infallible")
+	}
+
+	fn native_double(p: &ExtendedPoint<U256>) -> ExtendedPoint<U256> {
+		double(&mut NativeField(ED25519_P), p).expect("native arithmetic is infallible")
+	}
+}
+
+/// The unified addition of Hisil, Wong, Carter and Dawson, section 3.1, for `a = -1`.
+fn add<A: FieldArithmetic>(
+	f: &mut A,
+	p: &ExtendedPoint<A::Element>,
+	q: &ExtendedPoint<A::Element>,
+) -> Result<ExtendedPoint<A::Element>> {
+	let d2 = f.constant("2d", D2)?;
+
+	let y1_minus_x1 = f.sub("y1 - x1", &p.y, &p.x)?;
+	let y2_minus_x2 = f.sub("y2 - x2", &q.y, &q.x)?;
+	let a = f.mul("a", &y1_minus_x1, &y2_minus_x2)?;
+	let y1_plus_x1 = f.add("y1 + x1", &p.y, &p.x)?;
+	let y2_plus_x2 = f.add("y2 + x2", &q.y, &q.x)?;
+	let b = f.mul("b", &y1_plus_x1, &y2_plus_x2)?;
+	let t1_t2 = f.mul("t1 * t2", &p.t, &q.t)?;
+	let c = f.mul("c", &t1_t2, &d2)?;
+	let z1_z2 = f.mul("z1 * z2", &p.z, &q.z)?;
+	let d = f.add("d", &z1_z2, &z1_z2)?;
+	let e = f.sub("e", &b, &a)?;
+	let f_ = f.sub("f", &d, &c)?;
+	let g = f.add("g", &d, &c)?;
+	let h = f.add("h", &b, &a)?;
+
+	Ok(ExtendedPoint {
+		x: f.mul("x3", &e, &f_)?,
+		y: f.mul("y3", &g, &h)?,
+		t: f.mul("t3", &e, &h)?,
+		z: f.mul("z3", &f_, &g)?,
+	})
+}
+
+/// The doubling of Hisil, Wong, Carter and Dawson, section 3.3, for `a = -1`.
+fn double<A: FieldArithmetic>(
+	f: &mut A,
+	p: &ExtendedPoint<A::Element>,
+) -> Result<ExtendedPoint<A::Element>> {
+	let zero = f.constant("zero", U256::ZERO)?;
+
+	let a = f.mul("x^2", &p.x, &p.x)?;
+	let b = f.mul("y^2", &p.y, &p.y)?;
+	let z2 = f.mul("z^2", &p.z, &p.z)?;
+	let c = f.add("c", &z2, &z2)?;
+	let a_plus_b = f.add("a + b", &a, &b)?;
+	let x_plus_y = f.add("x + y", &p.x, &p.y)?;
+	let x_plus_y_2 = f.mul("(x + y)^2", &x_plus_y, &x_plus_y)?;
+	let e = f.sub("e", &x_plus_y_2, &a_plus_b)?;
+	// With a = -1: g = b - a and h = -a - b
+	let g = f.sub("g", &b, &a)?;
+	let f_ = f.sub("f", &g, &c)?;
+	let h = f.sub("h", &zero, &a_plus_b)?;
+
+	Ok(ExtendedPoint {
+		x: f.mul("x3", &e, &f_)?,
+		y: f.mul("y3", &g, &h)?,
+		t: f.mul("t3", &e, &h)?,
+		z: f.mul("z3", &f_, &g)?,
+	})
+}
+
+/// Lifts an affine point after checking that it lies on the curve.
+pub fn from_affine(
+	builder: &mut ConstraintSystemBuilder,
+	arith: &mut ModularArithmetic,
+	name: impl ToString,
+	point: &AffinePoint,
+) -> Result<ExtendedPoint> {
+	builder.push_namespace(name);
+	let mut f = FieldOps {
+		builder,
+		arith,
+		field: ED25519_P,
+	};
+	let x2 = f.mul("x^2", &point.x, &point.x)?;
+	let y2 = f.mul("y^2", &point.y, &point.y)?;
+	let lhs = f.sub("y^2 - x^2", &y2, &x2)?;
+	let x2_y2 = f.mul("x^2 y^2", &x2, &y2)?;
+	let d = f.constant("d", ED25519_D)?;
+	let d_x2_y2 = f.mul("d x^2 y^2", &d, &x2_y2)?;
+	let one = f.constant("one", U256::from(1))?;
+	let rhs = f.add("1 + d x^2 y^2", &one, &d_x2_y2)?;
+	f.assert_eq("on curve", &lhs, &rhs)?;
+	let t = f.mul("t", &point.x, &point.y)?;
+
+	builder.pop_namespace();
+	Ok(ExtendedPoint {
+		x: point.x,
+		y: point.y,
+		z: one,
+		t,
+	})
+}
+
+/// Computes `-p = (-X : Y : Z : -T)`.
+pub fn negate(
+	builder: &mut ConstraintSystemBuilder,
+	arith: &mut ModularArithmetic,
+	name: impl ToString,
+	p: &ExtendedPoint,
+) -> Result<ExtendedPoint> {
+	builder.push_namespace(name);
+	let mut f = FieldOps {
+		builder,
+		arith,
+		field: ED25519_P,
+	};
+	let zero = f.constant("zero", U256::ZERO)?;
+	let point = ExtendedPoint {
+		x: f.sub("-x", &zero, &p.x)?,
+		y: p.y,
+		z: p.z,
+		t: f.sub("-t", &zero, &p.t)?,
+	};
+	builder.pop_namespace();
+	Ok(point)
+}
+
+/// Constrains two points to be equal, i.e. to have proportional coordinates.
+pub fn assert_eq(
+	builder: &mut ConstraintSystemBuilder,
+	arith: &mut ModularArithmetic,
+	name: impl ToString,
+	p: &ExtendedPoint,
+	q: &ExtendedPoint,
+) -> Result<()> {
+	builder.push_namespace(name);
+	let mut f = FieldOps {
+		builder,
+		arith,
+		field: ED25519_P,
+	};
+	// Z is never zero on the curve, so comparing X / Z and Y / Z suffices
+	let x1_z2 = f.mul("x1 * z2", &p.x, &q.z)?;
+	let x2_z1 = f.mul("x2 * z1", &q.x, &p.z)?;
+	f.assert_eq("x", &x1_z2, &x2_z1)?;
+	let y1_z2 = f.mul("y1 * z2", &p.y, &q.z)?;
+	let y2_z1 = f.mul("y2 * z1", &q.y, &p.z)?;
+	f.assert_eq("y", &y1_z2, &y2_z1)?;
+	builder.pop_namespace();
+	Ok(())
+}
+
+/// Verifies an ed25519 signature `(R, S)` on every row, using the cofactorless equation
+/// `[S]B = R + [k]A`, checked as `[S]B + [k](-A) = R` with a single [`double_scalar_mul`].
+///
+/// Point decompression and the SHA-512 challenge are left to the caller: the public key `A` and
+/// the commitment `R` are given by their affine coordinates, and `challenge` holds
+/// `k = SHA-512(R || A || M) mod L`. The circuit checks that both points lie on the curve and
+/// that `S < L`, as required by RFC 8032.
+pub fn eddsa_verify(
+	builder: &mut ConstraintSystemBuilder,
+	arith: &mut ModularArithmetic,
+	name: impl ToString,
+	public_key: &AffinePoint,
+	r: &AffinePoint,
+	s: &FieldElement,
+	challenge: &FieldElement,
+) -> Result<()> {
+	builder.push_namespace(name);
+
+	arith.assert_canonical(builder, "s < l", &ED25519_L, s)?;
+	let public_key = from_affine(builder, arith, "A", public_key)?;
+	let r = from_affine(builder, arith, "R", r)?;
+
+	let base_point = Ed25519::constant(builder, arith, "B", ED25519_BASE_POINT)?;
+	let minus_public_key = negate(builder, arith, "-A", &public_key)?;
+	let lhs = double_scalar_mul::<Ed25519>(
+		builder,
+		arith,
+		"s * B - k * A",
+		&base_point,
+		s,
+		&minus_public_key,
+		challenge,
+	)?;
+	assert_eq(builder, arith, "s * B - k * A = R", &lhs, &r)?;
+
+	builder.pop_namespace();
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use alloy_primitives::{hex, U256, U512};
+	use binius_core::constraint_system::validate::validate_witness;
+	use binius_field::{BinaryField8b, TowerField};
+	use rand::{rngs::StdRng, Rng, SeedableRng};
+	use sha2::{Digest, Sha512};
+
+	use super::*;
+	use crate::ecc::field::read_element;
+
+	const P: U256 = ED25519_P.modulus;
+
+	/// Affine addition on the reference curve, which is complete for ed25519.
+	fn reference_add(p: (U256, U256), q: (U256, U256)) -> (U256, U256) {
+		let (x1, y1) = p;
+		let (x2, y2) = q;
+		let dxy = ED25519_D
+			.mul_mod(x1, P)
+			.mul_mod(x2, P)
+			.mul_mod(y1, P)
+			.mul_mod(y2, P);
+		let x = x1
+			.mul_mod(y2, P)
+			.add_mod(y1.mul_mod(x2, P), P)
+			.mul_mod(U256::from(1).add_mod(dxy, P).inv_mod(P).unwrap(), P);
+		let y = y1
+			.mul_mod(y2, P)
+			.add_mod(x1.mul_mod(x2, P), P)
+			.mul_mod(U256::from(1).add_mod(P - dxy, P).inv_mod(P).unwrap(), P);
+		(x, y)
+	}
+
+	fn reference_mul(k: U256, p: (U256, U256)) -> (U256, U256) {
+		let mut result = (U256::ZERO, U256::from(1));
+		for i in (0..256).rev() {
+			result = reference_add(result, result);
+			if k.bit(i) {
+				result = reference_add(result, p);
+			}
+		}
+		result
+	}
+
+	/// Decodes a compressed point as specified in RFC 8032, section 5.1.3.
+	fn decompress(bytes: [u8; 32]) -> (U256, U256) {
+		let mut y = U256::from_le_bytes(bytes);
+		let sign = y.bit(255);
+		y.set_bit(255, false);
+		let u = y.mul_mod(y, P).add_mod(P - U256::from(1), P);
+		let v = ED25519_D
+			.mul_mod(y, P)
+			.mul_mod(y, P)
+			.add_mod(U256::from(1), P);
+		let u_over_v = u.mul_mod(v.inv_mod(P).unwrap(), P);
+		let mut x = u_over_v.pow_mod((P + U256::from(3)) >> 3, P);
+		if x.mul_mod(x, P) != u_over_v {
+			x = x.mul_mod(U256::from(2).pow_mod((P - U256::from(1)) >> 2, P), P);
+		}
+		assert_eq!(x.mul_mod(x, P), u_over_v);
+		if x.bit(0) != sign {
+			x = P - x;
+		}
+		(x, y)
+	}
+
+	fn write_values(
+		builder: &mut ConstraintSystemBuilder,
+		name: &str,
+		log_size: usize,
+		values: &[U256],
+	) -> FieldElement {
+		let element: FieldElement =
+			builder.add_committed_multiple(name, log_size, BinaryField8b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			for (i, &byte) in element.iter().enumerate() {
+				let mut column = witness.new_column::<BinaryField8b>(byte);
+				let column = column.as_mut_slice::<u8>();
+				for (row, value) in values.iter().enumerate() {
+					column[row] = value.byte(i);
+				}
+			}
+		}
+		element
+	}
+
+	fn write_points(
+		builder: &mut ConstraintSystemBuilder,
+		name: &str,
+		log_size: usize,
+		points: &[(U256, U256)],
+	) -> AffinePoint {
+		let (x, y): (Vec<_>, Vec<_>) = points.iter().copied().unzip();
+		AffinePoint {
+			x: write_values(builder, &format!("{name}.x"), log_size, &x),
+			y: write_values(builder, &format!("{name}.y"), log_size, &y),
+		}
+	}
+
+	fn read_affine(
+		builder: &mut ConstraintSystemBuilder,
+		point: &ExtendedPoint,
+		log_size: usize,
+	) -> Vec<(U256, U256)> {
+		let witness = builder.witness().unwrap();
+		let x = read_element(witness, &point.x, log_size).unwrap();
+		let y = read_element(witness, &point.y, log_size).unwrap();
+		let z = read_element(witness, &point.z, log_size).unwrap();
+		let t = read_element(witness, &point.t, log_size).unwrap();
+		(0..1 << log_size)
+			.map(|row| {
+				let to_u256 = |value: U512| U256::from(value.reduce_mod(U512::from(P)));
+				let z_inv = to_u256(z[row]).inv_mod(P).unwrap();
+				let (x, y) = (to_u256(x[row]).mul_mod(z_inv, P), to_u256(y[row]).mul_mod(z_inv, P));
+				assert_eq!(to_u256(t[row]).mul_mod(z_inv, P), x.mul_mod(y, P));
+				(x, y)
+			})
+			.collect()
+	}
+
+	#[test]
+	fn test_add_and_double() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let mut rng = StdRng::seed_from_u64(0);
+		let log_size = 4;
+
+		let mut random_point = || reference_mul(U256::from(rng.gen::<u64>()), ED25519_BASE_POINT);
+		let ps: Vec<_> = (0..1 << log_size).map(|_| random_point()).collect();
+		// The formulas are complete, so include P + P, P + (-P) and P + O
+		let qs: Vec<_> = (0..1 << log_size)
+			.map(|i| match i {
+				0 => ps[0],
+				1 => (P - ps[1].0, ps[1].1),
+				2 => (U256::ZERO, U256::from(1)),
+				_ => random_point(),
+			})
+			.collect();
+
+		let p = write_points(&mut builder, "p", log_size, &ps);
+		let q = write_points(&mut builder, "q", log_size, &qs);
+		let mut arith = ModularArithmetic::new(&mut builder, log_size).unwrap();
+		let p = from_affine(&mut builder, &mut arith, "p", &p).unwrap();
+		let q = from_affine(&mut builder, &mut arith, "q", &q).unwrap();
+		let sum = Ed25519::add(&mut builder, &mut arith, "p + q", &p, &q).unwrap();
+		let double = Ed25519::double(&mut builder, &mut arith, "2p", &p).unwrap();
+		let double_via_add = Ed25519::add(&mut builder, &mut arith, "p + p", &p, &p).unwrap();
+		assert_eq(&mut builder, &mut arith, "2p = p + p", &double, &double_via_add).unwrap();
+		arith.execute(&mut builder).unwrap();
+
+		let sums = read_affine(&mut builder, &sum, log_size);
+		let doubles = read_affine(&mut builder, &double, log_size);
+		for row in 0..1 << log_size {
+			assert_eq!(sums[row], reference_add(ps[row], qs[row]));
+			assert_eq!(doubles[row], reference_add(ps[row], ps[row]));
+		}
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+
+	#[test]
+	fn test_eddsa_verify_rfc8032() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let log_size = 0;
+
+		// RFC 8032, section 7.1, TEST 1
+		let public_key = hex!("d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a");
+		let signature = hex!(
+			"e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e06522490155"
+			"5fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b"
+		);
+		let message: &[u8] = b"";
+
+		let (r_bytes, s_bytes) = signature.split_at(32);
+		let challenge = Sha512::new()
+			.chain_update(r_bytes)
+			.chain_update(public_key)
+			.chain_update(message)
+			.finalize();
+		let challenge =
+			U256::from(U512::from_le_slice(&challenge).reduce_mod(U512::from(ED25519_L.modulus)));
+
+		let rows = 1 << log_size;
+		let a = write_points(&mut builder, "A", log_size, &vec![decompress(public_key); rows]);
+		let r = decompress(r_bytes.try_into().unwrap());
+		let r = write_points(&mut builder, "R", log_size, &vec![r; rows]);
+		let s =
+			write_values(&mut builder, "S", log_size, &vec![U256::from_le_slice(s_bytes); rows]);
+		let k = write_values(&mut builder, "k", log_size, &vec![challenge; rows]);
+
+		let mut arith = ModularArithmetic::new(&mut builder, log_size).unwrap();
+		eddsa_verify(&mut builder, &mut arith, "eddsa", &a, &r, &s, &k).unwrap();
+		arith.execute(&mut builder).unwrap();
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+}
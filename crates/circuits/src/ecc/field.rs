@@ -0,0 +1,823 @@
+// Copyright 2025 Irreducible Inc.
+
+use alloy_primitives::{U256, U512};
+use anyhow::{anyhow, ensure, Result};
+use binius_core::oracle::OracleId;
+use binius_field::{
+	tower_levels::{TowerLevel, TowerLevel32, TowerLevel64},
+	BinaryField1b, BinaryField32b, BinaryField8b, Field, TowerField,
+};
+use binius_macros::arith_expr;
+use binius_math::ArithExpr;
+use binius_utils::checked_arithmetics::checked_log_2;
+
+use crate::{
+	builder::{types::F, witness, ConstraintSystemBuilder},
+	lasso::{
+		batch::LookupBatch,
+		big_integer_ops::{
+			byte_sliced_add, byte_sliced_equal, byte_sliced_less_than,
+			byte_sliced_modular_mul_with_batches, byte_sliced_modular_reduce, byte_sliced_sub,
+		},
+		lookups::u8_arithmetic::{add_carryfree_lookup, add_lookup, dci_lookup, mul_lookup},
+	},
+	transparent,
+};
+
+type B1 = BinaryField1b;
+type B8 = BinaryField8b;
+
+/// Number of bytes in a field element.
+pub const ELEMENT_BYTES: usize = 32;
+
+/// Base-2 logarithm of the number of bits in a field element.
+pub const LOG_ELEMENT_BITS: usize = checked_log_2(8 * ELEMENT_BYTES);
+
+/// A 256-bit integer per row, stored as little-endian byte columns.
+pub type FieldElement = [OracleId; ELEMENT_BYTES];
+
+/// A prime field with a modulus below 2^256.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrimeField {
+	pub modulus: U256,
+}
+
+impl PrimeField {
+	pub const fn new(modulus: U256) -> Self {
+		Self { modulus }
+	}
+
+	fn modulus_bytes(&self) -> [u8; ELEMENT_BYTES] {
+		self.modulus.to_le_bytes()
+	}
+}
+
+/// Modular arithmetic over 32-byte sliced integers.
+///
+/// Every operation records its lookups into a single set of batches, so that any number of field
+/// operations share one copy of each u8 arithmetic table. Call [`Self::execute`] once the circuit
+/// is complete.
+///
+/// Operations accept any 32-byte representative of their inputs and most return a canonical
+/// result in witness generation; only [`Self::assert_canonical`] constrains canonicity.
+pub struct ModularArithmetic {
+	log_size: usize,
+	zero_byte: OracleId,
+	zero_carry: OracleId,
+	lookup_batch_mul: LookupBatch,
+	lookup_batch_add: LookupBatch,
+	lookup_batch_add_carryfree: LookupBatch,
+	lookup_batch_dci: LookupBatch,
+	bit_steps: Option<Box<Self>>,
+}
+
+impl ModularArithmetic {
+	pub fn new(builder: &mut ConstraintSystemBuilder, log_size: usize) -> Result<Self> {
+		builder.push_namespace("modular arithmetic");
+		let zero_byte = transparent::constant(builder, "zero byte", log_size, B8::ZERO)?;
+		let zero_carry = transparent::constant(builder, "zero carry", log_size, B1::ZERO)?;
+
+		let lookup_batch_mul = LookupBatch::new([mul_lookup(builder, "mul table")?]);
+		let lookup_batch_add = LookupBatch::new([add_lookup(builder, "add table")?]);
+		let lookup_batch_add_carryfree =
+			LookupBatch::new([add_carryfree_lookup(builder, "add cf table")?]);
+		let lookup_batch_dci = LookupBatch::new([dci_lookup(builder, "dci table")?]);
+		builder.pop_namespace();
+
+		Ok(Self {
+			log_size,
+			zero_byte,
+			zero_carry,
+			lookup_batch_mul,
+			lookup_batch_add,
+			lookup_batch_add_carryfree,
+			lookup_batch_dci,
+			bit_steps: None,
+		})
+	}
+
+	pub const fn log_size(&self) -> usize {
+		self.log_size
+	}
+
+	/// The arithmetic of circuits with one row per bit of a field element in every row of this
+	/// one, as used to lay out scalar multiplications one step per row.
+	///
+	/// The instance is created on first use and executed along with this one.
+	pub fn bit_steps(&mut self, builder: &mut ConstraintSystemBuilder) -> Result<&mut Self> {
+		if self.bit_steps.is_none() {
+			builder.push_namespace("bit steps");
+			let bit_steps = Self::new(builder, self.log_size + LOG_ELEMENT_BITS)?;
+			builder.pop_namespace();
+			self.bit_steps = Some(Box::new(bit_steps));
+		}
+		Ok(self.bit_steps.as_mut().expect("created above"))
+	}
+
+	/// Proves all lookups recorded by the operations so far.
+	pub fn execute(self, builder: &mut ConstraintSystemBuilder) -> Result<()> {
+		if let Some(bit_steps) = self.bit_steps {
+			builder.push_namespace("bit steps");
+			bit_steps.execute(builder)?;
+			builder.pop_namespace();
+		}
+		builder.push_namespace("modular arithmetic");
+		self.lookup_batch_mul.execute::<BinaryField32b>(builder)?;
+		self.lookup_batch_add.execute::<BinaryField32b>(builder)?;
+		self.lookup_batch_add_carryfree
+			.execute::<BinaryField32b>(builder)?;
+		self.lookup_batch_dci.execute::<BinaryField32b>(builder)?;
+		builder.pop_namespace();
+		Ok(())
+	}
+
+	/// A transparent element equal to `value` on every row.
+	pub fn constant(
+		&mut self,
+		builder: &mut ConstraintSystemBuilder,
+		name: impl ToString,
+		value: U256,
+	) -> Result<FieldElement> {
+		builder.push_namespace(name);
+		let bytes = value.to_le_bytes::<ELEMENT_BYTES>();
+		let element = bytes.try_map(|byte| {
+			if byte == 0 {
+				return Ok(self.zero_byte);
+			}
+			transparent::constant(builder, "byte", self.log_size, B8::new(byte))
+		})?;
+		builder.pop_namespace();
+		Ok(element)
+	}
+
+	/// Computes `a + b mod p`.
+	pub fn add(
+		&mut self,
+		builder: &mut ConstraintSystemBuilder,
+		name: impl ToString,
+		field: &PrimeField,
+		a: &FieldElement,
+		b: &FieldElement,
+	) -> Result<FieldElement> {
+		builder.push_namespace(name);
+		let log_size = self.log_size;
+		let sum = builder.add_committed_multiple("sum", log_size, B8::TOWER_LEVEL);
+		let overflow = builder.add_committed("overflow", log_size, B1::TOWER_LEVEL);
+
+		builder.fill_witness(sum.into_iter().chain([overflow]), {
+			let (a, b, field) = (*a, *b, *field);
+			move |witness, _| {
+				let a = read_element(witness, &a, log_size)?;
+				let b = read_element(witness, &b, log_size)?;
+				let modulus = U512::from(field.modulus);
+				let rows = a
+					.into_iter()
+					.zip(b)
+					.map(|(a, b)| {
+						let total = a + b;
+						let wraps = total >= modulus;
+						Ok((total - if wraps { modulus } else { U512::ZERO }, wraps))
+					})
+					.collect::<Result<Vec<_>>>()?;
+				write_element(witness, &sum, rows.iter().map(|(sum, _)| *sum))?;
+				write_bits(witness, overflow, rows.iter().map(|(_, wraps)| *wraps));
+				Ok(())
+			}
+		})?;
+
+		// a + b = sum + overflow * p, as 257-bit integers
+		let multiple = self.multiple_of_modulus(builder, "overflow * p", field, overflow)?;
+		self.assert_sums_equal(builder, (a, b), (&sum, &multiple))?;
+
+		builder.pop_namespace();
+		Ok(sum)
+	}
+
+	/// Computes `a - b mod p`.
+	///
+	/// The difference is computed modulo 2^256 with a borrow, and `p` is added back exactly when
+	/// the subtraction borrowed, which requires `b <= a + p`.
+	pub fn sub(
+		&mut self,
+		builder: &mut ConstraintSystemBuilder,
+		name: impl ToString,
+		field: &PrimeField,
+		a: &FieldElement,
+		b: &FieldElement,
+	) -> Result<FieldElement> {
+		builder.push_namespace(name);
+		let (borrow, wrapped) = byte_sliced_sub::<TowerLevel32>(
+			builder,
+			"a - b",
+			a,
+			b,
+			self.zero_carry,
+			self.log_size,
+			&mut self.lookup_batch_add,
+		)?;
+
+		// (a - b + borrow * 2^256) + borrow * p overflows 256 bits exactly when a - b borrowed
+		let multiple = self.multiple_of_modulus(builder, "borrow * p", field, borrow)?;
+		let (carry, difference) = byte_sliced_add::<TowerLevel32>(
+			builder,
+			"a - b + borrow * p",
+			&wrapped,
+			&multiple,
+			self.zero_carry,
+			self.log_size,
+			&mut self.lookup_batch_add,
+		)?;
+		builder.assert_zero(
+			"carry is borrow",
+			[carry, borrow],
+			arith_expr!([x, y] = x - y).convert_field(),
+		);
+
+		builder.pop_namespace();
+		Ok(difference)
+	}
+
+	/// Computes `a * b mod p`.
+	pub fn mul(
+		&mut self,
+		builder: &mut ConstraintSystemBuilder,
+		name: impl ToString,
+		field: &PrimeField,
+		a: &FieldElement,
+		b: &FieldElement,
+	) -> Result<FieldElement> {
+		byte_sliced_modular_mul_with_batches::<TowerLevel32, TowerLevel64>(
+			builder,
+			name,
+			a,
+			b,
+			&field.modulus_bytes(),
+			self.log_size,
+			self.zero_byte,
+			self.zero_carry,
+			&mut self.lookup_batch_mul,
+			&mut self.lookup_batch_add,
+			&mut self.lookup_batch_add_carryfree,
+			&mut self.lookup_batch_dci,
+		)
+	}
+
+	/// Computes `a^{-1} mod p`.
+	///
+	/// The inverse is committed and checked by multiplying it with `a`, so the circuit is
+	/// unsatisfiable on rows where `a` is divisible by `p`.
+	pub fn inv(
+		&mut self,
+		builder: &mut ConstraintSystemBuilder,
+		name: impl ToString,
+		field: &PrimeField,
+		a: &FieldElement,
+	) -> Result<FieldElement> {
+		builder.push_namespace(name);
+		let log_size = self.log_size;
+		let inverse = builder.add_committed_multiple("inverse", log_size, B8::TOWER_LEVEL);
+
+		builder.fill_witness(inverse, {
+			let (a, field) = (*a, *field);
+			move |witness, _| {
+				let values = read_element(witness, &a, log_size)?
+					.into_iter()
+					.map(|a| {
+						let a = U256::from(a.reduce_mod(U512::from(field.modulus)));
+						a.inv_mod(field.modulus)
+							.map(U512::from)
+							.ok_or_else(|| anyhow!("element is not invertible"))
+					})
+					.collect::<Result<Vec<_>>>()?;
+				write_element(witness, &inverse, values)
+			}
+		})?;
+
+		let product = self.mul(builder, "a * inverse", field, a, &inverse)?;
+		for (i, byte) in product.into_iter().enumerate() {
+			let expected = if i == 0 { F::ONE } else { F::ZERO };
+			builder.assert_zero(
+				format!("product is one {i}"),
+				[byte],
+				ArithExpr::Var(0) - ArithExpr::Const(expected),
+			);
+		}
+
+		builder.pop_namespace();
+		Ok(inverse)
+	}
+
+	/// Selects `if_one` on rows where `bit` is set and `if_zero` elsewhere.
+	pub fn select(
+		&mut self,
+		builder: &mut ConstraintSystemBuilder,
+		name: impl ToString,
+		bit: OracleId,
+		if_one: &FieldElement,
+		if_zero: &FieldElement,
+	) -> Result<FieldElement> {
+		builder.push_namespace(name);
+		let log_size = self.log_size;
+		let selected = builder.add_committed_multiple("selected", log_size, B8::TOWER_LEVEL);
+
+		builder.fill_witness(selected, {
+			let (if_one, if_zero) = (*if_one, *if_zero);
+			move |witness, _| {
+				let bits = read_bits(witness, bit, log_size)?;
+				let if_one = read_element(witness, &if_one, log_size)?;
+				let if_zero = read_element(witness, &if_zero, log_size)?;
+				let values = bits
+					.into_iter()
+					.zip(if_one.into_iter().zip(if_zero))
+					.map(|(bit, (if_one, if_zero))| if bit { if_one } else { if_zero });
+				write_element(witness, &selected, values)
+			}
+		})?;
+
+		for i in 0..ELEMENT_BYTES {
+			builder.assert_zero(
+				format!("select {i}"),
+				[selected[i], if_one[i], if_zero[i], bit],
+				arith_expr!([s, x, y, bit] = s - y - bit * (x - y)).convert_field(),
+			);
+		}
+
+		builder.pop_namespace();
+		Ok(selected)
+	}
+
+	/// Constrains `a` and `b` to be congruent modulo `p`.
+	///
+	/// Both sides are reduced to their canonical representatives before comparing them, so any
+	/// 256-bit representatives are accepted.
+	pub fn assert_eq(
+		&mut self,
+		builder: &mut ConstraintSystemBuilder,
+		name: impl ToString,
+		field: &PrimeField,
+		a: &FieldElement,
+		b: &FieldElement,
+	) -> Result<()> {
+		builder.push_namespace(name);
+		let a = self.reduce(builder, "a mod p", field, a)?;
+		let b = self.reduce(builder, "b mod p", field, b)?;
+		let equal = byte_sliced_equal::<TowerLevel32>(builder, "a = b", &a, &b, self.log_size)?;
+		builder.assert_zero("equal", [equal], arith_expr!([x] = x - 1).convert_field());
+		builder.pop_namespace();
+		Ok(())
+	}
+
+	/// Constrains `a` to be the canonical representative of its residue, i.e. `a < p`.
+	pub fn assert_canonical(
+		&mut self,
+		builder: &mut ConstraintSystemBuilder,
+		name: impl ToString,
+		field: &PrimeField,
+		a: &FieldElement,
+	) -> Result<()> {
+		builder.push_namespace(name);
+		let modulus = self.constant(builder, "p", field.modulus)?;
+		let less_than = byte_sliced_less_than::<TowerLevel32>(
+			builder,
+			"a < p",
+			a,
+			&modulus,
+			self.zero_carry,
+			self.log_size,
+			&mut self.lookup_batch_add,
+		)?;
+		builder.assert_zero("a < p", [less_than], arith_expr!([x] = x - 1).convert_field());
+		builder.pop_namespace();
+		Ok(())
+	}
+
+	/// Reduces `a` to the canonical representative of its residue modulo `field`.
+	///
+	/// This is also used to map elements of one prime field into another, as when reducing a
+	/// curve coordinate modulo the group order.
+	pub fn reduce(
+		&mut self,
+		builder: &mut ConstraintSystemBuilder,
+		name: impl ToString,
+		field: &PrimeField,
+		a: &FieldElement,
+	) -> Result<FieldElement> {
+		builder.push_namespace(name);
+		let modulus = self.constant(builder, "p", field.modulus)?;
+		let reduced = byte_sliced_modular_reduce::<TowerLevel32, TowerLevel64>(
+			builder,
+			"a mod p",
+			&TowerLevel64::join(a, &[self.zero_byte; ELEMENT_BYTES]),
+			&modulus,
+			self.log_size,
+			self.zero_byte,
+			self.zero_carry,
+			&mut self.lookup_batch_mul,
+			&mut self.lookup_batch_add,
+			&mut self.lookup_batch_add_carryfree,
+			&mut self.lookup_batch_dci,
+		)?;
+		builder.pop_namespace();
+		Ok(reduced)
+	}
+
+	/// Decomposes a little-endian byte-sliced integer into bit columns, least significant first.
+	pub fn bits(
+		&mut self,
+		builder: &mut ConstraintSystemBuilder,
+		name: impl ToString,
+		bytes: &[OracleId],
+	) -> Result<Vec<OracleId>> {
+		builder.push_namespace(name);
+		let log_size = self.log_size;
+		let mut bits = Vec::with_capacity(8 * bytes.len());
+		for (i, &byte) in bytes.iter().enumerate() {
+			let byte_bits: [OracleId; 8] =
+				builder.add_committed_multiple(format!("byte[{i}]"), log_size, B1::TOWER_LEVEL);
+
+			builder.fill_witness(byte_bits, move |witness, _| {
+				let values = witness.get::<B8>(byte)?.as_slice::<u8>();
+				for (j, &bit) in byte_bits.iter().enumerate() {
+					write_bits(
+						witness,
+						bit,
+						values[..1 << log_size]
+							.iter()
+							.map(|&value| (value >> j) & 1 == 1),
+					);
+				}
+				Ok(())
+			})?;
+
+			let expr = (0..8).fold(ArithExpr::Var(0), |expr, j| {
+				expr - ArithExpr::Var(j + 1) * ArithExpr::Const(F::from(B8::new(1 << j)))
+			});
+			builder.assert_zero(
+				format!("decomposition {i}"),
+				std::iter::once(byte).chain(byte_bits),
+				expr,
+			);
+			bits.extend(byte_bits);
+		}
+		builder.pop_namespace();
+		Ok(bits)
+	}
+
+	/// Byte columns equal to `p` where `bit` is set and zero elsewhere.
+	fn multiple_of_modulus(
+		&self,
+		builder: &mut ConstraintSystemBuilder,
+		name: impl ToString,
+		field: &PrimeField,
+		bit: OracleId,
+	) -> Result<FieldElement> {
+		builder.push_namespace(name);
+		let modulus = field.modulus_bytes();
+		let multiple = modulus.try_map(|byte| {
+			if byte == 0 {
+				return Ok(self.zero_byte);
+			}
+			let id = builder.add_linear_combination(
+				"byte",
+				self.log_size,
+				[(bit, F::from(B8::new(byte)))],
+			)?;
+			let log_size = self.log_size;
+			builder.fill_witness([id], move |witness, _| {
+				let bits = read_bits(witness, bit, log_size)?;
+				let mut column = witness.new_column::<B8>(id);
+				let column = column.as_mut_slice::<u8>();
+				for (row, bit) in bits.into_iter().enumerate() {
+					column[row] = if bit { byte } else { 0 };
+				}
+				Ok(())
+			})?;
+			Ok::<_, anyhow::Error>(id)
+		})?;
+		builder.pop_namespace();
+		Ok(multiple)
+	}
+
+	/// Constrains `lhs.0 + lhs.1 = rhs.0 + rhs.1` as 257-bit integers.
+	fn assert_sums_equal(
+		&mut self,
+		builder: &mut ConstraintSystemBuilder,
+		lhs: (&FieldElement, &FieldElement),
+		rhs: (&FieldElement, &FieldElement),
+	) -> Result<()> {
+		let (lhs_carry, lhs_sum) = byte_sliced_add::<TowerLevel32>(
+			builder,
+			"lhs",
+			lhs.0,
+			lhs.1,
+			self.zero_carry,
+			self.log_size,
+			&mut self.lookup_batch_add,
+		)?;
+		let (rhs_carry, rhs_sum) = byte_sliced_add::<TowerLevel32>(
+			builder,
+			"rhs",
+			rhs.0,
+			rhs.1,
+			self.zero_carry,
+			self.log_size,
+			&mut self.lookup_batch_add,
+		)?;
+		let consistency = arith_expr!([x, y] = x - y).convert_field();
+		builder.assert_zero("carry consistency", [lhs_carry, rhs_carry], consistency.clone());
+		for i in 0..ELEMENT_BYTES {
+			builder.assert_zero(
+				format!("byte consistency {i}"),
+				[lhs_sum[i], rhs_sum[i]],
+				consistency.clone(),
+			);
+		}
+		Ok(())
+	}
+}
+
+/// The field operations used by curve formulas.
+///
+/// Formulas written against this trait run both in the circuit, through [`FieldOps`], and on
+/// plain integers, through [`NativeField`]. Since the circuit assigns canonical residues to the
+/// results of these operations, both compute the same coordinates, which lets witnesses that
+/// depend on later steps of a formula be computed ahead of the circuit.
+pub(super) trait FieldArithmetic {
+	type Element: Clone;
+
+	fn constant(&mut self, name: impl ToString, value: U256) -> Result<Self::Element>;
+
+	fn add(
+		&mut self,
+		name: impl ToString,
+		a: &Self::Element,
+		b: &Self::Element,
+	) -> Result<Self::Element>;
+
+	fn sub(
+		&mut self,
+		name: impl ToString,
+		a: &Self::Element,
+		b: &Self::Element,
+	) -> Result<Self::Element>;
+
+	fn mul(
+		&mut self,
+		name: impl ToString,
+		a: &Self::Element,
+		b: &Self::Element,
+	) -> Result<Self::Element>;
+}
+
+/// Shorthand for a sequence of operations over a single prime field, as used by curve formulas.
+pub(super) struct FieldOps<'a, 'arena> {
+	pub builder: &'a mut ConstraintSystemBuilder<'arena>,
+	pub arith: &'a mut ModularArithmetic,
+	pub field: PrimeField,
+}
+
+impl FieldOps<'_, '_> {
+	pub fn assert_eq(
+		&mut self,
+		name: impl ToString,
+		a: &FieldElement,
+		b: &FieldElement,
+	) -> Result<()> {
+		self.arith.assert_eq(self.builder, name, &self.field, a, b)
+	}
+}
+
+impl FieldArithmetic for FieldOps<'_, '_> {
+	type Element = FieldElement;
+
+	fn constant(&mut self, name: impl ToString, value: U256) -> Result<FieldElement> {
+		self.arith.constant(self.builder, name, value)
+	}
+
+	fn add(
+		&mut self,
+		name: impl ToString,
+		a: &FieldElement,
+		b: &FieldElement,
+	) -> Result<FieldElement> {
+		self.arith.add(self.builder, name, &self.field, a, b)
+	}
+
+	fn sub(
+		&mut self,
+		name: impl ToString,
+		a: &FieldElement,
+		b: &FieldElement,
+	) -> Result<FieldElement> {
+		self.arith.sub(self.builder, name, &self.field, a, b)
+	}
+
+	fn mul(
+		&mut self,
+		name: impl ToString,
+		a: &FieldElement,
+		b: &FieldElement,
+	) -> Result<FieldElement> {
+		self.arith.mul(self.builder, name, &self.field, a, b)
+	}
+}
+
+/// Arithmetic on canonical residues outside the circuit.
+pub(super) struct NativeField(pub PrimeField);
+
+impl FieldArithmetic for NativeField {
+	type Element = U256;
+
+	fn constant(&mut self, _name: impl ToString, value: U256) -> Result<U256> {
+		Ok(value)
+	}
+
+	fn add(&mut self, _name: impl ToString, a: &U256, b: &U256) -> Result<U256> {
+		Ok(a.add_mod(*b, self.0.modulus))
+	}
+
+	fn sub(&mut self, _name: impl ToString, a: &U256, b: &U256) -> Result<U256> {
+		Ok(a.add_mod(self.0.modulus - b, self.0.modulus))
+	}
+
+	fn mul(&mut self, _name: impl ToString, a: &U256, b: &U256) -> Result<U256> {
+		Ok(a.mul_mod(*b, self.0.modulus))
+	}
+}
+
+/// Reads the per-row integer values of a byte-sliced element from the witness.
+pub(super) fn read_element(
+	witness: &witness::Builder,
+	element: &FieldElement,
+	log_size: usize,
+) -> Result<Vec<U512>> {
+	let columns = element
+		.iter()
+		.map(|&byte| Ok(witness.get::<B8>(byte)?.as_slice::<u8>()))
+		.collect::<Result<Vec<_>>>()?;
+	Ok((0..1 << log_size)
+		.map(|row| {
+			let mut bytes = [0u8; ELEMENT_BYTES];
+			for (byte, column) in bytes.iter_mut().zip(&columns) {
+				*byte = column[row];
+			}
+			U512::from(U256::from_le_bytes(bytes))
+		})
+		.collect())
+}
+
+fn write_element(
+	witness: &witness::Builder,
+	element: &FieldElement,
+	values: impl IntoIterator<Item = U512>,
+) -> Result<()> {
+	let mut columns = element.map(|byte| witness.new_column::<B8>(byte));
+	for (row, value) in values.into_iter().enumerate() {
+		ensure!(value < U512::from(U256::MAX) + U512::from(1), "value does not fit in 256 bits");
+		for (i, column) in columns.iter_mut().enumerate() {
+			column.as_mut_slice::<u8>()[row] = value.byte(i);
+		}
+	}
+	Ok(())
+}
+
+pub(super) fn read_bits(
+	witness: &witness::Builder,
+	bit: OracleId,
+	log_size: usize,
+) -> Result<Vec<bool>> {
+	let packed = witness.get::<B1>(bit)?.as_slice::<u8>();
+	Ok((0..1 << log_size)
+		.map(|row| (packed[row / 8] >> (row % 8)) & 1 == 1)
+		.collect())
+}
+
+fn write_bits(witness: &witness::Builder, bit: OracleId, values: impl IntoIterator<Item = bool>) {
+	let mut column = witness.new_column::<B1>(bit);
+	let packed = column.as_mut_slice::<u8>();
+	for (row, value) in values.into_iter().enumerate() {
+		packed[row / 8] |= (value as u8) << (row % 8);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use alloy_primitives::U256;
+	use binius_core::constraint_system::validate::validate_witness;
+	use rand::{rngs::StdRng, Rng, SeedableRng};
+
+	use super::*;
+	use crate::{ecc::secp256k1::SECP256K1_P, unconstrained::unconstrained};
+
+	fn random_element(rng: &mut impl Rng, field: &PrimeField) -> U256 {
+		U256::from_le_bytes::<32>(rng.gen()).reduce_mod(field.modulus)
+	}
+
+	fn fixed_element(
+		builder: &mut ConstraintSystemBuilder,
+		name: &str,
+		log_size: usize,
+		values: &[U256],
+	) -> FieldElement {
+		let element = builder.add_committed_multiple(name, log_size, B8::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			write_element(witness, &element, values.iter().map(|&v| U512::from(v))).unwrap();
+		}
+		element
+	}
+
+	#[test]
+	fn test_field_ops() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let mut rng = StdRng::seed_from_u64(0);
+		let log_size = 4;
+		let field = SECP256K1_P;
+
+		let a_values: Vec<_> = (0..1 << log_size)
+			.map(|_| random_element(&mut rng, &field))
+			.collect();
+		let b_values: Vec<_> = (0..1 << log_size)
+			.map(|_| random_element(&mut rng, &field))
+			.collect();
+		let a = fixed_element(&mut builder, "a", log_size, &a_values);
+		let b = fixed_element(&mut builder, "b", log_size, &b_values);
+		let bit = unconstrained::<B1>(&mut builder, "bit", log_size).unwrap();
+
+		let mut arith = ModularArithmetic::new(&mut builder, log_size).unwrap();
+		let sum = arith.add(&mut builder, "add", &field, &a, &b).unwrap();
+		let difference = arith.sub(&mut builder, "sub", &field, &a, &b).unwrap();
+		let product = arith.mul(&mut builder, "mul", &field, &a, &b).unwrap();
+		let inverse = arith.inv(&mut builder, "inv", &field, &a).unwrap();
+		let selected = arith.select(&mut builder, "select", bit, &a, &b).unwrap();
+		let expected = arith
+			.constant(&mut builder, "expected", a_values[0].add_mod(b_values[0], field.modulus))
+			.unwrap();
+		arith
+			.assert_canonical(&mut builder, "canonical", &field, &sum)
+			.unwrap();
+		let sum_minus_b = arith
+			.sub(&mut builder, "sum - b", &field, &sum, &b)
+			.unwrap();
+		arith
+			.assert_eq(&mut builder, "sum - b = a", &field, &sum_minus_b, &a)
+			.unwrap();
+		arith.execute(&mut builder).unwrap();
+
+		let witness = builder.witness().unwrap();
+		let p = field.modulus;
+		let check = |element: &FieldElement, expected: &dyn Fn(usize) -> U256| {
+			let values = read_element(witness, element, log_size).unwrap();
+			for (row, value) in values.into_iter().enumerate() {
+				assert_eq!(value, U512::from(expected(row)));
+			}
+		};
+		check(&sum, &|i| a_values[i].add_mod(b_values[i], p));
+		check(&difference, &|i| a_values[i].add_mod(p - b_values[i], p));
+		check(&product, &|i| a_values[i].mul_mod(b_values[i], p));
+		check(&inverse, &|i| a_values[i].inv_mod(p).unwrap());
+		let bits = read_bits(witness, bit, log_size).unwrap();
+		check(&selected, &|i| if bits[i] { a_values[i] } else { b_values[i] });
+		check(&expected, &|_| a_values[0].add_mod(b_values[0], p));
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+
+	#[test]
+	fn test_reduce() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let mut rng = StdRng::seed_from_u64(1);
+		let log_size = 4;
+		let p = SECP256K1_P;
+		let n = crate::ecc::secp256k1::SECP256K1_N;
+
+		// Include values in [n, p), which must wrap around
+		let values: Vec<_> = (0..1 << log_size)
+			.map(|i| {
+				if i % 2 == 0 {
+					n.modulus + U256::from(i)
+				} else {
+					random_element(&mut rng, &p)
+				}
+			})
+			.collect();
+		let a = fixed_element(&mut builder, "a", log_size, &values);
+
+		let mut arith = ModularArithmetic::new(&mut builder, log_size).unwrap();
+		let reduced = arith.reduce(&mut builder, "reduce", &n, &a).unwrap();
+		arith.execute(&mut builder).unwrap();
+
+		let witness = builder.witness().unwrap();
+		let reduced = read_element(witness, &reduced, log_size).unwrap();
+		for (value, reduced) in values.into_iter().zip(reduced) {
+			assert_eq!(reduced, U512::from(value.reduce_mod(n.modulus)));
+		}
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+}
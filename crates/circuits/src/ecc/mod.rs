@@ -0,0 +1,23 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Elliptic-curve arithmetic over 256-bit prime fields.
+//!
+//! Field elements are byte-sliced integers, and all field operations are built on the u8 lookup
+//! gadgets in [`crate::lasso::big_integer_ops`], sharing a single set of lookup batches held by a
+//! [`ModularArithmetic`] instance. On top of these, [`secp256k1`] and [`ed25519`] provide point
+//! arithmetic and signature verification.
+
+pub mod curve;
+pub mod ed25519;
+pub mod field;
+pub mod secp256k1;
+
+pub use curve::{scalar_mul, Curve};
+pub use field::{FieldElement, ModularArithmetic, PrimeField};
+
+/// A point given by its affine coordinates.
+#[derive(Debug, Clone, Copy)]
+pub struct AffinePoint {
+	pub x: FieldElement,
+	pub y: FieldElement,
+}
@@ -0,0 +1,550 @@
+// Copyright 2025 Irreducible Inc.
+
+use alloy_primitives::{uint, U256};
+use anyhow::Result;
+use binius_core::oracle::OracleId;
+
+use super::{
+	curve::{double_scalar_mul, Coordinates, Curve},
+	field::{FieldArithmetic, FieldElement, FieldOps, ModularArithmetic, NativeField, PrimeField},
+	AffinePoint,
+};
+use crate::builder::ConstraintSystemBuilder;
+
+/// The base field of secp256k1.
+pub const SECP256K1_P: PrimeField =
+	PrimeField::new(uint!(0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F_U256));
+
+/// The scalar field of secp256k1, i.e. the order of its generator.
+pub const SECP256K1_N: PrimeField =
+	PrimeField::new(uint!(0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141_U256));
+
+/// The affine coordinates of the secp256k1 generator.
+pub const SECP256K1_GENERATOR: (U256, U256) = (
+	uint!(0x79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798_U256),
+	uint!(0x483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8_U256),
+);
+
+/// `3 * b` for the curve equation `y^2 = x^3 + b`, with `b = 7`.
+const B3: U256 = uint!(21_U256);
+
+/// A secp256k1 point in homogeneous projective coordinates `(X : Y : Z)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProjectivePoint<E = FieldElement> {
+	pub x: E,
+	pub y: E,
+	pub z: E,
+}
+
+impl<E: Clone> Coordinates for ProjectivePoint<E> {
+	type Element = E;
+
+	fn coordinates(&self) -> Vec<E> {
+		vec![self.x.clone(), self.y.clone(), self.z.clone()]
+	}
+
+	fn from_coordinates(coordinates: &[E]) -> Self {
+		let [x, y, z] = coordinates else {
+			panic!("a projective point has three coordinates");
+		};
+		Self {
+			x: x.clone(),
+			y: y.clone(),
+			z: z.clone(),
+		}
+	}
+}
+
+/// The secp256k1 curve, using the complete formulas of Renes, Costello and Batina for `a = 0`
+/// (<https://eprint.iacr.org/2015/1060>, algorithms 7 and 9).
+pub struct Secp256k1;
+
+impl Curve for Secp256k1 {
+	type Point = ProjectivePoint;
+	type NativePoint = ProjectivePoint<U256>;
+
+	fn constant(
+		builder: &mut ConstraintSystemBuilder,
+		arith: &mut ModularArithmetic,
+		name: impl ToString,
+		affine: (U256, U256),
+	) -> Result<ProjectivePoint> {
+		builder.push_namespace(name);
+		let point = ProjectivePoint {
+			x: arith.constant(builder, "x", affine.0)?,
+			y: arith.constant(builder, "y", affine.1)?,
+			z: arith.constant(builder, "z", U256::from(1))?,
+		};
+		builder.pop_namespace();
+		Ok(point)
+	}
+
+	fn identity(
+		builder: &mut ConstraintSystemBuilder,
+		arith: &mut ModularArithmetic,
+		name: impl ToString,
+	) -> Result<ProjectivePoint> {
+		builder.push_namespace(name);
+		let point = ProjectivePoint {
+			x: arith.constant(builder, "x", U256::ZERO)?,
+			y: arith.constant(builder, "y", U256::from(1))?,
+			z: arith.constant(builder, "z", U256::ZERO)?,
+		};
+		builder.pop_namespace();
+		Ok(point)
+	}
+
+	fn add(
+		builder: &mut ConstraintSystemBuilder,
+		arith: &mut ModularArithmetic,
+		name: impl ToString,
+		p: &ProjectivePoint,
+		q: &ProjectivePoint,
+	) -> Result<ProjectivePoint> {
+		builder.push_namespace(name);
+		let mut f = FieldOps {
+			builder,
+			arith,
+			field: SECP256K1_P,
+		};
+		let sum = add(&mut f, p, q)?;
+		builder.pop_namespace();
+		Ok(sum)
+	}
+
+	fn double(
+		builder: &mut ConstraintSystemBuilder,
+		arith: &mut ModularArithmetic,
+		name: impl ToString,
+		p: &ProjectivePoint,
+	) -> Result<ProjectivePoint> {
+		builder.push_namespace(name);
+		let mut f = FieldOps {
+			builder,
+			arith,
+			field: SECP256K1_P,
+		};
+		let double = double(&mut f, p)?;
+		builder.pop_namespace();
+		Ok(double)
+	}
+
+	fn select(
+		builder: &mut ConstraintSystemBuilder,
+		arith: &mut ModularArithmetic,
+		name: impl ToString,
+		bit: OracleId,
+		if_one: &ProjectivePoint,
+		if_zero: &ProjectivePoint,
+	) -> Result<ProjectivePoint> {
+		builder.push_namespace(name);
+		let point = ProjectivePoint {
+			x: arith.select(builder, "x", bit, &if_one.x, &if_zero.x)?,
+			y: arith.select(builder, "y", bit, &if_one.y, &if_zero.y)?,
+			z: arith.select(builder, "z", bit, &if_one.z, &if_zero.z)?,
+		};
+		builder.pop_namespace();
+		Ok(point)
+	}
+
+	fn native_constant(affine: (U256, U256)) -> ProjectivePoint<U256> {
+		ProjectivePoint {
+			x: affine.0,
+			y: affine.1,
+			z: U256::from(1),
+		}
+	}
+
+	fn native_identity() -> ProjectivePoint<U256> {
+		ProjectivePoint {
+			x: U256::ZERO,
+			y: U256::from(1),
+			z: U256::ZERO,
+		}
+	}
+
+	fn native_add(p: &ProjectivePoint<U256>, q: &ProjectivePoint<U256>) -> ProjectivePoint<U256> {
+		add(&mut NativeField(SECP256K1_P), p, q).expect("native arithmetic is infallible")
+	}
+
+	fn native_double(p: &ProjectivePoint<U256>) -> ProjectivePoint<U256> {
+		double(&mut NativeField(SECP256K1_P), p).expect("native arithmetic is infallible")
+	}
+}
+
+/// Algorithm 7 of Renes, Costello and Batina, for `a = 0`.
+fn add<A: FieldArithmetic>(
+	f: &mut A,
+	p: &ProjectivePoint<A::Element>,
+	q: &ProjectivePoint<A::Element>,
+) -> Result<ProjectivePoint<A::Element>> {
+	let b3 = f.constant("b3", B3)?;
+
+	let t0 = f.mul("t0", &p.x, &q.x)?;
+	let t1 = f.mul("t1", &p.y, &q.y)?;
+	let t2 = f.mul("t2", &p.z, &q.z)?;
+	let t3 = f.add("x1 + y1", &p.x, &p.y)?;
+	let t4 = f.add("x2 + y2", &q.x, &q.y)?;
+	let t3 = f.mul("t3", &t3, &t4)?;
+	let t4 = f.add("t0 + t1", &t0, &t1)?;
+	let t3 = f.sub("t3 - t4", &t3, &t4)?;
+	let t4 = f.add("y1 + z1", &p.y, &p.z)?;
+	let x3 = f.add("y2 + z2", &q.y, &q.z)?;
+	let t4 = f.mul("t4", &t4, &x3)?;
+	let x3 = f.add("t1 + t2", &t1, &t2)?;
+	let t4 = f.sub("t4 - x3", &t4, &x3)?;
+	let x3 = f.add("x1 + z1", &p.x, &p.z)?;
+	let y3 = f.add("x2 + z2", &q.x, &q.z)?;
+	let x3 = f.mul("x3", &x3, &y3)?;
+	let y3 = f.add("t0 + t2", &t0, &t2)?;
+	let y3 = f.sub("x3 - y3", &x3, &y3)?;
+	let x3 = f.add("t0 + t0", &t0, &t0)?;
+	let t0 = f.add("3 * t0", &x3, &t0)?;
+	let t2 = f.mul("b3 * t2", &b3, &t2)?;
+	let z3 = f.add("t1 + t2", &t1, &t2)?;
+	let t1 = f.sub("t1 - t2", &t1, &t2)?;
+	let y3 = f.mul("b3 * y3", &b3, &y3)?;
+	let x3 = f.mul("t4 * y3", &t4, &y3)?;
+	let t2 = f.mul("t3 * t1", &t3, &t1)?;
+	let x3 = f.sub("t2 - x3", &t2, &x3)?;
+	let y3 = f.mul("y3 * t0", &y3, &t0)?;
+	let t1 = f.mul("t1 * z3", &t1, &z3)?;
+	let y3 = f.add("t1 + y3", &t1, &y3)?;
+	let t0 = f.mul("t0 * t3", &t0, &t3)?;
+	let z3 = f.mul("z3 * t4", &z3, &t4)?;
+	let z3 = f.add("z3 + t0", &z3, &t0)?;
+
+	Ok(ProjectivePoint {
+		x: x3,
+		y: y3,
+		z: z3,
+	})
+}
+
+/// Algorithm 9 of Renes, Costello and Batina, for `a = 0`.
+fn double<A: FieldArithmetic>(
+	f: &mut A,
+	p: &ProjectivePoint<A::Element>,
+) -> Result<ProjectivePoint<A::Element>> {
+	let b3 = f.constant("b3", B3)?;
+
+	let t0 = f.mul("y * y", &p.y, &p.y)?;
+	let z3 = f.add("2 * t0", &t0, &t0)?;
+	let z3 = f.add("4 * t0", &z3, &z3)?;
+	let z3 = f.add("8 * t0", &z3, &z3)?;
+	let t1 = f.mul("y * z", &p.y, &p.z)?;
+	let t2 = f.mul("z * z", &p.z, &p.z)?;
+	let t2 = f.mul("b3 * t2", &b3, &t2)?;
+	let x3 = f.mul("t2 * z3", &t2, &z3)?;
+	let y3 = f.add("t0 + t2", &t0, &t2)?;
+	let z3 = f.mul("t1 * z3", &t1, &z3)?;
+	let t1 = f.add("2 * t2", &t2, &t2)?;
+	let t2 = f.add("3 * t2", &t1, &t2)?;
+	let t0 = f.sub("t0 - t2", &t0, &t2)?;
+	let y3 = f.mul("t0 * y3", &t0, &y3)?;
+	let y3 = f.add("x3 + y3", &x3, &y3)?;
+	let t1 = f.mul("x * y", &p.x, &p.y)?;
+	let x3 = f.mul("t0 * t1", &t0, &t1)?;
+	let x3 = f.add("2 * x3", &x3, &x3)?;
+
+	Ok(ProjectivePoint {
+		x: x3,
+		y: y3,
+		z: z3,
+	})
+}
+
+/// Lifts an affine point after checking that it lies on the curve `y^2 = x^3 + 7`.
+pub fn from_affine(
+	builder: &mut ConstraintSystemBuilder,
+	arith: &mut ModularArithmetic,
+	name: impl ToString,
+	point: &AffinePoint,
+) -> Result<ProjectivePoint> {
+	builder.push_namespace(name);
+	let mut f = FieldOps {
+		builder,
+		arith,
+		field: SECP256K1_P,
+	};
+	let y2 = f.mul("y^2", &point.y, &point.y)?;
+	let x2 = f.mul("x^2", &point.x, &point.x)?;
+	let x3 = f.mul("x^3", &x2, &point.x)?;
+	let b = f.constant("b", uint!(7_U256))?;
+	let rhs = f.add("x^3 + b", &x3, &b)?;
+	f.assert_eq("on curve", &y2, &rhs)?;
+	let z = f.constant("z", U256::from(1))?;
+
+	builder.pop_namespace();
+	Ok(ProjectivePoint {
+		x: point.x,
+		y: point.y,
+		z,
+	})
+}
+
+/// Computes the affine x-coordinate `X / Z` of a point, which must not be the identity.
+pub fn affine_x(
+	builder: &mut ConstraintSystemBuilder,
+	arith: &mut ModularArithmetic,
+	name: impl ToString,
+	point: &ProjectivePoint,
+) -> Result<FieldElement> {
+	builder.push_namespace(name);
+	let z_inv = arith.inv(builder, "1 / z", &SECP256K1_P, &point.z)?;
+	let x = arith.mul(builder, "x / z", &SECP256K1_P, &point.x, &z_inv)?;
+	builder.pop_namespace();
+	Ok(x)
+}
+
+/// Verifies an ECDSA signature `(r, s)` over secp256k1 on every row.
+///
+/// `message_hash` holds the hash of the signed message as an integer (for SHA-256 and Keccak-256
+/// digests, the big-endian interpretation of the digest bytes). The circuit is satisfiable exactly
+/// on rows holding valid signatures: `r` and `s` must lie in `[1, n)`, the public key must lie on
+/// the curve, and `u1 * G + u2 * Q` must be a point with x-coordinate congruent to `r` modulo `n`,
+/// where `u1 = z / s` and `u2 = r / s`. Both scalar multiplications share one
+/// [`double_scalar_mul`].
+pub fn ecdsa_verify(
+	builder: &mut ConstraintSystemBuilder,
+	arith: &mut ModularArithmetic,
+	name: impl ToString,
+	public_key: &AffinePoint,
+	message_hash: &FieldElement,
+	r: &FieldElement,
+	s: &FieldElement,
+) -> Result<()> {
+	builder.push_namespace(name);
+
+	arith.assert_canonical(builder, "r < n", &SECP256K1_N, r)?;
+	arith.assert_canonical(builder, "s < n", &SECP256K1_N, s)?;
+	// Inverting r rules out r = 0; inverting s both rules out s = 0 and yields the exponent.
+	arith.inv(builder, "r != 0", &SECP256K1_N, r)?;
+	let s_inv = arith.inv(builder, "1 / s", &SECP256K1_N, s)?;
+	let u1 = arith.mul(builder, "u1", &SECP256K1_N, message_hash, &s_inv)?;
+	let u2 = arith.mul(builder, "u2", &SECP256K1_N, r, &s_inv)?;
+
+	let generator = Secp256k1::constant(builder, arith, "G", SECP256K1_GENERATOR)?;
+	let public_key = from_affine(builder, arith, "Q", public_key)?;
+	let point = double_scalar_mul::<Secp256k1>(
+		builder,
+		arith,
+		"u1 * G + u2 * Q",
+		&generator,
+		&u1,
+		&public_key,
+		&u2,
+	)?;
+
+	// The affine x-coordinate is a canonical residue modulo p, reduced once more modulo n
+	let x = affine_x(builder, arith, "x", &point)?;
+	arith.assert_canonical(builder, "x < p", &SECP256K1_P, &x)?;
+	arith.assert_eq(builder, "x = r", &SECP256K1_N, &x, r)?;
+
+	builder.pop_namespace();
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use alloy_primitives::{U256, U512};
+	use binius_core::constraint_system::validate::validate_witness;
+	use binius_field::{BinaryField8b, TowerField};
+	use k256::{
+		ecdsa::{signature::hazmat::PrehashSigner, Signature, SigningKey},
+		elliptic_curve::{sec1::ToEncodedPoint, PrimeField as _},
+		ProjectivePoint as K256Point, Scalar,
+	};
+	use rand::{rngs::StdRng, Rng, SeedableRng};
+
+	use super::*;
+	use crate::ecc::{curve::scalar_mul, field::read_element};
+
+	fn write_values(
+		builder: &mut ConstraintSystemBuilder,
+		name: &str,
+		log_size: usize,
+		values: &[U256],
+	) -> FieldElement {
+		let element: FieldElement =
+			builder.add_committed_multiple(name, log_size, BinaryField8b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			for (i, &byte) in element.iter().enumerate() {
+				let mut column = witness.new_column::<BinaryField8b>(byte);
+				let column = column.as_mut_slice::<u8>();
+				for (row, value) in values.iter().enumerate() {
+					column[row] = value.byte(i);
+				}
+			}
+		}
+		element
+	}
+
+	fn affine(point: K256Point) -> (U256, U256) {
+		let encoded = point.to_affine().to_encoded_point(false);
+		(U256::from_be_slice(encoded.x().unwrap()), U256::from_be_slice(encoded.y().unwrap()))
+	}
+
+	fn random_scalar(rng: &mut impl Rng) -> Scalar {
+		Scalar::from_repr(rng.gen::<[u8; 32]>().into()).unwrap_or(Scalar::ONE)
+	}
+
+	fn read_affine(
+		builder: &mut ConstraintSystemBuilder,
+		point: &ProjectivePoint,
+		log_size: usize,
+	) -> Vec<Option<(U256, U256)>> {
+		let witness = builder.witness().unwrap();
+		let p = SECP256K1_P.modulus;
+		let x = read_element(witness, &point.x, log_size).unwrap();
+		let y = read_element(witness, &point.y, log_size).unwrap();
+		let z = read_element(witness, &point.z, log_size).unwrap();
+		(0..1 << log_size)
+			.map(|row| {
+				let to_u256 = |value: U512| U256::from(value.reduce_mod(U512::from(p)));
+				let z_inv = to_u256(z[row]).inv_mod(p)?;
+				Some((to_u256(x[row]).mul_mod(z_inv, p), to_u256(y[row]).mul_mod(z_inv, p)))
+			})
+			.collect()
+	}
+
+	#[test]
+	fn test_add_and_double() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let mut rng = StdRng::seed_from_u64(0);
+		let log_size = 4;
+
+		let ps: Vec<_> = (0..1 << log_size)
+			.map(|_| K256Point::GENERATOR * random_scalar(&mut rng))
+			.collect();
+		// Exercise the exceptional cases of incomplete formulas: P + P and P + (-P)
+		let qs: Vec<_> = (0..1 << log_size)
+			.map(|i| match i {
+				0 => ps[0],
+				1 => -ps[1],
+				_ => K256Point::GENERATOR * random_scalar(&mut rng),
+			})
+			.collect();
+
+		let mut arith = ModularArithmetic::new(&mut builder, log_size).unwrap();
+		let mut input = |name: &str, points: &[K256Point]| {
+			let (x, y): (Vec<_>, Vec<_>) = points.iter().map(|&p| affine(p)).unzip();
+			let point = AffinePoint {
+				x: write_values(&mut builder, &format!("{name}.x"), log_size, &x),
+				y: write_values(&mut builder, &format!("{name}.y"), log_size, &y),
+			};
+			from_affine(&mut builder, &mut arith, name, &point).unwrap()
+		};
+		let p = input("p", &ps);
+		let q = input("q", &qs);
+
+		let sum = Secp256k1::add(&mut builder, &mut arith, "p + q", &p, &q).unwrap();
+		let double = Secp256k1::double(&mut builder, &mut arith, "2p", &p).unwrap();
+		arith.execute(&mut builder).unwrap();
+
+		let sums = read_affine(&mut builder, &sum, log_size);
+		let doubles = read_affine(&mut builder, &double, log_size);
+		for row in 0..1 << log_size {
+			let expected = ps[row] + qs[row];
+			// The identity has no affine representation
+			if row == 1 {
+				assert_eq!(expected, K256Point::IDENTITY);
+				assert_eq!(sums[row], None);
+			} else {
+				assert_eq!(sums[row], Some(affine(expected)));
+			}
+			assert_eq!(doubles[row], Some(affine(ps[row].double())));
+		}
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+
+	#[test]
+	fn test_scalar_mul() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let mut rng = StdRng::seed_from_u64(1);
+		let log_size = 4;
+
+		// A 3-bit scalar, which covers a partial window and keeps the circuit small
+		let scalars: Vec<u8> = (0..1 << log_size).map(|_| rng.gen_range(0..8)).collect();
+		let scalar = write_values(
+			&mut builder,
+			"scalar",
+			log_size,
+			&scalars.iter().map(|&k| U256::from(k)).collect::<Vec<_>>(),
+		);
+
+		let mut arith = ModularArithmetic::new(&mut builder, log_size).unwrap();
+		let bits = arith.bits(&mut builder, "bits", &scalar[..1]).unwrap();
+		let generator =
+			Secp256k1::constant(&mut builder, &mut arith, "G", SECP256K1_GENERATOR).unwrap();
+		let product =
+			scalar_mul::<Secp256k1>(&mut builder, &mut arith, "k * G", &generator, &bits[..3], 2)
+				.unwrap();
+		arith.execute(&mut builder).unwrap();
+
+		let products = read_affine(&mut builder, &product, log_size);
+		for (row, &k) in scalars.iter().enumerate() {
+			let expected = (k != 0).then(|| affine(K256Point::GENERATOR * Scalar::from(k as u64)));
+			assert_eq!(products[row], expected);
+		}
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+
+	#[test]
+	fn test_ecdsa_verify() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let mut rng = StdRng::seed_from_u64(2);
+		let log_size = 1;
+
+		let mut columns = [vec![], vec![], vec![], vec![], vec![]];
+		for _ in 0..1 << log_size {
+			let signing_key = SigningKey::from_bytes(&rng.gen::<[u8; 32]>().into()).unwrap();
+			let message_hash: [u8; 32] = rng.gen();
+			let signature: Signature = signing_key.sign_prehash(&message_hash).unwrap();
+			let (x, y) = affine(K256Point::from(*signing_key.verifying_key().as_affine()));
+			let values = [
+				x,
+				y,
+				U256::from_be_bytes(message_hash),
+				U256::from_be_slice(&signature.r().to_bytes()),
+				U256::from_be_slice(&signature.s().to_bytes()),
+			];
+			for (column, value) in columns.iter_mut().zip(values) {
+				column.push(value);
+			}
+		}
+		let [x, y, message_hash, r, s] = ["x", "y", "message_hash", "r", "s"]
+			.into_iter()
+			.zip(&columns)
+			.map(|(name, values)| write_values(&mut builder, name, log_size, values))
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+
+		let mut arith = ModularArithmetic::new(&mut builder, log_size).unwrap();
+		ecdsa_verify(
+			&mut builder,
+			&mut arith,
+			"ecdsa",
+			&AffinePoint { x, y },
+			&message_hash,
+			&r,
+			&s,
+		)
+		.unwrap();
+		arith.execute(&mut builder).unwrap();
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+}
@@ -1,405 +1,355 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+//! The Grøstl-256 permutations and the two-to-one compression used by Grøstl Merkle trees.
+//!
+//! The 64-byte Grøstl state is laid out column-major, byte `i` of a block is in row `i % 8` and
+//! column `i / 8` of the state matrix. As in [`crate::aes`], bytes are elements of the Rijndael
+//! field mapped into the canonical tower, so that the S-box can be shared with AES and the linear
+//! layers are linear combinations over [`BinaryField8b`]. AddRoundConstant, ShiftBytes and
+//! MixBytes are folded into a single linear combination per byte and round.
+
 use std::array;
 
 use anyhow::Result;
 use binius_core::oracle::OracleId;
-use binius_field::{
-	as_packed_field::{PackScalar, PackedType},
-	packed::{get_packed_slice, set_packed_slice},
-	AESTowerField8b, BinaryField1b, BinaryField8b, ExtensionField, PackedField, TowerField,
+use binius_field::BinaryField8b;
+use binius_hash::groestl::{GroestlShortImpl, GroestlShortInternal};
+
+use crate::{
+	aes::{aes_byte_to_tower, sub_byte, tower_to_aes_byte, AffineByte},
+	builder::ConstraintSystemBuilder,
 };
-use binius_math::ArithExpr;
-use binius_maybe_rayon::prelude::*;
-use bytemuck::Pod;
 
-use crate::{builder::ConstraintSystemBuilder, transparent, unconstrained::unconstrained};
+type B8 = BinaryField8b;
 
-pub fn groestl_p_permutation<U, F>(
-	builder: &mut ConstraintSystemBuilder<U, F>,
-	log_size: usize,
-) -> Result<[OracleId; STATE_SIZE]>
-where
-	U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<AESTowerField8b> + Pod,
-	F: TowerField + ExtensionField<AESTowerField8b>,
-	PackedType<U, F>: Pod,
-{
-	let p_in = array::try_from_fn(|i| {
-		unconstrained::<U, F, AESTowerField8b>(builder, format!("p_in[{i}]"), log_size)
-	})?;
-	let multiples_16: [_; 8] = array::from_fn(|i| {
-		transparent::constant(
-			builder,
-			format!("multiples_16[{i}]"),
-			log_size,
-			AESTowerField8b::new(i as u8 * 0x10),
-		)
-		.unwrap()
-	});
+/// Number of bytes in the Grøstl-256 state.
+pub const STATE_SIZE: usize = 64;
 
-	let round_consts = permutation_round_consts(builder, log_size, 0, multiples_16, p_in)?;
-	let mut output =
-		groestl_p_permutation_round(builder, "round[0]", log_size, round_consts, p_in)?;
-	for round_index in 1..N_ROUNDS {
-		let round_consts =
-			permutation_round_consts(builder, log_size, round_index, multiples_16, output)?;
-		output = groestl_p_permutation_round(
-			builder,
-			format!("rounds[{round_index}]"),
-			log_size,
-			round_consts,
-			output,
-		)?;
-	}
-	let p_out = output;
-
-	#[cfg(debug_assertions)]
-	if let Some(witness) = builder.witness() {
-		use binius_field::PackedAESBinaryField64x8b;
-		use binius_hash::Groestl256Core;
-
-		let inputs = p_in
-			.try_map(|id| witness.get::<AESTowerField8b>(id))?
-			.map(|col| col.as_slice::<AESTowerField8b>());
-		let outputs = p_out
-			.try_map(|id| witness.get::<AESTowerField8b>(id))?
-			.map(|col| col.as_slice::<AESTowerField8b>());
-
-		for z in 0..1 << log_size {
-			assert_eq!(
-				Groestl256Core.permutation_p(PackedAESBinaryField64x8b::from_fn(|i| inputs[i][z])),
-				PackedAESBinaryField64x8b::from_fn(|i| outputs[i][z])
-			);
-		}
-	}
+/// Number of bytes in a Grøstl-256 digest.
+pub const DIGEST_SIZE: usize = 32;
 
-	Ok(p_out)
+/// Computes the Grøstl-256 permutation P of the state in every row.
+pub fn groestl_p_permutation(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	log_size: usize,
+	input: [OracleId; STATE_SIZE],
+) -> Result<[OracleId; STATE_SIZE]> {
+	groestl_permutation(builder, name, log_size, Permutation::P, input)
 }
 
-#[allow(clippy::needless_range_loop)]
-fn groestl_p_permutation_round<U, F>(
-	builder: &mut ConstraintSystemBuilder<U, F>,
+/// Computes the Grøstl-256 permutation Q of the state in every row.
+pub fn groestl_q_permutation(
+	builder: &mut ConstraintSystemBuilder,
 	name: impl ToString,
 	log_size: usize,
-	round_consts: [OracleId; 8],
 	input: [OracleId; STATE_SIZE],
-) -> Result<[OracleId; STATE_SIZE]>
-where
-	U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<AESTowerField8b> + Pod,
-	F: TowerField + ExtensionField<AESTowerField8b>,
-{
+) -> Result<[OracleId; STATE_SIZE]> {
+	groestl_permutation(builder, name, log_size, Permutation::Q, input)
+}
+
+/// Compresses two digests into one, as
+/// [`binius_hash::groestl::Groestl256ByteCompression`] does.
+///
+/// The result is the Grøstl-256 digest of the 64-byte message `left || right`, which takes the
+/// compression of the message block and of the padding block, followed by the output
+/// transformation. Q of the padding block does not depend on the input and is a constant.
+pub fn groestl256_byte_compression(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	log_size: usize,
+	left: [OracleId; DIGEST_SIZE],
+	right: [OracleId; DIGEST_SIZE],
+) -> Result<[OracleId; DIGEST_SIZE]> {
 	builder.push_namespace(name);
 
-	let p_sub_bytes_out: [OracleId; STATE_SIZE] = array::try_from_fn(|i| {
-		groestl_p_permutation_sbox(
-			builder,
-			format!("s_box[{i}]"),
-			log_size,
-			if i % 8 == 0 {
-				round_consts[i / 8]
-			} else {
-				input[i]
-			},
-		)
-	})?;
+	let mut initial = [0u8; STATE_SIZE];
+	initial[STATE_SIZE - 8..].copy_from_slice(&(8 * DIGEST_SIZE as u64).to_be_bytes());
+	let initial = constant_state(&initial);
 
-	// Shift and mix bytes using committed columns
-	let output = builder.add_committed_multiple("output", log_size, BinaryField8b::TOWER_LEVEL);
-
-	if let Some(witness) = builder.witness() {
-		let p_sub_bytes_out = p_sub_bytes_out.try_map(|id| witness.get::<AESTowerField8b>(id))?;
-		let mut output = output.map(|id| witness.new_column::<AESTowerField8b>(id));
-		let mut output = output
-			.iter_mut()
-			.map(|col| col.as_mut_slice::<AESTowerField8b>())
-			.collect::<Vec<_>>();
-
-		let two = AESTowerField8b::new(2);
-		for z in 0..1 << log_size {
-			for j in 0..8 {
-				let a_j: [_; 8] = array::from_fn(|i| {
-					let shift_p = ((i + j) % 8) * 8 + i; // ShiftBytes & MixBytes
-					get_packed_slice(p_sub_bytes_out[shift_p].packed(), z)
-				});
-				for i in 0..8 {
-					let ij = j * 8 + i;
-					let a_i: [AESTowerField8b; 8] = array::from_fn(|k| a_j[(i + k) % 8]);
-					// Here we are using an optimized matrix multiplication, as documented in
-					// section 4.4.2 of https://www.groestl.info/groestl-implementation-guide.pdf
-					let b_ij = two
-						* (two * (a_i[3] + a_i[4] + a_i[6] + a_i[7])
-							+ a_i[0] + a_i[1] + a_i[2]
-							+ a_i[5] + a_i[7])
-						+ a_i[2] + a_i[4] + a_i[5]
-						+ a_i[6] + a_i[7];
-
-					output[ij][z] = b_ij;
-				}
-			}
-		}
-	}
-
-	for ij in 0..STATE_SIZE {
-		let i = ij / 8;
-		let j = ij % 8;
+	let message: [AffineByte; STATE_SIZE] = array::from_fn(|i| {
+		AffineByte::from_oracle(if i < DIGEST_SIZE {
+			left[i]
+		} else {
+			right[i - DIGEST_SIZE]
+		})
+	});
 
-		let mut mix_shift_oracles = [OracleId::default(); 9];
-		mix_shift_oracles[0] = output[ij];
-		for k in 0..8 {
-			let j_prime = (j + k) % 8;
-			let i_prime = (i + j_prime) % 8;
-			mix_shift_oracles[k + 1] = p_sub_bytes_out[i_prime * 8 + j_prime];
-		}
-		// This is not required if the columns are virtual
-		builder.assert_zero(
-			"mix_column_{ij}",
-			mix_shift_oracles,
-			mix_column_expr().convert_field(),
-		);
-	}
+	// A single message block followed by a padding block
+	let mut padding = [0u8; STATE_SIZE];
+	padding[0] = 0x80;
+	padding[STATE_SIZE - 8..].copy_from_slice(&2u64.to_be_bytes());
 
+	builder.push_namespace("message_block");
+	let state = compress_block(builder, log_size, &initial, &message)?;
 	builder.pop_namespace();
-	Ok(output)
-}
 
-fn groestl_p_permutation_sbox<U, F>(
-	builder: &mut ConstraintSystemBuilder<U, F>,
-	name: impl ToString,
-	log_size: usize,
-	input: OracleId,
-) -> Result<OracleId, anyhow::Error>
-where
-	U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<AESTowerField8b> + Pod,
-	F: TowerField + ExtensionField<AESTowerField8b>,
-{
-	builder.push_namespace(name);
-	let inv_bits: [OracleId; 8] =
-		builder.add_committed_multiple("inv_bits", log_size, BinaryField1b::TOWER_LEVEL);
-
-	let inv = builder.add_linear_combination(
-		"inv",
+	builder.push_namespace("padding_block");
+	let p_out = permutation(
+		builder,
 		log_size,
-		(0..8).map(|b| {
-			let basis = <AESTowerField8b as ExtensionField<BinaryField1b>>::basis(b)
-				.expect("index is less than extension degree");
-			(inv_bits[b], basis.into())
-		}),
+		Permutation::P,
+		&add_states(&state, &constant_state(&padding)),
 	)?;
+	let q_out = constant_state(&reference_q_permutation(&padding));
+	let state = add_states(&add_states(&p_out, &q_out), &state);
+	builder.pop_namespace();
 
-	let output = builder.add_linear_combination_with_offset(
-		"output",
-		log_size,
-		SBOX_VEC.into(),
-		(0..8).map(|b| (inv_bits[b], SBOX_MATRIX[b].into())),
-	)?;
+	builder.push_namespace("output_transformation");
+	let p_out = permutation(builder, log_size, Permutation::P, &state)?;
+	let output = add_states(&p_out, &state);
+	builder.pop_namespace();
 
-	if let Some(witness) = builder.witness() {
-		let input = witness
-			.get::<AESTowerField8b>(input)?
-			.as_slice::<AESTowerField8b>();
+	let digest = array::try_from_fn(|i| {
+		output[STATE_SIZE - DIGEST_SIZE + i].materialize(builder, format!("digest[{i}]"), log_size)
+	})?;
 
-		let mut inv_bits_witness: [_; 8] =
-			inv_bits.map(|id| witness.new_column::<BinaryField1b>(id));
-		let inv_bits = inv_bits_witness.each_mut().map(|bit| bit.packed());
+	builder.pop_namespace();
+	Ok(digest)
+}
 
-		let mut inv = witness.new_column::<AESTowerField8b>(inv);
-		let inv = inv.as_mut_slice::<AESTowerField8b>();
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Permutation {
+	P,
+	Q,
+}
 
-		let mut output = witness.new_column::<AESTowerField8b>(output);
-		let output = output.as_mut_slice::<AESTowerField8b>();
+impl Permutation {
+	/// Number of columns each row is rotated left by in ShiftBytes.
+	const fn shift(self, row: usize) -> usize {
+		match self {
+			Self::P => row,
+			Self::Q => [1, 3, 5, 7, 0, 2, 4, 6][row],
+		}
+	}
 
-		for z in 0..(1 << log_size) {
-			inv[z] = input[z].invert_or_zero();
-			output[z] = s_box(input[z]);
-			let inv_bits_bases = ExtensionField::<BinaryField1b>::iter_bases(&inv[z]);
-			for (b, bit) in inv_bits_bases.enumerate() {
-				set_packed_slice(inv_bits[b], z, bit);
-			}
+	/// The round constant added to byte `i` of the state in the given round.
+	const fn round_constant(self, round: usize, i: usize) -> u8 {
+		let (col, row) = (i / 8, i % 8);
+		let constant = ((col << 4) ^ round) as u8;
+		match self {
+			Self::P if row == 0 => constant,
+			Self::P => 0,
+			Self::Q if row == 7 => 0xff ^ constant,
+			Self::Q => 0xff,
 		}
 	}
+}
+
+fn groestl_permutation(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	log_size: usize,
+	perm: Permutation,
+	input: [OracleId; STATE_SIZE],
+) -> Result<[OracleId; STATE_SIZE]> {
+	builder.push_namespace(name);
+	let output = permutation(builder, log_size, perm, &input.map(AffineByte::from_oracle))?;
+	let output =
+		array::try_from_fn(|i| output[i].materialize(builder, format!("output[{i}]"), log_size))?;
+
+	if cfg!(debug_assertions) {
+		builder.fill_witness([], move |witness, _| {
+			let inputs = input.try_map(|id| witness.get::<B8>(id))?;
+			let inputs = inputs.map(|col| col.as_slice::<B8>());
+			let outputs = output.try_map(|id| witness.get::<B8>(id))?;
+			let outputs = outputs.map(|col| col.as_slice::<B8>());
+
+			for z in 0..1 << log_size {
+				let state = array::from_fn(|i| tower_to_aes_byte(inputs[i][z]));
+				let expected = match perm {
+					Permutation::P => reference_p_permutation(&state),
+					Permutation::Q => reference_q_permutation(&state),
+				};
+				assert_eq!(expected, array::from_fn(|i| tower_to_aes_byte(outputs[i][z])));
+			}
+			Ok(())
+		})?;
+	}
 
-	builder.assert_zero("s_box", [input, inv], s_box_expr()?);
 	builder.pop_namespace();
 	Ok(output)
 }
 
-// TODO: Get rid of round constants and bake them into the constraints
-fn permutation_round_consts<U, F>(
-	builder: &mut ConstraintSystemBuilder<U, F>,
+/// The Grøstl compression function `P(h + m) + Q(m) + h`, without materializing the result.
+fn compress_block(
+	builder: &mut ConstraintSystemBuilder,
 	log_size: usize,
-	round_index: usize,
-	multiples_16: [OracleId; 8],
-	input: [OracleId; STATE_SIZE],
-) -> Result<[OracleId; 8], anyhow::Error>
-where
-	U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<AESTowerField8b> + Pod,
-	F: TowerField + ExtensionField<AESTowerField8b>,
-{
-	let round = transparent::constant(
-		builder,
-		format!("round_index[{round_index}]"),
-		log_size,
-		AESTowerField8b::new(round_index as u8),
-	)?;
+	state: &[AffineByte; STATE_SIZE],
+	message: &[AffineByte; STATE_SIZE],
+) -> Result<[AffineByte; STATE_SIZE]> {
+	let p_out = permutation(builder, log_size, Permutation::P, &add_states(state, message))?;
+	let q_out = permutation(builder, log_size, Permutation::Q, message)?;
+	Ok(add_states(&add_states(&p_out, &q_out), state))
+}
 
-	let round_consts: [OracleId; 8] = array::try_from_fn(|i| {
-		builder.add_linear_combination(
-			format!("round_consts[{i}]"),
-			log_size,
-			[
-				(input[8 * i], F::ONE),
-				(round, F::ONE),
-				(multiples_16[i], F::ONE),
-			],
-		)
-	})?;
-	if let Some(witness) = builder.witness() {
-		let mut round_consts_witness: [_; 8] =
-			round_consts.map(|id| witness.new_column::<AESTowerField8b>(id));
-		{
-			let input = input.try_map(|id| witness.get::<AESTowerField8b>(id))?;
-			let round = witness.get::<AESTowerField8b>(round)?;
-			let multiples_16 = multiples_16.try_map(|id| witness.get::<AESTowerField8b>(id))?;
-
-			round_consts_witness
-				.each_mut()
-				.map(|col| col.packed())
-				.par_iter_mut()
+/// Applies the rounds of P or Q, returning the output of the last round without materializing it.
+fn permutation(
+	builder: &mut ConstraintSystemBuilder,
+	log_size: usize,
+	perm: Permutation,
+	input: &[AffineByte; STATE_SIZE],
+) -> Result<[AffineByte; STATE_SIZE]> {
+	let mut state = input.clone();
+	for round in 0..N_ROUNDS {
+		builder.push_namespace(format!("{perm:?}_round[{round}]"));
+
+		let state_in: [OracleId; STATE_SIZE] = array::try_from_fn(|i| {
+			let round_constant = aes_byte_to_tower(perm.round_constant(round, i));
+			state[i]
+				.add(&AffineByte::constant(round_constant))
+				.materialize(builder, format!("state_in[{i}]"), log_size)
+		})?;
+		let sub_bytes: [OracleId; STATE_SIZE] = array::try_from_fn(|i| {
+			sub_byte(builder, format!("s_box[{i}]"), log_size, state_in[i])
+		})?;
+
+		let shift_bytes: [OracleId; STATE_SIZE] = array::from_fn(|i| {
+			let (col, row) = (i / 8, i % 8);
+			sub_bytes[8 * ((col + perm.shift(row)) % 8) + row]
+		});
+
+		state = array::from_fn(|i| {
+			let (col, row) = (i / 8, i % 8);
+			MIX_BYTES_ROW
+				.iter()
 				.enumerate()
-				.for_each(|(i, round_consts)| {
-					(
-						&mut **round_consts,
-						input[8 * i].packed(),
-						round.packed(),
-						multiples_16[i].packed(),
-					)
-						.into_par_iter()
-						.for_each(|(round_const, input, round, multiple16)| {
-							*round_const = (*input) + (*round) + (*multiple16);
-						});
-				});
-		}
+				.fold(AffineByte::default(), |acc, (k, &coeff)| {
+					let byte = AffineByte::from_oracle(shift_bytes[8 * col + (row + k) % 8]);
+					acc.add(&byte.scale(aes_byte_to_tower(coeff)))
+				})
+		});
+
+		builder.pop_namespace();
 	}
-	Ok(round_consts)
+	Ok(state)
 }
 
-/// Number of rounds in a Grøstl-256 compression
-const N_ROUNDS: usize = 10;
-
-const STATE_SIZE: usize = 64;
-
-/// Constant vector of the Rijndael S-box affine transformation.
-const SBOX_VEC: AESTowerField8b = AESTowerField8b::new(0x63);
-/// Matrix columns of the Rijndael S-box affine transformation.
-const SBOX_MATRIX: [AESTowerField8b; 8] = [
-	AESTowerField8b::new(0b00011111),
-	AESTowerField8b::new(0b00111110),
-	AESTowerField8b::new(0b01111100),
-	AESTowerField8b::new(0b11111000),
-	AESTowerField8b::new(0b11110001),
-	AESTowerField8b::new(0b11100011),
-	AESTowerField8b::new(0b11000111),
-	AESTowerField8b::new(0b10001111),
-];
-/// The first row of the circulant matrix defining the MixBytes step in Grøstl.
-const MIX_BYTES_VEC: [AESTowerField8b; 8] = [
-	AESTowerField8b::new(0x02),
-	AESTowerField8b::new(0x02),
-	AESTowerField8b::new(0x03),
-	AESTowerField8b::new(0x04),
-	AESTowerField8b::new(0x05),
-	AESTowerField8b::new(0x03),
-	AESTowerField8b::new(0x05),
-	AESTowerField8b::new(0x07),
-];
-
-fn mix_column_expr() -> ArithExpr<AESTowerField8b> {
-	let output = ArithExpr::<AESTowerField8b>::Var(0);
-	let mixed = MIX_BYTES_VEC
-		.into_iter()
-		.enumerate()
-		.map(|(i, coeff)| ArithExpr::Var(i + 1) * ArithExpr::Const(coeff))
-		.sum::<ArithExpr<_>>();
-	mixed - output
+fn add_states(
+	a: &[AffineByte; STATE_SIZE],
+	b: &[AffineByte; STATE_SIZE],
+) -> [AffineByte; STATE_SIZE] {
+	array::from_fn(|i| a[i].add(&b[i]))
 }
 
-fn s_box_expr<F: TowerField>() -> Result<ArithExpr<F>> {
-	let x = ArithExpr::Var(0);
-	let inv = ArithExpr::Var(1);
-
-	// x * inv == 1
-	let non_zero_case = x.clone() * inv.clone() - ArithExpr::one();
-
-	// x == 0 AND inv == 0
-	// TODO: Implement `mul_primitive` expression for ArithExpr
-	let beta = <F as ExtensionField<BinaryField1b>>::basis(1 << 3)?;
-	let zero_case = x + inv * ArithExpr::Const(beta);
+fn constant_state(bytes: &[u8; STATE_SIZE]) -> [AffineByte; STATE_SIZE] {
+	array::from_fn(|i| AffineByte::constant(aes_byte_to_tower(bytes[i])))
+}
 
-	// (x * inv == 1) OR (x == 0 AND inv == 0)
-	Ok(non_zero_case * zero_case)
+fn reference_p_permutation(state: &[u8; STATE_SIZE]) -> [u8; STATE_SIZE] {
+	let mut state = GroestlShortImpl::state_from_bytes(state);
+	GroestlShortImpl::p_perm(&mut state);
+	GroestlShortImpl::state_to_bytes(&state)
 }
 
-fn s_box(x: AESTowerField8b) -> AESTowerField8b {
-	#[rustfmt::skip]
-	const S_BOX: [u8; 256] = [
-		0x63, 0x7c, 0x77, 0x7b, 0xf2, 0x6b, 0x6f, 0xc5,
-		0x30, 0x01, 0x67, 0x2b, 0xfe, 0xd7, 0xab, 0x76,
-		0xca, 0x82, 0xc9, 0x7d, 0xfa, 0x59, 0x47, 0xf0,
-		0xad, 0xd4, 0xa2, 0xaf, 0x9c, 0xa4, 0x72, 0xc0,
-		0xb7, 0xfd, 0x93, 0x26, 0x36, 0x3f, 0xf7, 0xcc,
-		0x34, 0xa5, 0xe5, 0xf1, 0x71, 0xd8, 0x31, 0x15,
-		0x04, 0xc7, 0x23, 0xc3, 0x18, 0x96, 0x05, 0x9a,
-		0x07, 0x12, 0x80, 0xe2, 0xeb, 0x27, 0xb2, 0x75,
-		0x09, 0x83, 0x2c, 0x1a, 0x1b, 0x6e, 0x5a, 0xa0,
-		0x52, 0x3b, 0xd6, 0xb3, 0x29, 0xe3, 0x2f, 0x84,
-		0x53, 0xd1, 0x00, 0xed, 0x20, 0xfc, 0xb1, 0x5b,
-		0x6a, 0xcb, 0xbe, 0x39, 0x4a, 0x4c, 0x58, 0xcf,
-		0xd0, 0xef, 0xaa, 0xfb, 0x43, 0x4d, 0x33, 0x85,
-		0x45, 0xf9, 0x02, 0x7f, 0x50, 0x3c, 0x9f, 0xa8,
-		0x51, 0xa3, 0x40, 0x8f, 0x92, 0x9d, 0x38, 0xf5,
-		0xbc, 0xb6, 0xda, 0x21, 0x10, 0xff, 0xf3, 0xd2,
-		0xcd, 0x0c, 0x13, 0xec, 0x5f, 0x97, 0x44, 0x17,
-		0xc4, 0xa7, 0x7e, 0x3d, 0x64, 0x5d, 0x19, 0x73,
-		0x60, 0x81, 0x4f, 0xdc, 0x22, 0x2a, 0x90, 0x88,
-		0x46, 0xee, 0xb8, 0x14, 0xde, 0x5e, 0x0b, 0xdb,
-		0xe0, 0x32, 0x3a, 0x0a, 0x49, 0x06, 0x24, 0x5c,
-		0xc2, 0xd3, 0xac, 0x62, 0x91, 0x95, 0xe4, 0x79,
-		0xe7, 0xc8, 0x37, 0x6d, 0x8d, 0xd5, 0x4e, 0xa9,
-		0x6c, 0x56, 0xf4, 0xea, 0x65, 0x7a, 0xae, 0x08,
-		0xba, 0x78, 0x25, 0x2e, 0x1c, 0xa6, 0xb4, 0xc6,
-		0xe8, 0xdd, 0x74, 0x1f, 0x4b, 0xbd, 0x8b, 0x8a,
-		0x70, 0x3e, 0xb5, 0x66, 0x48, 0x03, 0xf6, 0x0e,
-		0x61, 0x35, 0x57, 0xb9, 0x86, 0xc1, 0x1d, 0x9e,
-		0xe1, 0xf8, 0x98, 0x11, 0x69, 0xd9, 0x8e, 0x94,
-		0x9b, 0x1e, 0x87, 0xe9, 0xce, 0x55, 0x28, 0xdf,
-		0x8c, 0xa1, 0x89, 0x0d, 0xbf, 0xe6, 0x42, 0x68,
-		0x41, 0x99, 0x2d, 0x0f, 0xb0, 0x54, 0xbb, 0x16,
-	];
-	let idx = u8::from(x) as usize;
-	AESTowerField8b::from(S_BOX[idx])
+/// Computes Q through the compression function, as `compress(0, m) = P(m) + Q(m)`.
+fn reference_q_permutation(state: &[u8; STATE_SIZE]) -> [u8; STATE_SIZE] {
+	let mut compressed = GroestlShortImpl::state_from_bytes(&[0; STATE_SIZE]);
+	GroestlShortImpl::compress(&mut compressed, state);
+	let compressed = GroestlShortImpl::state_to_bytes(&compressed);
+	let p_out = reference_p_permutation(state);
+	array::from_fn(|i| compressed[i] ^ p_out[i])
 }
 
+/// Number of rounds in a Grøstl-256 permutation.
+const N_ROUNDS: usize = 10;
+
+/// The first row of the circulant MixBytes matrix.
+const MIX_BYTES_ROW: [u8; 8] = [0x02, 0x02, 0x03, 0x04, 0x05, 0x03, 0x05, 0x07];
+
 #[cfg(test)]
 mod tests {
 	use binius_core::constraint_system::validate::validate_witness;
-	use binius_field::{arch::OptimalUnderlier, AESTowerField16b};
+	use binius_field::TowerField;
+	use binius_hash::{groestl::Groestl256ByteCompression, PseudoCompressionFunction};
+	use rand::{rngs::StdRng, Rng, SeedableRng};
+
+	use super::*;
+
+	fn committed_bytes<const N: usize>(
+		builder: &mut ConstraintSystemBuilder,
+		name: &str,
+		log_size: usize,
+		values: &[[u8; N]],
+	) -> [OracleId; N] {
+		let ids = builder.add_committed_multiple(name, log_size, B8::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			for (i, &id) in ids.iter().enumerate() {
+				let mut column = witness.new_column::<B8>(id);
+				for (out, value) in column.as_mut_slice::<B8>().iter_mut().zip(values) {
+					*out = aes_byte_to_tower(value[i]);
+				}
+			}
+		}
+		ids
+	}
 
-	use super::groestl_p_permutation;
-	use crate::builder::ConstraintSystemBuilder;
+	fn read_bytes<const N: usize>(
+		builder: &mut ConstraintSystemBuilder,
+		ids: [OracleId; N],
+	) -> Vec<[u8; N]> {
+		let witness = builder.witness().unwrap();
+		let columns = ids.map(|id| witness.get::<B8>(id).unwrap().as_slice::<B8>());
+		(0..columns[0].len())
+			.map(|z| array::from_fn(|i| tower_to_aes_byte(columns[i][z])))
+			.collect()
+	}
+
+	fn random_blocks<const N: usize>(rng: &mut StdRng, log_size: usize) -> Vec<[u8; N]> {
+		(0..1 << log_size)
+			.map(|_| array::from_fn(|_| rng.gen()))
+			.collect()
+	}
 
 	#[test]
-	fn test_groestl() {
+	fn test_groestl_permutations() {
+		let log_size = 4;
+		let mut rng = StdRng::seed_from_u64(0);
+		let states = random_blocks::<STATE_SIZE>(&mut rng, log_size);
+
 		let allocator = bumpalo::Bump::new();
-		let mut builder =
-			ConstraintSystemBuilder::<OptimalUnderlier, AESTowerField16b>::new_with_witness(
-				&allocator,
-			);
-		let log_size = 9;
-		let _state_out = groestl_p_permutation(&mut builder, log_size).unwrap();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let input = committed_bytes(&mut builder, "input", log_size, &states);
+		let p_out = groestl_p_permutation(&mut builder, "p", log_size, input).unwrap();
+		let q_out = groestl_q_permutation(&mut builder, "q", log_size, input).unwrap();
+
+		let p_values = read_bytes(&mut builder, p_out);
+		let q_values = read_bytes(&mut builder, q_out);
+		for ((state, p_out), q_out) in states.iter().zip(p_values).zip(q_values) {
+			assert_eq!(p_out, reference_p_permutation(state));
+			assert_eq!(q_out, reference_q_permutation(state));
+		}
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+
+	#[test]
+	fn test_groestl256_byte_compression() {
+		let log_size = 4;
+		let mut rng = StdRng::seed_from_u64(0);
+		let lefts = random_blocks::<DIGEST_SIZE>(&mut rng, log_size);
+		let rights = random_blocks::<DIGEST_SIZE>(&mut rng, log_size);
+
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let left = committed_bytes(&mut builder, "left", log_size, &lefts);
+		let right = committed_bytes(&mut builder, "right", log_size, &rights);
+		let digest =
+			groestl256_byte_compression(&mut builder, "compress", log_size, left, right).unwrap();
+
+		for ((left, right), digest) in lefts
+			.iter()
+			.zip(&rights)
+			.zip(read_bytes(&mut builder, digest))
+		{
+			let expected = Groestl256ByteCompression.compress([(*left).into(), (*right).into()]);
+			assert_eq!(digest, <[u8; DIGEST_SIZE]>::from(expected));
+		}
 
 		let witness = builder.take_witness().unwrap();
 		let constraint_system = builder.build().unwrap();
-		let boundaries = vec![];
-		validate_witness(&constraint_system, &boundaries, &witness).unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
 	}
 }
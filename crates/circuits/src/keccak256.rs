@@ -0,0 +1,702 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Keccak-256 and SHA3-256 hashing of variable-length messages.
+//!
+//! The gadgets hash a batch of `1 << log_size` messages of at most `max_message_len` bytes each
+//! with the Keccak sponge construction over [`keccakf`] permutations. Every message is allotted
+//! `max_message_len / RATE_BYTES + 1` blocks, which are absorbed by consecutive permutations. A
+//! message of `len` bytes is padded in its block `len / RATE_BYTES`, the last one it absorbs,
+//! and its remaining blocks are inactive.
+//!
+//! The permutations are laid out block-major, the permutation at row `b * (1 << log_size) + m`
+//! absorbs block `b` of message `m`. The state entering each permutation is the sum of the
+//! previous permutation output, obtained by shifting the output columns by `1 << log_size` rows,
+//! and the padded message block. The end of each message is marked by a committed mask of its
+//! bytes, which selects the position of the padding per row, and the digest is carried from the
+//! last block of each message back to its first block.
+
+use std::array;
+
+use anyhow::{anyhow, ensure};
+use binius_core::oracle::{OracleId, ProjectionVariant, ShiftVariant};
+use binius_field::{
+	BinaryField1b, BinaryField64b, BinaryField8b, ExtensionField, Field, TowerField,
+};
+use binius_math::ArithExpr;
+use bytemuck::pod_read_unaligned;
+use itertools::chain;
+
+use crate::{
+	builder::{types::F, ConstraintSystemBuilder},
+	keccakf::{keccakf, KeccakfState},
+	transparent::step_down,
+};
+
+type B1 = BinaryField1b;
+type B8 = BinaryField8b;
+type B64 = BinaryField64b;
+
+/// Number of message bytes absorbed per permutation by a sponge with 256-bit output.
+pub const RATE_BYTES: usize = 136;
+
+/// Number of bytes of a Keccak-256 or SHA3-256 digest.
+pub const DIGEST_BYTES: usize = 32;
+
+const RATE_LANES: usize = RATE_BYTES / 8;
+const DIGEST_LANES: usize = DIGEST_BYTES / 8;
+const STATE_LANES: usize = 25;
+
+/// Domain separation byte of the original Keccak padding, as used by Ethereum.
+const KECCAK_DOMAIN: u8 = 0x01;
+
+/// Domain separation byte of the FIPS 202 SHA-3 padding.
+const SHA3_DOMAIN: u8 = 0x06;
+
+/// The final bit of the pad10*1 padding, in the last byte of the padded block.
+const PADDING_END: u8 = 0x80;
+
+pub struct Keccak256Oracles {
+	/// The message bytes, committed block-major.
+	///
+	/// Column `i` at row `b * (1 << log_size) + m` holds byte `RATE_BYTES * b + i` of message `m`,
+	/// and is constrained to be zero beyond the end of the message.
+	pub message: [OracleId; RATE_BYTES],
+	/// Whether the byte at the same position of `message` belongs to the message.
+	///
+	/// The mask is all ones in the blocks before the last block of a message, a proper prefix in
+	/// its last block and all zeros after it, so the length of a message is the number of ones in
+	/// its rows.
+	pub message_mask: [OracleId; RATE_BYTES],
+	/// The digest as little-endian 64-bit lanes, one message per row.
+	pub digest: [OracleId; DIGEST_LANES],
+}
+
+/// Hashes `1 << log_size` messages of at most `max_message_len` bytes with Keccak-256, as used by
+/// Ethereum.
+///
+/// If the builder has a witness, `messages` must be provided. Missing messages are taken to be
+/// empty.
+pub fn keccak256(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	log_size: usize,
+	max_message_len: usize,
+	messages: Option<&[impl AsRef<[u8]>]>,
+) -> Result<Keccak256Oracles, anyhow::Error> {
+	sponge(builder, name, log_size, max_message_len, messages, KECCAK_DOMAIN)
+}
+
+/// Hashes `1 << log_size` messages of at most `max_message_len` bytes with SHA3-256.
+///
+/// If the builder has a witness, `messages` must be provided. Missing messages are taken to be
+/// empty.
+pub fn sha3_256(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	log_size: usize,
+	max_message_len: usize,
+	messages: Option<&[impl AsRef<[u8]>]>,
+) -> Result<Keccak256Oracles, anyhow::Error> {
+	sponge(builder, name, log_size, max_message_len, messages, SHA3_DOMAIN)
+}
+
+fn sponge(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	log_size: usize,
+	max_message_len: usize,
+	messages: Option<&[impl AsRef<[u8]>]>,
+	domain: u8,
+) -> Result<Keccak256Oracles, anyhow::Error> {
+	builder.push_namespace(name);
+
+	let n_blocks = max_message_len / RATE_BYTES + 1;
+	let log_blocks = n_blocks.next_power_of_two().ilog2() as usize;
+	let n_vars = log_size + log_blocks;
+
+	let message: [OracleId; RATE_BYTES] =
+		builder.add_committed_multiple("message", n_vars, B8::TOWER_LEVEL);
+	let message_mask: [OracleId; RATE_BYTES] =
+		builder.add_committed_multiple("message_mask", n_vars, B1::TOWER_LEVEL);
+
+	let lanes: [OracleId; RATE_LANES] = array::try_from_fn(|k| {
+		builder.add_linear_combination(
+			format!("lane[{k}]"),
+			n_vars,
+			(0..8).map(|j| {
+				let basis = <B64 as ExtensionField<B8>>::basis(j)
+					.expect("index is less than extension degree");
+				(message[8 * k + j], basis.into())
+			}),
+		)
+	})?;
+
+	// Whether a row absorbs a block of its message, and whether the row of the next block of the
+	// same message does. With a single block, every row is active and absorbs the last block.
+	let (active, next_active) = if log_blocks > 0 {
+		let active = builder.add_committed("active", n_vars, B1::TOWER_LEVEL);
+		let next_active = builder.add_shifted(
+			"next_active",
+			active,
+			1 << log_size,
+			n_vars,
+			ShiftVariant::LogicalRight,
+		)?;
+		(Some(active), Some(next_active))
+	} else {
+		(None, None)
+	};
+
+	let messages = messages
+		.map(|messages| collect_messages(messages, log_size, max_message_len))
+		.transpose()?;
+	let states = messages
+		.as_deref()
+		.map(|messages| absorb(messages, n_vars, domain, |_, _, _, _| {}));
+
+	let ids = chain!(message, message_mask, lanes, active, next_active).collect::<Vec<_>>();
+	builder.fill_witness(ids, move |witness, _| {
+		let messages = messages
+			.as_deref()
+			.ok_or_else(|| anyhow!("builder witness available and messages are not"))?;
+
+		let mut message_cols = message.map(|id| witness.new_column::<B8>(id));
+		let message_cols = message_cols.each_mut().map(|col| col.as_mut_slice::<u8>());
+		let mut mask_cols = message_mask.map(|id| witness.new_column::<B1>(id));
+		let mut mask_cols = mask_cols.each_mut().map(|col| col.as_mut_slice::<u8>());
+		let mut lane_cols = lanes.map(|id| witness.new_column::<B64>(id));
+		let lane_cols = lane_cols.each_mut().map(|col| col.as_mut_slice::<u64>());
+		let mut active_cols = [active, next_active]
+			.into_iter()
+			.flatten()
+			.map(|id| witness.new_column::<B1>(id))
+			.collect::<Vec<_>>();
+		let mut active_cols = active_cols
+			.iter_mut()
+			.map(|col| col.as_mut_slice::<u8>())
+			.collect::<Vec<_>>();
+
+		absorb(messages, n_vars, domain, |row, b, block, len| {
+			for (i, &byte) in block.iter().enumerate() {
+				message_cols[i][row] = byte;
+			}
+			for mask_col in &mut mask_cols[..len] {
+				set_bit(mask_col, row);
+			}
+			for k in 0..RATE_LANES {
+				lane_cols[k][row] = pod_read_unaligned(&block[8 * k..8 * (k + 1)]);
+			}
+			if let [active_col, next_active_col] = &mut active_cols[..] {
+				set_bit(active_col, row);
+				if b > 0 {
+					set_bit(next_active_col, row - (1 << log_size));
+				}
+			}
+		});
+		Ok(())
+	})?;
+
+	let keccakf_oracles = keccakf(builder, &states, n_vars)?;
+
+	// The output of the permutation absorbing the previous block of the same message.
+	let prev_output: Option<[OracleId; STATE_LANES]> = (log_blocks > 0)
+		.then(|| {
+			array::try_from_fn(|xy| {
+				builder.add_shifted(
+					format!("prev_output[{xy}]"),
+					keccakf_oracles.output[xy],
+					1 << log_size,
+					n_vars,
+					ShiftVariant::LogicalLeft,
+				)
+			})
+		})
+		.transpose()?;
+
+	// The digest of the message, carried from the row of its last block back to its first block.
+	let carry: Option<([OracleId; DIGEST_LANES], [OracleId; DIGEST_LANES])> = (log_blocks > 0)
+		.then(|| {
+			let carry: [OracleId; DIGEST_LANES] =
+				builder.add_committed_multiple("carry", n_vars, B64::TOWER_LEVEL);
+			let next_carry = array::try_from_fn(|k| {
+				builder.add_shifted(
+					format!("next_carry[{k}]"),
+					carry[k],
+					1 << log_size,
+					n_vars,
+					ShiftVariant::LogicalRight,
+				)
+			})?;
+			Ok::<_, anyhow::Error>((carry, next_carry))
+		})
+		.transpose()?;
+
+	let digest: [OracleId; DIGEST_LANES] = if let Some((carry, _)) = carry {
+		array::try_from_fn(|k| {
+			builder.add_projected(
+				format!("digest[{k}]"),
+				carry[k],
+				vec![F::ZERO; log_blocks],
+				ProjectionVariant::LastVars,
+			)
+		})?
+	} else {
+		array::from_fn(|k| keccakf_oracles.output[k])
+	};
+
+	let ids = chain!(
+		prev_output.into_iter().flatten(),
+		carry
+			.into_iter()
+			.flat_map(|(carry, next_carry)| chain!(carry, next_carry, digest)),
+	)
+	.collect::<Vec<_>>();
+	builder.fill_witness(ids, move |witness, _| {
+		let states = states
+			.as_deref()
+			.ok_or_else(|| anyhow!("builder witness available and messages are not"))?;
+		let outputs = states
+			.iter()
+			.map(|state| {
+				let mut state = state.0;
+				tiny_keccak::keccakf(&mut state);
+				state
+			})
+			.collect::<Vec<_>>();
+
+		if let Some(prev_output) = prev_output {
+			for (xy, id) in prev_output.into_iter().enumerate() {
+				let mut col = witness.new_column::<B64>(id);
+				let col = col.as_mut_slice::<u64>();
+				for (row, output) in outputs[..outputs.len() - (1 << log_size)]
+					.iter()
+					.enumerate()
+				{
+					col[row + (1 << log_size)] = output[xy];
+				}
+			}
+		}
+
+		if let (Some((carry, next_carry)), Some(active)) = (carry, active) {
+			let active = witness.get::<B1>(active)?.as_slice::<u8>();
+			let is_active = |row: usize| row < 1 << n_vars && get_bit(active, row);
+
+			// The lanes of the digest of each message, from the row of its last block.
+			let mut digests = vec![[0u64; DIGEST_LANES]; 1 << log_size];
+			for (row, output) in outputs.iter().enumerate() {
+				if is_active(row) && !is_active(row + (1 << log_size)) {
+					digests[row % (1 << log_size)].copy_from_slice(&output[..DIGEST_LANES]);
+				}
+			}
+
+			for k in 0..DIGEST_LANES {
+				let mut carry_col = witness.new_column::<B64>(carry[k]);
+				let carry_col = carry_col.as_mut_slice::<u64>();
+				let mut next_carry_col = witness.new_column::<B64>(next_carry[k]);
+				let next_carry_col = next_carry_col.as_mut_slice::<u64>();
+				let mut digest_col = witness.new_column::<B64>(digest[k]);
+				let digest_col = digest_col.as_mut_slice::<u64>();
+
+				for row in (0..1 << n_vars).filter(|&row| is_active(row)) {
+					carry_col[row] = digests[row % (1 << log_size)][k];
+					if row >= 1 << log_size {
+						next_carry_col[row - (1 << log_size)] = carry_col[row];
+					}
+				}
+				for (m, digest) in digests.iter().enumerate() {
+					digest_col[m] = digest[k];
+				}
+			}
+		}
+		Ok(())
+	})?;
+
+	// Blocks are absorbed from the first one on, and never beyond the allotted blocks.
+	if let (Some(active), Some(next_active)) = (active, next_active) {
+		let first_block = step_down(builder, "first_block", n_vars, 1 << log_size)?;
+		builder.assert_zero(
+			"first_block_active",
+			[active, first_block],
+			(ArithExpr::one() - ArithExpr::Var(0)) * ArithExpr::Var(1),
+		);
+		builder.assert_zero(
+			"active_monotone",
+			[next_active, active],
+			ArithExpr::Var(0) * (ArithExpr::one() - ArithExpr::Var(1)),
+		);
+		if n_blocks < 1 << log_blocks {
+			let all_blocks = step_down(builder, "all_blocks", n_vars, n_blocks << log_size)?;
+			builder.assert_zero(
+				"active_blocks",
+				[active, all_blocks],
+				ArithExpr::Var(0) * (ArithExpr::one() - ArithExpr::Var(1)),
+			);
+		}
+		builder.assert_zero(
+			"mask_inactive",
+			[message_mask[0], active],
+			ArithExpr::Var(0) * (ArithExpr::one() - ArithExpr::Var(1)),
+		);
+	}
+
+	// The mask is a prefix of each block, which is complete unless the block is the last one of
+	// its message.
+	for i in 1..RATE_BYTES {
+		builder.assert_zero(
+			format!("mask_prefix[{i}]"),
+			[message_mask[i], message_mask[i - 1]],
+			ArithExpr::Var(0) * (ArithExpr::one() - ArithExpr::Var(1)),
+		);
+	}
+	match next_active {
+		Some(next_active) => builder.assert_zero(
+			"mask_end",
+			[message_mask[RATE_BYTES - 1], next_active],
+			ArithExpr::Var(0) - ArithExpr::Var(1),
+		),
+		None => builder.assert_zero("mask_end", [message_mask[RATE_BYTES - 1]], ArithExpr::Var(0)),
+	}
+
+	// Message bytes beyond the end of the message are zero.
+	for (i, (&byte, &mask)) in message.iter().zip(&message_mask).enumerate() {
+		builder.assert_zero(
+			format!("message_end[{i}]"),
+			[byte, mask],
+			ArithExpr::Var(0) * (ArithExpr::one() - ArithExpr::Var(1)),
+		);
+	}
+
+	// The permutation absorbing block b is applied to the previous output plus the block, which
+	// is padded if it is the last block of its message.
+	for xy in 0..STATE_LANES {
+		let mut vars = Vec::new();
+		let input = var(&mut vars, keccakf_oracles.input[xy]);
+		let (active, is_last) = match (active, next_active) {
+			(Some(active), Some(next_active)) => {
+				let active = var(&mut vars, active);
+				(active.clone(), active - var(&mut vars, next_active))
+			}
+			_ => (ArithExpr::one(), ArithExpr::one()),
+		};
+
+		let mut absorbed = input;
+		if let Some(prev_output) = prev_output {
+			absorbed -= var(&mut vars, prev_output[xy]);
+		}
+		if xy < RATE_LANES {
+			absorbed -= var(&mut vars, lanes[xy]);
+
+			let mask = (8 * xy..8 * (xy + 1))
+				.map(|i| var(&mut vars, message_mask[i]))
+				.collect::<Vec<_>>();
+			let prev_mask = if xy > 0 {
+				var(&mut vars, message_mask[8 * xy - 1])
+			} else {
+				ArithExpr::one()
+			};
+			absorbed -= is_last * absorbed_padding(xy, prev_mask, &mask, domain);
+		}
+
+		builder.assert_zero(format!("absorb[{xy}]"), vars, absorbed * active);
+	}
+
+	// The digest is the output of the last block, carried back through the preceding blocks.
+	if let (Some((carry, next_carry)), Some(active), Some(next_active)) =
+		(carry, active, next_active)
+	{
+		for k in 0..DIGEST_LANES {
+			builder.assert_zero(
+				format!("carry[{k}]"),
+				[
+					carry[k],
+					keccakf_oracles.output[k],
+					active,
+					next_active,
+					next_carry[k],
+				],
+				ArithExpr::Var(0)
+					- (ArithExpr::Var(2) - ArithExpr::Var(3)) * ArithExpr::Var(1)
+					- ArithExpr::Var(3) * ArithExpr::Var(4),
+			);
+		}
+	}
+
+	builder.pop_namespace();
+
+	Ok(Keccak256Oracles {
+		message,
+		message_mask,
+		digest,
+	})
+}
+
+/// Checks the lengths of the messages and pads the batch with empty messages to `1 << log_size`.
+fn collect_messages(
+	messages: &[impl AsRef<[u8]>],
+	log_size: usize,
+	max_message_len: usize,
+) -> Result<Vec<Vec<u8>>, anyhow::Error> {
+	ensure!(
+		messages.len() <= 1 << log_size,
+		"at most {} messages can be hashed, got {}",
+		1 << log_size,
+		messages.len()
+	);
+
+	(0..1 << log_size)
+		.map(|m| {
+			let message = messages.get(m).map_or(&[][..], |msg| msg.as_ref());
+			ensure!(
+				message.len() <= max_message_len,
+				"message {m} has length {}, expected at most {max_message_len}",
+				message.len()
+			);
+			Ok(message.to_vec())
+		})
+		.collect()
+}
+
+/// Absorbs the blocks of the messages and returns the state entering the permutation of every
+/// row.
+///
+/// `on_block` is called with the row, the block index within its message, the block bytes before
+/// padding and the number of message bytes in the block.
+fn absorb(
+	messages: &[Vec<u8>],
+	n_vars: usize,
+	domain: u8,
+	mut on_block: impl FnMut(usize, usize, &[u8; RATE_BYTES], usize),
+) -> Vec<KeccakfState> {
+	let log_size = messages.len().ilog2() as usize;
+	let mut states = vec![KeccakfState::default(); 1 << n_vars];
+	for (m, message) in messages.iter().enumerate() {
+		let message_blocks = message.len() / RATE_BYTES + 1;
+		for b in 0..message_blocks {
+			let row = b << log_size | m;
+
+			let start = RATE_BYTES * b;
+			let end = (RATE_BYTES * (b + 1)).min(message.len());
+			let mut block = [0u8; RATE_BYTES];
+			block[..end - start].copy_from_slice(&message[start..end]);
+			on_block(row, b, &block, end - start);
+
+			if b == message_blocks - 1 {
+				for (byte, &pad) in block.iter_mut().zip(&padding_block(end - start, domain)) {
+					*byte ^= pad;
+				}
+			}
+
+			let mut state = if b == 0 {
+				[0u64; STATE_LANES]
+			} else {
+				let mut state = states[row - (1 << log_size)].0;
+				tiny_keccak::keccakf(&mut state);
+				state
+			};
+			for (k, lane) in state.iter_mut().take(RATE_LANES).enumerate() {
+				*lane ^= pod_read_unaligned::<u64>(&block[8 * k..8 * (k + 1)]);
+			}
+			states[row] = KeccakfState(state);
+		}
+	}
+	states
+}
+
+/// Adds an oracle to the list of constraint variables and returns the variable.
+fn var(vars: &mut Vec<OracleId>, id: OracleId) -> ArithExpr<F> {
+	vars.push(id);
+	ArithExpr::Var(vars.len() - 1)
+}
+
+/// The padding added to a last block of `last_block_len` message bytes, as pad10*1 with the
+/// domain separation bits.
+fn padding_block(last_block_len: usize, domain: u8) -> [u8; RATE_BYTES] {
+	let mut padding = [0u8; RATE_BYTES];
+	padding[last_block_len] ^= domain;
+	padding[RATE_BYTES - 1] ^= PADDING_END;
+	padding
+}
+
+/// The padding absorbed into lane `xy` of a last block, given the mask of the lane bytes and of
+/// the byte preceding the lane.
+///
+/// The domain separation byte is placed at the first byte outside of the mask.
+fn absorbed_padding(
+	xy: usize,
+	prev_mask: ArithExpr<F>,
+	mask: &[ArithExpr<F>],
+	domain: u8,
+) -> ArithExpr<F> {
+	let byte_const = |j: usize, byte: u8| {
+		let basis =
+			<B64 as ExtensionField<B8>>::basis(j).expect("index is less than extension degree");
+		ArithExpr::Const((basis * B64::from(B8::new(byte))).into())
+	};
+
+	let mut padding = ArithExpr::zero();
+	let mut prev_mask = prev_mask;
+	for (j, mask) in mask.iter().enumerate() {
+		padding += byte_const(j, domain) * (prev_mask - mask.clone());
+		if 8 * xy + j == RATE_BYTES - 1 {
+			padding += byte_const(j, PADDING_END);
+		}
+		prev_mask = mask.clone();
+	}
+	padding
+}
+
+fn set_bit(bits: &mut [u8], index: usize) {
+	bits[index / 8] |= 1 << (index % 8);
+}
+
+fn get_bit(bits: &[u8], index: usize) -> bool {
+	bits[index / 8] >> (index % 8) & 1 == 1
+}
+
+#[cfg(test)]
+mod tests {
+	use alloy_primitives::{b256, keccak256 as reference_keccak256};
+	use binius_core::constraint_system::validate::validate_witness;
+	use rand::{rngs::StdRng, Rng, SeedableRng};
+	use sha3::{Digest, Sha3_256};
+
+	use super::*;
+
+	fn hash_and_check(
+		log_size: usize,
+		messages: &[Vec<u8>],
+		max_message_len: usize,
+		sha3: bool,
+	) -> Vec<[u8; DIGEST_BYTES]> {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let oracles = if sha3 {
+			sha3_256(&mut builder, "sha3_256", log_size, max_message_len, Some(messages)).unwrap()
+		} else {
+			keccak256(&mut builder, "keccak256", log_size, max_message_len, Some(messages)).unwrap()
+		};
+
+		let digests = {
+			let witness = builder.witness().unwrap();
+			let lanes = oracles
+				.digest
+				.map(|id| witness.get::<B64>(id).unwrap().as_slice::<u64>());
+			(0..messages.len())
+				.map(|m| {
+					let mut digest = [0u8; DIGEST_BYTES];
+					for (k, lane) in lanes.iter().enumerate() {
+						digest[8 * k..8 * (k + 1)].copy_from_slice(&lane[m].to_le_bytes());
+					}
+					digest
+				})
+				.collect()
+		};
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+
+		digests
+	}
+
+	fn random_messages(rng: &mut StdRng, n: usize, message_len: usize) -> Vec<Vec<u8>> {
+		(0..n)
+			.map(|_| (0..message_len).map(|_| rng.gen()).collect())
+			.collect()
+	}
+
+	#[test]
+	fn test_keccak256_ethereum_vectors() {
+		let messages = vec![Vec::new(), Vec::new()];
+		let digests = hash_and_check(1, &messages, 0, false);
+		assert_eq!(
+			digests[0],
+			b256!("c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470").0
+		);
+
+		let messages = vec![b"hello".to_vec(), b"world".to_vec()];
+		let digests = hash_and_check(1, &messages, 5, false);
+		assert_eq!(
+			digests[0],
+			b256!("1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8").0
+		);
+		assert_eq!(digests[1], reference_keccak256(b"world").0);
+	}
+
+	#[test]
+	fn test_keccak256_multi_block() {
+		let mut rng = StdRng::seed_from_u64(0);
+		// Exercise a padding byte of 0x81, a full padding block and three absorbed blocks.
+		for message_len in [RATE_BYTES - 1, RATE_BYTES, 2 * RATE_BYTES + 17] {
+			let messages = random_messages(&mut rng, 2, message_len);
+			let digests = hash_and_check(1, &messages, message_len, false);
+			for (message, digest) in messages.iter().zip(digests) {
+				assert_eq!(digest, reference_keccak256(message).0);
+			}
+		}
+	}
+
+	#[test]
+	fn test_sha3_256() {
+		let mut rng = StdRng::seed_from_u64(0);
+		for message_len in [0, 200] {
+			let messages = random_messages(&mut rng, 2, message_len);
+			let digests = hash_and_check(1, &messages, message_len, true);
+			for (message, digest) in messages.iter().zip(digests) {
+				assert_eq!(digest[..], Sha3_256::digest(message)[..]);
+			}
+		}
+	}
+
+	#[test]
+	fn test_keccak256_mixed_lengths() {
+		let mut rng = StdRng::seed_from_u64(0);
+		// Three allotted blocks, so that the last block row of the batch is never active.
+		let lengths = [
+			0,
+			1,
+			RATE_BYTES - 1,
+			RATE_BYTES,
+			RATE_BYTES + 1,
+			2 * RATE_BYTES,
+			300,
+			17,
+		];
+		let messages = lengths
+			.iter()
+			.map(|&len| (0..len).map(|_| rng.gen()).collect::<Vec<u8>>())
+			.collect::<Vec<_>>();
+		let digests = hash_and_check(3, &messages, 300, false);
+		for (message, digest) in messages.iter().zip(digests) {
+			assert_eq!(digest, reference_keccak256(message).0);
+		}
+
+		// Missing messages are empty.
+		let messages = vec![b"hello".to_vec(), vec![0xff; RATE_BYTES]];
+		let digests = hash_and_check(2, &messages, 2 * RATE_BYTES - 1, false);
+		assert_eq!(digests[0], reference_keccak256(b"hello").0);
+		assert_eq!(digests[1], reference_keccak256([0xff; RATE_BYTES]).0);
+	}
+
+	#[test]
+	fn test_sha3_256_mixed_lengths() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let lengths = [200, 0, RATE_BYTES, 55];
+		let messages = lengths
+			.iter()
+			.map(|&len| (0..len).map(|_| rng.gen()).collect::<Vec<u8>>())
+			.collect::<Vec<_>>();
+		let digests = hash_and_check(2, &messages, 200, true);
+		for (message, digest) in messages.iter().zip(digests) {
+			assert_eq!(digest[..], Sha3_256::digest(message)[..]);
+		}
+	}
+
+	#[test]
+	fn test_message_too_long() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let messages = vec![vec![0u8; 5]];
+		assert!(keccak256(&mut builder, "keccak256", 1, 4, Some(&messages)).is_err());
+	}
+}
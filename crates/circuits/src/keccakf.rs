@@ -13,6 +13,7 @@ use binius_field::{
 };
 use binius_macros::arith_expr;
 use bytemuck::{pod_collect_to_vec, Pod};
+use itertools::chain;
 
 use crate::{
 	builder::{
@@ -57,31 +58,35 @@ pub fn keccakf(
 		)
 	})?;
 
-	if let Some(witness) = builder.witness() {
-		let mut round_consts_single =
-			round_consts_single.map(|id| witness.new_column::<BinaryField1b>(id));
-		let mut round_consts = round_consts.map(|id| witness.new_column::<BinaryField1b>(id));
+	builder.fill_witness(
+		round_consts_single.into_iter().chain(round_consts),
+		move |witness, _| {
+			let mut round_consts_single =
+				round_consts_single.map(|id| witness.new_column::<BinaryField1b>(id));
+			let mut round_consts = round_consts.map(|id| witness.new_column::<BinaryField1b>(id));
 
-		let round_consts_single_u64 = round_consts_single
-			.each_mut()
-			.map(|col| col.as_mut_slice::<u64>());
-		let round_consts_u64 = round_consts.each_mut().map(|col| col.as_mut_slice::<u64>());
+			let round_consts_single_u64 = round_consts_single
+				.each_mut()
+				.map(|col| col.as_mut_slice::<u64>());
+			let round_consts_u64 = round_consts.each_mut().map(|col| col.as_mut_slice::<u64>());
 
-		for row_within_permutation in 0..STATE_ROWS_PER_PERMUTATION {
-			for round_within_row in 0..ROUNDS_PER_STATE_ROW {
-				round_consts_single_u64[round_within_row][row_within_permutation] =
-					KECCAKF_RC[ROUNDS_PER_STATE_ROW * row_within_permutation + round_within_row];
+			for row_within_permutation in 0..STATE_ROWS_PER_PERMUTATION {
+				for round_within_row in 0..ROUNDS_PER_STATE_ROW {
+					round_consts_single_u64[round_within_row][row_within_permutation] = KECCAKF_RC
+						[ROUNDS_PER_STATE_ROW * row_within_permutation + round_within_row];
+				}
 			}
-		}
 
-		for state_row_idx in 0..1 << (internal_log_size - LOG_BIT_ROWS_PER_STATE_ROW) {
-			let row_within_permutation = state_row_idx % STATE_ROWS_PER_PERMUTATION;
-			for round_within_row in 0..ROUNDS_PER_STATE_ROW {
-				round_consts_u64[round_within_row][state_row_idx] =
-					KECCAKF_RC[ROUNDS_PER_STATE_ROW * row_within_permutation + round_within_row];
+			for state_row_idx in 0..1 << (internal_log_size - LOG_BIT_ROWS_PER_STATE_ROW) {
+				let row_within_permutation = state_row_idx % STATE_ROWS_PER_PERMUTATION;
+				for round_within_row in 0..ROUNDS_PER_STATE_ROW {
+					round_consts_u64[round_within_row][state_row_idx] = KECCAKF_RC
+						[ROUNDS_PER_STATE_ROW * row_within_permutation + round_within_row];
+				}
 			}
-		}
-	}
+			Ok(())
+		},
+	)?;
 
 	let selector_single = step_down(
 		builder,
@@ -209,11 +214,28 @@ pub fn keccakf(
 		)
 	})?;
 
-	if let Some(witness) = builder.witness() {
+	let ids = chain!(
+		input,
+		packed_state_in,
+		packed_state_out,
+		output,
+		state.as_flattened().iter().copied(),
+		c.as_flattened().iter().copied(),
+		d.as_flattened().iter().copied(),
+		c_shift.as_flattened().iter().copied(),
+		a_theta.as_flattened().iter().copied(),
+		b.as_flattened().iter().copied(),
+		next_state_in,
+		[selector_single, selector],
+	)
+	.collect::<Vec<_>>();
+	let input_witness = input_witness
+		.as_ref()
+		.map(|states| states.as_ref().to_vec());
+	builder.fill_witness(ids, move |witness, _| {
 		let input_witness = input_witness
-			.as_ref()
-			.ok_or_else(|| anyhow!("builder witness available and input witness is not"))?
-			.as_ref();
+			.as_deref()
+			.ok_or_else(|| anyhow!("builder witness available and input witness is not"))?;
 
 		let mut input = input.map(|id| witness.new_column::<BinaryField64b>(id));
 
@@ -387,7 +409,8 @@ pub fn keccakf(
 
 			assert_eq!(expected_output_this_perm, actual_output_this_perm);
 		}
-	}
+		Ok(())
+	})?;
 
 	let chi_iota = arith_expr!([s, b0, b1, b2, rc] = s - (rc + b0 + (1 - b1) * b2));
 	let chi = arith_expr!([s, b0, b1, b2] = s - (b0 + (1 - b1) * b2));
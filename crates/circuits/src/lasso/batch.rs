@@ -8,14 +8,14 @@ use binius_field::{
 };
 use itertools::Itertools;
 
-use super::lasso::lasso;
+use super::lasso::{lasso, LookupMapping};
 use crate::builder::{
 	types::{F, U},
 	ConstraintSystemBuilder,
 };
 pub struct LookupBatch {
 	lookup_us: Vec<Vec<OracleId>>,
-	u_to_t_mappings: Vec<Vec<usize>>,
+	u_to_t_mappings: Vec<LookupMapping>,
 	lookup_col_lens: Vec<usize>,
 	lookup_t: Vec<OracleId>,
 	executed: bool,
@@ -40,15 +40,20 @@ impl LookupBatch {
 		}
 	}
 
+	/// Adds a lookup of the first `lookup_u_col_len` rows of `lookup_u` to the batch.
+	///
+	/// Returns the mapping of the looked up rows to table indices, which the witness fill of
+	/// `lookup_u` sets.
 	pub fn add(
 		&mut self,
 		lookup_u: impl IntoIterator<Item = OracleId>,
-		u_to_t_mapping: Vec<usize>,
 		lookup_u_col_len: usize,
-	) {
+	) -> LookupMapping {
+		let u_to_t_mapping = LookupMapping::new();
 		self.lookup_us.push(lookup_u.into_iter().collect_vec());
-		self.u_to_t_mappings.push(u_to_t_mapping);
+		self.u_to_t_mappings.push(u_to_t_mapping.clone());
 		self.lookup_col_lens.push(lookup_u_col_len);
+		u_to_t_mapping
 	}
 
 	pub fn execute<FC>(mut self, builder: &mut ConstraintSystemBuilder) -> Result<(), anyhow::Error>
@@ -69,56 +69,61 @@ pub fn byte_sliced_add_carryfree<Level: TowerLevel<Data<OracleId>: Sized>>(
 	let sum = Level::join(&lower_sum, &upper_sum);
 
 	// Everything below is for test assertions
-	if let Some(witness) = builder.witness() {
-		let x_bytes_as_u8 = (0..Level::WIDTH).map(|this_byte_idx| {
-			let this_byte_oracle = x_in[this_byte_idx];
-			witness
-				.get::<B8>(this_byte_oracle)
-				.unwrap()
-				.as_slice::<u8>()
-		});
-
-		let y_bytes_as_u8 = (0..Level::WIDTH).map(|this_byte_idx| {
-			let this_byte_oracle = y_in[this_byte_idx];
-			witness
-				.get::<B8>(this_byte_oracle)
-				.unwrap()
-				.as_slice::<u8>()
-		});
-
-		let sum_bytes_as_u8 = (0..Level::WIDTH).map(|this_byte_idx| {
-			let this_byte_oracle = sum[this_byte_idx];
-			witness
-				.get::<B8>(this_byte_oracle)
-				.unwrap()
-				.as_slice::<u8>()
-		});
-
-		let cin_as_u8_packed = witness.get::<B1>(carry_in).unwrap().as_slice::<u8>();
-
-		for row_idx in 0..1 << log_size {
-			let mut x_u512 = U512::ZERO;
-			for (byte_idx, x_byte_column) in x_bytes_as_u8.clone().enumerate() {
-				x_u512 |= U512::from(x_byte_column[row_idx]) << (8 * byte_idx);
+	builder.fill_witness([], {
+		let (x_in, y_in, sum) =
+			(x_in.as_ref().to_vec(), y_in.as_ref().to_vec(), sum.as_ref().to_vec());
+		move |witness, _| {
+			let x_bytes_as_u8 = (0..Level::WIDTH).map(|this_byte_idx| {
+				let this_byte_oracle = x_in[this_byte_idx];
+				witness
+					.get::<B8>(this_byte_oracle)
+					.unwrap()
+					.as_slice::<u8>()
+			});
+
+			let y_bytes_as_u8 = (0..Level::WIDTH).map(|this_byte_idx| {
+				let this_byte_oracle = y_in[this_byte_idx];
+				witness
+					.get::<B8>(this_byte_oracle)
+					.unwrap()
+					.as_slice::<u8>()
+			});
+
+			let sum_bytes_as_u8 = (0..Level::WIDTH).map(|this_byte_idx| {
+				let this_byte_oracle = sum[this_byte_idx];
+				witness
+					.get::<B8>(this_byte_oracle)
+					.unwrap()
+					.as_slice::<u8>()
+			});
+
+			let cin_as_u8_packed = witness.get::<B1>(carry_in).unwrap().as_slice::<u8>();
+
+			for row_idx in 0..1 << log_size {
+				let mut x_u512 = U512::ZERO;
+				for (byte_idx, x_byte_column) in x_bytes_as_u8.clone().enumerate() {
+					x_u512 |= U512::from(x_byte_column[row_idx]) << (8 * byte_idx);
+				}
+
+				let mut y_u512 = U512::ZERO;
+				for (byte_idx, y_byte_column) in y_bytes_as_u8.clone().enumerate() {
+					y_u512 |= U512::from(y_byte_column[row_idx]) << (8 * byte_idx);
+				}
+
+				let mut sum_u512 = U512::ZERO;
+				for (byte_idx, sum_byte_column) in sum_bytes_as_u8.clone().enumerate() {
+					sum_u512 |= U512::from(sum_byte_column[row_idx]) << (8 * byte_idx);
+				}
+
+				let cin_u512 = U512::from((cin_as_u8_packed[row_idx / 8] >> (row_idx % 8)) & 1);
+
+				let expected_sum_u512 = x_u512 + y_u512 + cin_u512;
+
+				assert_eq!(expected_sum_u512, sum_u512);
 			}
-
-			let mut y_u512 = U512::ZERO;
-			for (byte_idx, y_byte_column) in y_bytes_as_u8.clone().enumerate() {
-				y_u512 |= U512::from(y_byte_column[row_idx]) << (8 * byte_idx);
-			}
-
-			let mut sum_u512 = U512::ZERO;
-			for (byte_idx, sum_byte_column) in sum_bytes_as_u8.clone().enumerate() {
-				sum_u512 |= U512::from(sum_byte_column[row_idx]) << (8 * byte_idx);
-			}
-
-			let cin_u512 = U512::from((cin_as_u8_packed[row_idx / 8] >> (row_idx % 8)) & 1);
-
-			let expected_sum_u512 = x_u512 + y_u512 + cin_u512;
-
-			assert_eq!(expected_sum_u512, sum_u512);
+			Ok(())
 		}
-	}
+	})?;
 	builder.pop_namespace();
 
 	Ok(sum)
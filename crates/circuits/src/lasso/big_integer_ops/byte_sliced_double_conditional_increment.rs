@@ -68,54 +68,60 @@ pub fn byte_sliced_double_conditional_increment<Level: TowerLevel<Data<OracleId>
 	let sum = Level::join(&lower_sum, &upper_sum);
 
 	// Everything below is for test assertions
-	if let Some(witness) = builder.witness() {
-		let x_bytes_as_u8 = (0..Level::WIDTH).map(|this_byte_idx| {
-			let this_byte_oracle = x_in[this_byte_idx];
-			witness
-				.get::<B8>(this_byte_oracle)
-				.unwrap()
-				.as_slice::<u8>()
-		});
-
-		let sum_bytes_as_u8 = (0..Level::WIDTH).map(|this_byte_idx| {
-			let this_byte_oracle = sum[this_byte_idx];
-			witness
-				.get::<B8>(this_byte_oracle)
-				.unwrap()
-				.as_slice::<u8>()
-		});
-
-		let first_cin_as_u8_packed = witness.get::<B1>(first_carry_in).unwrap().as_slice::<u8>();
-		let second_cin_as_u8_packed = witness.get::<B1>(second_carry_in).unwrap().as_slice::<u8>();
-
-		let cout_as_u8_packed = witness.get::<B1>(carry_out).unwrap().as_slice::<u8>();
-
-		for row_idx in 0..1 << log_size {
-			let mut x_u512 = U512::ZERO;
-			for (byte_idx, x_byte_column) in x_bytes_as_u8.clone().enumerate() {
-				x_u512 |= U512::from(x_byte_column[row_idx]) << (8 * byte_idx);
+	builder.fill_witness([], {
+		let (x_in, sum) = (x_in.as_ref().to_vec(), sum.as_ref().to_vec());
+		move |witness, _| {
+			let x_bytes_as_u8 = (0..Level::WIDTH).map(|this_byte_idx| {
+				let this_byte_oracle = x_in[this_byte_idx];
+				witness
+					.get::<B8>(this_byte_oracle)
+					.unwrap()
+					.as_slice::<u8>()
+			});
+
+			let sum_bytes_as_u8 = (0..Level::WIDTH).map(|this_byte_idx| {
+				let this_byte_oracle = sum[this_byte_idx];
+				witness
+					.get::<B8>(this_byte_oracle)
+					.unwrap()
+					.as_slice::<u8>()
+			});
+
+			let first_cin_as_u8_packed =
+				witness.get::<B1>(first_carry_in).unwrap().as_slice::<u8>();
+			let second_cin_as_u8_packed =
+				witness.get::<B1>(second_carry_in).unwrap().as_slice::<u8>();
+
+			let cout_as_u8_packed = witness.get::<B1>(carry_out).unwrap().as_slice::<u8>();
+
+			for row_idx in 0..1 << log_size {
+				let mut x_u512 = U512::ZERO;
+				for (byte_idx, x_byte_column) in x_bytes_as_u8.clone().enumerate() {
+					x_u512 |= U512::from(x_byte_column[row_idx]) << (8 * byte_idx);
+				}
+
+				let mut sum_u512 = U512::ZERO;
+				for (byte_idx, sum_byte_column) in sum_bytes_as_u8.clone().enumerate() {
+					sum_u512 |= U512::from(sum_byte_column[row_idx]) << (8 * byte_idx);
+				}
+
+				let first_cin_u512 =
+					U512::from((first_cin_as_u8_packed[row_idx / 8] >> (row_idx % 8)) & 1);
+
+				let second_cin_u512 =
+					U512::from((second_cin_as_u8_packed[row_idx / 8] >> (row_idx % 8)) & 1);
+
+				let cout_u512 = U512::from((cout_as_u8_packed[row_idx / 8] >> (row_idx % 8)) & 1);
+
+				let expected_sum_u128 = x_u512 + first_cin_u512 + second_cin_u512;
+
+				let sum_according_to_witness = sum_u512 | (cout_u512 << (Level::WIDTH * 8));
+
+				assert_eq!(expected_sum_u128, sum_according_to_witness);
 			}
-
-			let mut sum_u512 = U512::ZERO;
-			for (byte_idx, sum_byte_column) in sum_bytes_as_u8.clone().enumerate() {
-				sum_u512 |= U512::from(sum_byte_column[row_idx]) << (8 * byte_idx);
-			}
-
-			let first_cin_u512 =
-				U512::from((first_cin_as_u8_packed[row_idx / 8] >> (row_idx % 8)) & 1);
-
-			let second_cin_u512 =
-				U512::from((second_cin_as_u8_packed[row_idx / 8] >> (row_idx % 8)) & 1);
-
-			let cout_u512 = U512::from((cout_as_u8_packed[row_idx / 8] >> (row_idx % 8)) & 1);
-
-			let expected_sum_u128 = x_u512 + first_cin_u512 + second_cin_u512;
-
-			let sum_according_to_witness = sum_u512 | (cout_u512 << (Level::WIDTH * 8));
-
-			assert_eq!(expected_sum_u128, sum_according_to_witness);
+			Ok(())
 		}
-	}
+	})?;
 	builder.pop_namespace();
 
 	Ok((carry_out, sum))
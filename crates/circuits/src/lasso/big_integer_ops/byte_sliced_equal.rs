@@ -0,0 +1,107 @@
+// Copyright 2025 Irreducible Inc.
+
+use anyhow::Result;
+use binius_core::oracle::OracleId;
+use binius_field::{tower_levels::TowerLevel, BinaryField1b, BinaryField8b, Field, TowerField};
+use binius_macros::arith_expr;
+
+use crate::builder::ConstraintSystemBuilder;
+
+type B1 = BinaryField1b;
+type B8 = BinaryField8b;
+
+/// Returns a bit column that is set exactly on the rows where `x = y`.
+///
+/// Each byte pair is compared by committing the inverse of its difference, and the per-byte
+/// results are combined pairwise up the tower, so every constraint has degree 2.
+pub fn byte_sliced_equal<Level: TowerLevel<Data<OracleId>: Sized>>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	x_in: &Level::Data<OracleId>,
+	y_in: &Level::Data<OracleId>,
+	log_size: usize,
+) -> Result<OracleId, anyhow::Error> {
+	builder.push_namespace(name);
+
+	if Level::WIDTH == 1 {
+		let equal = builder.add_committed("equal", log_size, B1::TOWER_LEVEL);
+		let inverse = builder.add_committed("inverse", log_size, B8::TOWER_LEVEL);
+
+		builder.fill_witness([equal, inverse], {
+			let (x_in, y_in) = (x_in.as_ref().to_vec(), y_in.as_ref().to_vec());
+			move |witness, _| {
+				let x_as_u8 = witness.get::<B8>(x_in[0])?.as_slice::<u8>();
+				let y_as_u8 = witness.get::<B8>(y_in[0])?.as_slice::<u8>();
+
+				let mut equal_column = witness.new_column::<B1>(equal);
+				let mut inverse_column = witness.new_column::<B8>(inverse);
+				let equal_as_u8_packed = equal_column.as_mut_slice::<u8>();
+				let inverse_as_u8 = inverse_column.as_mut_slice::<u8>();
+
+				for row_idx in 0..1 << log_size {
+					let difference = B8::new(x_as_u8[row_idx]) - B8::new(y_as_u8[row_idx]);
+					inverse_as_u8[row_idx] = difference.invert().unwrap_or(B8::ZERO).val();
+					equal_as_u8_packed[row_idx / 8] |=
+						((difference == B8::ZERO) as u8) << (row_idx % 8);
+				}
+				Ok(())
+			}
+		})?;
+
+		// equal = 1 - (x - y) * inverse, and equal * (x - y) = 0
+		builder.assert_zero(
+			"equal_definition",
+			[equal, x_in[0], y_in[0], inverse],
+			arith_expr!([e, x, y, inv] = e - 1 + (x - y) * inv).convert_field(),
+		);
+		builder.assert_zero(
+			"equal_nonzero_difference",
+			[equal, x_in[0], y_in[0]],
+			arith_expr!([e, x, y] = e * (x - y)).convert_field(),
+		);
+
+		builder.pop_namespace();
+		return Ok(equal);
+	}
+
+	let (lower_half_x, upper_half_x) = Level::split(x_in);
+	let (lower_half_y, upper_half_y) = Level::split(y_in);
+
+	let lower_equal = byte_sliced_equal::<Level::Base>(
+		builder,
+		format!("lower equal {}b", Level::Base::WIDTH),
+		lower_half_x,
+		lower_half_y,
+		log_size,
+	)?;
+	let upper_equal = byte_sliced_equal::<Level::Base>(
+		builder,
+		format!("upper equal {}b", Level::Base::WIDTH),
+		upper_half_x,
+		upper_half_y,
+		log_size,
+	)?;
+
+	let equal = builder.add_committed("equal", log_size, B1::TOWER_LEVEL);
+	builder.fill_witness([equal], move |witness, _| {
+		let lower_as_u8_packed = witness.get::<B1>(lower_equal)?.as_slice::<u8>();
+		let upper_as_u8_packed = witness.get::<B1>(upper_equal)?.as_slice::<u8>();
+		let mut equal_column = witness.new_column::<B1>(equal);
+		for (equal, (lower, upper)) in equal_column
+			.as_mut_slice::<u8>()
+			.iter_mut()
+			.zip(lower_as_u8_packed.iter().zip(upper_as_u8_packed))
+		{
+			*equal = lower & upper;
+		}
+		Ok(())
+	})?;
+	builder.assert_zero(
+		"equal_conjunction",
+		[equal, lower_equal, upper_equal],
+		arith_expr!([e, lo, hi] = e - lo * hi).convert_field(),
+	);
+
+	builder.pop_namespace();
+	Ok(equal)
+}
@@ -0,0 +1,34 @@
+// Copyright 2025 Irreducible Inc.
+
+use anyhow::Result;
+use binius_core::oracle::OracleId;
+use binius_field::tower_levels::TowerLevel;
+
+use super::byte_sliced_sub;
+use crate::{builder::ConstraintSystemBuilder, lasso::batch::LookupBatch};
+
+/// Returns a bit column that is set exactly on the rows where `x < y`.
+///
+/// The comparison is the borrow out of the subtraction `x - y`.
+pub fn byte_sliced_less_than<Level: TowerLevel<Data<OracleId>: Sized>>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	x_in: &Level::Data<OracleId>,
+	y_in: &Level::Data<OracleId>,
+	zero_carry_oracle: OracleId,
+	log_size: usize,
+	lookup_batch_add: &mut LookupBatch,
+) -> Result<OracleId, anyhow::Error> {
+	builder.push_namespace(name);
+	let (borrow_out, _difference) = byte_sliced_sub::<Level>(
+		builder,
+		"x - y",
+		x_in,
+		y_in,
+		zero_carry_oracle,
+		log_size,
+		lookup_batch_add,
+	)?;
+	builder.pop_namespace();
+	Ok(borrow_out)
+}
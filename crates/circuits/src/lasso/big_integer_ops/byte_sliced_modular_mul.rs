@@ -7,6 +7,7 @@ use binius_field::{
 	tower_levels::TowerLevel, underlier::WithUnderlier, BinaryField32b, BinaryField8b, TowerField,
 };
 use binius_macros::arith_expr;
+use itertools::chain;
 
 use super::{byte_sliced_add_carryfree, byte_sliced_mul};
 use crate::{
@@ -50,6 +51,57 @@ pub fn byte_sliced_modular_mul<LevelIn: TowerLevel, LevelOut: TowerLevel<Base =
 	// This batch WILL NOT get executed if we are instantiating it for 8b mul
 	let mut lookup_batch_dci = LookupBatch::new([lookup_t_dci]);
 
+	let remainder = byte_sliced_modular_mul_with_batches::<LevelIn, LevelOut>(
+		builder,
+		"modular mul",
+		mult_a,
+		mult_b,
+		modulus_input,
+		log_size,
+		zero_byte_oracle,
+		zero_carry_oracle,
+		&mut lookup_batch_mul,
+		&mut lookup_batch_add,
+		&mut lookup_batch_add_carryfree,
+		&mut lookup_batch_dci,
+	)?;
+
+	lookup_batch_mul.execute::<BinaryField32b>(builder)?;
+	lookup_batch_add.execute::<BinaryField32b>(builder)?;
+	lookup_batch_add_carryfree.execute::<BinaryField32b>(builder)?;
+
+	if LevelIn::WIDTH != 1 {
+		lookup_batch_dci.execute::<BinaryField32b>(builder)?;
+	}
+
+	builder.pop_namespace();
+	Ok(remainder)
+}
+
+/// Same as [`byte_sliced_modular_mul`], but records its lookups into caller-owned batches.
+///
+/// This lets a gadget performing many modular multiplications share a single set of lookup
+/// tables; the caller is responsible for executing the batches once all operations are added.
+#[allow(clippy::too_many_arguments)]
+pub fn byte_sliced_modular_mul_with_batches<
+	LevelIn: TowerLevel,
+	LevelOut: TowerLevel<Base = LevelIn>,
+>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	mult_a: &LevelIn::Data<OracleId>,
+	mult_b: &LevelIn::Data<OracleId>,
+	modulus_input: &[u8],
+	log_size: usize,
+	zero_byte_oracle: OracleId,
+	zero_carry_oracle: OracleId,
+	lookup_batch_mul: &mut LookupBatch,
+	lookup_batch_add: &mut LookupBatch,
+	lookup_batch_add_carryfree: &mut LookupBatch,
+	lookup_batch_dci: &mut LookupBatch,
+) -> Result<LevelIn::Data<OracleId>, anyhow::Error> {
+	builder.push_namespace(name);
+
 	let mut quotient = LevelIn::default();
 	let mut remainder = LevelIn::default();
 	let mut modulus = LevelIn::default();
@@ -75,71 +127,84 @@ pub fn byte_sliced_modular_mul<LevelIn: TowerLevel, LevelOut: TowerLevel<Base =
 		mult_b,
 		log_size,
 		zero_carry_oracle,
-		&mut lookup_batch_mul,
-		&mut lookup_batch_add,
-		&mut lookup_batch_dci,
+		lookup_batch_mul,
+		lookup_batch_add,
+		lookup_batch_dci,
 	)?;
 
-	if let Some(witness) = builder.witness() {
-		let ab_bytes_as_u8: Vec<_> = (0..LevelOut::WIDTH)
-			.map(|this_byte_idx| {
-				let this_byte_oracle = ab[this_byte_idx];
-				witness
-					.get::<B8>(this_byte_oracle)
-					.unwrap()
-					.as_slice::<u8>()
-			})
-			.collect();
-
-		let mut quotient: Vec<_> = (0..LevelIn::WIDTH)
-			.map(|this_byte_idx| {
-				let this_byte_oracle = quotient[this_byte_idx];
-				witness.new_column::<B8>(this_byte_oracle)
-			})
-			.collect();
-
-		let mut remainder: Vec<_> = (0..LevelIn::WIDTH)
-			.map(|this_byte_idx| {
-				let this_byte_oracle: usize = remainder[this_byte_idx];
-				witness.new_column::<B8>(this_byte_oracle)
-			})
-			.collect();
-
-		let mut modulus: Vec<_> = (0..LevelIn::WIDTH)
-			.map(|this_byte_idx| {
-				let this_byte_oracle = modulus[this_byte_idx];
-				witness.new_column::<B8>(this_byte_oracle)
-			})
-			.collect();
-
-		let mut modulus_u512 = U512::ZERO;
-
-		for (byte_idx, modulus_byte_column) in modulus.iter_mut().enumerate() {
-			let modulus_byte_column_u8 = modulus_byte_column.as_mut_slice::<u8>();
-			modulus_u512 |= U512::from(modulus_input[byte_idx]) << (8 * byte_idx);
-			modulus_byte_column_u8.fill(modulus_input[byte_idx]);
-		}
-
-		for row_idx in 0..1 << log_size {
-			let mut ab_u512 = U512::ZERO;
-			for (byte_idx, ab_byte_column) in ab_bytes_as_u8.iter().enumerate() {
-				ab_u512 |= U512::from(ab_byte_column[row_idx]) << (8 * byte_idx);
-			}
-
-			let quotient_u512 = ab_u512 / modulus_u512;
-			let remainder_u512 = ab_u512 % modulus_u512;
-
-			for (byte_idx, quotient_byte_column) in quotient.iter_mut().enumerate() {
-				let quotient_byte_column_u8 = quotient_byte_column.as_mut_slice::<u8>();
-				quotient_byte_column_u8[row_idx] = quotient_u512.byte(byte_idx);
+	builder.fill_witness(
+		chain!(quotient.as_ref(), remainder.as_ref(), modulus.as_ref()).copied(),
+		{
+			let (ab, quotient, remainder, modulus) = (
+				ab.as_ref().to_vec(),
+				quotient.as_ref().to_vec(),
+				remainder.as_ref().to_vec(),
+				modulus.as_ref().to_vec(),
+			);
+			let modulus_input = modulus_input.to_vec();
+			move |witness, _| {
+				let ab_bytes_as_u8: Vec<_> = (0..LevelOut::WIDTH)
+					.map(|this_byte_idx| {
+						let this_byte_oracle = ab[this_byte_idx];
+						witness
+							.get::<B8>(this_byte_oracle)
+							.unwrap()
+							.as_slice::<u8>()
+					})
+					.collect();
+
+				let mut quotient: Vec<_> = (0..LevelIn::WIDTH)
+					.map(|this_byte_idx| {
+						let this_byte_oracle = quotient[this_byte_idx];
+						witness.new_column::<B8>(this_byte_oracle)
+					})
+					.collect();
+
+				let mut remainder: Vec<_> = (0..LevelIn::WIDTH)
+					.map(|this_byte_idx| {
+						let this_byte_oracle: usize = remainder[this_byte_idx];
+						witness.new_column::<B8>(this_byte_oracle)
+					})
+					.collect();
+
+				let mut modulus: Vec<_> = (0..LevelIn::WIDTH)
+					.map(|this_byte_idx| {
+						let this_byte_oracle = modulus[this_byte_idx];
+						witness.new_column::<B8>(this_byte_oracle)
+					})
+					.collect();
+
+				let mut modulus_u512 = U512::ZERO;
+
+				for (byte_idx, modulus_byte_column) in modulus.iter_mut().enumerate() {
+					let modulus_byte_column_u8 = modulus_byte_column.as_mut_slice::<u8>();
+					modulus_u512 |= U512::from(modulus_input[byte_idx]) << (8 * byte_idx);
+					modulus_byte_column_u8.fill(modulus_input[byte_idx]);
+				}
+
+				for row_idx in 0..1 << log_size {
+					let mut ab_u512 = U512::ZERO;
+					for (byte_idx, ab_byte_column) in ab_bytes_as_u8.iter().enumerate() {
+						ab_u512 |= U512::from(ab_byte_column[row_idx]) << (8 * byte_idx);
+					}
+
+					let quotient_u512 = ab_u512 / modulus_u512;
+					let remainder_u512 = ab_u512 % modulus_u512;
+
+					for (byte_idx, quotient_byte_column) in quotient.iter_mut().enumerate() {
+						let quotient_byte_column_u8 = quotient_byte_column.as_mut_slice::<u8>();
+						quotient_byte_column_u8[row_idx] = quotient_u512.byte(byte_idx);
+					}
+
+					for (byte_idx, remainder_byte_column) in remainder.iter_mut().enumerate() {
+						let remainder_byte_column_u8 = remainder_byte_column.as_mut_slice::<u8>();
+						remainder_byte_column_u8[row_idx] = remainder_u512.byte(byte_idx);
+					}
+				}
+				Ok(())
 			}
-
-			for (byte_idx, remainder_byte_column) in remainder.iter_mut().enumerate() {
-				let remainder_byte_column_u8 = remainder_byte_column.as_mut_slice::<u8>();
-				remainder_byte_column_u8[row_idx] = remainder_u512.byte(byte_idx);
-			}
-		}
-	}
+		},
+	)?;
 
 	let qm = byte_sliced_mul::<LevelIn, LevelOut>(
 		builder,
@@ -148,9 +213,9 @@ pub fn byte_sliced_modular_mul<LevelIn: TowerLevel, LevelOut: TowerLevel<Base =
 		&modulus,
 		log_size,
 		zero_carry_oracle,
-		&mut lookup_batch_mul,
-		&mut lookup_batch_add,
-		&mut lookup_batch_dci,
+		lookup_batch_mul,
+		lookup_batch_add,
+		lookup_batch_dci,
 	)?;
 
 	let mut repeating_zero = LevelIn::default();
@@ -165,18 +230,10 @@ pub fn byte_sliced_modular_mul<LevelIn: TowerLevel, LevelOut: TowerLevel<Base =
 		&LevelOut::join(&remainder, &repeating_zero),
 		zero_carry_oracle,
 		log_size,
-		&mut lookup_batch_add,
-		&mut lookup_batch_add_carryfree,
+		lookup_batch_add,
+		lookup_batch_add_carryfree,
 	)?;
 
-	lookup_batch_mul.execute::<BinaryField32b>(builder)?;
-	lookup_batch_add.execute::<BinaryField32b>(builder)?;
-	lookup_batch_add_carryfree.execute::<BinaryField32b>(builder)?;
-
-	if LevelIn::WIDTH != 1 {
-		lookup_batch_dci.execute::<BinaryField32b>(builder)?;
-	}
-
 	let consistency = arith_expr!([x, y] = x - y);
 
 	for byte_idx in 0..LevelOut::WIDTH {
@@ -0,0 +1,141 @@
+// Copyright 2025 Irreducible Inc.
+
+use alloy_primitives::U512;
+use anyhow::{ensure, Result};
+use binius_core::oracle::OracleId;
+use binius_field::{tower_levels::TowerLevel, BinaryField8b, TowerField};
+use binius_macros::arith_expr;
+use itertools::chain;
+
+use super::{byte_sliced_add_carryfree, byte_sliced_less_than, byte_sliced_mul};
+use crate::{builder::ConstraintSystemBuilder, lasso::batch::LookupBatch};
+
+type B8 = BinaryField8b;
+
+/// Reduces a double-width `value` modulo a `modulus` that varies per row.
+///
+/// The quotient and remainder are committed and checked by `quotient * modulus + remainder =
+/// value` together with `remainder < modulus`, so the result is the canonical remainder. The
+/// quotient must fit in `LevelIn`, which holds whenever the upper half of `value` is below the
+/// modulus, e.g. for products of two reduced operands.
+#[allow(clippy::too_many_arguments)]
+pub fn byte_sliced_modular_reduce<LevelIn: TowerLevel, LevelOut: TowerLevel<Base = LevelIn>>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	value: &LevelOut::Data<OracleId>,
+	modulus: &LevelIn::Data<OracleId>,
+	log_size: usize,
+	zero_byte_oracle: OracleId,
+	zero_carry_oracle: OracleId,
+	lookup_batch_mul: &mut LookupBatch,
+	lookup_batch_add: &mut LookupBatch,
+	lookup_batch_add_carryfree: &mut LookupBatch,
+	lookup_batch_dci: &mut LookupBatch,
+) -> Result<LevelIn::Data<OracleId>, anyhow::Error> {
+	builder.push_namespace(name);
+
+	let quotient =
+		LevelIn::from_fn(|_| builder.add_committed("quotient", log_size, B8::TOWER_LEVEL));
+	let remainder =
+		LevelIn::from_fn(|_| builder.add_committed("remainder", log_size, B8::TOWER_LEVEL));
+
+	builder.fill_witness(chain(quotient.as_ref(), remainder.as_ref()).copied(), {
+		let (value, modulus, quotient, remainder) = (
+			value.as_ref().to_vec(),
+			modulus.as_ref().to_vec(),
+			quotient.as_ref().to_vec(),
+			remainder.as_ref().to_vec(),
+		);
+		move |witness, _| {
+			let value_bytes_as_u8 = (0..LevelOut::WIDTH)
+				.map(|byte_idx| Ok(witness.get::<B8>(value[byte_idx])?.as_slice::<u8>()))
+				.collect::<Result<Vec<_>>>()?;
+			let modulus_bytes_as_u8 = (0..LevelIn::WIDTH)
+				.map(|byte_idx| Ok(witness.get::<B8>(modulus[byte_idx])?.as_slice::<u8>()))
+				.collect::<Result<Vec<_>>>()?;
+
+			let mut quotient_columns = (0..LevelIn::WIDTH)
+				.map(|byte_idx| witness.new_column::<B8>(quotient[byte_idx]))
+				.collect::<Vec<_>>();
+			let mut remainder_columns = (0..LevelIn::WIDTH)
+				.map(|byte_idx| witness.new_column::<B8>(remainder[byte_idx]))
+				.collect::<Vec<_>>();
+
+			let quotient_bound = U512::from(1u8) << (8 * LevelIn::WIDTH);
+			for row_idx in 0..1 << log_size {
+				let mut value_u512 = U512::ZERO;
+				for (byte_idx, value_byte_column) in value_bytes_as_u8.iter().enumerate() {
+					value_u512 |= U512::from(value_byte_column[row_idx]) << (8 * byte_idx);
+				}
+				let mut modulus_u512 = U512::ZERO;
+				for (byte_idx, modulus_byte_column) in modulus_bytes_as_u8.iter().enumerate() {
+					modulus_u512 |= U512::from(modulus_byte_column[row_idx]) << (8 * byte_idx);
+				}
+
+				ensure!(modulus_u512 != U512::ZERO, "modulus is zero in row {row_idx}");
+				let quotient_u512 = value_u512 / modulus_u512;
+				let remainder_u512 = value_u512 % modulus_u512;
+				ensure!(quotient_u512 < quotient_bound, "quotient overflows in row {row_idx}");
+
+				for (byte_idx, quotient_column) in quotient_columns.iter_mut().enumerate() {
+					quotient_column.as_mut_slice::<u8>()[row_idx] = quotient_u512.byte(byte_idx);
+				}
+				for (byte_idx, remainder_column) in remainder_columns.iter_mut().enumerate() {
+					remainder_column.as_mut_slice::<u8>()[row_idx] = remainder_u512.byte(byte_idx);
+				}
+			}
+			Ok(())
+		}
+	})?;
+
+	let qm = byte_sliced_mul::<LevelIn, LevelOut>(
+		builder,
+		"qm",
+		&quotient,
+		modulus,
+		log_size,
+		zero_carry_oracle,
+		lookup_batch_mul,
+		lookup_batch_add,
+		lookup_batch_dci,
+	)?;
+
+	let repeating_zero = LevelIn::from_fn(|_| zero_byte_oracle);
+	let qm_plus_r = byte_sliced_add_carryfree::<LevelOut>(
+		builder,
+		"qm + r",
+		&qm,
+		&LevelOut::join(&remainder, &repeating_zero),
+		zero_carry_oracle,
+		log_size,
+		lookup_batch_add,
+		lookup_batch_add_carryfree,
+	)?;
+
+	let consistency = arith_expr!([x, y] = x - y);
+	for byte_idx in 0..LevelOut::WIDTH {
+		builder.assert_zero(
+			format!("byte_consistency_{byte_idx}"),
+			[value[byte_idx], qm_plus_r[byte_idx]],
+			consistency.convert_field(),
+		);
+	}
+
+	let remainder_is_reduced = byte_sliced_less_than::<LevelIn>(
+		builder,
+		"r < m",
+		&remainder,
+		modulus,
+		zero_carry_oracle,
+		log_size,
+		lookup_batch_add,
+	)?;
+	builder.assert_zero(
+		"remainder_is_reduced",
+		[remainder_is_reduced],
+		arith_expr!([x] = x - 1).convert_field(),
+	);
+
+	builder.pop_namespace();
+	Ok(remainder)
+}
@@ -138,50 +138,55 @@ pub fn byte_sliced_mul<LevelIn: TowerLevel, LevelOut: TowerLevel<Base = LevelIn>
 	let product = LevelOut::join(&final_lower_half, &final_upper_half);
 
 	// All of the code below is for test assertions
-	if let Some(witness) = builder.witness() {
-		let a_bytes_as_u8 = (0..LevelIn::WIDTH).map(|this_byte_idx| {
-			let this_byte_oracle = mult_a[this_byte_idx];
-			witness
-				.get::<B8>(this_byte_oracle)
-				.unwrap()
-				.as_slice::<u8>()
-		});
-
-		let b_bytes_as_u8 = (0..LevelIn::WIDTH).map(|this_byte_idx| {
-			let this_byte_oracle = mult_b[this_byte_idx];
-			witness
-				.get::<B8>(this_byte_oracle)
-				.unwrap()
-				.as_slice::<u8>()
-		});
-
-		let product_bytes_as_u8 = (0..LevelOut::WIDTH).map(|this_byte_idx| {
-			let this_byte_oracle = product[this_byte_idx];
-			witness
-				.get::<B8>(this_byte_oracle)
-				.unwrap()
-				.as_slice::<u8>()
-		});
-
-		for row_idx in 0..1 << log_size {
-			let mut a_u512 = U512::ZERO;
-			for (byte_idx, a_byte_column) in a_bytes_as_u8.clone().enumerate() {
-				a_u512 |= U512::from(a_byte_column[row_idx]) << (8 * byte_idx);
+	builder.fill_witness([], {
+		let (mult_a, mult_b, product) =
+			(mult_a.as_ref().to_vec(), mult_b.as_ref().to_vec(), product.as_ref().to_vec());
+		move |witness, _| {
+			let a_bytes_as_u8 = (0..LevelIn::WIDTH).map(|this_byte_idx| {
+				let this_byte_oracle = mult_a[this_byte_idx];
+				witness
+					.get::<B8>(this_byte_oracle)
+					.unwrap()
+					.as_slice::<u8>()
+			});
+
+			let b_bytes_as_u8 = (0..LevelIn::WIDTH).map(|this_byte_idx| {
+				let this_byte_oracle = mult_b[this_byte_idx];
+				witness
+					.get::<B8>(this_byte_oracle)
+					.unwrap()
+					.as_slice::<u8>()
+			});
+
+			let product_bytes_as_u8 = (0..LevelOut::WIDTH).map(|this_byte_idx| {
+				let this_byte_oracle = product[this_byte_idx];
+				witness
+					.get::<B8>(this_byte_oracle)
+					.unwrap()
+					.as_slice::<u8>()
+			});
+
+			for row_idx in 0..1 << log_size {
+				let mut a_u512 = U512::ZERO;
+				for (byte_idx, a_byte_column) in a_bytes_as_u8.clone().enumerate() {
+					a_u512 |= U512::from(a_byte_column[row_idx]) << (8 * byte_idx);
+				}
+
+				let mut b_u512 = U512::ZERO;
+				for (byte_idx, b_byte_column) in b_bytes_as_u8.clone().enumerate() {
+					b_u512 |= U512::from(b_byte_column[row_idx]) << (8 * byte_idx);
+				}
+
+				let mut product_u512 = U512::ZERO;
+				for (byte_idx, product_byte_column) in product_bytes_as_u8.clone().enumerate() {
+					product_u512 |= U512::from(product_byte_column[row_idx]) << (8 * byte_idx);
+				}
+
+				assert_eq!(a_u512 * b_u512, product_u512);
 			}
-
-			let mut b_u512 = U512::ZERO;
-			for (byte_idx, b_byte_column) in b_bytes_as_u8.clone().enumerate() {
-				b_u512 |= U512::from(b_byte_column[row_idx]) << (8 * byte_idx);
-			}
-
-			let mut product_u512 = U512::ZERO;
-			for (byte_idx, product_byte_column) in product_bytes_as_u8.clone().enumerate() {
-				product_u512 |= U512::from(product_byte_column[row_idx]) << (8 * byte_idx);
-			}
-
-			assert_eq!(a_u512 * b_u512, product_u512);
+			Ok(())
 		}
-	}
+	})?;
 
 	builder.pop_namespace();
 	Ok(product)
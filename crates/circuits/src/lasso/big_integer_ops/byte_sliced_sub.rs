@@ -0,0 +1,102 @@
+// Copyright 2025 Irreducible Inc.
+
+use alloy_primitives::U512;
+use anyhow::Result;
+use binius_core::oracle::OracleId;
+use binius_field::{tower_levels::TowerLevel, BinaryField1b, BinaryField8b, TowerField};
+use binius_macros::arith_expr;
+
+use super::byte_sliced_add;
+use crate::{builder::ConstraintSystemBuilder, lasso::batch::LookupBatch};
+
+type B1 = BinaryField1b;
+type B8 = BinaryField8b;
+
+/// Computes `x - y - borrow_in`, returning the borrow out and the difference modulo `2^(8 * WIDTH)`.
+///
+/// The difference and borrow are committed and checked by the addition
+/// `difference + y + borrow_in = x + borrow_out * 2^(8 * WIDTH)`.
+pub fn byte_sliced_sub<Level: TowerLevel<Data<OracleId>: Sized>>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	x_in: &Level::Data<OracleId>,
+	y_in: &Level::Data<OracleId>,
+	borrow_in: OracleId,
+	log_size: usize,
+	lookup_batch_add: &mut LookupBatch,
+) -> Result<(OracleId, Level::Data<OracleId>), anyhow::Error> {
+	builder.push_namespace(name);
+
+	let difference =
+		Level::from_fn(|_| builder.add_committed("difference", log_size, B8::TOWER_LEVEL));
+	let borrow_out = builder.add_committed("bout", log_size, B1::TOWER_LEVEL);
+
+	builder.fill_witness(difference.as_ref().iter().copied().chain([borrow_out]), {
+		let (x_in, y_in, difference) =
+			(x_in.as_ref().to_vec(), y_in.as_ref().to_vec(), difference.as_ref().to_vec());
+		move |witness, _| {
+			let x_bytes_as_u8 = (0..Level::WIDTH)
+				.map(|byte_idx| Ok(witness.get::<B8>(x_in[byte_idx])?.as_slice::<u8>()))
+				.collect::<Result<Vec<_>>>()?;
+			let y_bytes_as_u8 = (0..Level::WIDTH)
+				.map(|byte_idx| Ok(witness.get::<B8>(y_in[byte_idx])?.as_slice::<u8>()))
+				.collect::<Result<Vec<_>>>()?;
+			let bin_as_u8_packed = witness.get::<B1>(borrow_in)?.as_slice::<u8>();
+
+			let mut difference_columns = (0..Level::WIDTH)
+				.map(|byte_idx| witness.new_column::<B8>(difference[byte_idx]))
+				.collect::<Vec<_>>();
+			let mut bout_column = witness.new_column::<B1>(borrow_out);
+			let bout_as_u8_packed = bout_column.as_mut_slice::<u8>();
+
+			let modulus = U512::from(1u8) << (8 * Level::WIDTH);
+			for row_idx in 0..1 << log_size {
+				let mut x_u512 = U512::ZERO;
+				let mut y_u512 = U512::ZERO;
+				for byte_idx in 0..Level::WIDTH {
+					x_u512 |= U512::from(x_bytes_as_u8[byte_idx][row_idx]) << (8 * byte_idx);
+					y_u512 |= U512::from(y_bytes_as_u8[byte_idx][row_idx]) << (8 * byte_idx);
+				}
+				let bin_u512 = U512::from((bin_as_u8_packed[row_idx / 8] >> (row_idx % 8)) & 1);
+
+				let subtrahend = y_u512 + bin_u512;
+				let borrow = x_u512 < subtrahend;
+				let difference_u512 = if borrow {
+					x_u512 + modulus - subtrahend
+				} else {
+					x_u512 - subtrahend
+				};
+
+				for (byte_idx, difference_column) in difference_columns.iter_mut().enumerate() {
+					difference_column.as_mut_slice::<u8>()[row_idx] =
+						difference_u512.byte(byte_idx);
+				}
+				bout_as_u8_packed[row_idx / 8] |= (borrow as u8) << (row_idx % 8);
+			}
+			Ok(())
+		}
+	})?;
+
+	let (carry_out, sum) = byte_sliced_add::<Level>(
+		builder,
+		"difference + y",
+		&difference,
+		y_in,
+		borrow_in,
+		log_size,
+		lookup_batch_add,
+	)?;
+
+	let consistency = arith_expr!([x, y] = x - y);
+	builder.assert_zero("borrow_consistency", [borrow_out, carry_out], consistency.convert_field());
+	for byte_idx in 0..Level::WIDTH {
+		builder.assert_zero(
+			format!("byte_consistency_{byte_idx}"),
+			[sum[byte_idx], x_in[byte_idx]],
+			consistency.convert_field(),
+		);
+	}
+
+	builder.pop_namespace();
+	Ok((borrow_out, difference))
+}
@@ -3,18 +3,21 @@
 use std::{array, fmt::Debug};
 
 use alloy_primitives::U512;
+use anyhow::Result;
 use binius_core::oracle::OracleId;
 use binius_field::{
 	tower_levels::TowerLevel, BinaryField1b, BinaryField32b, BinaryField8b, Field, TowerField,
 };
+use itertools::chain;
 use rand::{rngs::StdRng, thread_rng, Rng, SeedableRng};
 
 use super::{
 	byte_sliced_add, byte_sliced_add_carryfree, byte_sliced_double_conditional_increment,
-	byte_sliced_modular_mul, byte_sliced_mul,
+	byte_sliced_equal, byte_sliced_less_than, byte_sliced_modular_mul, byte_sliced_modular_reduce,
+	byte_sliced_mul, byte_sliced_sub,
 };
 use crate::{
-	builder::test_utils::test_circuit,
+	builder::{test_utils::test_circuit, ConstraintSystemBuilder},
 	lasso::{
 		batch::LookupBatch,
 		lookups::u8_arithmetic::{add_carryfree_lookup, add_lookup, dci_lookup, mul_lookup},
@@ -31,6 +34,42 @@ pub fn random_u512(rng: &mut impl Rng) -> U512 {
 	U512::from_limbs(limbs)
 }
 
+fn read_u512(columns: &[&[u8]], row_idx: usize) -> U512 {
+	columns
+		.iter()
+		.enumerate()
+		.fold(U512::ZERO, |acc, (byte_idx, column)| {
+			acc | (U512::from(column[row_idx]) << (8 * byte_idx))
+		})
+}
+
+fn read_bit(packed: &[u8], row_idx: usize) -> bool {
+	(packed[row_idx / 8] >> (row_idx % 8)) & 1 == 1
+}
+
+/// Commits `WIDTH` byte columns holding `values(row)` on every row.
+fn committed_u512<const WIDTH: usize>(
+	builder: &mut ConstraintSystemBuilder,
+	name: &str,
+	log_size: usize,
+	values: impl FnMut(usize) -> U512,
+) -> Result<[OracleId; WIDTH]> {
+	let oracles = builder.add_committed_multiple::<WIDTH>(name, log_size, B8::TOWER_LEVEL);
+	let values = (0..1 << log_size).map(values).collect::<Vec<_>>();
+	builder.fill_witness(oracles, move |witness, _| {
+		let mut columns: [_; WIDTH] =
+			array::from_fn(|byte_idx| witness.new_column::<BinaryField8b>(oracles[byte_idx]));
+		let mut columns_u8 = columns.each_mut().map(|col| col.as_mut_slice::<u8>());
+		for (row_idx, value) in values.iter().enumerate() {
+			for (byte_idx, column) in columns_u8.iter_mut().enumerate() {
+				column[row_idx] = value.byte(byte_idx);
+			}
+		}
+		Ok(())
+	})?;
+	Ok(oracles)
+}
+
 pub fn test_bytesliced_add<const WIDTH: usize, TL>()
 where
 	TL: TowerLevel,
@@ -69,44 +108,55 @@ where
 			TL::from_fn(|_| builder.add_committed("y", log_size, BinaryField8b::TOWER_LEVEL));
 		let c_in = builder.add_committed("c", log_size, BinaryField1b::TOWER_LEVEL);
 
-		if let Some(witness) = builder.witness() {
-			let mut x_in: [_; WIDTH] =
-				array::from_fn(|byte_idx| witness.new_column::<BinaryField8b>(x_in[byte_idx]));
-			let mut y_in: [_; WIDTH] =
-				array::from_fn(|byte_idx| witness.new_column::<BinaryField8b>(y_in[byte_idx]));
-			let mut c_in = witness.new_column::<BinaryField1b>(c_in);
+		builder.fill_witness(
+			chain!(x_in.as_ref().iter().copied(), y_in.as_ref().iter().copied(), [c_in]),
+			{
+				let (x_in, y_in) = (x_in.as_ref().to_vec(), y_in.as_ref().to_vec());
+				move |witness, _| {
+					let mut x_in: [_; WIDTH] = array::from_fn(|byte_idx| {
+						witness.new_column::<BinaryField8b>(x_in[byte_idx])
+					});
+					let mut y_in: [_; WIDTH] = array::from_fn(|byte_idx| {
+						witness.new_column::<BinaryField8b>(y_in[byte_idx])
+					});
+					let mut c_in = witness.new_column::<BinaryField1b>(c_in);
 
-			let x_in_bytes_u8: [_; WIDTH] = x_in.each_mut().map(|col| col.as_mut_slice::<u8>());
-			let y_in_bytes_u8: [_; WIDTH] = y_in.each_mut().map(|col| col.as_mut_slice::<u8>());
-			let c_in_u8 = c_in.as_mut_slice::<u8>();
+					let x_in_bytes_u8: [_; WIDTH] =
+						x_in.each_mut().map(|col| col.as_mut_slice::<u8>());
+					let y_in_bytes_u8: [_; WIDTH] =
+						y_in.each_mut().map(|col| col.as_mut_slice::<u8>());
+					let c_in_u8 = c_in.as_mut_slice::<u8>();
 
-			for row_idx in 0..1 << log_size {
-				let mut rng = thread_rng();
-				let input_bitmask = (U512::from(1u8) << (8 * WIDTH)) - U512::from(1u8);
-				let mut x = random_u512(&mut rng);
-				x &= input_bitmask;
-				let mut y = random_u512(&mut rng);
-				y &= input_bitmask;
-
-				let mut c: bool = rng.gen();
-
-				while (x + y + U512::from(c)) > input_bitmask {
-					x = random_u512(&mut rng);
-					x &= input_bitmask;
-					y = random_u512(&mut rng);
-					y &= input_bitmask;
-					c = rng.gen();
-				}
+					for row_idx in 0..1 << log_size {
+						let mut rng = thread_rng();
+						let input_bitmask = (U512::from(1u8) << (8 * WIDTH)) - U512::from(1u8);
+						let mut x = random_u512(&mut rng);
+						x &= input_bitmask;
+						let mut y = random_u512(&mut rng);
+						y &= input_bitmask;
 
-				for byte_idx in 0..WIDTH {
-					x_in_bytes_u8[byte_idx][row_idx] = x.byte(byte_idx);
+						let mut c: bool = rng.gen();
 
-					y_in_bytes_u8[byte_idx][row_idx] = y.byte(byte_idx);
-				}
+						while (x + y + U512::from(c)) > input_bitmask {
+							x = random_u512(&mut rng);
+							x &= input_bitmask;
+							y = random_u512(&mut rng);
+							y &= input_bitmask;
+							c = rng.gen();
+						}
 
-				c_in_u8[row_idx / 8] |= (c as u8) << (row_idx % 8);
-			}
-		}
+						for byte_idx in 0..WIDTH {
+							x_in_bytes_u8[byte_idx][row_idx] = x.byte(byte_idx);
+
+							y_in_bytes_u8[byte_idx][row_idx] = y.byte(byte_idx);
+						}
+
+						c_in_u8[row_idx / 8] |= (c as u8) << (row_idx % 8);
+					}
+					Ok(())
+				}
+			},
+		)?;
 
 		let lookup_t_add = add_lookup(builder, "add table")?;
 		let lookup_t_add_carryfree = add_carryfree_lookup(builder, "add table")?;
@@ -202,14 +252,14 @@ where
 {
 	test_circuit(|builder| {
 		let log_size = 12;
-		let mut rng = thread_rng();
 		let mult_a = builder.add_committed_multiple::<WIDTH>("a", log_size, B8::TOWER_LEVEL);
 		let mult_b = builder.add_committed_multiple::<WIDTH>("b", log_size, B8::TOWER_LEVEL);
 		let input_bitmask = (U512::from(1u8) << (8 * WIDTH)) - U512::from(1u8);
 		let modulus =
 			(random_u512(&mut StdRng::from_seed([42; 32])) % input_bitmask) + U512::from(1u8);
 
-		if let Some(witness) = builder.witness() {
+		builder.fill_witness(chain!(mult_a, mult_b), move |witness, _| {
+			let mut rng = thread_rng();
 			let mut mult_a: [_; WIDTH] =
 				array::from_fn(|byte_idx| witness.new_column::<BinaryField8b>(mult_a[byte_idx]));
 
@@ -232,7 +282,8 @@ where
 					mult_b_u8[byte_idx][row_idx] = b.byte(byte_idx);
 				}
 			}
-		}
+			Ok(())
+		})?;
 
 		let modulus_input: [_; WIDTH] = array::from_fn(|byte_idx| modulus.byte(byte_idx));
 		let zero_oracle_byte =
@@ -253,3 +304,211 @@ where
 	})
 	.unwrap();
 }
+
+pub fn test_bytesliced_sub<const WIDTH: usize, TL>()
+where
+	TL: TowerLevel<Data<usize> = [OracleId; WIDTH]>,
+{
+	test_circuit(|builder| {
+		let log_size = 14;
+		let x_in = TL::from_fn(|_| unconstrained::<BinaryField8b>(builder, "x", log_size).unwrap());
+		let y_in = TL::from_fn(|_| unconstrained::<BinaryField8b>(builder, "y", log_size).unwrap());
+		let b_in = unconstrained::<BinaryField1b>(builder, "bin", log_size)?;
+		let lookup_t_add = add_lookup(builder, "add table")?;
+		let mut lookup_batch_add = LookupBatch::new([lookup_t_add]);
+		let (b_out, difference) = byte_sliced_sub::<TL>(
+			builder,
+			"lasso_bytesliced_sub",
+			&x_in,
+			&y_in,
+			b_in,
+			log_size,
+			&mut lookup_batch_add,
+		)?;
+		lookup_batch_add.execute::<B32>(builder)?;
+
+		if let Some(witness) = builder.witness() {
+			let column = |oracle| witness.get::<B8>(oracle).unwrap().as_slice::<u8>();
+			let x = x_in.map(column);
+			let y = y_in.map(column);
+			let difference = difference.map(column);
+			let b_in = witness.get::<BinaryField1b>(b_in)?.as_slice::<u8>();
+			let b_out = witness.get::<BinaryField1b>(b_out)?.as_slice::<u8>();
+			let modulus = U512::from(1u8) << (8 * WIDTH);
+			for row_idx in 0..1 << log_size {
+				let lhs = read_u512(&x, row_idx) + modulus * U512::from(read_bit(b_out, row_idx));
+				let rhs = read_u512(&difference, row_idx)
+					+ read_u512(&y, row_idx)
+					+ U512::from(read_bit(b_in, row_idx));
+				assert_eq!(lhs, rhs);
+			}
+		}
+		Ok(vec![])
+	})
+	.unwrap();
+}
+
+pub fn test_bytesliced_less_than<const WIDTH: usize, TL>()
+where
+	TL: TowerLevel<Data<usize> = [OracleId; WIDTH]>,
+{
+	test_circuit(|builder| {
+		let log_size = 14;
+		let mut rng = StdRng::seed_from_u64(0);
+		let input_bitmask = (U512::from(1u8) << (8 * WIDTH)) - U512::from(1u8);
+		let xs = (0..1 << log_size)
+			.map(|_| random_u512(&mut rng) & input_bitmask)
+			.collect::<Vec<_>>();
+		// Every third row compares equal values, which must not count as less-than
+		let ys = xs
+			.iter()
+			.enumerate()
+			.map(|(row_idx, &x)| {
+				if row_idx % 3 == 0 {
+					x
+				} else {
+					random_u512(&mut rng) & input_bitmask
+				}
+			})
+			.collect::<Vec<_>>();
+		let x_in = committed_u512::<WIDTH>(builder, "x", log_size, |row_idx| xs[row_idx])?;
+		let y_in = committed_u512::<WIDTH>(builder, "y", log_size, |row_idx| ys[row_idx])?;
+		let zero_oracle_carry =
+			transparent::constant(builder, "zero carry", log_size, BinaryField1b::ZERO)?;
+		let lookup_t_add = add_lookup(builder, "add table")?;
+		let mut lookup_batch_add = LookupBatch::new([lookup_t_add]);
+		let less_than = byte_sliced_less_than::<TL>(
+			builder,
+			"lasso_bytesliced_less_than",
+			&x_in,
+			&y_in,
+			zero_oracle_carry,
+			log_size,
+			&mut lookup_batch_add,
+		)?;
+		lookup_batch_add.execute::<B32>(builder)?;
+
+		if let Some(witness) = builder.witness() {
+			let less_than = witness.get::<BinaryField1b>(less_than)?.as_slice::<u8>();
+			for row_idx in 0..1 << log_size {
+				assert_eq!(read_bit(less_than, row_idx), xs[row_idx] < ys[row_idx]);
+			}
+		}
+		Ok(vec![])
+	})
+	.unwrap();
+}
+
+pub fn test_bytesliced_equal<const WIDTH: usize, TL>()
+where
+	TL: TowerLevel<Data<usize> = [OracleId; WIDTH]>,
+{
+	test_circuit(|builder| {
+		let log_size = 14;
+		let mut rng = StdRng::seed_from_u64(0);
+		let input_bitmask = (U512::from(1u8) << (8 * WIDTH)) - U512::from(1u8);
+		let xs = (0..1 << log_size)
+			.map(|_| random_u512(&mut rng) & input_bitmask)
+			.collect::<Vec<_>>();
+		// Half of the rows are equal, the others differ in a single random byte
+		let ys = xs
+			.iter()
+			.enumerate()
+			.map(|(row_idx, &x)| {
+				if row_idx % 2 == 0 {
+					x
+				} else {
+					let byte_idx = rng.gen_range(0..WIDTH);
+					x ^ (U512::from(rng.gen_range(1..=255u8)) << (8 * byte_idx))
+				}
+			})
+			.collect::<Vec<_>>();
+		let x_in = committed_u512::<WIDTH>(builder, "x", log_size, |row_idx| xs[row_idx])?;
+		let y_in = committed_u512::<WIDTH>(builder, "y", log_size, |row_idx| ys[row_idx])?;
+		let equal =
+			byte_sliced_equal::<TL>(builder, "lasso_bytesliced_equal", &x_in, &y_in, log_size)?;
+
+		if let Some(witness) = builder.witness() {
+			let equal = witness.get::<BinaryField1b>(equal)?.as_slice::<u8>();
+			for row_idx in 0..1 << log_size {
+				assert_eq!(read_bit(equal, row_idx), row_idx % 2 == 0);
+			}
+		}
+		Ok(vec![])
+	})
+	.unwrap();
+}
+
+pub fn test_bytesliced_modular_reduce<const WIDTH: usize, TL>()
+where
+	TL: TowerLevel<Data<usize>: Debug>,
+	TL::Base: TowerLevel<Data<usize> = [OracleId; WIDTH]>,
+{
+	test_circuit(|builder| {
+		let log_size = 12;
+		let mut rng = StdRng::seed_from_u64(0);
+		let input_bitmask = (U512::from(1u8) << (8 * WIDTH)) - U512::from(1u8);
+		// The modulus differs from row to row
+		let moduli = (0..1 << log_size)
+			.map(|_| (random_u512(&mut rng) % input_bitmask) + U512::from(1u8))
+			.collect::<Vec<_>>();
+		let products = moduli
+			.iter()
+			.map(|&m| (random_u512(&mut rng) % m) * (random_u512(&mut rng) % m))
+			.collect::<Vec<_>>();
+
+		let modulus =
+			committed_u512::<WIDTH>(builder, "modulus", log_size, |row_idx| moduli[row_idx])?;
+		let lo = committed_u512::<WIDTH>(builder, "value lo", log_size, |row_idx| {
+			products[row_idx] & input_bitmask
+		})?;
+		let hi = committed_u512::<WIDTH>(builder, "value hi", log_size, |row_idx| {
+			products[row_idx] >> (8 * WIDTH)
+		})?;
+		let value = TL::join(&lo, &hi);
+
+		let zero_oracle_byte =
+			transparent::constant(builder, "zero byte", log_size, BinaryField8b::ZERO)?;
+		let zero_oracle_carry =
+			transparent::constant(builder, "zero carry", log_size, BinaryField1b::ZERO)?;
+		let lookup_t_mul = mul_lookup(builder, "mul table")?;
+		let lookup_t_add = add_lookup(builder, "add table")?;
+		let lookup_t_add_carryfree = add_carryfree_lookup(builder, "add cf table")?;
+		let lookup_t_dci = dci_lookup(builder, "dci table")?;
+		let mut lookup_batch_mul = LookupBatch::new([lookup_t_mul]);
+		let mut lookup_batch_add = LookupBatch::new([lookup_t_add]);
+		let mut lookup_batch_add_carryfree = LookupBatch::new([lookup_t_add_carryfree]);
+		let mut lookup_batch_dci = LookupBatch::new([lookup_t_dci]);
+
+		let remainder = byte_sliced_modular_reduce::<TL::Base, TL>(
+			builder,
+			"lasso_bytesliced_modular_reduce",
+			&value,
+			&modulus,
+			log_size,
+			zero_oracle_byte,
+			zero_oracle_carry,
+			&mut lookup_batch_mul,
+			&mut lookup_batch_add,
+			&mut lookup_batch_add_carryfree,
+			&mut lookup_batch_dci,
+		)?;
+
+		lookup_batch_mul.execute::<B32>(builder)?;
+		lookup_batch_add.execute::<B32>(builder)?;
+		lookup_batch_add_carryfree.execute::<B32>(builder)?;
+		if WIDTH != 1 {
+			lookup_batch_dci.execute::<B32>(builder)?;
+		}
+
+		if let Some(witness) = builder.witness() {
+			let remainder =
+				remainder.map(|oracle| witness.get::<B8>(oracle).unwrap().as_slice::<u8>());
+			for row_idx in 0..1 << log_size {
+				assert_eq!(read_u512(&remainder, row_idx), products[row_idx] % moduli[row_idx]);
+			}
+		}
+		Ok(vec![])
+	})
+	.unwrap();
+}
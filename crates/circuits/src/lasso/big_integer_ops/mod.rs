@@ -3,15 +3,23 @@
 pub mod byte_sliced_add;
 pub mod byte_sliced_add_carryfree;
 pub mod byte_sliced_double_conditional_increment;
+pub mod byte_sliced_equal;
+pub mod byte_sliced_less_than;
 pub mod byte_sliced_modular_mul;
+pub mod byte_sliced_modular_reduce;
 pub mod byte_sliced_mul;
+pub mod byte_sliced_sub;
 pub mod byte_sliced_test_utils;
 
 pub use byte_sliced_add::byte_sliced_add;
 pub use byte_sliced_add_carryfree::byte_sliced_add_carryfree;
 pub use byte_sliced_double_conditional_increment::byte_sliced_double_conditional_increment;
-pub use byte_sliced_modular_mul::byte_sliced_modular_mul;
+pub use byte_sliced_equal::byte_sliced_equal;
+pub use byte_sliced_less_than::byte_sliced_less_than;
+pub use byte_sliced_modular_mul::{byte_sliced_modular_mul, byte_sliced_modular_mul_with_batches};
+pub use byte_sliced_modular_reduce::byte_sliced_modular_reduce;
 pub use byte_sliced_mul::byte_sliced_mul;
+pub use byte_sliced_sub::byte_sliced_sub;
 
 #[cfg(test)]
 mod tests {
@@ -21,8 +29,9 @@ mod tests {
 
 	use super::byte_sliced_test_utils::{
 		test_bytesliced_add, test_bytesliced_add_carryfree,
-		test_bytesliced_double_conditional_increment, test_bytesliced_modular_mul,
-		test_bytesliced_mul,
+		test_bytesliced_double_conditional_increment, test_bytesliced_equal,
+		test_bytesliced_less_than, test_bytesliced_modular_mul, test_bytesliced_modular_reduce,
+		test_bytesliced_mul, test_bytesliced_sub,
 	};
 
 	#[test]
@@ -76,4 +85,36 @@ mod tests {
 		test_bytesliced_add_carryfree::<4, TowerLevel4>();
 		test_bytesliced_add_carryfree::<8, TowerLevel8>();
 	}
+
+	#[test]
+	fn test_lasso_bytesliced_sub() {
+		test_bytesliced_sub::<1, TowerLevel1>();
+		test_bytesliced_sub::<2, TowerLevel2>();
+		test_bytesliced_sub::<4, TowerLevel4>();
+		test_bytesliced_sub::<8, TowerLevel8>();
+	}
+
+	#[test]
+	fn test_lasso_bytesliced_less_than() {
+		test_bytesliced_less_than::<1, TowerLevel1>();
+		test_bytesliced_less_than::<2, TowerLevel2>();
+		test_bytesliced_less_than::<4, TowerLevel4>();
+		test_bytesliced_less_than::<8, TowerLevel8>();
+	}
+
+	#[test]
+	fn test_lasso_bytesliced_equal() {
+		test_bytesliced_equal::<1, TowerLevel1>();
+		test_bytesliced_equal::<2, TowerLevel2>();
+		test_bytesliced_equal::<4, TowerLevel4>();
+		test_bytesliced_equal::<8, TowerLevel8>();
+	}
+
+	#[test]
+	fn test_lasso_modular_reduce_bytesliced() {
+		test_bytesliced_modular_reduce::<1, TowerLevel2>();
+		test_bytesliced_modular_reduce::<2, TowerLevel4>();
+		test_bytesliced_modular_reduce::<4, TowerLevel8>();
+		test_bytesliced_modular_reduce::<8, TowerLevel16>();
+	}
 }
@@ -1,12 +1,17 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+use std::{
+	cell::{Ref, RefCell},
+	rc::Rc,
+};
+
 use anyhow::{ensure, Error, Result};
 use binius_core::{constraint_system::channel::ChannelId, oracle::OracleId};
 use binius_field::{
 	as_packed_field::{PackScalar, PackedType},
 	ExtensionField, Field, PackedFieldIndexable, TowerField,
 };
-use itertools::{izip, Itertools};
+use itertools::{chain, izip, Itertools};
 
 use crate::{
 	builder::{
@@ -16,11 +21,32 @@ use crate::{
 	transparent,
 };
 
+/// The table indices of the looked up values of one lookup, in the order of the lookup rows.
+///
+/// The witness fill of a lookup sets the mapping for the witness fill of [`lasso`], which runs
+/// later, to read.
+#[derive(Debug, Clone, Default)]
+pub struct LookupMapping(Rc<RefCell<Vec<usize>>>);
+
+impl LookupMapping {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	pub fn set(&self, u_to_t_mapping: Vec<usize>) {
+		*self.0.borrow_mut() = u_to_t_mapping;
+	}
+
+	pub fn get(&self) -> Ref<'_, Vec<usize>> {
+		self.0.borrow()
+	}
+}
+
 pub fn lasso<FC>(
 	builder: &mut ConstraintSystemBuilder,
 	name: impl ToString,
 	n_lookups: &[usize],
-	u_to_t_mappings: &[impl AsRef<[usize]>],
+	u_to_t_mappings: &[LookupMapping],
 	lookups_u: &[impl AsRef<[OracleId]>],
 	lookup_t: impl AsRef<[OracleId]>,
 	channel: ChannelId,
@@ -79,11 +105,15 @@ where
 		})
 		.collect::<Result<Vec<_>, anyhow::Error>>()?;
 
-	if let Some(witness) = builder.witness() {
-		if u_log_rows.len() != u_to_t_mappings.len() {
-			Err(anyhow::Error::msg("u_log_rows and u_to_t_mappings must be of the same length"))?;
-		}
+	if u_log_rows.len() != u_to_t_mappings.len() {
+		Err(anyhow::Error::msg("u_log_rows and u_to_t_mappings must be of the same length"))?;
+	}
 
+	let ids = chain!([lookup_f], lookups_r.clone(), lookups_w.clone());
+	let columns =
+		(n_lookups.to_vec(), u_to_t_mappings.to_vec(), lookups_r.clone(), lookups_w.clone());
+	builder.fill_witness(ids, move |witness, _| {
+		let (n_lookups, u_to_t_mappings, lookups_r, lookups_w) = &columns;
 		let mut lookup_f_witness = witness.new_column::<FC>(lookup_f);
 
 		let lookup_f_scalars = PackedType::<U, FC>::unpack_scalars_mut(lookup_f_witness.packed());
@@ -93,7 +123,7 @@ where
 		lookup_f_scalars.fill(FC::ONE);
 
 		for (u_to_t_mapping, &n_lookups, &lookup_r, &lookup_w) in
-			izip!(u_to_t_mappings, n_lookups, &lookups_r, &lookups_w)
+			izip!(u_to_t_mappings, n_lookups, lookups_r, lookups_w)
 		{
 			let mut lookup_r_witness = witness.new_column::<FC>(lookup_r);
 			let mut lookup_w_witness = witness.new_column::<FC>(lookup_w);
@@ -106,8 +136,10 @@ where
 			lookup_r_scalars.fill(FC::ONE);
 			lookup_w_scalars.fill(alpha);
 
+			let u_to_t_mapping = u_to_t_mapping.get();
+			ensure!(u_to_t_mapping.len() >= n_lookups, "lookup mapping is missing looked up rows");
 			for (&index, r, w) in
-				izip!(u_to_t_mapping.as_ref(), lookup_r_scalars, lookup_w_scalars).take(n_lookups)
+				izip!(u_to_t_mapping.iter(), lookup_r_scalars, lookup_w_scalars).take(n_lookups)
 			{
 				let ts = lookup_f_scalars[index];
 				*r = ts;
@@ -115,7 +147,8 @@ where
 				lookup_f_scalars[index] *= alpha;
 			}
 		}
-	}
+		Ok(())
+	})?;
 
 	lookups_r
 		.iter()
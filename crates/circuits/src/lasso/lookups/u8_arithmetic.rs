@@ -19,7 +19,7 @@ pub fn mul_lookup(
 
 	let lookup_t = builder.add_committed("lookup_t", T_LOG_SIZE_MUL, B32::TOWER_LEVEL);
 
-	if let Some(witness) = builder.witness() {
+	builder.fill_witness([lookup_t], move |witness, _| {
 		let mut lookup_t = witness.new_column::<B32>(lookup_t);
 
 		let lookup_t_u32 = lookup_t.as_mut_slice::<u32>();
@@ -32,7 +32,8 @@ pub fn mul_lookup(
 			assert_eq!(lookup_index, i);
 			*lookup_t = (lookup_index << 16 | ab_product) as u32;
 		}
-	}
+		Ok(())
+	})?;
 
 	builder.pop_namespace();
 	Ok(lookup_t)
@@ -46,7 +47,7 @@ pub fn add_lookup(
 
 	let lookup_t = builder.add_committed("lookup_t", T_LOG_SIZE_ADD, B32::TOWER_LEVEL);
 
-	if let Some(witness) = builder.witness() {
+	builder.fill_witness([lookup_t], move |witness, _| {
 		let mut lookup_t = witness.new_column::<B32>(lookup_t);
 
 		let lookup_t_u32 = lookup_t.as_mut_slice::<u32>();
@@ -67,7 +68,8 @@ pub fn add_lookup(
 				}
 			}
 		}
-	}
+		Ok(())
+	})?;
 
 	builder.pop_namespace();
 	Ok(lookup_t)
@@ -81,7 +83,7 @@ pub fn add_carryfree_lookup(
 
 	let lookup_t = builder.add_committed("lookup_t", T_LOG_SIZE_ADD, B32::TOWER_LEVEL);
 
-	if let Some(witness) = builder.witness() {
+	builder.fill_witness([lookup_t], move |witness, _| {
 		let mut lookup_t = witness.new_column::<B32>(lookup_t);
 
 		let lookup_t_u32 = lookup_t.as_mut_slice::<u32>();
@@ -104,7 +106,8 @@ pub fn add_carryfree_lookup(
 				}
 			}
 		}
-	}
+		Ok(())
+	})?;
 
 	builder.pop_namespace();
 	Ok(lookup_t)
@@ -118,7 +121,7 @@ pub fn dci_lookup(
 
 	let lookup_t = builder.add_committed("lookup_t", T_LOG_SIZE_DCI, B32::TOWER_LEVEL);
 
-	if let Some(witness) = builder.witness() {
+	builder.fill_witness([lookup_t], move |witness, _| {
 		let mut lookup_t = witness.new_column::<B32>(lookup_t);
 
 		let lookup_t_u32 = lookup_t.as_mut_slice::<u32>();
@@ -141,7 +144,8 @@ pub fn dci_lookup(
 				}
 			}
 		}
-	}
+		Ok(())
+	})?;
 
 	builder.pop_namespace();
 	Ok(lookup_t)
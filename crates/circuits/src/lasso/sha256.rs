@@ -8,7 +8,10 @@ use binius_field::{
 };
 use itertools::izip;
 
-use super::{lasso::lasso, u32add::SeveralU32add};
+use super::{
+	lasso::{lasso, LookupMapping},
+	u32add::SeveralU32add,
+};
 use crate::{
 	arithmetic::u32::u32const_repeating,
 	builder::{
@@ -30,7 +33,7 @@ struct SeveralBitwise {
 	n_lookups: Vec<usize>,
 	lookup_t: OracleId,
 	lookups_u: Vec<[OracleId; 1]>,
-	u_to_t_mappings: Vec<Vec<usize>>,
+	u_to_t_mappings: Vec<LookupMapping>,
 	f: fn(u32, u32, u32) -> u32,
 }
 
@@ -39,7 +42,7 @@ impl SeveralBitwise {
 		let lookup_t =
 			builder.add_committed("bitwise lookup_t", CH_MAJ_T_LOG_SIZE, B16::TOWER_LEVEL);
 
-		if let Some(witness) = builder.witness() {
+		builder.fill_witness([lookup_t], move |witness, _| {
 			let mut lookup_t_witness = witness.new_column::<B16>(lookup_t);
 
 			let lookup_t_scalars =
@@ -55,7 +58,8 @@ impl SeveralBitwise {
 				let lookup_index = (((x << 4) | y) << 4) | z;
 				*lookup_t = B16::new((lookup_index << 4) | res as u16);
 			}
-		}
+			Ok(())
+		})?;
 		Ok(Self {
 			n_lookups: Vec::new(),
 			lookup_t,
@@ -94,7 +98,10 @@ impl SeveralBitwise {
 			],
 		)?;
 
-		if let Some(witness) = builder.witness() {
+		let u_to_t_mapping = LookupMapping::new();
+		self.u_to_t_mappings.push(u_to_t_mapping.clone());
+		let f = self.f;
+		builder.fill_witness([lookup_u, res, res_packed], move |witness, _| {
 			let mut lookup_u_witness = witness.new_column::<B16>(lookup_u);
 			let lookup_u_u16 = PackedType::<U, B16>::unpack_scalars_mut(lookup_u_witness.packed());
 
@@ -112,7 +119,7 @@ impl SeveralBitwise {
 			for (res, x, y, z, lookup_u) in
 				izip!(res_u32.iter_mut(), xin_u32, yin_u32, zin_u32, lookup_u_u16.chunks_mut(8))
 			{
-				*res = (self.f)(*x, *y, *z);
+				*res = f(*x, *y, *z);
 
 				#[allow(clippy::needless_range_loop)]
 				for i in 0..8 {
@@ -131,8 +138,9 @@ impl SeveralBitwise {
 			let res_packed_witness = witness.get::<B1>(res)?;
 			witness.set::<B4>(res_packed, res_packed_witness.repacked::<B4>())?;
 
-			self.u_to_t_mappings.push(u_to_t_mapping_witness);
-		}
+			u_to_t_mapping.set(u_to_t_mapping_witness);
+			Ok(())
+		})?;
 
 		self.lookups_u.push([lookup_u]);
 		self.n_lookups.push(1 << (log_size - B4::TOWER_LEVEL));
@@ -12,7 +12,7 @@ use binius_field::{
 };
 use itertools::izip;
 
-use super::lasso::lasso;
+use super::lasso::{lasso, LookupMapping};
 use crate::{
 	builder::{
 		types::{F, U},
@@ -50,7 +50,7 @@ pub struct SeveralU32add {
 	n_lookups: Vec<usize>,
 	lookup_t: OracleId,
 	lookups_u: Vec<[OracleId; 1]>,
-	u_to_t_mappings: Vec<Vec<usize>>,
+	u_to_t_mappings: Vec<LookupMapping>,
 	finalized: bool,
 	_phantom: PhantomData<(U, F)>,
 }
@@ -59,7 +59,7 @@ impl SeveralU32add {
 	pub fn new(builder: &mut ConstraintSystemBuilder) -> Result<Self> {
 		let lookup_t = builder.add_committed("lookup_t", ADD_T_LOG_SIZE, B32::TOWER_LEVEL);
 
-		if let Some(witness) = builder.witness() {
+		builder.fill_witness([lookup_t], move |witness, _| {
 			let mut lookup_t_witness = witness.new_column::<B32>(lookup_t);
 
 			let lookup_t_scalars =
@@ -78,7 +78,8 @@ impl SeveralU32add {
 
 				*lookup_t = BinaryField32b::new(lookup_t_u32);
 			}
-		}
+			Ok(())
+		})?;
 		Ok(Self {
 			n_lookups: Vec::new(),
 			lookup_t,
@@ -138,7 +139,9 @@ impl SeveralU32add {
 			],
 		)?;
 
-		if let Some(witness) = builder.witness() {
+		let u_to_t_mapping = LookupMapping::new();
+		self.u_to_t_mappings.push(u_to_t_mapping.clone());
+		builder.fill_witness([sum, sum_packed, cin, cout, lookup_u], move |witness, _| {
 			let mut sum_witness = witness.new_column::<FOutput>(sum);
 			let mut cin_witness = witness.new_column::<B1>(cin);
 			let mut cout_witness = witness.new_column::<B1>(cout);
@@ -199,8 +202,9 @@ impl SeveralU32add {
 
 			witness.set::<B8>(sum_packed, sum_packed_witness.repacked::<B8>())?;
 
-			self.u_to_t_mappings.push(u_to_t_mapping_witness)
-		}
+			u_to_t_mapping.set(u_to_t_mapping_witness);
+			Ok(())
+		})?;
 
 		self.lookups_u.push([lookup_u]);
 		self.n_lookups.push(1 << b8_log_size);
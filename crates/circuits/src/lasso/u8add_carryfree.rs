@@ -35,9 +35,9 @@ pub fn u8add_carryfree(
 		],
 	)?;
 
-	let mut u_to_t_mapping = vec![];
+	let u_to_t_mapping = lookup_batch.add([lookup_u], 1 << log_size);
 
-	if let Some(witness) = builder.witness() {
+	builder.fill_witness([sum, lookup_u], move |witness, _| {
 		let mut sum_witness = witness.new_column::<B8>(sum);
 		let mut lookup_u_witness = witness.new_column::<B32>(lookup_u);
 		let mut u_to_t_mapping_witness = vec![0; 1 << log_size];
@@ -69,10 +69,9 @@ pub fn u8add_carryfree(
 			u_to_t_mapping_witness[row_idx] = lookup_index;
 		}
 
-		u_to_t_mapping = u_to_t_mapping_witness;
-	}
-
-	lookup_batch.add([lookup_u], u_to_t_mapping, 1 << log_size);
+		u_to_t_mapping.set(u_to_t_mapping_witness);
+		Ok(())
+	})?;
 
 	builder.pop_namespace();
 	Ok(sum)
@@ -35,9 +35,9 @@ pub fn u8mul_bytesliced(
 		],
 	)?;
 
-	let mut u_to_t_mapping = Vec::new();
+	let u_to_t_mapping = lookup_batch.add([lookup_u], n_multiplications);
 
-	if let Some(witness) = builder.witness() {
+	builder.fill_witness([product[0], product[1], lookup_u], move |witness, _| {
 		let mut product_low_witness = witness.new_column::<B8>(product[0]);
 		let mut product_high_witness = witness.new_column::<B8>(product[1]);
 		let mut lookup_u_witness = witness.new_column::<B32>(lookup_u);
@@ -70,10 +70,9 @@ pub fn u8mul_bytesliced(
 			*u_to_t = lookup_index;
 		}
 
-		u_to_t_mapping = u_to_t_mapping_witness;
-	}
-
-	lookup_batch.add([lookup_u], u_to_t_mapping, n_multiplications);
+		u_to_t_mapping.set(u_to_t_mapping_witness);
+		Ok(())
+	})?;
 
 	builder.pop_namespace();
 	Ok(product)
@@ -103,7 +102,7 @@ pub fn u8mul(
 		],
 	)?;
 
-	if let Some(witness) = builder.witness() {
+	builder.fill_witness([product], move |witness, _| {
 		let product_low_witness = witness.get::<B8>(product_bytesliced[0])?;
 		let product_high_witness = witness.get::<B8>(product_bytesliced[1])?;
 
@@ -117,7 +116,8 @@ pub fn u8mul(
 		for (row_idx, row_product) in product_u16.iter_mut().enumerate() {
 			*row_product = (product_high_u8[row_idx] as u16) << 8 | product_low_u8[row_idx] as u16;
 		}
-	}
+		Ok(())
+	})?;
 
 	builder.pop_namespace();
 	Ok(product)
@@ -9,15 +9,22 @@
 #![feature(array_try_map, array_try_from_fn)]
 #![allow(clippy::module_inception)]
 
+pub mod aes;
 pub mod arithmetic;
 pub mod bitwise;
 pub mod blake3;
 pub mod builder;
 pub mod collatz;
+pub mod ecc;
+pub mod groestl;
+pub mod keccak256;
 pub mod keccakf;
 pub mod lasso;
+pub mod memory;
+pub mod merkle;
 mod pack;
 pub mod plain_lookup;
+pub mod range_check;
 pub mod sha256;
 pub mod transparent;
 pub mod u32fib;
@@ -30,29 +37,45 @@ mod tests {
 		constraint_system::{
 			self,
 			channel::{validate_witness, Boundary, FlushDirection},
+			ConstraintSystem, PersistedCommitment,
 		},
 		fiat_shamir::HasherChallenger,
+		merkle_tree::BinaryMerkleTreeProver,
 		oracle::ShiftVariant,
+		piop::{self, tensor::make_tensor_params, TensorPCS},
 		polynomial::ArithCircuitPoly,
 		tower::CanonicalTowerFamily,
+		transcript::trace::{record_trace, replay},
+		witness::MultilinearExtensionIndex,
 	};
 	use binius_field::{
 		arch::OptimalUnderlier, as_packed_field::PackedType, underlier::WithUnderlier,
-		BinaryField128b, BinaryField64b, BinaryField8b, Field,
+		BinaryField128b, BinaryField32b, BinaryField64b, BinaryField8b, Field,
 	};
 	use binius_hal::make_portable_backend;
-	use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+	use binius_hash::{
+		blake3::{Blake3, Blake3Compression},
+		groestl::{Groestl256, Groestl256ByteCompression, Groestl256Parallel},
+		keccak::{Keccak256, Keccak256Compression},
+		MultiDigest, PseudoCompressionFunction,
+	};
 	use binius_macros::arith_expr;
 	use binius_math::{
 		CompositionPoly, DefaultEvaluationDomainFactory, IsomorphicEvaluationDomainFactory,
 	};
+	use binius_utils::{DeserializeBytes, SerializationMode, SerializeBytes};
+	use digest::{core_api::BlockSizeUser, FixedOutputReset, Output};
 
 	type B128 = BinaryField128b;
 	type B64 = BinaryField64b;
 
-	use crate::builder::{
-		types::{F, U},
-		ConstraintSystemBuilder,
+	use crate::{
+		builder::{
+			types::{F, U},
+			ConstraintSystemBuilder,
+		},
+		u32fib::u32fib,
+		unconstrained::fixed_u32,
 	};
 
 	#[test]
@@ -395,4 +418,238 @@ mod tests {
 		>(&constraint_system, log_inv_rate, security_bits, &[], proof)
 		.unwrap();
 	}
+
+	fn test_prove_verify_with_hash<Hash, Compress>()
+	where
+		Hash: MultiDigest<Digest: BlockSizeUser + FixedOutputReset + Default>,
+		Compress: PseudoCompressionFunction<Output<Hash::Digest>, 2> + Default + Sync,
+	{
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		u32fib(&mut builder, "u32fib", 10).unwrap();
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+
+		let domain_factory = DefaultEvaluationDomainFactory::default();
+		let backend = make_portable_backend();
+
+		let proof = constraint_system::prove::<
+			U,
+			CanonicalTowerFamily,
+			_,
+			Hash,
+			Compress,
+			HasherChallenger<Hash::Digest>,
+			_,
+		>(&constraint_system, 1, 30, &[], witness, &domain_factory, &backend)
+		.unwrap();
+
+		constraint_system::verify::<
+			U,
+			CanonicalTowerFamily,
+			Hash::Digest,
+			Compress,
+			HasherChallenger<Hash::Digest>,
+		>(&constraint_system, 1, 30, &[], proof)
+		.unwrap();
+	}
+
+	#[test]
+	fn test_prove_verify_groestl() {
+		test_prove_verify_with_hash::<Groestl256, Groestl256ByteCompression>();
+	}
+
+	#[test]
+	fn test_prove_verify_groestl_parallel() {
+		test_prove_verify_with_hash::<Groestl256Parallel, Groestl256ByteCompression>();
+	}
+
+	#[test]
+	fn test_prove_verify_keccak() {
+		test_prove_verify_with_hash::<Keccak256, Keccak256Compression>();
+	}
+
+	#[test]
+	fn test_prove_verify_blake3() {
+		test_prove_verify_with_hash::<Blake3, Blake3Compression>();
+	}
+
+	#[test]
+	fn test_prove_verify_tensor_pcs() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		u32fib(&mut builder, "u32fib", 10).unwrap();
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+
+		let merkle_prover =
+			BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+		let (commit_meta, _) = piop::make_oracle_commit_meta(&constraint_system.oracles).unwrap();
+		let params =
+			make_tensor_params::<_, BinaryField32b, _>(&commit_meta, merkle_prover.scheme(), 30, 1)
+				.unwrap();
+		let pcs = TensorPCS::new(params, merkle_prover);
+
+		let domain_factory = DefaultEvaluationDomainFactory::default();
+		let backend = make_portable_backend();
+
+		let proof = constraint_system::prove_with_pcs::<
+			U,
+			CanonicalTowerFamily,
+			_,
+			_,
+			HasherChallenger<Groestl256>,
+			_,
+		>(&constraint_system, &pcs, &[], witness, &domain_factory, &backend)
+		.unwrap();
+
+		constraint_system::verify_with_pcs::<
+			U,
+			CanonicalTowerFamily,
+			_,
+			HasherChallenger<Groestl256>,
+		>(&constraint_system, &pcs, &[], proof)
+		.unwrap();
+	}
+
+	/// Builds a constraint system with committed columns of fixed values and a trivial constraint,
+	/// so that its witness is the same every time it is built.
+	fn build_with_fixed_column(
+		allocator: &bumpalo::Bump,
+		values: Vec<u32>,
+	) -> (ConstraintSystem<F>, MultilinearExtensionIndex<U, F>) {
+		let mut builder = ConstraintSystemBuilder::new_with_witness(allocator);
+		let unchanged =
+			fixed_u32::<BinaryField32b>(&mut builder, "unchanged", 10, (0..1 << 10).collect())
+				.unwrap();
+		let fixed = fixed_u32::<BinaryField32b>(&mut builder, "fixed", 6, values).unwrap();
+		for (name, column) in [("unchanged", unchanged), ("fixed", fixed)] {
+			builder.assert_zero(
+				name,
+				[column, column],
+				arith_expr!([x, y] = x - y).convert_field(),
+			);
+		}
+		let witness = builder.take_witness().unwrap();
+		(builder.build().unwrap(), witness)
+	}
+
+	#[test]
+	fn test_prove_with_persisted_commitment() {
+		let allocator = bumpalo::Bump::new();
+		let values = (0..1 << 6).collect::<Vec<_>>();
+		let (constraint_system, mut witness) = build_with_fixed_column(&allocator, values.clone());
+
+		let persisted = constraint_system::commit::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+		>(&constraint_system, 1, 30, &mut witness)
+		.unwrap();
+		let commitment = persisted.output.commitment;
+		let mut bytes = Vec::new();
+		persisted
+			.serialize(&mut bytes, SerializationMode::Native)
+			.unwrap();
+		let load = || {
+			PersistedCommitment::deserialize(bytes.as_slice(), SerializationMode::Native).unwrap()
+		};
+
+		let domain_factory = DefaultEvaluationDomainFactory::default();
+		let backend = make_portable_backend();
+		let prove = |values: Vec<u32>, commitment: &Output<Groestl256>| {
+			let (_, witness) = build_with_fixed_column(&allocator, values);
+			constraint_system::prove_with_commitment::<
+				U,
+				CanonicalTowerFamily,
+				_,
+				Groestl256,
+				Groestl256ByteCompression,
+				HasherChallenger<Groestl256>,
+				_,
+			>(
+				&constraint_system, 1, 30, &[], witness, commitment, load(), &domain_factory, &backend
+			)
+		};
+		let verify = |proof| {
+			constraint_system::verify::<
+				U,
+				CanonicalTowerFamily,
+				Groestl256,
+				Groestl256ByteCompression,
+				HasherChallenger<Groestl256>,
+			>(&constraint_system, 1, 30, &[], proof)
+		};
+
+		// The persisted commitment is reused for an unchanged witness, after checking it against
+		// the expected root.
+		let proof = prove(values.clone(), &commitment).unwrap();
+		assert_eq!(&proof.transcript[..commitment.len()], commitment.as_slice());
+		verify(proof).unwrap();
+
+		let mut wrong_commitment = commitment;
+		wrong_commitment[0] ^= 1;
+		assert!(prove(values.clone(), &wrong_commitment).is_err());
+
+		// A changed column invalidates the persisted commitment, so the witness is committed again.
+		let mut changed_values = values;
+		changed_values[0] ^= 1;
+		let proof = prove(changed_values, &commitment).unwrap();
+		assert_ne!(&proof.transcript[..commitment.len()], commitment.as_slice());
+		verify(proof).unwrap();
+	}
+
+	#[test]
+	fn test_replay_tampered_proof() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		u32fib(&mut builder, "u32fib", 10).unwrap();
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+
+		let domain_factory = DefaultEvaluationDomainFactory::default();
+		let backend = make_portable_backend();
+
+		let (proof, prover_trace) = record_trace(|| {
+			constraint_system::prove::<
+				U,
+				CanonicalTowerFamily,
+				_,
+				Groestl256,
+				Groestl256ByteCompression,
+				HasherChallenger<Groestl256>,
+				_,
+			>(&constraint_system, 1, 30, &[], witness, &domain_factory, &backend)
+			.unwrap()
+		});
+
+		let mut tampered = proof.clone();
+		tampered.transcript[1] ^= 1;
+		let (result, divergence) = replay(&prover_trace, || {
+			constraint_system::verify::<
+				U,
+				CanonicalTowerFamily,
+				Groestl256,
+				Groestl256ByteCompression,
+				HasherChallenger<Groestl256>,
+			>(&constraint_system, 1, 30, &[], tampered)
+		});
+		assert!(result.is_err());
+		let divergence = divergence.unwrap();
+		assert_eq!(divergence.phase.as_deref(), Some("commitment"));
+		assert_eq!(divergence.offset, 1);
+
+		let (result, divergence) = replay(&prover_trace, || {
+			constraint_system::verify::<
+				U,
+				CanonicalTowerFamily,
+				Groestl256,
+				Groestl256ByteCompression,
+				HasherChallenger<Groestl256>,
+			>(&constraint_system, 1, 30, &[], proof)
+		});
+		result.unwrap();
+		assert_eq!(divergence, None);
+	}
 }
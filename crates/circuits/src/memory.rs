@@ -0,0 +1,299 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Offline memory checking of a read/write memory.
+//!
+//! Every memory cell is a tuple `(address, value, timestamp)` travelling through a channel. The
+//! initial memory pushes each cell with timestamp zero, the access at row `i` of the trace pulls
+//! the cell it reads and pushes it back with the value it writes and timestamp `i + 1`, and the
+//! final memory pulls each cell in its last state. The channel balances only if every access reads
+//! the value last written to its address, provided every read timestamp is below the timestamp of
+//! the access. The latter is checked by the addition `read_timestamp + delta = i`, where range
+//! checks of both terms rule out a wraparound.
+
+use anyhow::{ensure, Result};
+use binius_core::oracle::OracleId;
+use binius_field::{BinaryField1b, BinaryField32b, Field, TowerField};
+use binius_macros::arith_expr;
+
+use crate::{
+	arithmetic, builder::ConstraintSystemBuilder, range_check::range_check_bits, transparent,
+};
+
+type B1 = BinaryField1b;
+type B32 = BinaryField32b;
+
+/// An access of the memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Access {
+	Read { address: u32 },
+	Write { address: u32, value: u32 },
+}
+
+/// The oracles of the accesses, one access per row.
+///
+/// The addresses and values hold [`BinaryField32b`] elements, and `is_write` is a bit column.
+/// Circuits using the memory constrain these oracles to their own columns.
+#[derive(Debug, Clone, Copy)]
+pub struct MemoryAccesses {
+	pub address: OracleId,
+	pub is_write: OracleId,
+	/// The value of the cell before the access.
+	pub read_value: OracleId,
+	/// The value of the cell after the access, which equals `read_value` for reads.
+	pub write_value: OracleId,
+}
+
+/// A memory of `u32` values at `u32` addresses below the size of its public initial state.
+pub struct Memory {
+	init: Vec<u32>,
+	accesses: Vec<Access>,
+	values: Vec<u32>,
+}
+
+impl Memory {
+	/// Creates a memory with the given initial values, whose number must be a power of two.
+	pub fn new(init: Vec<u32>) -> Self {
+		Self {
+			values: init.clone(),
+			init,
+			accesses: vec![],
+		}
+	}
+
+	/// Records a read of `address` and returns the value read.
+	pub fn read(&mut self, address: u32) -> u32 {
+		self.accesses.push(Access::Read { address });
+		self.values[address as usize]
+	}
+
+	/// Records a write of `value` to `address`.
+	pub fn write(&mut self, address: u32, value: u32) {
+		self.accesses.push(Access::Write { address, value });
+		self.values[address as usize] = value;
+	}
+
+	/// The recorded accesses, in order.
+	pub fn accesses(&self) -> &[Access] {
+		&self.accesses
+	}
+
+	/// Adds the memory checking of `count` accesses in a trace of `1 << log_size` rows.
+	///
+	/// The prover must have recorded exactly `count` accesses, the verifier records none.
+	pub fn build(
+		&self,
+		builder: &mut ConstraintSystemBuilder,
+		name: impl ToString,
+		log_size: usize,
+		count: usize,
+	) -> Result<MemoryAccesses> {
+		ensure!(self.init.len().is_power_of_two(), "memory size must be a power of two");
+		ensure!(count <= 1 << log_size, "number of accesses exceeds the trace size");
+		ensure!(log_size < 32, "timestamps must fit into 32 bits");
+		let log_memory_size = self.init.len().ilog2() as usize;
+
+		builder.push_namespace(name);
+		let channel = builder.add_channel();
+
+		// the memory before the first and after the last access
+		let cell_address = transparent::make_transparent(
+			builder,
+			"cell_address",
+			&(0..self.init.len() as u32)
+				.map(B32::new)
+				.collect::<Vec<_>>(),
+		)?;
+		let init_value = transparent::make_transparent(
+			builder,
+			"init_value",
+			&self.init.iter().copied().map(B32::new).collect::<Vec<_>>(),
+		)?;
+		let init_timestamp =
+			transparent::constant(builder, "init_timestamp", log_memory_size, B32::ZERO)?;
+		let final_value = builder.add_committed("final_value", log_memory_size, B32::TOWER_LEVEL);
+		let final_timestamp =
+			builder.add_committed("final_timestamp", log_memory_size, B32::TOWER_LEVEL);
+
+		// the accesses
+		let index = transparent::make_transparent(
+			builder,
+			"index",
+			&(0..1 << log_size).map(B32::new).collect::<Vec<_>>(),
+		)?;
+		let timestamp = transparent::make_transparent(
+			builder,
+			"timestamp",
+			&(1..=1 << log_size).map(B32::new).collect::<Vec<_>>(),
+		)?;
+		let address = builder.add_committed("address", log_size, B32::TOWER_LEVEL);
+		let is_write = builder.add_committed("is_write", log_size, B1::TOWER_LEVEL);
+		let read_value = builder.add_committed("read_value", log_size, B32::TOWER_LEVEL);
+		let write_value = builder.add_committed("write_value", log_size, B32::TOWER_LEVEL);
+		let read_timestamp_bits =
+			builder.add_committed("read_timestamp_bits", log_size + 5, B1::TOWER_LEVEL);
+		let delta_bits = builder.add_committed("delta_bits", log_size + 5, B1::TOWER_LEVEL);
+
+		let (init, accesses) = (self.init.clone(), self.accesses.clone());
+		builder.fill_witness(
+			[
+				address,
+				is_write,
+				read_value,
+				write_value,
+				read_timestamp_bits,
+				delta_bits,
+				final_value,
+				final_timestamp,
+			],
+			move |witness, _| {
+				ensure!(count == accesses.len(), "count does not match the recorded accesses");
+
+				let mut values = init.clone();
+				let mut timestamps = vec![0u32; init.len()];
+
+				let mut address_col = witness.new_column::<B32>(address);
+				let mut is_write_col = witness.new_column::<B1>(is_write);
+				let mut read_value_col = witness.new_column::<B32>(read_value);
+				let mut write_value_col = witness.new_column::<B32>(write_value);
+				let mut read_timestamp_col = witness.new_column::<B1>(read_timestamp_bits);
+				let mut delta_col = witness.new_column::<B1>(delta_bits);
+				let address_u32 = address_col.as_mut_slice::<u32>();
+				let is_write_u8 = is_write_col.as_mut_slice::<u8>();
+				let read_value_u32 = read_value_col.as_mut_slice::<u32>();
+				let write_value_u32 = write_value_col.as_mut_slice::<u32>();
+				let read_timestamp_u32 = read_timestamp_col.as_mut_slice::<u32>();
+				let delta_u32 = delta_col.as_mut_slice::<u32>();
+
+				for (i, access) in accesses.iter().enumerate() {
+					let (address, write) = match *access {
+						Access::Read { address } => (address, None),
+						Access::Write { address, value } => (address, Some(value)),
+					};
+					let cell = address as usize;
+					ensure!(cell < values.len(), "address {address} out of bounds");
+
+					address_u32[i] = address;
+					is_write_u8[i / 8] |= (write.is_some() as u8) << (i % 8);
+					read_value_u32[i] = values[cell];
+					read_timestamp_u32[i] = timestamps[cell];
+					values[cell] = write.unwrap_or(values[cell]);
+					write_value_u32[i] = values[cell];
+					timestamps[cell] = i as u32 + 1;
+				}
+				// padding rows read the zero timestamp, so that the addition holds on every row
+				for (i, delta) in delta_u32.iter_mut().enumerate() {
+					*delta = i as u32 - read_timestamp_u32[i];
+				}
+
+				witness.new_column::<B32>(final_value).as_mut_slice::<u32>()[..values.len()]
+					.copy_from_slice(&values);
+				witness
+					.new_column::<B32>(final_timestamp)
+					.as_mut_slice::<u32>()[..timestamps.len()]
+					.copy_from_slice(&timestamps);
+				Ok(())
+			},
+		)?;
+
+		builder.assert_zero(
+			"read_only",
+			[is_write, read_value, write_value],
+			arith_expr!(
+				[is_write, read_value, write_value] = (1 - is_write) * (write_value - read_value)
+			)
+			.convert_field(),
+		);
+
+		// read_timestamp + delta = index, without wraparound as both terms are below the trace size
+		let read_timestamp =
+			arithmetic::u32::packed(builder, "read_timestamp", read_timestamp_bits)?;
+		let delta = arithmetic::u32::packed(builder, "delta", delta_bits)?;
+		range_check_bits::<B32>(builder, "read_timestamp_range", read_timestamp, count, log_size)?;
+		range_check_bits::<B32>(builder, "delta_range", delta, count, log_size)?;
+		let sum_bits = arithmetic::u32::add(
+			builder,
+			"sum",
+			read_timestamp_bits,
+			delta_bits,
+			arithmetic::Flags::Unchecked,
+		)?;
+		let sum = arithmetic::u32::packed(builder, "sum_packed", sum_bits)?;
+		builder.assert_zero(
+			"monotonic",
+			[sum, index],
+			arith_expr!([sum, index] = sum - index).convert_field(),
+		);
+
+		builder.send(channel, 1 << log_memory_size, [cell_address, init_value, init_timestamp])?;
+		builder.receive(channel, count, [address, read_value, read_timestamp])?;
+		builder.send(channel, count, [address, write_value, timestamp])?;
+		builder.receive(
+			channel,
+			1 << log_memory_size,
+			[cell_address, final_value, final_timestamp],
+		)?;
+		builder.pop_namespace();
+
+		Ok(MemoryAccesses {
+			address,
+			is_write,
+			read_value,
+			write_value,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_core::constraint_system::validate::validate_witness;
+	use rand::{rngs::StdRng, Rng, SeedableRng};
+
+	use super::*;
+	use crate::builder::test_utils::test_circuit;
+
+	const LOG_SIZE: usize = 7;
+	const COUNT: usize = 100;
+
+	fn random_memory(init: Vec<u32>, count: usize) -> Memory {
+		let mut rng = StdRng::seed_from_u64(0);
+		let size = init.len() as u32;
+		let mut memory = Memory::new(init);
+		for _ in 0..count {
+			let address = rng.gen_range(0..size);
+			if rng.gen() {
+				memory.write(address, rng.gen());
+			} else {
+				memory.read(address);
+			}
+		}
+		memory
+	}
+
+	#[test]
+	fn test_memory() {
+		test_circuit(|builder| {
+			let init = (0..16).map(|i| i * 7).collect::<Vec<_>>();
+			random_memory(init, COUNT).build(builder, "memory", LOG_SIZE, COUNT)?;
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_wrong_init() {
+		let mut verifier_builder = ConstraintSystemBuilder::new();
+		Memory::new(vec![0; 16])
+			.build(&mut verifier_builder, "memory", LOG_SIZE, COUNT)
+			.unwrap();
+		let constraint_system = verifier_builder.build().unwrap();
+
+		let allocator = bumpalo::Bump::new();
+		let mut prover_builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		random_memory(vec![1; 16], COUNT)
+			.build(&mut prover_builder, "memory", LOG_SIZE, COUNT)
+			.unwrap();
+		let witness = prover_builder.take_witness().unwrap();
+
+		assert!(validate_witness(&constraint_system, &[], &witness).is_err());
+	}
+}
@@ -0,0 +1,534 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Verification of Merkle tree authentication paths.
+//!
+//! Every row of the trace verifies one path from a leaf digest up to the root, so a trace with
+//! `1 << log_size` rows verifies a batch of paths of equal depth. At each level, the bit of the
+//! leaf index decides whether the current node is the left or the right child, and the parent is
+//! the compression of both children with the function the tree was built with, see
+//! [`Groestl256Compression`], [`VisionHasherCompression`] and [`VisionTruncatedCompression`].
+
+use anyhow::{ensure, Result};
+use binius_core::{
+	constraint_system::channel::{Boundary, FlushDirection},
+	oracle::OracleId,
+};
+use binius_field::{
+	as_packed_field::PackScalar, BinaryField1b, BinaryField32b, BinaryField8b, ExtensionField,
+	Field, TowerField,
+};
+use binius_macros::arith_expr;
+use bytemuck::Pod;
+
+use crate::{
+	aes::aes_byte_to_tower,
+	builder::{
+		types::{F, U},
+		ConstraintSystemBuilder,
+	},
+	groestl, vision,
+};
+
+/// Number of bytes in the digests of the supported hash functions.
+pub const DIGEST_BYTES: usize = 32;
+
+/// A two-to-one compression function arithmetized over digests held in oracles.
+pub trait MerkleCompression {
+	/// Field of the digest elements.
+	type Element: TowerField + Pod;
+
+	/// The oracles of a digest, one digest per row.
+	type Digest: Copy + Default + AsRef<[OracleId]> + AsMut<[OracleId]>;
+
+	fn compress(
+		builder: &mut ConstraintSystemBuilder,
+		name: impl ToString,
+		log_size: usize,
+		left: Self::Digest,
+		right: Self::Digest,
+	) -> Result<Self::Digest>;
+
+	/// Splits a digest computed outside of the circuit into its elements.
+	fn digest_elements(digest: &[u8; DIGEST_BYTES]) -> Vec<Self::Element>;
+}
+
+/// The compression of [`binius_hash::groestl::Groestl256ByteCompression`].
+///
+/// Digest bytes are tower field elements, see [`crate::aes`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Groestl256Compression;
+
+impl MerkleCompression for Groestl256Compression {
+	type Element = BinaryField8b;
+	type Digest = [OracleId; groestl::DIGEST_SIZE];
+
+	fn compress(
+		builder: &mut ConstraintSystemBuilder,
+		name: impl ToString,
+		log_size: usize,
+		left: Self::Digest,
+		right: Self::Digest,
+	) -> Result<Self::Digest> {
+		groestl::groestl256_byte_compression(builder, name, log_size, left, right)
+	}
+
+	fn digest_elements(digest: &[u8; DIGEST_BYTES]) -> Vec<Self::Element> {
+		digest.iter().copied().map(aes_byte_to_tower).collect()
+	}
+}
+
+/// The compression of a Merkle tree hashed with [`binius_hash::VisionHasherDigest`].
+///
+/// Digests are held as the little-endian 32-bit words of the bytes.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct VisionHasherCompression;
+
+impl MerkleCompression for VisionHasherCompression {
+	type Element = BinaryField32b;
+	type Digest = [OracleId; vision::DIGEST_SIZE];
+
+	fn compress(
+		builder: &mut ConstraintSystemBuilder,
+		name: impl ToString,
+		log_size: usize,
+		left: Self::Digest,
+		right: Self::Digest,
+	) -> Result<Self::Digest> {
+		vision::vision_compression(builder, name, log_size, left, right)
+	}
+
+	fn digest_elements(digest: &[u8; DIGEST_BYTES]) -> Vec<Self::Element> {
+		vision_digest_elements(digest)
+	}
+}
+
+/// The compression of [`binius_hash::VisionByteCompression`], a single permutation of the
+/// digest elements.
+///
+/// Digests are held as the little-endian 32-bit words of the bytes.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct VisionTruncatedCompression;
+
+impl MerkleCompression for VisionTruncatedCompression {
+	type Element = BinaryField32b;
+	type Digest = [OracleId; vision::DIGEST_SIZE];
+
+	fn compress(
+		builder: &mut ConstraintSystemBuilder,
+		name: impl ToString,
+		log_size: usize,
+		left: Self::Digest,
+		right: Self::Digest,
+	) -> Result<Self::Digest> {
+		vision::vision_truncated_compression(builder, name, log_size, left, right)
+	}
+
+	fn digest_elements(digest: &[u8; DIGEST_BYTES]) -> Vec<Self::Element> {
+		vision_digest_elements(digest)
+	}
+}
+
+fn vision_digest_elements(digest: &[u8; DIGEST_BYTES]) -> Vec<BinaryField32b> {
+	digest
+		.chunks_exact(4)
+		.map(|word| {
+			BinaryField32b::new(u32::from_le_bytes(word.try_into().expect("chunk is 4 bytes")))
+		})
+		.collect()
+}
+
+/// Computes the root of the path from `leaf` in every row.
+///
+/// `index_bits` are the bits of the leaf index, least significant first, and `siblings` holds the
+/// sibling of the node at every level, starting from the leaf level.
+pub fn merkle_root<C>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	log_size: usize,
+	leaf: C::Digest,
+	index_bits: &[OracleId],
+	siblings: &[C::Digest],
+) -> Result<C::Digest>
+where
+	C: MerkleCompression,
+	U: PackScalar<C::Element>,
+	F: ExtensionField<C::Element>,
+{
+	ensure!(
+		index_bits.len() == siblings.len(),
+		"path has {} index bits and {} siblings",
+		index_bits.len(),
+		siblings.len()
+	);
+	builder.push_namespace(name);
+
+	let mut node = leaf;
+	for (level, (&bit, &sibling)) in index_bits.iter().zip(siblings).enumerate() {
+		builder.push_namespace(format!("level[{level}]"));
+		let (left, right) = order_children::<C>(builder, log_size, bit, node, sibling)?;
+		node = C::compress(builder, "compress", log_size, left, right)?;
+		builder.pop_namespace();
+	}
+
+	builder.pop_namespace();
+	Ok(node)
+}
+
+/// Verifies the paths in the first `count` rows against the public `root`.
+///
+/// The computed roots are pushed to a new channel, and the returned boundary pulls `root` from it
+/// `count` times, so that the channel balances only if every path leads to `root`.
+#[allow(clippy::too_many_arguments)]
+pub fn verify_merkle_paths<C>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	log_size: usize,
+	count: usize,
+	leaf: C::Digest,
+	index_bits: &[OracleId],
+	siblings: &[C::Digest],
+	root: &[u8; DIGEST_BYTES],
+) -> Result<Boundary<F>>
+where
+	C: MerkleCompression,
+	U: PackScalar<C::Element>,
+	F: ExtensionField<C::Element>,
+{
+	builder.push_namespace(name);
+	let computed_root = merkle_root::<C>(builder, "root", log_size, leaf, index_bits, siblings)?;
+
+	let channel_id = builder.add_channel();
+	builder.send(channel_id, count, computed_root.as_ref().iter().copied())?;
+	builder.pop_namespace();
+
+	Ok(Boundary {
+		values: C::digest_elements(root)
+			.into_iter()
+			.map(Into::into)
+			.collect(),
+		channel_id,
+		direction: FlushDirection::Pull,
+		multiplicity: count as u64,
+	})
+}
+
+/// Returns the children in order, `(sibling, node)` where the index bit is set and
+/// `(node, sibling)` elsewhere.
+///
+/// The left child is committed and selected by the bit, the right one is the sum of both children
+/// and the left one.
+fn order_children<C>(
+	builder: &mut ConstraintSystemBuilder,
+	log_size: usize,
+	bit: OracleId,
+	node: C::Digest,
+	sibling: C::Digest,
+) -> Result<(C::Digest, C::Digest)>
+where
+	C: MerkleCompression,
+	U: PackScalar<C::Element>,
+	F: ExtensionField<C::Element>,
+{
+	let mut left = C::Digest::default();
+	let mut right = C::Digest::default();
+	for (i, (&node, &sibling)) in node.as_ref().iter().zip(sibling.as_ref()).enumerate() {
+		let left_id =
+			builder.add_committed(format!("left[{i}]"), log_size, C::Element::TOWER_LEVEL);
+		let right_id = builder.add_linear_combination(
+			format!("right[{i}]"),
+			log_size,
+			[(node, F::ONE), (sibling, F::ONE), (left_id, F::ONE)],
+		)?;
+
+		builder.fill_witness([left_id, right_id], move |witness, _| {
+			let bits = witness.get::<BinaryField1b>(bit)?.as_slice::<u8>();
+			let node = witness.get::<C::Element>(node)?.as_slice::<C::Element>();
+			let sibling = witness.get::<C::Element>(sibling)?.as_slice::<C::Element>();
+
+			let mut left_column = witness.new_column::<C::Element>(left_id);
+			let mut right_column = witness.new_column::<C::Element>(right_id);
+			let left_values = left_column.as_mut_slice::<C::Element>();
+			let right_values = right_column.as_mut_slice::<C::Element>();
+			for z in 0..1 << log_size {
+				if (bits[z / 8] >> (z % 8)) & 1 == 1 {
+					left_values[z] = sibling[z];
+					right_values[z] = node[z];
+				} else {
+					left_values[z] = node[z];
+					right_values[z] = sibling[z];
+				}
+			}
+			Ok(())
+		})?;
+
+		builder.assert_zero(
+			format!("select[{i}]"),
+			[left_id, node, sibling, bit],
+			arith_expr!([left, node, sibling, bit] = left - node - bit * (sibling - node))
+				.convert_field(),
+		);
+
+		left.as_mut()[i] = left_id;
+		right.as_mut()[i] = right_id;
+	}
+	Ok((left, right))
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_core::constraint_system::validate::validate_witness;
+	use binius_hash::{
+		groestl::Groestl256ByteCompression, PseudoCompressionFunction, VisionByteCompression,
+		VisionHasherDigest,
+	};
+	use digest::Digest;
+	use rand::{rngs::StdRng, Rng, SeedableRng};
+
+	use super::*;
+	use crate::builder::test_utils::test_circuit;
+
+	type Compress = fn(&[u8; DIGEST_BYTES], &[u8; DIGEST_BYTES]) -> [u8; DIGEST_BYTES];
+
+	fn groestl_compress(
+		left: &[u8; DIGEST_BYTES],
+		right: &[u8; DIGEST_BYTES],
+	) -> [u8; DIGEST_BYTES] {
+		Groestl256ByteCompression
+			.compress([(*left).into(), (*right).into()])
+			.into()
+	}
+
+	fn vision_compress(
+		left: &[u8; DIGEST_BYTES],
+		right: &[u8; DIGEST_BYTES],
+	) -> [u8; DIGEST_BYTES] {
+		VisionHasherDigest::new()
+			.chain_update(left)
+			.chain_update(right)
+			.finalize()
+			.into()
+	}
+
+	fn vision_truncated_compress(
+		left: &[u8; DIGEST_BYTES],
+		right: &[u8; DIGEST_BYTES],
+	) -> [u8; DIGEST_BYTES] {
+		VisionByteCompression::default()
+			.compress([(*left).into(), (*right).into()])
+			.into()
+	}
+
+	/// The paths of random leaves of a random tree, with the root of the tree.
+	struct Paths {
+		root: [u8; DIGEST_BYTES],
+		leaves: Vec<[u8; DIGEST_BYTES]>,
+		indices: Vec<usize>,
+		siblings: Vec<Vec<[u8; DIGEST_BYTES]>>,
+	}
+
+	impl Paths {
+		fn random(compress: Compress, depth: usize, n_paths: usize) -> Self {
+			let mut rng = StdRng::seed_from_u64(0);
+			let mut layers = vec![(0..1 << depth)
+				.map(|_| rng.gen())
+				.collect::<Vec<[u8; DIGEST_BYTES]>>()];
+			for _ in 0..depth {
+				let layer = layers
+					.last()
+					.unwrap()
+					.chunks_exact(2)
+					.map(|pair| compress(&pair[0], &pair[1]))
+					.collect();
+				layers.push(layer);
+			}
+
+			let indices = (0..n_paths)
+				.map(|_| rng.gen_range(0..1 << depth))
+				.collect::<Vec<usize>>();
+			let siblings = (0..depth)
+				.map(|level| {
+					indices
+						.iter()
+						.map(|&index| layers[level][(index >> level) ^ 1])
+						.collect()
+				})
+				.collect();
+			Self {
+				root: layers[depth][0],
+				leaves: indices.iter().map(|&index| layers[0][index]).collect(),
+				indices,
+				siblings,
+			}
+		}
+
+		fn depth(&self) -> usize {
+			self.siblings.len()
+		}
+	}
+
+	fn committed_digests<C>(
+		builder: &mut ConstraintSystemBuilder,
+		name: impl ToString,
+		log_size: usize,
+		digests: &[[u8; DIGEST_BYTES]],
+	) -> Result<C::Digest>
+	where
+		C: MerkleCompression,
+		U: PackScalar<C::Element>,
+		F: ExtensionField<C::Element>,
+	{
+		builder.push_namespace(name);
+		let elements = digests.iter().map(C::digest_elements).collect::<Vec<_>>();
+		let mut ids = C::Digest::default();
+		for (i, id) in ids.as_mut().iter_mut().enumerate() {
+			*id = builder.add_committed(format!("{i}"), log_size, C::Element::TOWER_LEVEL);
+			let id = *id;
+			let values = elements
+				.iter()
+				.map(|elements| elements[i])
+				.collect::<Vec<_>>();
+			builder.fill_witness([id], move |witness, _| {
+				witness
+					.new_column::<C::Element>(id)
+					.as_mut_slice::<C::Element>()[..values.len()]
+					.copy_from_slice(&values);
+				Ok(())
+			})?;
+		}
+		builder.pop_namespace();
+		Ok(ids)
+	}
+
+	fn committed_index_bits(
+		builder: &mut ConstraintSystemBuilder,
+		log_size: usize,
+		depth: usize,
+		indices: &[usize],
+	) -> Result<Vec<OracleId>> {
+		(0..depth)
+			.map(|level| {
+				let id = builder.add_committed(
+					format!("index_bits[{level}]"),
+					log_size,
+					BinaryField1b::TOWER_LEVEL,
+				);
+				let indices = indices.to_vec();
+				builder.fill_witness([id], move |witness, _| {
+					let mut column = witness.new_column::<BinaryField1b>(id);
+					let bits = column.as_mut_slice::<u8>();
+					for (z, index) in indices.iter().enumerate() {
+						bits[z / 8] |= (((index >> level) & 1) as u8) << (z % 8);
+					}
+					Ok(())
+				})?;
+				Ok(id)
+			})
+			.collect()
+	}
+
+	fn build_verification<C>(
+		builder: &mut ConstraintSystemBuilder,
+		log_size: usize,
+		count: usize,
+		paths: &Paths,
+		root: &[u8; DIGEST_BYTES],
+	) -> Result<Vec<Boundary<F>>>
+	where
+		C: MerkleCompression,
+		U: PackScalar<C::Element>,
+		F: ExtensionField<C::Element>,
+	{
+		let leaf = committed_digests::<C>(builder, "leaf", log_size, &paths.leaves)?;
+		let index_bits = committed_index_bits(builder, log_size, paths.depth(), &paths.indices)?;
+		let siblings = paths
+			.siblings
+			.iter()
+			.enumerate()
+			.map(|(level, siblings)| {
+				committed_digests::<C>(builder, format!("siblings[{level}]"), log_size, siblings)
+			})
+			.collect::<Result<Vec<_>>>()?;
+		let boundary = verify_merkle_paths::<C>(
+			builder,
+			"merkle",
+			log_size,
+			count,
+			leaf,
+			&index_bits,
+			&siblings,
+			root,
+		)?;
+		Ok(vec![boundary])
+	}
+
+	#[test]
+	fn test_groestl_paths() {
+		test_circuit(|builder| {
+			let log_size = 4;
+			let paths = Paths::random(groestl_compress, 2, 1 << log_size);
+			build_verification::<Groestl256Compression>(
+				builder,
+				log_size,
+				1 << log_size,
+				&paths,
+				&paths.root,
+			)
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_vision_paths() {
+		test_circuit(|builder| {
+			let log_size = 4;
+			let paths = Paths::random(vision_compress, 3, 1 << log_size);
+			build_verification::<VisionHasherCompression>(
+				builder,
+				log_size,
+				11,
+				&paths,
+				&paths.root,
+			)
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_vision_truncated_paths() {
+		test_circuit(|builder| {
+			let log_size = 4;
+			let paths = Paths::random(vision_truncated_compress, 3, 1 << log_size);
+			build_verification::<VisionTruncatedCompression>(
+				builder,
+				log_size,
+				1 << log_size,
+				&paths,
+				&paths.root,
+			)
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_wrong_root() {
+		let log_size = 4;
+		let paths = Paths::random(vision_compress, 2, 1 << log_size);
+		let mut root = paths.root;
+		root[0] ^= 1;
+
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let boundaries = build_verification::<VisionHasherCompression>(
+			&mut builder,
+			log_size,
+			1 << log_size,
+			&paths,
+			&root,
+		)
+		.unwrap();
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		assert!(validate_witness(&constraint_system, &boundaries, &witness).is_err());
+	}
+}
@@ -27,11 +27,12 @@ where
 	let packed_id =
 		builder.add_packed(name, oracle_id, FOutput::TOWER_LEVEL - FInput::TOWER_LEVEL)?;
 
-	if let Some(witness) = builder.witness() {
+	builder.fill_witness([packed_id], move |witness, _| {
 		let values_witness = witness.get::<FInput>(oracle_id)?;
 
 		witness.set(packed_id, values_witness.repacked::<FOutput>())?;
-	}
+		Ok(())
+	})?;
 
 	Ok(packed_id)
 }
@@ -52,26 +52,38 @@ where
 	F: ExtensionField<FS>,
 	FS: TowerField + Pod,
 {
-	let n_vars = builder.log_rows([table])?;
+	plain_lookup_multiple::<FS>(
+		builder,
+		table,
+		&[(lookup_values, lookup_values_count)],
+		LOG_MAX_MULTIPLICITY,
+	)
+}
 
+/// Checks the values of several oracles to be in `table`.
+///
+/// Each entry of `lookups` is an oracle with the number of its leading values to look up, as in
+/// [`plain_lookup`]. All values are pushed into a single channel, so that the table is pulled with
+/// a single set of `log_max_multiplicity` multiplicity bit columns, where the multiplicity of a
+/// table value counts its occurrences across all lookups.
+pub fn plain_lookup_multiple<FS>(
+	builder: &mut ConstraintSystemBuilder,
+	table: OracleId,
+	lookups: &[(OracleId, usize)],
+	log_max_multiplicity: usize,
+) -> Result<(), anyhow::Error>
+where
+	U: PackScalar<FS> + Pod,
+	F: ExtensionField<FS>,
+	FS: TowerField + Pod,
+{
 	let channel = builder.add_channel();
 
-	builder.send(channel, lookup_values_count, [lookup_values])?;
-
-	let mut multiplicities = None;
-	// have prover compute and fill the multiplicities
-	if let Some(witness) = builder.witness() {
-		let table_slice = witness.get::<FS>(table)?.as_slice::<FS>();
-		let values_slice = witness.get::<FS>(lookup_values)?.as_slice::<FS>();
-
-		multiplicities = Some(count_multiplicities(
-			&table_slice[0..1 << n_vars],
-			&values_slice[0..lookup_values_count],
-			false,
-		)?);
+	for &(lookup_values, lookup_values_count) in lookups {
+		builder.send(channel, lookup_values_count, [lookup_values])?;
 	}
 
-	let bits: [OracleId; LOG_MAX_MULTIPLICITY] = get_bits(builder, table, multiplicities)?;
+	let bits = get_bits::<FS>(builder, table, lookups, log_max_multiplicity)?;
 	bits.into_iter().enumerate().try_for_each(|(i, bit)| {
 		builder.flush_custom(FlushDirection::Pull, channel, bit, [table], 1 << i)
 	})?;
@@ -80,11 +92,12 @@ where
 }
 
 // the `i`'th returned bit column holds the `i`'th multiplicity bit.
-fn get_bits<FS, const LOG_MAX_MULTIPLICITY: usize>(
+fn get_bits<FS>(
 	builder: &mut ConstraintSystemBuilder,
 	table: OracleId,
-	multiplicities: Option<Vec<usize>>,
-) -> Result<[OracleId; LOG_MAX_MULTIPLICITY], anyhow::Error>
+	lookups: &[(OracleId, usize)],
+	log_max_multiplicity: usize,
+) -> Result<Vec<OracleId>, anyhow::Error>
 where
 	U: PackScalar<FS>,
 	F: ExtensionField<FS>,
@@ -92,33 +105,50 @@ where
 {
 	let n_vars = builder.log_rows([table])?;
 
-	let bits: [OracleId; LOG_MAX_MULTIPLICITY] = builder
-		.add_committed_multiple::<LOG_MAX_MULTIPLICITY>("bits", n_vars, BinaryField1b::TOWER_LEVEL);
+	let bits = (0..log_max_multiplicity)
+		.map(|i| builder.add_committed(format!("bits_{i}"), n_vars, BinaryField1b::TOWER_LEVEL))
+		.collect::<Vec<_>>();
 
-	if let Some(witness) = builder.witness() {
-		let multiplicities =
-			multiplicities.ok_or_else(|| anyhow::anyhow!("multiplicities empty for prover"))?;
+	let (lookups, bit_ids) = (lookups.to_vec(), bits.clone());
+	builder.fill_witness(bits.clone(), move |witness, _| {
+		// have prover compute and fill the multiplicities
+		let table_slice = witness.get::<FS>(table)?.as_slice::<FS>();
+		let values = lookups
+			.iter()
+			.map(|&(lookup_values, lookup_values_count)| {
+				let values_slice = witness.get::<FS>(lookup_values)?.as_slice::<FS>();
+				Ok(&values_slice[0..lookup_values_count])
+			})
+			.collect::<Result<Vec<_>, anyhow::Error>>()?
+			.concat();
+		let multiplicities = count_multiplicities(&table_slice[0..1 << n_vars], &values, false)?;
 		debug_assert_eq!(1 << n_vars, multiplicities.len());
 
 		// check all multiplicities are in range
 		if multiplicities
 			.iter()
-			.any(|&multiplicity| multiplicity >= 1 << LOG_MAX_MULTIPLICITY)
+			.any(|&multiplicity| multiplicity >= 1 << log_max_multiplicity)
 		{
 			return Err(anyhow::anyhow!(
-				"one or more multiplicities exceed `1 << LOG_MAX_MULTIPLICITY`"
+				"one or more multiplicities exceed `1 << log_max_multiplicity`"
 			));
 		}
 
 		// create the columns for the bits
-		let mut bit_cols = bits.map(|bit| witness.new_column::<BinaryField1b>(bit));
-		let mut packed_bit_cols = bit_cols.each_mut().map(|bit_col| bit_col.packed());
+		let mut bit_cols = bit_ids
+			.iter()
+			.map(|&bit| witness.new_column::<BinaryField1b>(bit))
+			.collect::<Vec<_>>();
+		let mut packed_bit_cols = bit_cols
+			.iter_mut()
+			.map(|bit_col| bit_col.packed())
+			.collect::<Vec<_>>();
 
 		multiplicities
 			.iter()
 			.enumerate()
 			.for_each(|(i, multiplicity)| {
-				(0..LOG_MAX_MULTIPLICITY).for_each(|j| {
+				(0..log_max_multiplicity).for_each(|j| {
 					let bit_set = multiplicity & (1 << j) != 0;
 					set_packed_slice(
 						packed_bit_cols[j],
@@ -130,7 +160,8 @@ where
 					);
 				})
 			});
-	}
+		Ok(())
+	})?;
 
 	Ok(bits)
 }
@@ -0,0 +1,393 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Range checks of field elements interpreted as unsigned integers.
+//!
+//! A value is decomposed into 4-bit limbs, and every bound is checked by a limb-wise subtraction
+//! of the bound from the value. Each limb step of the subtraction is a lookup into a table of all
+//! `(limb, bound limb, borrow in, borrow out)` tuples. The table oracle is shared by all range
+//! checks of the circuit, but every call still allocates its own lookup channel and multiplicity
+//! columns. The borrow out of the most significant limb decides the comparison: the subtraction
+//! `value - a` must not borrow for `value >= a`, and `value - b` must borrow for `value < b`.
+
+use std::ops::{Bound, RangeBounds};
+
+use anyhow::{ensure, Result};
+use binius_core::oracle::OracleId;
+use binius_field::{
+	as_packed_field::PackScalar, BinaryField16b, BinaryField1b, BinaryField4b, ExtensionField,
+	TowerField,
+};
+use binius_math::ArithExpr;
+use bytemuck::Pod;
+use itertools::chain;
+
+use crate::{
+	builder::{
+		types::{F, U},
+		ConstraintSystemBuilder,
+	},
+	plain_lookup::plain_lookup_multiple,
+	transparent,
+};
+
+type B1 = BinaryField1b;
+type B4 = BinaryField4b;
+type B16 = BinaryField16b;
+
+const LIMB_BITS: usize = 4;
+const LIMB_MASK: u64 = (1 << LIMB_BITS) - 1;
+const BOUND_LIMB_SHIFT: usize = LIMB_BITS;
+const BORROW_IN_SHIFT: usize = 2 * LIMB_BITS;
+const BORROW_OUT_SHIFT: usize = 2 * LIMB_BITS + 1;
+const LOG_TABLE_SIZE: usize = 2 * LIMB_BITS + 1;
+
+/// Checks that the first `count` values of `value`, read as unsigned integers, are in `range`.
+///
+/// `value` holds elements of `FS`, whose canonical basis coordinates are the bits of the integer,
+/// least significant bit first. An upper bound beyond the width of `FS` holds for every value and
+/// is not checked, while a lower bound beyond it fails with an error. The remaining rows of `value`
+/// are decomposed into limbs as well, but not looked up.
+pub fn range_check<FS>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	value: OracleId,
+	count: usize,
+	range: impl RangeBounds<u64>,
+) -> Result<()>
+where
+	U: PackScalar<FS> + Pod,
+	F: ExtensionField<FS>,
+	FS: TowerField + ExtensionField<B4> + Pod,
+{
+	ensure!(FS::N_BITS <= u64::BITS as usize, "range checks support values of at most 64 bits");
+
+	let lower = match range.start_bound() {
+		Bound::Included(&a) => a as u128,
+		Bound::Excluded(&a) => a as u128 + 1,
+		Bound::Unbounded => 0,
+	};
+	let upper = match range.end_bound() {
+		Bound::Included(&b) => b as u128 + 1,
+		Bound::Excluded(&b) => b as u128,
+		Bound::Unbounded => 1 << u64::BITS,
+	};
+	ensure!(lower < upper, "range check of an empty range");
+
+	let mut checks = Vec::new();
+	if lower > 0 {
+		ensure!(lower >> FS::N_BITS == 0, "lower bound exceeds the width of the values");
+		checks.push(BoundCheck {
+			bound: lower as u64,
+			borrow: false,
+		});
+	}
+	if upper >> FS::N_BITS == 0 {
+		checks.push(BoundCheck {
+			bound: upper as u64,
+			borrow: true,
+		});
+	}
+	if checks.is_empty() {
+		return Ok(());
+	}
+
+	builder.push_namespace(name);
+	let limbs = decompose::<FS>(builder, value)?;
+	let claims = checks
+		.iter()
+		.enumerate()
+		.map(|(i, check)| check.add_claims(builder, &format!("bound_{i}"), &limbs))
+		.collect::<Result<Vec<_>>>()?
+		.concat();
+
+	let table = limb_table(builder)?;
+	let lookups = claims
+		.iter()
+		.map(|&claim| (claim, count))
+		.collect::<Vec<_>>();
+	let n_lookups = lookups.len() * count;
+	let log_max_multiplicity = (usize::BITS - n_lookups.leading_zeros()) as usize;
+	plain_lookup_multiple::<B16>(builder, table, &lookups, log_max_multiplicity)?;
+	builder.pop_namespace();
+
+	Ok(())
+}
+
+/// Checks that the first `count` values of `value`, read as unsigned integers, fit into `n_bits`
+/// bits.
+pub fn range_check_bits<FS>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	value: OracleId,
+	count: usize,
+	n_bits: usize,
+) -> Result<()>
+where
+	U: PackScalar<FS> + Pod,
+	F: ExtensionField<FS>,
+	FS: TowerField + ExtensionField<B4> + Pod,
+{
+	if n_bits >= FS::N_BITS {
+		return Ok(());
+	}
+	range_check::<FS>(builder, name, value, count, ..1 << n_bits)
+}
+
+/// The table of all limb subtraction steps, shared by all range checks of a circuit.
+///
+/// Only the table oracle is shared. The lookups into it are separate per range check.
+///
+/// Entry `limb | bound_limb << 4 | borrow_in << 8` holds `borrow_out << 9` on top of its index,
+/// where `borrow_out` tells whether `limb - bound_limb - borrow_in` is negative.
+fn limb_table(builder: &mut ConstraintSystemBuilder) -> Result<OracleId> {
+	builder.shared_oracle("range_check_limb_table", |builder| {
+		let entries = (0..1 << LOG_TABLE_SIZE)
+			.map(|index: u64| {
+				let limb = index & LIMB_MASK;
+				let bound_limb = (index >> BOUND_LIMB_SHIFT) & LIMB_MASK;
+				let borrow_in = index >> BORROW_IN_SHIFT;
+				let borrow_out = limb < bound_limb + borrow_in;
+				B16::new((index | (borrow_out as u64) << BORROW_OUT_SHIFT) as u16)
+			})
+			.collect::<Vec<_>>();
+		transparent::make_transparent(builder, "range_check_limb_table", &entries)
+	})
+}
+
+/// Commits the limbs of `value`, least significant first.
+fn decompose<FS>(builder: &mut ConstraintSystemBuilder, value: OracleId) -> Result<Vec<OracleId>>
+where
+	U: PackScalar<FS> + Pod,
+	F: ExtensionField<FS>,
+	FS: TowerField + ExtensionField<B4> + Pod,
+{
+	let log_size = builder.log_rows([value])?;
+	let n_limbs = FS::N_BITS / LIMB_BITS;
+	let limbs = (0..n_limbs)
+		.map(|j| builder.add_committed(format!("limb_{j}"), log_size, B4::TOWER_LEVEL))
+		.collect::<Vec<_>>();
+
+	let limb_ids = limbs.clone();
+	builder.fill_witness(limbs.clone(), move |witness, _| {
+		let values = witness.get::<FS>(value)?;
+		let values = read_values::<FS>(values.as_slice::<u8>());
+		for (j, &limb) in limb_ids.iter().enumerate() {
+			let mut limb = witness.new_column::<B4>(limb);
+			let limb = limb.as_mut_slice::<u8>();
+			for (z, &value) in values.iter().enumerate() {
+				limb[z / 2] |= (((value >> (LIMB_BITS * j)) & LIMB_MASK) as u8) << (4 * (z % 2));
+			}
+		}
+		Ok(())
+	})?;
+
+	// value = Σ_j limb_j · X^{4j}, where X^{4j} is the basis element of FS over B4 at bit 4j
+	let composition = limbs
+		.iter()
+		.enumerate()
+		.fold(ArithExpr::Var(0), |acc, (j, _)| {
+			acc - ArithExpr::Var(j + 1) * ArithExpr::Const(F::new(1 << (LIMB_BITS * j)))
+		});
+	builder.assert_zero("decompose", std::iter::once(value).chain(limbs.clone()), composition);
+
+	Ok(limbs)
+}
+
+/// Reads the values of a column of `FS` elements as integers.
+fn read_values<FS: TowerField>(bytes: &[u8]) -> Vec<u64> {
+	bytes
+		.chunks_exact(FS::N_BITS / 8)
+		.map(|chunk| {
+			chunk
+				.iter()
+				.rev()
+				.fold(0, |acc, &byte| acc << 8 | byte as u64)
+		})
+		.collect()
+}
+
+/// A comparison against a constant bound, by the limb-wise subtraction `value - bound`.
+struct BoundCheck {
+	bound: u64,
+	/// The expected borrow out of the most significant limb.
+	borrow: bool,
+}
+
+impl BoundCheck {
+	/// Adds the lookup claims of the subtraction steps from the least significant nonzero limb of
+	/// the bound upwards. Below it, the limbs of the bound are zero and no step can borrow.
+	fn add_claims(
+		&self,
+		builder: &mut ConstraintSystemBuilder,
+		name: &str,
+		limbs: &[OracleId],
+	) -> Result<Vec<OracleId>> {
+		let log_size = builder.log_rows(limbs.iter().copied())?;
+		let first = self.bound.trailing_zeros() as usize / LIMB_BITS;
+		let last = limbs.len() - 1;
+
+		builder.push_namespace(name);
+		// borrows[j] is the borrow out of limb `first + j` into the next limb
+		let borrows = (first..last)
+			.map(|j| builder.add_committed(format!("borrow_{j}"), log_size, B1::TOWER_LEVEL))
+			.collect::<Vec<_>>();
+		let claims = (first..=last)
+			.map(|j| builder.add_committed(format!("claim_{j}"), log_size, B16::TOWER_LEVEL))
+			.collect::<Vec<_>>();
+
+		let columns = (limbs.to_vec(), borrows.clone(), claims.clone());
+		let (bound, borrow) = (self.bound, self.borrow);
+		builder.fill_witness(chain!(borrows.clone(), claims.clone()), move |witness, _| {
+			let (limbs, borrows, claims) = &columns;
+			let values = limbs
+				.iter()
+				.map(|&limb| witness.get::<B4>(limb).map(|limb| limb.as_slice::<u8>()))
+				.collect::<Result<Vec<_>, _>>()?;
+			let mut borrow_cols = borrows
+				.iter()
+				.map(|&borrow| witness.new_column::<B1>(borrow))
+				.collect::<Vec<_>>();
+			let mut claim_cols = claims
+				.iter()
+				.map(|&claim| witness.new_column::<B16>(claim))
+				.collect::<Vec<_>>();
+			let mut borrow_slices = borrow_cols
+				.iter_mut()
+				.map(|col| col.as_mut_slice::<u8>())
+				.collect::<Vec<_>>();
+			let mut claim_slices = claim_cols
+				.iter_mut()
+				.map(|col| col.as_mut_slice::<u16>())
+				.collect::<Vec<_>>();
+
+			for z in 0..1 << log_size {
+				let mut borrow_in = false;
+				for j in first..=last {
+					let limb = ((values[j][z / 2] >> (4 * (z % 2))) as u64) & LIMB_MASK;
+					let bound_limb = (bound >> (LIMB_BITS * j)) & LIMB_MASK;
+					let borrow_out = limb < bound_limb + borrow_in as u64;
+					// the most significant step claims the expected borrow, so that the lookup
+					// fails for values out of range
+					let claimed_borrow = if j == last {
+						borrow
+					} else {
+						borrow_slices[j - first][z / 8] |= (borrow_out as u8) << (z % 8);
+						borrow_out
+					};
+					claim_slices[j - first][z] = (limb
+						| bound_limb << BOUND_LIMB_SHIFT
+						| (borrow_in as u64) << BORROW_IN_SHIFT
+						| (claimed_borrow as u64) << BORROW_OUT_SHIFT)
+						as u16;
+					borrow_in = borrow_out;
+				}
+			}
+			Ok(())
+		})?;
+
+		for (i, j) in (first..=last).enumerate() {
+			let bound_limb = (self.bound >> (LIMB_BITS * j)) & LIMB_MASK;
+			let mut oracles = vec![claims[i], limbs[j]];
+			let mut composition = ArithExpr::Var(0)
+				- ArithExpr::Var(1)
+				- ArithExpr::Const(F::new((bound_limb as u128) << BOUND_LIMB_SHIFT));
+			if i > 0 {
+				oracles.push(borrows[i - 1]);
+				composition -= ArithExpr::Var(oracles.len() - 1)
+					* ArithExpr::Const(F::new(1 << BORROW_IN_SHIFT));
+			}
+			if j < last {
+				oracles.push(borrows[i]);
+				composition -= ArithExpr::Var(oracles.len() - 1)
+					* ArithExpr::Const(F::new(1 << BORROW_OUT_SHIFT));
+			} else if self.borrow {
+				composition -= ArithExpr::Const(F::new(1 << BORROW_OUT_SHIFT));
+			}
+			builder.assert_zero(format!("claim_{j}"), oracles, composition);
+		}
+		builder.pop_namespace();
+
+		Ok(claims)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_core::constraint_system::validate::validate_witness;
+	use binius_field::{BinaryField32b, BinaryField64b};
+	use rand::{rngs::StdRng, Rng, SeedableRng};
+
+	use super::*;
+	use crate::builder::test_utils::test_circuit;
+
+	fn random_values<FS: TowerField>(
+		builder: &mut ConstraintSystemBuilder,
+		name: &str,
+		log_size: usize,
+		range: std::ops::Range<u64>,
+	) -> Result<OracleId>
+	where
+		U: PackScalar<FS>,
+		F: ExtensionField<FS>,
+	{
+		let value = builder.add_committed(name, log_size, FS::TOWER_LEVEL);
+		builder.fill_witness([value], move |witness, _| {
+			let mut rng = StdRng::seed_from_u64(0);
+			let mut col = witness.new_column::<FS>(value);
+			let bytes = col.as_mut_slice::<u8>();
+			for chunk in bytes.chunks_exact_mut(FS::N_BITS / 8) {
+				let value = rng.gen_range(range.clone());
+				chunk.copy_from_slice(&value.to_le_bytes()[..FS::N_BITS / 8]);
+			}
+			Ok(())
+		})?;
+		Ok(value)
+	}
+
+	#[test]
+	fn test_range_check() {
+		test_circuit(|builder| {
+			let log_size = 6;
+			let a = random_values::<BinaryField32b>(builder, "a", log_size, 1000..70000)?;
+			range_check::<BinaryField32b>(builder, "a_range", a, 1 << log_size, 1000..70000)?;
+			range_check::<BinaryField32b>(builder, "a_lower", a, 1 << log_size, 1000..)?;
+			range_check_bits::<BinaryField32b>(builder, "a_bits", a, 1 << log_size, 17)?;
+
+			let b = random_values::<BinaryField64b>(builder, "b", log_size, 1 << 40..u64::MAX)?;
+			range_check::<BinaryField64b>(builder, "b_range", b, 50, 1 << 40..=u64::MAX)?;
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_out_of_range() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let log_size = 6;
+		let a = random_values::<BinaryField32b>(&mut builder, "a", log_size, 0..1 << 20).unwrap();
+		range_check_bits::<BinaryField32b>(&mut builder, "a_bits", a, 1 << log_size, 16).unwrap();
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		assert!(validate_witness(&constraint_system, &[], &witness).is_err());
+	}
+
+	#[test]
+	fn test_bounds_beyond_width() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let log_size = 6;
+		let a = random_values::<BinaryField32b>(&mut builder, "a", log_size, 0..1 << 20).unwrap();
+		range_check::<BinaryField32b>(&mut builder, "a_upper", a, 1 << log_size, ..1 << 40)
+			.unwrap();
+		assert!(range_check::<BinaryField32b>(
+			&mut builder,
+			"a_lower",
+			a,
+			1 << log_size,
+			1 << 40..
+		)
+		.is_err());
+	}
+}
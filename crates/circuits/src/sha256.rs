@@ -1,14 +1,20 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+use std::{collections::HashMap, rc::Rc};
+
 use binius_core::oracle::{OracleId, ShiftVariant};
 use binius_field::{as_packed_field::PackedType, BinaryField1b, Field, TowerField};
 use binius_macros::arith_expr;
-use itertools::izip;
+use binius_math::ArithExpr;
+use itertools::{chain, izip};
 
 use crate::{
 	arithmetic,
 	arithmetic::u32::{u32const_repeating, LOG_U32_BITS},
-	builder::{types::U, ConstraintSystemBuilder},
+	builder::{
+		types::{F, U},
+		ConstraintSystemBuilder,
+	},
 };
 
 type B1 = BinaryField1b;
@@ -29,6 +35,7 @@ pub const INIT: [u32; 8] = [
 	0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
 ];
 
+#[derive(Debug, Clone, Copy)]
 pub enum RotateRightType {
 	Circular,
 	Logical,
@@ -68,33 +75,54 @@ pub fn rotate_and_xor(
 		shifted_oracle_ids.iter().map(|s| (*s, Field::ONE)),
 	)?;
 
-	if let Some(witness) = builder.witness() {
-		let mut result_witness = witness.new_column::<B1>(result_oracle_id);
-		let result_u32 = result_witness.as_mut_slice::<u32>();
-
-		for ((oracle_id, shift, t), shifted_oracle_id) in r.iter().zip(&shifted_oracle_ids) {
-			let values_u32 = witness.get::<B1>(*oracle_id)?.as_slice::<u32>();
+	let r = r.to_vec();
+	builder.fill_witness(
+		chain!([result_oracle_id], shifted_oracle_ids.clone()),
+		move |witness, _| {
+			let mut result_witness = witness.new_column::<B1>(result_oracle_id);
+			let result_u32 = result_witness.as_mut_slice::<u32>();
+
+			for ((oracle_id, shift, t), shifted_oracle_id) in r.iter().zip(&shifted_oracle_ids) {
+				let values_u32 = witness.get::<B1>(*oracle_id)?.as_slice::<u32>();
+
+				let mut shifted_witness = witness.new_column::<B1>(*shifted_oracle_id);
+				let shifted_u32 = shifted_witness.as_mut_slice::<u32>();
+
+				izip!(shifted_u32.iter_mut(), values_u32, result_u32.iter_mut()).for_each(
+					|(shifted, val, res)| {
+						*shifted = match t {
+							RotateRightType::Circular => val.rotate_right(*shift as u32),
+							RotateRightType::Logical => val >> shift,
+						};
+						*res ^= *shifted;
+					},
+				);
+			}
+			Ok(())
+		},
+	)?;
 
-			let mut shifted_witness = witness.new_column::<B1>(*shifted_oracle_id);
-			let shifted_u32 = shifted_witness.as_mut_slice::<u32>();
+	Ok(result_oracle_id)
+}
 
-			izip!(shifted_u32.iter_mut(), values_u32, result_u32.iter_mut()).for_each(
-				|(shifted, val, res)| {
-					*shifted = match t {
-						RotateRightType::Circular => val.rotate_right(*shift as u32),
-						RotateRightType::Logical => val >> shift,
-					};
-					*res ^= *shifted;
-				},
-			);
-		}
+pub fn sha256(
+	builder: &mut ConstraintSystemBuilder,
+	input: [OracleId; 16],
+	log_size: usize,
+) -> Result<[OracleId; 8], anyhow::Error> {
+	if log_size < <PackedType<U, BinaryField1b>>::LOG_WIDTH {
+		Err(anyhow::Error::msg("log_size too small"))?
 	}
 
-	Ok(result_oracle_id)
+	let init_oracles = INIT.map(|val| u32const_repeating(log_size, builder, val, "INIT").unwrap());
+	sha256_compress(builder, init_oracles, input, log_size)
 }
 
-pub fn sha256(
+/// The SHA-256 compression function applied to the chaining value `state` and message block
+/// `input`, returning the next chaining value.
+pub fn sha256_compress(
 	builder: &mut ConstraintSystemBuilder,
+	state: [OracleId; 8],
 	input: [OracleId; 16],
 	log_size: usize,
 ) -> Result<[OracleId; 8], anyhow::Error> {
@@ -144,9 +172,7 @@ pub fn sha256(
 		)?;
 	}
 
-	let init_oracles = INIT.map(|val| u32const_repeating(log_size, builder, val, "INIT").unwrap());
-
-	let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = init_oracles;
+	let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state;
 
 	let k = ROUND_CONSTS_K
 		.map(|val| u32const_repeating(log_size, builder, val, "ROUND_CONSTS_K").unwrap());
@@ -166,7 +192,7 @@ pub fn sha256(
 			],
 		)?;
 
-		if let Some(witness) = builder.witness() {
+		builder.fill_witness([ch[i]], move |witness, _| {
 			let mut ch_witness = witness.new_column::<B1>(ch[i]);
 			let ch_u32 = ch_witness.as_mut_slice::<u32>();
 			let e_u32 = witness.get::<B1>(e)?.as_slice::<u32>();
@@ -175,7 +201,8 @@ pub fn sha256(
 			izip!(ch_u32.iter_mut(), e_u32, f_u32, g_u32).for_each(|(ch, e, f, g)| {
 				*ch = g ^ (e & (f ^ g));
 			});
-		}
+			Ok(())
+		})?;
 
 		let h_sigma1 =
 			arithmetic::u32::add(builder, "h_sigma1", h, sigma1, arithmetic::Flags::Unchecked)?;
@@ -201,7 +228,7 @@ pub fn sha256(
 			],
 		)?;
 
-		if let Some(witness) = builder.witness() {
+		builder.fill_witness([maj[i]], move |witness, _| {
 			let mut maj_witness = witness.new_column::<B1>(maj[i]);
 			let maj_u32 = maj_witness.as_mut_slice::<u32>();
 			let a_u32 = witness.get::<B1>(a)?.as_slice::<u32>();
@@ -210,7 +237,8 @@ pub fn sha256(
 			izip!(maj_u32.iter_mut(), a_u32, b_u32, c_u32).for_each(|(maj, a, b, c)| {
 				*maj = (a & (b ^ c)) ^ (b & c);
 			});
-		}
+			Ok(())
+		})?;
 
 		let temp2 =
 			arithmetic::u32::add(builder, "temp2", sigma0, maj[i], arithmetic::Flags::Unchecked)?;
@@ -244,27 +272,365 @@ pub fn sha256(
 	let abcdefgh = [a, b, c, d, e, f, g, h];
 
 	let output = std::array::from_fn(|i| {
-		arithmetic::u32::add(
-			builder,
-			"output",
-			init_oracles[i],
-			abcdefgh[i],
-			arithmetic::Flags::Unchecked,
-		)
-		.unwrap()
+		arithmetic::u32::add(builder, "output", state[i], abcdefgh[i], arithmetic::Flags::Unchecked)
+			.unwrap()
 	});
 
 	Ok(output)
 }
 
+pub struct Sha256MessageOracles {
+	/// The committed message words, word `i` holding message bytes `4 * i..4 * i + 4` in
+	/// big-endian order, one message per 32-bit row.
+	///
+	/// Bytes beyond the end of a message are constrained to be zero.
+	pub message: Vec<OracleId>,
+	/// The message lengths in unary: column `k` is all ones in the row of a message with more than
+	/// `k` bytes, and zero otherwise.
+	pub message_mask: Vec<OracleId>,
+	/// The digest as big-endian 32-bit words.
+	pub digest: [OracleId; 8],
+}
+
+/// Hashes `1 << (log_size - LOG_U32_BITS)` messages of at most `max_message_len` bytes with
+/// SHA-256.
+///
+/// Each message is padded with a single one bit, zero bits and its 64-bit bit length to a
+/// multiple of the 64-byte block size, and its blocks are absorbed by consecutive compressions,
+/// each starting from the chaining value output by the previous one. All messages go through
+/// the compressions of a message of `max_message_len` bytes, and the digest of each message is
+/// selected from the chaining value after its own last block.
+///
+/// The message lengths are committed in unary by [`Sha256MessageOracles::message_mask`], which
+/// determines the position of the padding and the encoded length in every row.
+///
+/// If the builder has a witness, `messages` must be provided. Missing messages are taken to be
+/// empty.
+pub fn sha256_message(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	log_size: usize,
+	max_message_len: usize,
+	messages: Option<&[impl AsRef<[u8]>]>,
+) -> Result<Sha256MessageOracles, anyhow::Error> {
+	if log_size < <PackedType<U, BinaryField1b>>::LOG_WIDTH {
+		Err(anyhow::Error::msg("log_size too small"))?
+	}
+
+	builder.push_namespace(name);
+
+	let n_messages = 1 << (log_size - LOG_U32_BITS);
+	let n_message_words = max_message_len.div_ceil(4);
+	let n_blocks = last_block(max_message_len) + 1;
+
+	let message = (0..n_message_words)
+		.map(|i| builder.add_committed(format!("message[{i}]"), log_size, B1::TOWER_LEVEL))
+		.collect::<Vec<_>>();
+	let message_mask = (0..max_message_len)
+		.map(|k| builder.add_committed(format!("message_mask[{k}]"), log_size, B1::TOWER_LEVEL))
+		.collect::<Vec<_>>();
+	let message_mask_shifted = message_mask
+		.iter()
+		.enumerate()
+		.map(|(k, &id)| {
+			builder.add_shifted(
+				format!("message_mask_shifted[{k}]"),
+				id,
+				1,
+				LOG_U32_BITS,
+				ShiftVariant::LogicalRight,
+			)
+		})
+		.collect::<Result<Vec<_>, _>>()?;
+
+	// The standard padding of every message, which is also the input of the compressions.
+	let messages = messages
+		.map(|messages| {
+			anyhow::ensure!(
+				messages.len() <= n_messages,
+				"at most {n_messages} messages can be hashed, got {}",
+				messages.len()
+			);
+			(0..n_messages)
+				.map(|m| {
+					let message = messages.get(m).map_or(&[][..], |msg| msg.as_ref());
+					anyhow::ensure!(
+						message.len() <= max_message_len,
+						"message {m} has length {}, expected at most {max_message_len}",
+						message.len()
+					);
+					Ok(message.to_vec())
+				})
+				.collect::<Result<Vec<_>, anyhow::Error>>()
+		})
+		.transpose()?;
+	let padded_messages = messages.as_deref().map(|messages| {
+		Rc::new(
+			messages
+				.iter()
+				.map(|message| {
+					let mut padded = vec![0u8; 64 * n_blocks];
+					padded[..message.len()].copy_from_slice(message);
+					padded[message.len()] = 0x80;
+					let end = 64 * (last_block(message.len()) + 1);
+					padded[end - 8..end].copy_from_slice(&(8 * message.len() as u64).to_be_bytes());
+					(message.len(), padded)
+				})
+				.collect::<Vec<_>>(),
+		)
+	});
+
+	let ids = chain!(message.clone(), message_mask.clone(), message_mask_shifted.clone());
+	let columns = (message.clone(), message_mask.clone(), message_mask_shifted.clone());
+	builder.fill_witness(ids, move |witness, _| {
+		let (message, message_mask, message_mask_shifted) = &columns;
+		let messages = messages
+			.as_deref()
+			.ok_or_else(|| anyhow::anyhow!("builder witness available and messages are not"))?;
+
+		for (i, &id) in message.iter().enumerate() {
+			let mut message_witness = witness.new_column::<B1>(id);
+			let message_u32 = message_witness.as_mut_slice::<u32>();
+			for (word, message) in message_u32.iter_mut().zip(messages) {
+				*word = be_word(message, i);
+			}
+		}
+
+		for (k, (&id, &shifted_id)) in message_mask.iter().zip(message_mask_shifted).enumerate() {
+			let mut mask_witness = witness.new_column::<B1>(id);
+			let mut shifted_witness = witness.new_column::<B1>(shifted_id);
+			for (mask, shifted, message) in izip!(
+				mask_witness.as_mut_slice::<u32>(),
+				shifted_witness.as_mut_slice::<u32>(),
+				messages
+			) {
+				*mask = if message.len() > k { u32::MAX } else { 0 };
+				*shifted = *mask >> 1;
+			}
+		}
+		Ok(())
+	})?;
+
+	let mut consts = HashMap::new();
+	let mut constant = |builder: &mut ConstraintSystemBuilder, value: u32| -> anyhow::Result<_> {
+		if let Some(&id) = consts.get(&value) {
+			return Ok(id);
+		}
+		let id = u32const_repeating(log_size, builder, value, &format!("{value:#010x}"))?;
+		consts.insert(value, id);
+		Ok(id)
+	};
+
+	// Every row of a mask column repeats the same bit.
+	let not_top = constant(builder, u32::MAX >> 1)?;
+	for (k, (&id, &shifted_id)) in message_mask.iter().zip(&message_mask_shifted).enumerate() {
+		builder.assert_zero(
+			format!("message_mask_repeated[{k}]"),
+			[id, shifted_id, not_top],
+			arith_expr!([mask, shifted, not_top] = (mask - shifted) * not_top).convert_field(),
+		);
+	}
+
+	// The mask is the unary representation of the message length.
+	for k in 1..max_message_len {
+		builder.assert_zero(
+			format!("message_mask_monotone[{k}]"),
+			[message_mask[k], message_mask[k - 1]],
+			arith_expr!([mask, prev_mask] = mask * (1 - prev_mask)).convert_field(),
+		);
+	}
+
+	// Message bytes beyond the end of the message are zero.
+	for (i, &word) in message.iter().enumerate() {
+		let mut vars = Vars::default();
+		let word = vars.var(word);
+		let mut in_message = ArithExpr::zero();
+		for b in (0..4).filter(|&b| 4 * i + b < max_message_len) {
+			let byte_mask = constant(builder, 0xff000000 >> (8 * b))?;
+			in_message += vars.var(message_mask[4 * i + b]) * vars.var(byte_mask);
+		}
+		builder.assert_zero(
+			format!("message_end[{i}]"),
+			vars.ids,
+			word * (ArithExpr::one() - in_message),
+		);
+	}
+
+	// Whether the message length is at least `k` bytes, and exactly `k` bytes.
+	let at_least = |vars: &mut Vars, k: usize| match k {
+		0 => ArithExpr::one(),
+		k if k > max_message_len => ArithExpr::zero(),
+		k => vars.var(message_mask[k - 1]),
+	};
+	let exactly = |vars: &mut Vars, k: usize| at_least(vars, k) - at_least(vars, k + 1);
+
+	let padded_words = (0..16 * n_blocks)
+		.map(|i| {
+			let mut vars = Vars::default();
+			let mut padding = ArithExpr::zero();
+
+			// The one bit after the last message byte.
+			for b in (0..4).filter(|&b| 4 * i + b <= max_message_len) {
+				let marker = constant(builder, 0x80000000 >> (8 * b))?;
+				padding += exactly(&mut vars, 4 * i + b) * vars.var(marker);
+			}
+
+			// The bit length in the last word of the last block. The high word of the length is
+			// always zero.
+			if i % 16 == 15 {
+				let block_lengths = (0..=max_message_len)
+					.filter(|&k| last_block(k) == i / 16)
+					.collect::<Vec<_>>();
+				for bit in 0..32 {
+					let mut selector = ArithExpr::zero();
+					for &k in block_lengths.iter().filter(|&&k| (8 * k) >> bit & 1 == 1) {
+						selector += exactly(&mut vars, k);
+					}
+					if !vars.ids.is_empty() {
+						let bit_const = constant(builder, 1 << bit)?;
+						padding += selector * vars.var(bit_const);
+					}
+				}
+			}
+
+			if vars.ids.is_empty() {
+				return match message.get(i) {
+					Some(&word) => Ok(word),
+					None => constant(builder, 0),
+				};
+			}
+
+			let padded = builder.add_committed(format!("padded[{i}]"), log_size, B1::TOWER_LEVEL);
+			let mut expr = vars.var(padded) - padding;
+			if let Some(&word) = message.get(i) {
+				expr -= vars.var(word);
+			}
+			builder.assert_zero(format!("padding[{i}]"), vars.ids, expr);
+
+			let padded_messages = padded_messages.clone();
+			builder.fill_witness([padded], move |witness, _| {
+				let padded_messages = padded_messages.as_deref().ok_or_else(|| {
+					anyhow::anyhow!("builder witness available and messages are not")
+				})?;
+				let mut padded_witness = witness.new_column::<B1>(padded);
+				for (word, (_, padded_message)) in padded_witness
+					.as_mut_slice::<u32>()
+					.iter_mut()
+					.zip(padded_messages)
+				{
+					*word = be_word(padded_message, i);
+				}
+				Ok(())
+			})?;
+			Ok(padded)
+		})
+		.collect::<Result<Vec<_>, anyhow::Error>>()?;
+
+	let mut state = INIT.map(|val| u32const_repeating(log_size, builder, val, "INIT").unwrap());
+	let mut block_states = Vec::with_capacity(n_blocks);
+	for (b, block) in padded_words.chunks_exact(16).enumerate() {
+		builder.push_namespace(format!("block[{b}]"));
+		let input = block.try_into().expect("chunks have 16 words");
+		state = sha256_compress(builder, state, input, log_size)?;
+		block_states.push(state);
+		builder.pop_namespace();
+	}
+
+	// The digest is the chaining value after the last block of each message.
+	let digest = if n_blocks == 1 {
+		state
+	} else {
+		let digest: [OracleId; 8] = std::array::from_fn(|w| {
+			builder.add_committed(format!("digest[{w}]"), log_size, B1::TOWER_LEVEL)
+		});
+		for (w, &digest_word) in digest.iter().enumerate() {
+			let mut vars = Vars::default();
+			let mut expr = vars.var(digest_word);
+			for (b, state) in block_states.iter().enumerate() {
+				let first = (64 * b).saturating_sub(8);
+				let end = (64 * (b + 1) - 8).min(max_message_len + 1);
+				expr -=
+					(at_least(&mut vars, first) - at_least(&mut vars, end)) * vars.var(state[w]);
+			}
+			builder.assert_zero(format!("digest[{w}]"), vars.ids, expr);
+		}
+
+		builder.fill_witness(digest, move |witness, _| {
+			let padded_messages = padded_messages
+				.as_deref()
+				.ok_or_else(|| anyhow::anyhow!("builder witness available and messages are not"))?;
+			let last_blocks = padded_messages
+				.iter()
+				.map(|(len, _)| last_block(*len))
+				.collect::<Vec<_>>();
+			for (w, &id) in digest.iter().enumerate() {
+				let states = block_states
+					.iter()
+					.map(|state| Ok(witness.get::<B1>(state[w])?.as_slice::<u32>()))
+					.collect::<Result<Vec<_>, anyhow::Error>>()?;
+				let mut digest_witness = witness.new_column::<B1>(id);
+				for (m, (word, &last)) in digest_witness
+					.as_mut_slice::<u32>()
+					.iter_mut()
+					.zip(&last_blocks)
+					.enumerate()
+				{
+					*word = states[last][m];
+				}
+			}
+			Ok(())
+		})?;
+		digest
+	};
+
+	builder.pop_namespace();
+
+	Ok(Sha256MessageOracles {
+		message,
+		message_mask,
+		digest,
+	})
+}
+
+/// The index of the block holding the bit length of a message of `len` bytes.
+const fn last_block(len: usize) -> usize {
+	(len + 8) / 64
+}
+
+/// The big-endian word `i` of `bytes`, zero-padded.
+fn be_word(bytes: &[u8], i: usize) -> u32 {
+	let mut be_bytes = [0u8; 4];
+	for (be_byte, &byte) in be_bytes.iter_mut().zip(bytes.iter().skip(4 * i)) {
+		*be_byte = byte;
+	}
+	u32::from_be_bytes(be_bytes)
+}
+
+/// The oracles referred to by a constraint, in the order of their variables.
+#[derive(Default)]
+struct Vars {
+	ids: Vec<OracleId>,
+}
+
+impl Vars {
+	fn var(&mut self, id: OracleId) -> ArithExpr<F> {
+		let index = self.ids.iter().position(|&x| x == id).unwrap_or_else(|| {
+			self.ids.push(id);
+			self.ids.len() - 1
+		});
+		ArithExpr::Var(index)
+	}
+}
+
 #[cfg(test)]
 mod tests {
-	use binius_core::oracle::OracleId;
+	use binius_core::{constraint_system::validate::validate_witness, oracle::OracleId};
 	use binius_field::{as_packed_field::PackedType, BinaryField1b};
-	use sha2::{compress256, digest::generic_array::GenericArray};
+	use rand::{rngs::StdRng, Rng, SeedableRng};
+	use sha2::{compress256, digest::generic_array::GenericArray, Digest, Sha256};
 
 	use crate::{
-		builder::{test_utils::test_circuit, types::U},
+		builder::{test_utils::test_circuit, types::U, ConstraintSystemBuilder},
 		unconstrained::unconstrained,
 	};
 
@@ -316,4 +682,65 @@ mod tests {
 		})
 		.unwrap();
 	}
+
+	fn check_sha256_message(log_size: usize, max_message_len: usize, lengths: &[usize]) {
+		let mut rng = StdRng::seed_from_u64(0);
+		let messages = lengths
+			.iter()
+			.map(|&len| (0..len).map(|_| rng.gen()).collect::<Vec<u8>>())
+			.collect::<Vec<_>>();
+
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let oracles = super::sha256_message(
+			&mut builder,
+			"sha256",
+			log_size,
+			max_message_len,
+			Some(&messages),
+		)
+		.unwrap();
+
+		let witness = builder.witness().unwrap();
+		let digest_words = oracles
+			.digest
+			.map(|id| witness.get::<BinaryField1b>(id).unwrap().as_slice::<u32>());
+		for m in 0..digest_words[0].len() {
+			let message = messages.get(m).map_or(&[][..], |message| &message[..]);
+			let digest = digest_words
+				.iter()
+				.flat_map(|words| words[m].to_be_bytes())
+				.collect::<Vec<_>>();
+			assert_eq!(digest[..], Sha256::digest(message)[..]);
+		}
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+
+	#[test]
+	fn test_sha256_message() {
+		// Empty, the longest single-block, the shortest two-block, a full block and a message
+		// spanning three blocks, hashed together with the remaining rows left empty.
+		let log_size = PackedType::<U, BinaryField1b>::LOG_WIDTH + 1;
+		check_sha256_message(log_size, 130, &[0, 55, 56, 64, 130, 1, 119]);
+	}
+
+	#[test]
+	fn test_sha256_message_single_block() {
+		let log_size = PackedType::<U, BinaryField1b>::LOG_WIDTH;
+		check_sha256_message(log_size, 55, &[0, 3, 55, 32]);
+	}
+
+	#[test]
+	fn test_sha256_message_too_long() {
+		let log_size = PackedType::<U, BinaryField1b>::LOG_WIDTH;
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let messages = [vec![0u8; 57]];
+		assert!(
+			super::sha256_message(&mut builder, "sha256", log_size, 56, Some(&messages)).is_err()
+		);
+	}
 }
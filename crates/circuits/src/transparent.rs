@@ -19,9 +19,10 @@ pub fn step_down(
 ) -> Result<OracleId, anyhow::Error> {
 	let step_down = transparent::step_down::StepDown::new(log_size, index)?;
 	let id = builder.add_transparent(name, step_down.clone())?;
-	if let Some(witness) = builder.witness() {
+	builder.fill_witness([id], move |witness, _| {
 		step_down.populate(witness.new_column::<BinaryField1b>(id).packed());
-	}
+		Ok(())
+	})?;
 	Ok(id)
 }
 
@@ -33,9 +34,10 @@ pub fn step_up(
 ) -> Result<OracleId, anyhow::Error> {
 	let step_up = transparent::step_up::StepUp::new(log_size, index)?;
 	let id = builder.add_transparent(name, step_up.clone())?;
-	if let Some(witness) = builder.witness() {
+	builder.fill_witness([id], move |witness, _| {
 		step_up.populate(witness.new_column::<BinaryField1b>(id).packed());
-	}
+		Ok(())
+	})?;
 	Ok(id)
 }
 
@@ -52,12 +54,13 @@ where
 {
 	let poly = transparent::constant::Constant::new(log_size, value);
 	let id = builder.add_transparent(name, poly)?;
-	if let Some(witness) = builder.witness() {
+	builder.fill_witness([id], move |witness, _| {
 		witness
 			.new_column::<FS>(id)
 			.packed()
 			.fill(<PackedType<U, FS>>::broadcast(value));
-	}
+		Ok(())
+	})?;
 	Ok(id)
 }
 
@@ -84,10 +87,11 @@ where
 
 	let oracle = builder.add_transparent(name, mle)?;
 
-	if let Some(witness) = builder.witness() {
+	builder.fill_witness([oracle], move |witness, _| {
 		let mut entry_builder = witness.new_column::<FS>(oracle);
 		entry_builder.packed().copy_from_slice(&packed_values);
-	}
+		Ok(())
+	})?;
 
 	Ok(oracle)
 }
@@ -23,7 +23,7 @@ pub fn u32fib(
 	let next_next =
 		builder.add_shifted("next_next", current, 64, log_size, ShiftVariant::LogicalRight)?;
 
-	if let Some(witness) = builder.witness() {
+	builder.fill_witness([current, next, next_next], move |witness, _| {
 		let mut current = witness.new_column::<BinaryField1b>(current);
 		let mut next = witness.new_column::<BinaryField1b>(next);
 		let mut next_next = witness.new_column::<BinaryField1b>(next_next);
@@ -46,7 +46,8 @@ pub fn u32fib(
 			.for_each(|(next_next, current)| {
 				*next_next = *current;
 			});
-	}
+		Ok(())
+	})?;
 
 	let packed_log_size = log_size - 5;
 	let enabled = step_down(builder, "enabled", packed_log_size, (1 << packed_log_size) - 2)?;
@@ -54,13 +55,13 @@ pub fn u32fib(
 	let sum_packed = builder.add_packed("sum_packed", sum, 5)?;
 	let next_next_packed = builder.add_packed("next_next_packed", next_next, 5)?;
 
-	if let Some(witness) = builder.witness() {
+	builder.fill_witness([next_next_packed, sum_packed], move |witness, _| {
 		let next_next_packed_witness = witness.get::<BinaryField1b>(next_next)?;
 		witness.set(next_next_packed, next_next_packed_witness.repacked::<BinaryField32b>())?;
 
 		let sum_packed_witness = witness.get::<BinaryField1b>(sum)?;
-		witness.set(sum_packed, sum_packed_witness.repacked::<BinaryField32b>())?;
-	}
+		witness.set(sum_packed, sum_packed_witness.repacked::<BinaryField32b>())
+	})?;
 
 	builder.assert_zero(
 		"step",
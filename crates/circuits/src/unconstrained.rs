@@ -1,6 +1,8 @@
 // Copyright 2024-2025 Irreducible Inc.
 use binius_core::oracle::OracleId;
-use binius_field::{as_packed_field::PackScalar, ExtensionField, TowerField};
+use binius_field::{
+	as_packed_field::PackScalar, packed::set_packed_slice, ExtensionField, TowerField,
+};
 use binius_maybe_rayon::prelude::*;
 use bytemuck::Pod;
 use rand::{thread_rng, Rng};
@@ -22,7 +24,7 @@ where
 {
 	let rng = builder.add_committed(name, log_size, FS::TOWER_LEVEL);
 
-	if let Some(witness) = builder.witness() {
+	builder.fill_witness([rng], move |witness, _| {
 		witness
 			.new_column::<FS>(rng)
 			.as_mut_slice::<u8>()
@@ -30,7 +32,8 @@ where
 			.for_each_init(thread_rng, |rng, data| {
 				*data = rng.gen();
 			});
-	}
+		Ok(())
+	})?;
 
 	Ok(rng)
 }
@@ -49,16 +52,46 @@ where
 {
 	let fixed = builder.add_committed(name, log_size, FS::TOWER_LEVEL);
 
-	if let Some(witness) = builder.witness() {
+	builder.fill_witness([fixed], move |witness, _| {
 		witness
 			.new_column::<FS>(fixed)
 			.as_mut_slice::<u32>()
 			.into_par_iter()
-			.zip(values.into_par_iter())
+			.zip(values.par_iter())
 			.for_each(|(data, value)| {
-				*data = value;
+				*data = *value;
 			});
-	}
+		Ok(())
+	})?;
 
 	Ok(fixed)
 }
+
+// Same as 'fixed_u32' but takes the values from a `Vec<FS>` witness input, named like the oracle
+// including its namespace, e.g. "sha256::message" for an input "message" added in "sha256"
+pub fn input<FS>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	log_size: usize,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<FS> + Pod,
+	F: TowerField + ExtensionField<FS>,
+	FS: TowerField,
+{
+	let input = builder.add_committed(name.to_string(), log_size, FS::TOWER_LEVEL);
+	let name = builder.scoped_name(name);
+
+	builder.fill_witness([input], move |witness, inputs| {
+		let values = inputs.get::<Vec<FS>>(&name)?;
+		anyhow::ensure!(values.len() <= 1 << log_size, "Witness input {name} is too long");
+		let mut column = witness.new_column::<FS>(input);
+		let column = column.packed();
+		for (i, &value) in values.iter().enumerate() {
+			set_packed_slice(column, i, value);
+		}
+		Ok(())
+	})?;
+
+	Ok(input)
+}
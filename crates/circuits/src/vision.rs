@@ -22,7 +22,10 @@ use binius_macros::arith_expr;
 use binius_math::ArithExpr;
 use bytemuck::must_cast_slice;
 
-use crate::builder::{types::F, ConstraintSystemBuilder};
+use crate::{
+	builder::{types::F, ConstraintSystemBuilder},
+	transparent,
+};
 
 pub fn vision_permutation(
 	builder: &mut ConstraintSystemBuilder,
@@ -38,7 +41,7 @@ pub fn vision_permutation(
 		BinaryField32b::TOWER_LEVEL,
 	);
 
-	if let Some(witness) = builder.witness() {
+	builder.fill_witness(round_0_input, move |witness, _| {
 		let perm_in_data_owned: [_; STATE_SIZE] =
 			array::try_from_fn(|i| witness.get::<B32>(p_in[i]))?;
 		let perm_in_data: [_; STATE_SIZE] = perm_in_data_owned.map(|elem| elem.as_slice::<B32>());
@@ -53,7 +56,8 @@ pub fn vision_permutation(
 				round_0_input_slice[s][z] = perm_in_data[s][z] + B32::new(VISION_ROUND_0[s]);
 			}
 		}
-	}
+		Ok(())
+	})?;
 
 	for s in 0..STATE_SIZE {
 		builder.assert_zero(
@@ -67,38 +71,139 @@ pub fn vision_permutation(
 		vision_round(builder, log_size, round_i, state)
 	})?;
 
-	#[cfg(debug_assertions)]
-	if let Some(witness) = builder.witness() {
-		use binius_hash::{permutation::Permutation, Vision32bPermutation};
-
-		let vision_perm = Vision32bPermutation::default();
-		let p_in_data: [_; STATE_SIZE] =
-			array::try_from_fn(|i| witness.get::<B32>(p_in[i])).unwrap();
-		let p_in_slice: [_; STATE_SIZE] = p_in_data.map(|elem| elem.as_slice::<B32>());
-		let p_out_data: [_; STATE_SIZE] =
-			array::try_from_fn(|i| witness.get::<B32>(perm_out[i])).unwrap();
-		let p_out_slice: [_; STATE_SIZE] = p_out_data.map(|elem| elem.as_slice::<B32>());
-		for z in 0..1 << log_size {
-			let mut in_out: [_; 3] = array::from_fn(|i| {
-				PackedAESBinaryField8x32b::from_fn(|j| p_in_slice[i * 8 + j][z].into())
-			});
-			let expected_out: [B32; STATE_SIZE] = array::from_fn(|s| p_out_slice[s][z]);
+	if cfg!(debug_assertions) {
+		builder.fill_witness([], move |witness, _| {
+			use binius_hash::{permutation::Permutation, Vision32bPermutation};
+
+			let vision_perm = Vision32bPermutation::default();
+			let p_in_data: [_; STATE_SIZE] =
+				array::try_from_fn(|i| witness.get::<B32>(p_in[i])).unwrap();
+			let p_in_slice: [_; STATE_SIZE] = p_in_data.map(|elem| elem.as_slice::<B32>());
+			let p_out_data: [_; STATE_SIZE] =
+				array::try_from_fn(|i| witness.get::<B32>(perm_out[i])).unwrap();
+			let p_out_slice: [_; STATE_SIZE] = p_out_data.map(|elem| elem.as_slice::<B32>());
+			for z in 0..1 << log_size {
+				let mut in_out: [_; 3] = array::from_fn(|i| {
+					PackedAESBinaryField8x32b::from_fn(|j| p_in_slice[i * 8 + j][z].into())
+				});
+				let expected_out: [B32; STATE_SIZE] = array::from_fn(|s| p_out_slice[s][z]);
 
-			vision_perm.permute_mut(&mut in_out);
+				vision_perm.permute_mut(&mut in_out);
 
-			for (out, expected) in
-				PackedAESBinaryField8x32b::iter_slice(&in_out).zip(expected_out.iter())
-			{
-				assert_eq!(out, AESTowerField32b::from(*expected));
+				for (out, expected) in
+					PackedAESBinaryField8x32b::iter_slice(&in_out).zip(expected_out.iter())
+				{
+					assert_eq!(out, AESTowerField32b::from(*expected));
+				}
 			}
-		}
+			Ok(())
+		})?;
 	}
 
 	Ok(perm_out)
 }
 
+/// Compresses two digests into one, as hashing `left || right` with
+/// [`binius_hash::VisionHasherDigest`] does.
+///
+/// Digest elements are the little-endian 32-bit words of the byte digest. The 64-byte message
+/// fills the rate exactly, so one permutation absorbs the message and a second one absorbs the
+/// constant padding block.
+pub fn vision_compression(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	log_size: usize,
+	left: [OracleId; DIGEST_SIZE],
+	right: [OracleId; DIGEST_SIZE],
+) -> Result<[OracleId; DIGEST_SIZE]> {
+	builder.push_namespace(name);
+
+	let zero = transparent::constant(builder, "zero", log_size, BinaryField32b::ZERO)?;
+	let padding_start = transparent::constant(
+		builder,
+		"padding_start",
+		log_size,
+		BinaryField32b::new(PADDING_START),
+	)?;
+	let padding_end =
+		transparent::constant(builder, "padding_end", log_size, BinaryField32b::new(PADDING_END))?;
+
+	builder.push_namespace("message_block");
+	let state = vision_permutation(
+		builder,
+		log_size,
+		array::from_fn(|i| {
+			if i < DIGEST_SIZE {
+				left[i]
+			} else if i < RATE {
+				right[i - DIGEST_SIZE]
+			} else {
+				zero
+			}
+		}),
+	)?;
+	builder.pop_namespace();
+
+	builder.push_namespace("padding_block");
+	let state = vision_permutation(
+		builder,
+		log_size,
+		array::from_fn(|i| match i {
+			0 => padding_start,
+			i if i == RATE - 1 => padding_end,
+			i if i < RATE => zero,
+			i => state[i],
+		}),
+	)?;
+	builder.pop_namespace();
+
+	builder.pop_namespace();
+	Ok(array::from_fn(|i| state[i]))
+}
+
+/// Compresses two digests of field elements into one, as [`binius_hash::VisionCompression`] does.
+///
+/// Unlike [`vision_compression`], the digests are not serialized to bytes, and the compression
+/// takes a single permutation of the digests followed by zero elements, truncated to the digest
+/// size.
+pub fn vision_truncated_compression(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	log_size: usize,
+	left: [OracleId; DIGEST_SIZE],
+	right: [OracleId; DIGEST_SIZE],
+) -> Result<[OracleId; DIGEST_SIZE]> {
+	builder.push_namespace(name);
+	let zero = transparent::constant(builder, "zero", log_size, BinaryField32b::ZERO)?;
+	let state = vision_permutation(
+		builder,
+		log_size,
+		array::from_fn(|i| {
+			if i < DIGEST_SIZE {
+				left[i]
+			} else if i < 2 * DIGEST_SIZE {
+				right[i - DIGEST_SIZE]
+			} else {
+				zero
+			}
+		}),
+	)?;
+	builder.pop_namespace();
+	Ok(array::from_fn(|i| state[i]))
+}
+
+/// Number of 32-bit elements in a Vision digest.
+pub const DIGEST_SIZE: usize = 8;
+
 const N_ROUNDS: usize = 8;
 const STATE_SIZE: usize = 24;
+/// Number of state elements the sponge absorbs per permutation.
+const RATE: usize = 16;
+
+/// The first and last words of the padding block of a message whose length is a multiple of the
+/// rate, `0x80` in the first byte and `0x01` in the last one.
+const PADDING_START: u32 = 0x80;
+const PADDING_END: u32 = 0x01 << 24;
 
 #[rustfmt::skip]
 const VISION_RC_EVEN: [[u32; 8]; STATE_SIZE] = [
@@ -336,116 +441,137 @@ where {
 	type B32 = BinaryField32b;
 
 	// Witness gen
-	if let Some(witness) = builder.witness() {
-		let perm_in_data_owned: [_; STATE_SIZE] =
-			array::try_from_fn(|i| witness.get::<B32>(perm_in[i]))?;
-		let perm_in_data: [_; STATE_SIZE] = perm_in_data_owned.map(|elem| elem.as_slice::<B32>());
-
-		let mut even_round_consts = even_round_consts.map(|id| witness.new_column::<B32>(id));
-		let mut inv_0 = inv_0.map(|id| witness.new_column::<B32>(id));
-		let mut s_box_out_0 = s_box_out_0.map(|id| witness.new_column::<B32>(id));
-		let mut mds_out_0 = mds_out_0.map(|id| witness.new_column::<B32>(id));
-		let mut round_out_0 = round_out_0.map(|id| witness.new_column::<B32>(id));
-		let mut odd_round_consts = odd_round_consts.map(|id| witness.new_column::<B32>(id));
-		let mut inv_1 = inv_1.map(|id| witness.new_column::<B32>(id));
-		let mut s_box_out_1 = s_box_out_1.map(|id| witness.new_column::<B32>(id));
-		let mut mds_out_1 = mds_out_1.map(|id| witness.new_column::<B32>(id));
-		let mut perm_out = perm_out.map(|id| witness.new_column::<B32>(id));
-
-		let inv_0_slice = inv_0.each_mut().map(|elem| elem.as_mut_slice());
-		let s_box_out_0_slice = s_box_out_0.each_mut().map(|elem| elem.as_mut_slice());
-		let mds_out_0_slice = mds_out_0.each_mut().map(|elem| elem.as_mut_slice());
-		let round_out_0_slice = round_out_0
-			.each_mut()
-			.map(|elem| elem.as_mut_slice::<B32>());
-		let inv_1_slice = inv_1.each_mut().map(|elem| elem.as_mut_slice());
-		let s_box_out_1_slice = s_box_out_1.each_mut().map(|elem| elem.as_mut_slice());
-		let mds_out_1_slice = mds_out_1.each_mut().map(|elem| elem.as_mut_slice());
-		let perm_out_slice = perm_out.each_mut().map(|elem| elem.as_mut_slice());
-		let even_round_consts_slice = even_round_consts
-			.each_mut()
-			.map(|elem| elem.as_mut_slice::<B32>());
-		let odd_round_consts_slice = odd_round_consts
-			.each_mut()
-			.map(|elem| elem.as_mut_slice::<B32>());
-
-		// Fill in constants
-		for i in 0..STATE_SIZE {
-			even_round_consts_slice[i]
-				.iter_mut()
-				.for_each(|rc| *rc = BinaryField32b::new(VISION_RC_EVEN[i][round_i]));
-			odd_round_consts_slice[i]
-				.iter_mut()
-				.for_each(|rc| *rc = BinaryField32b::new(VISION_RC_ODD[i][round_i]));
-		}
-
-		for z in 0..1 << log_size {
-			// Even rounds
-			let input: [_; STATE_SIZE] =
-				array::from_fn(|row| must_cast_slice::<_, B32>(perm_in_data[row])[z]);
-			let inverse_0 = input.map(B32::invert_or_zero);
-
-			let sbox_out_packed: [PackedBinaryField8x32b; 3] = array::from_fn(|arr_idx| {
-				let inp = PackedAESBinaryField8x32b::from_fn(|pack_idx| {
-					inverse_0[pack_idx + arr_idx * 8].into()
-				});
-				Transformation::<PackedAESBinaryField8x32b, PackedBinaryField8x32b>::transform(
-					&aes_to_bin_packed,
-					&INV_PACKED_TRANS_AES.transform(&inp),
-				) + inv_const_packed
-			});
-
+	builder.fill_witness(
+		[
+			even_round_consts,
+			inv_0,
+			s_box_out_0,
+			mds_out_0,
+			round_out_0,
+			odd_round_consts,
+			inv_1,
+			s_box_out_1,
+			mds_out_1,
+			perm_out,
+		]
+		.concat(),
+		move |witness, _| {
+			let perm_in_data_owned: [_; STATE_SIZE] =
+				array::try_from_fn(|i| witness.get::<B32>(perm_in[i]))?;
+			let perm_in_data: [_; STATE_SIZE] =
+				perm_in_data_owned.map(|elem| elem.as_slice::<B32>());
+
+			let mut even_round_consts = even_round_consts.map(|id| witness.new_column::<B32>(id));
+			let mut inv_0 = inv_0.map(|id| witness.new_column::<B32>(id));
+			let mut s_box_out_0 = s_box_out_0.map(|id| witness.new_column::<B32>(id));
+			let mut mds_out_0 = mds_out_0.map(|id| witness.new_column::<B32>(id));
+			let mut round_out_0 = round_out_0.map(|id| witness.new_column::<B32>(id));
+			let mut odd_round_consts = odd_round_consts.map(|id| witness.new_column::<B32>(id));
+			let mut inv_1 = inv_1.map(|id| witness.new_column::<B32>(id));
+			let mut s_box_out_1 = s_box_out_1.map(|id| witness.new_column::<B32>(id));
+			let mut mds_out_1 = mds_out_1.map(|id| witness.new_column::<B32>(id));
+			let mut perm_out = perm_out.map(|id| witness.new_column::<B32>(id));
+
+			let inv_0_slice = inv_0.each_mut().map(|elem| elem.as_mut_slice());
+			let s_box_out_0_slice = s_box_out_0.each_mut().map(|elem| elem.as_mut_slice());
+			let mds_out_0_slice = mds_out_0.each_mut().map(|elem| elem.as_mut_slice());
+			let round_out_0_slice = round_out_0
+				.each_mut()
+				.map(|elem| elem.as_mut_slice::<B32>());
+			let inv_1_slice = inv_1.each_mut().map(|elem| elem.as_mut_slice());
+			let s_box_out_1_slice = s_box_out_1.each_mut().map(|elem| elem.as_mut_slice());
+			let mds_out_1_slice = mds_out_1.each_mut().map(|elem| elem.as_mut_slice());
+			let perm_out_slice = perm_out.each_mut().map(|elem| elem.as_mut_slice());
+			let even_round_consts_slice = even_round_consts
+				.each_mut()
+				.map(|elem| elem.as_mut_slice::<B32>());
+			let odd_round_consts_slice = odd_round_consts
+				.each_mut()
+				.map(|elem| elem.as_mut_slice::<B32>());
+
+			// Fill in constants
 			for i in 0..STATE_SIZE {
-				let sbox_out = get_packed_slice(&sbox_out_packed, i);
-				inv_0_slice[i][z] = inverse_0[i];
-				s_box_out_0_slice[i][z] = sbox_out;
+				even_round_consts_slice[i]
+					.iter_mut()
+					.for_each(|rc| *rc = BinaryField32b::new(VISION_RC_EVEN[i][round_i]));
+				odd_round_consts_slice[i]
+					.iter_mut()
+					.for_each(|rc| *rc = BinaryField32b::new(VISION_RC_ODD[i][round_i]));
 			}
-			let mut inp_as_packed_aes: [PackedAESBinaryField8x32b; 3] = array::from_fn(|arr_idx| {
-				PackedAESBinaryField8x32b::from_fn(|pack_idx| {
-					s_box_out_0_slice[pack_idx + arr_idx * 8][z].into()
-				})
-			});
-			mds_trans
-				.transform(PackedAESBinaryField8x32b::cast_base_arr_mut(&mut inp_as_packed_aes));
-			let inp_as_packed_bin: [PackedBinaryField8x32b; 3] =
-				inp_as_packed_aes.map(|x| aes_to_bin_packed.transform(&x));
 
-			for i in 0..STATE_SIZE {
-				let mds_even_out: B32 = get_packed_slice(&inp_as_packed_bin, i);
-				let round_even_out = mds_even_out + even_round_consts_slice[i][z];
-				mds_out_0_slice[i][z] = mds_even_out;
-				round_out_0_slice[i][z] = round_even_out;
-
-				// Odd rounds
-				let inv_odd = round_even_out.invert_or_zero();
-				let inv_pow2_odd = inv_odd.square();
-				let inv_pow4_odd = inv_pow2_odd.square();
-				let sbox_out_odd = SBOX_FWD_CONST
-					+ inv_odd * SBOX_FWD_TRANS[0]
-					+ inv_pow2_odd * SBOX_FWD_TRANS[1]
-					+ inv_pow4_odd * SBOX_FWD_TRANS[2];
-
-				inv_1_slice[i][z] = inv_odd;
-				s_box_out_1_slice[i][z] = sbox_out_odd;
-			}
+			for z in 0..1 << log_size {
+				// Even rounds
+				let input: [_; STATE_SIZE] =
+					array::from_fn(|row| must_cast_slice::<_, B32>(perm_in_data[row])[z]);
+				let inverse_0 = input.map(B32::invert_or_zero);
+
+				let sbox_out_packed: [PackedBinaryField8x32b; 3] = array::from_fn(|arr_idx| {
+					let inp = PackedAESBinaryField8x32b::from_fn(|pack_idx| {
+						inverse_0[pack_idx + arr_idx * 8].into()
+					});
+					Transformation::<PackedAESBinaryField8x32b, PackedBinaryField8x32b>::transform(
+						&aes_to_bin_packed,
+						&INV_PACKED_TRANS_AES.transform(&inp),
+					) + inv_const_packed
+				});
 
-			let mut inp_as_packed_aes: [PackedAESBinaryField8x32b; 3] = array::from_fn(|arr_idx| {
-				PackedAESBinaryField8x32b::from_fn(|pack_idx| {
-					s_box_out_1_slice[pack_idx + arr_idx * 8][z].into()
-				})
-			});
-			mds_trans
-				.transform(PackedAESBinaryField8x32b::cast_base_arr_mut(&mut inp_as_packed_aes));
-			let inp_as_packed: [PackedBinaryField8x32b; 3] =
-				inp_as_packed_aes.map(|x| aes_to_bin_packed.transform(&x));
-			for i in 0..24 {
-				let mds_out_odd: B32 = get_packed_slice(&inp_as_packed, i);
-				mds_out_1_slice[i][z] = mds_out_odd;
-				let output = mds_out_odd + odd_round_consts_slice[i][z];
-				perm_out_slice[i][z] = output;
+				for i in 0..STATE_SIZE {
+					let sbox_out = get_packed_slice(&sbox_out_packed, i);
+					inv_0_slice[i][z] = inverse_0[i];
+					s_box_out_0_slice[i][z] = sbox_out;
+				}
+				let mut inp_as_packed_aes: [PackedAESBinaryField8x32b; 3] =
+					array::from_fn(|arr_idx| {
+						PackedAESBinaryField8x32b::from_fn(|pack_idx| {
+							s_box_out_0_slice[pack_idx + arr_idx * 8][z].into()
+						})
+					});
+				mds_trans.transform(PackedAESBinaryField8x32b::cast_base_arr_mut(
+					&mut inp_as_packed_aes,
+				));
+				let inp_as_packed_bin: [PackedBinaryField8x32b; 3] =
+					inp_as_packed_aes.map(|x| aes_to_bin_packed.transform(&x));
+
+				for i in 0..STATE_SIZE {
+					let mds_even_out: B32 = get_packed_slice(&inp_as_packed_bin, i);
+					let round_even_out = mds_even_out + even_round_consts_slice[i][z];
+					mds_out_0_slice[i][z] = mds_even_out;
+					round_out_0_slice[i][z] = round_even_out;
+
+					// Odd rounds
+					let inv_odd = round_even_out.invert_or_zero();
+					let inv_pow2_odd = inv_odd.square();
+					let inv_pow4_odd = inv_pow2_odd.square();
+					let sbox_out_odd = SBOX_FWD_CONST
+						+ inv_odd * SBOX_FWD_TRANS[0]
+						+ inv_pow2_odd * SBOX_FWD_TRANS[1]
+						+ inv_pow4_odd * SBOX_FWD_TRANS[2];
+
+					inv_1_slice[i][z] = inv_odd;
+					s_box_out_1_slice[i][z] = sbox_out_odd;
+				}
+
+				let mut inp_as_packed_aes: [PackedAESBinaryField8x32b; 3] =
+					array::from_fn(|arr_idx| {
+						PackedAESBinaryField8x32b::from_fn(|pack_idx| {
+							s_box_out_1_slice[pack_idx + arr_idx * 8][z].into()
+						})
+					});
+				mds_trans.transform(PackedAESBinaryField8x32b::cast_base_arr_mut(
+					&mut inp_as_packed_aes,
+				));
+				let inp_as_packed: [PackedBinaryField8x32b; 3] =
+					inp_as_packed_aes.map(|x| aes_to_bin_packed.transform(&x));
+				for i in 0..24 {
+					let mds_out_odd: B32 = get_packed_slice(&inp_as_packed, i);
+					mds_out_1_slice[i][z] = mds_out_odd;
+					let output = mds_out_odd + odd_round_consts_slice[i][z];
+					perm_out_slice[i][z] = output;
+				}
 			}
-		}
-	}
+			Ok(())
+		},
+	)?;
 
 	// zero check constraints
 	for s in 0..STATE_SIZE {
@@ -476,11 +602,19 @@ where {
 
 #[cfg(test)]
 mod tests {
-	use binius_core::oracle::OracleId;
-	use binius_field::BinaryField32b;
-
-	use super::vision_permutation;
-	use crate::{builder::test_utils::test_circuit, unconstrained::unconstrained};
+	use binius_core::{constraint_system::validate::validate_witness, oracle::OracleId};
+	use binius_field::{BinaryField32b, TowerField};
+	use binius_hash::{PseudoCompressionFunction, VisionCompression, VisionHasherDigest};
+	use digest::Digest;
+	use rand::{rngs::StdRng, Rng, SeedableRng};
+
+	use super::{
+		vision_compression, vision_permutation, vision_truncated_compression, DIGEST_SIZE,
+	};
+	use crate::{
+		builder::{test_utils::test_circuit, ConstraintSystemBuilder},
+		unconstrained::unconstrained,
+	};
 
 	#[test]
 	fn test_vision32b() {
@@ -494,4 +628,87 @@ mod tests {
 		})
 		.unwrap();
 	}
+
+	#[test]
+	fn test_vision_compression() {
+		let log_size = 4;
+		let mut rng = StdRng::seed_from_u64(0);
+		let inputs: Vec<[[u32; DIGEST_SIZE]; 2]> = (0..1 << log_size).map(|_| rng.gen()).collect();
+
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let [left, right] = std::array::from_fn(|side| {
+			let ids = builder.add_committed_multiple::<DIGEST_SIZE>(
+				format!("input[{side}]"),
+				log_size,
+				BinaryField32b::TOWER_LEVEL,
+			);
+			let witness = builder.witness().unwrap();
+			for (i, &id) in ids.iter().enumerate() {
+				let mut column = witness.new_column::<BinaryField32b>(id);
+				for (out, input) in column.as_mut_slice::<u32>().iter_mut().zip(&inputs) {
+					*out = input[side][i];
+				}
+			}
+			ids
+		});
+		let digest = vision_compression(&mut builder, "compress", log_size, left, right).unwrap();
+
+		let witness = builder.witness().unwrap();
+		let digest = digest.map(|id| witness.get::<BinaryField32b>(id).unwrap().as_slice::<u32>());
+		for (z, [left, right]) in inputs.iter().enumerate() {
+			let message = left
+				.iter()
+				.chain(right)
+				.flat_map(|word| word.to_le_bytes())
+				.collect::<Vec<_>>();
+			let expected = VisionHasherDigest::digest(message);
+			let words = digest.map(|column| column[z]);
+			assert_eq!(words.map(u32::to_le_bytes).concat(), expected.as_slice());
+		}
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+
+	#[test]
+	fn test_vision_truncated_compression() {
+		let log_size = 4;
+		let mut rng = StdRng::seed_from_u64(0);
+		let inputs: Vec<[[u32; DIGEST_SIZE]; 2]> = (0..1 << log_size).map(|_| rng.gen()).collect();
+
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let [left, right] = std::array::from_fn(|side| {
+			let ids = builder.add_committed_multiple::<DIGEST_SIZE>(
+				format!("input[{side}]"),
+				log_size,
+				BinaryField32b::TOWER_LEVEL,
+			);
+			let witness = builder.witness().unwrap();
+			for (i, &id) in ids.iter().enumerate() {
+				let mut column = witness.new_column::<BinaryField32b>(id);
+				for (out, input) in column.as_mut_slice::<u32>().iter_mut().zip(&inputs) {
+					*out = input[side][i];
+				}
+			}
+			ids
+		});
+		let digest =
+			vision_truncated_compression(&mut builder, "compress", log_size, left, right).unwrap();
+
+		let witness = builder.witness().unwrap();
+		let digest = digest.map(|id| witness.get::<BinaryField32b>(id).unwrap().as_slice::<u32>());
+		let compression = VisionCompression::default();
+		for (z, input) in inputs.iter().enumerate() {
+			let expected =
+				compression.compress(input.map(|digest| digest.map(BinaryField32b::new)));
+			assert_eq!(digest.map(|column| BinaryField32b::new(column[z])), expected);
+		}
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
 }
@@ -5,11 +5,13 @@ use std::iter::repeat_with;
 use binius_core::merkle_tree::{BinaryMerkleTreeProver, MerkleTreeProver};
 use binius_field::{BinaryField128b, Field};
 use binius_hash::{
-	groestl::{Groestl256, Groestl256ByteCompression},
-	PseudoCompressionFunction,
+	groestl::{
+		Groestl256, Groestl256ByteCompression, Groestl256MultiCompression, Groestl256Parallel,
+	},
+	MultiDigest, PseudoCompressionFunction,
 };
 use criterion::{criterion_group, criterion_main, Criterion, Throughput};
-use digest::{core_api::BlockSizeUser, Digest, FixedOutputReset, Output};
+use digest::{core_api::BlockSizeUser, Output};
 use rand::thread_rng;
 
 const LOG_ELEMS: usize = 17;
@@ -17,17 +19,20 @@ const LOG_ELEMS_IN_LEAF: usize = 4;
 
 type F = BinaryField128b;
 
-fn bench_binary_merkle_tree<H, C>(c: &mut Criterion, compression: C, hash_name: &str)
-where
-	H: Digest + BlockSizeUser + FixedOutputReset,
-	C: PseudoCompressionFunction<Output<H>, 2> + Sync,
+fn bench_binary_merkle_tree<H, C, const N: usize>(
+	c: &mut Criterion,
+	compression: C,
+	hash_name: &str,
+) where
+	H: MultiDigest<Digest: BlockSizeUser>,
+	C: PseudoCompressionFunction<Output<H::Digest>, N> + Sync,
 {
-	let merkle_prover = BinaryMerkleTreeProver::<_, H, C>::new(compression);
+	let merkle_prover = BinaryMerkleTreeProver::<_, H, C, N>::new(compression);
 	let mut rng = thread_rng();
 	let data: Vec<F> = repeat_with(|| Field::random(&mut rng))
 		.take(1 << (LOG_ELEMS + LOG_ELEMS_IN_LEAF))
 		.collect();
-	let mut group = c.benchmark_group(format!("slow/merkle_tree/{}", hash_name));
+	let mut group = c.benchmark_group(format!("slow/merkle_tree/{}/arity {}", hash_name, N));
 	group.throughput(Throughput::Bytes(
 		((1 << (LOG_ELEMS + LOG_ELEMS_IN_LEAF)) * std::mem::size_of::<F>()) as u64,
 	));
@@ -42,7 +47,14 @@ where
 }
 
 fn bench_groestl_merkle_tree(c: &mut Criterion) {
-	bench_binary_merkle_tree::<Groestl256, _>(c, Groestl256ByteCompression, "Grøstl-256");
+	bench_binary_merkle_tree::<Groestl256, _, 2>(c, Groestl256ByteCompression, "Grøstl-256");
+	bench_binary_merkle_tree::<Groestl256, _, 4>(c, Groestl256MultiCompression::<4>, "Grøstl-256");
+	bench_binary_merkle_tree::<Groestl256, _, 8>(c, Groestl256MultiCompression::<8>, "Grøstl-256");
+	bench_binary_merkle_tree::<Groestl256Parallel, _, 2>(
+		c,
+		Groestl256ByteCompression,
+		"Grøstl-256 parallel",
+	);
 }
 
 criterion_main!(binary_merkle_tree);
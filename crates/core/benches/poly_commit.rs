@@ -3,122 +3,147 @@
 use std::iter::repeat_with;
 
 use binius_core::{
+	fiat_shamir::HasherChallenger,
 	merkle_tree::BinaryMerkleTreeProver,
 	piop,
-	piop::CommitMeta,
-	protocols::{fri, fri::FRIParams},
-	reed_solomon::reed_solomon::ReedSolomonCode,
+	piop::{tensor, CommitMeta, FRIBiniusPCS, PIOPSumcheckClaim, PolyCommitScheme, TensorPCS},
+	transcript::ProverTranscript,
 };
 use binius_field::{
-	arch::OptimalUnderlier, as_packed_field::PackedType, packed::set_packed_slice, BinaryField128b,
-	BinaryField32b, PackedField,
+	arch::OptimalUnderlier, as_packed_field::PackedType, BinaryField128b, BinaryField32b,
+	BinaryField8b, PackedField,
 };
+use binius_hal::make_portable_backend;
 use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
-use binius_math::{MLEDirectAdapter, MultilinearExtension, MultilinearPoly};
-use binius_ntt::{NTTOptions, ThreadingSettings};
-use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
-use itertools::Itertools;
+use binius_math::{
+	IsomorphicEvaluationDomainFactory, MLEDirectAdapter, MultilinearExtension, MultilinearPoly,
+};
+use binius_maybe_rayon::prelude::*;
+use criterion::{
+	criterion_group, criterion_main, measurement::WallTime, BatchSize, BenchmarkGroup, BenchmarkId,
+	Criterion, Throughput,
+};
 use rand::thread_rng;
 
 type U = OptimalUnderlier;
 type F = BinaryField128b;
-
-fn merge_multilins<P, M>(multilins: &[M], message_buffer: &mut [P])
-where
-	P: PackedField,
-	M: MultilinearPoly<P>,
-{
-	let mut packed_offset = 0;
-	let mut mle_iter = multilins.iter().rev();
-
-	// First copy all the polynomials where the number of elements is a multiple of the packing
-	// width.
-	let get_n_packed_vars = |mle: &M| mle.n_vars() - mle.log_extension_degree();
-	for mle in mle_iter.peeking_take_while(|mle| get_n_packed_vars(mle) >= P::LOG_WIDTH) {
-		let evals = mle
-			.packed_evals()
-			.expect("guaranteed by function precondition");
-		message_buffer[packed_offset..packed_offset + evals.len()].copy_from_slice(evals);
-		packed_offset += evals.len();
-	}
-
-	// Now copy scalars from the remaining multilinears, which have too few elements to copy full
-	// packed elements.
-	let mut scalar_offset = packed_offset << P::LOG_WIDTH;
-	for mle in mle_iter {
-		let evals = mle
-			.packed_evals()
-			.expect("guaranteed by function precondition");
-		let packed_eval = evals[0];
-		for i in 0..1 << mle.n_vars() {
-			set_packed_slice(message_buffer, scalar_offset, packed_eval.get(i));
-			scalar_offset += 1;
-		}
-	}
-}
+type P = PackedType<U, F>;
+type FEncode = BinaryField32b;
+type FDomain = BinaryField8b;
+type MerkleProver = BinaryMerkleTreeProver<F, Groestl256, Groestl256ByteCompression>;
 
 const LOG_SIZE: usize = 20;
+const LOG_INV_RATE: usize = 1;
+const SECURITY_BITS: usize = 100;
 
 fn bench_poly_commit(c: &mut Criterion) {
-	let (fri_params, merkle_prover, committed_multilins) = create_poly_commit(LOG_SIZE);
-	let rs_code = ReedSolomonCode::new(
-		fri_params.rs_code().log_dim(),
-		fri_params.rs_code().log_inv_rate(),
-		&NTTOptions {
-			precompute_twiddles: true,
-			thread_settings: ThreadingSettings::MultithreadedDefault,
-		},
+	let committed_multilins = vec![random_multilin(LOG_SIZE)];
+	let transparent_multilins = vec![random_multilin(LOG_SIZE)];
+	let commit_meta = CommitMeta::with_vars([LOG_SIZE]);
+	let claims = [PIOPSumcheckClaim {
+		n_vars: LOG_SIZE,
+		committed: 0,
+		transparent: 0,
+		sum: (0..1 << LOG_SIZE)
+			.into_par_iter()
+			.map(|i| {
+				committed_multilins[0].evaluate_on_hypercube(i).unwrap()
+					* transparent_multilins[0].evaluate_on_hypercube(i).unwrap()
+			})
+			.sum(),
+	}];
+
+	let merkle_prover = MerkleProver::new(Groestl256ByteCompression);
+	let fri_params = piop::make_commit_params_with_optimal_arity::<_, FEncode, _>(
+		&commit_meta,
+		merkle_prover.scheme(),
+		SECURITY_BITS,
+		LOG_INV_RATE,
 	)
 	.unwrap();
+	let fri_pcs = FRIBiniusPCS::new(fri_params, merkle_prover);
+
+	let merkle_prover = MerkleProver::new(Groestl256ByteCompression);
+	let tensor_params = tensor::make_tensor_params::<_, FEncode, _>(
+		&commit_meta,
+		merkle_prover.scheme(),
+		SECURITY_BITS,
+		LOG_INV_RATE,
+	)
+	.unwrap();
+	let tensor_pcs = TensorPCS::new(tensor_params, merkle_prover);
+
 	let mut group = c.benchmark_group("Polynomial Commitment");
 	group.throughput(Throughput::Bytes(
 		((1 << LOG_SIZE) * committed_multilins.len() * std::mem::size_of::<F>()) as u64,
 	));
-	group.bench_function(BenchmarkId::new("log_size", LOG_SIZE), |b| {
-		b.iter(|| {
-			fri::commit_interleaved_with(&rs_code, &fri_params, &merkle_prover, |message_buffer| {
-				merge_multilins(&committed_multilins, message_buffer)
-			})
-			.unwrap();
-		});
-	});
+	let witness = Witness {
+		commit_meta: &commit_meta,
+		committed_multilins: &committed_multilins,
+		transparent_multilins: &transparent_multilins,
+		claims: &claims,
+	};
+	bench_scheme(&mut group, "FRI-Binius", &fri_pcs, &witness);
+	bench_scheme(&mut group, "Tensor", &tensor_pcs, &witness);
 	group.finish();
 }
 
-#[allow(clippy::type_complexity)]
-fn create_poly_commit(
-	log_size: usize,
-) -> (
-	FRIParams<F, BinaryField32b>,
-	BinaryMerkleTreeProver<F, Groestl256, Groestl256ByteCompression>,
-	Vec<impl MultilinearPoly<PackedType<U, F>>>,
-) {
-	const LOG_INV_RATE: usize = 1;
-	const SECURITY_BITS: usize = 100;
-
-	let mut rng = thread_rng();
-	let poly_data = repeat_with(|| PackedType::<U, F>::random(&mut rng))
-		.take(1 << LOG_SIZE.saturating_sub(PackedType::<U, F>::LOG_WIDTH))
-		.collect::<Vec<_>>();
+type Multilin = MLEDirectAdapter<P>;
 
-	let committed_multilins = vec![MLEDirectAdapter::from(
-		MultilinearExtension::new(log_size, poly_data).unwrap(),
-	)];
+struct Witness<'a> {
+	commit_meta: &'a CommitMeta,
+	committed_multilins: &'a [Multilin],
+	transparent_multilins: &'a [Multilin],
+	claims: &'a [PIOPSumcheckClaim<F>],
+}
 
-	let merkle_prover = BinaryMerkleTreeProver::new(Groestl256ByteCompression);
-	let merkle_scheme = merkle_prover.scheme();
+/// Benchmarks committing and proving with a scheme.
+fn bench_scheme<PCS>(
+	group: &mut BenchmarkGroup<'_, WallTime>,
+	name: &str,
+	pcs: &PCS,
+	witness: &Witness,
+) where
+	PCS: PolyCommitScheme<F, P>,
+{
+	let backend = make_portable_backend();
+	let domain_factory = IsomorphicEvaluationDomainFactory::<FDomain>::default();
 
-	let commit_meta = CommitMeta::with_vars([LOG_SIZE]);
+	group.bench_function(BenchmarkId::new(format!("{name}/commit"), LOG_SIZE), |b| {
+		b.iter(|| pcs.commit(witness.committed_multilins).unwrap());
+	});
 
-	let fri_params = piop::make_commit_params_with_optimal_arity::<_, _, _>(
-		&commit_meta,
-		merkle_scheme,
-		SECURITY_BITS,
-		LOG_INV_RATE,
-	)
-	.unwrap();
+	let prove = |committed| {
+		let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		pcs.prove::<FDomain, _, _, _, _>(
+			domain_factory.clone(),
+			witness.commit_meta,
+			committed,
+			witness.committed_multilins,
+			witness.transparent_multilins,
+			witness.claims,
+			&mut transcript,
+			&backend,
+		)
+		.unwrap();
+		transcript.finalize()
+	};
+
+	group.bench_function(BenchmarkId::new(format!("{name}/prove"), LOG_SIZE), |b| {
+		b.iter_batched(
+			|| pcs.commit(witness.committed_multilins).unwrap().1,
+			prove,
+			BatchSize::LargeInput,
+		);
+	});
+}
 
-	(fri_params, merkle_prover, committed_multilins)
+fn random_multilin(n_vars: usize) -> Multilin {
+	let mut rng = thread_rng();
+	let evals = repeat_with(|| P::random(&mut rng))
+		.take(1 << n_vars.saturating_sub(P::LOG_WIDTH))
+		.collect::<Vec<_>>();
+	MLEDirectAdapter::from(MultilinearExtension::new(n_vars, evals).unwrap())
 }
 
 criterion_main!(poly_commit);
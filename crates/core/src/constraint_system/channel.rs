@@ -49,7 +49,7 @@
 //!                                       +-+-+
 //! ```
 
-use std::collections::HashMap;
+use std::collections::{hash_map::Entry, HashMap};
 
 use binius_field::{as_packed_field::PackScalar, underlier::UnderlierType, TowerField};
 use binius_macros::{DeserializeBytes, SerializeBytes};
@@ -207,11 +207,26 @@ impl<F: TowerField> Channel<F> {
 				got: values.len(),
 			});
 		}
-		*self.multiplicities.entry(values).or_default() += (multiplicity as i64)
+		let delta = (multiplicity as i64)
 			* (match direction {
 				FlushDirection::Pull => -1i64,
 				FlushDirection::Push => 1i64,
 			});
+		// Drop balanced entries right away, so that long chains of lookups only keep the
+		// rows still in flight in memory.
+		match self.multiplicities.entry(values) {
+			Entry::Occupied(mut entry) => {
+				*entry.get_mut() += delta;
+				if *entry.get() == 0 {
+					entry.remove();
+				}
+			}
+			Entry::Vacant(entry) => {
+				if delta != 0 {
+					entry.insert(delta);
+				}
+			}
+		}
 		Ok(())
 	}
 
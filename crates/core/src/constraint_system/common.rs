@@ -2,6 +2,9 @@
 
 use crate::tower::{ProverTowerFamily, TowerFamily};
 
+/// The domain separator of the constraint system protocol transcript.
+pub const DOMAIN_SEPARATOR: &str = "binius.constraint_system";
+
 /// The cryptographic extension field that the constraint system protocol is defined over.
 pub type FExt<Tower> = <Tower as TowerFamily>::B128;
 
@@ -13,8 +13,8 @@ use binius_macros::SerializeBytes;
 use binius_utils::{DeserializeBytes, SerializationError, SerializationMode};
 use channel::{ChannelId, Flush};
 use exp::Exp;
-pub use prove::prove;
-pub use verify::verify;
+pub use prove::{commit, prove, prove_with_commitment, prove_with_pcs, PersistedCommitment};
+pub use verify::{verify, verify_with_pcs};
 
 use crate::oracle::{ConstraintSet, MultilinearOracleSet, OracleId};
 
@@ -5,19 +5,20 @@ use std::{cmp::Reverse, env, marker::PhantomData, slice::from_mut};
 use binius_field::{
 	as_packed_field::{PackScalar, PackedType},
 	linear_transformation::{PackedTransformationFactory, Transformation},
-	underlier::WithUnderlier,
+	underlier::{UnderlierType, WithUnderlier},
 	BinaryField, ExtensionField, Field, PackedExtension, PackedField, PackedFieldIndexable,
 	RepackedExtension, TowerField,
 };
 use binius_hal::ComputationBackend;
-use binius_hash::PseudoCompressionFunction;
+use binius_hash::{MultiDigest, PseudoCompressionFunction};
 use binius_math::{
 	EvaluationDomainFactory, EvaluationOrder, IsomorphicEvaluationDomainFactory, MLEDirectAdapter,
 	MultilinearExtension, MultilinearPoly,
 };
 use binius_maybe_rayon::prelude::*;
-use binius_utils::bail;
-use digest::{core_api::BlockSizeUser, Digest, FixedOutputReset, Output};
+use binius_utils::{bail, DeserializeBytes, SerializationError, SerializationMode, SerializeBytes};
+use bytes::{Buf, BufMut};
+use digest::{core_api::BlockSizeUser, Digest, Output};
 use either::Either;
 use itertools::{chain, izip};
 use tracing::instrument;
@@ -33,14 +34,14 @@ use super::{
 };
 use crate::{
 	constraint_system::{
-		common::{FDomain, FEncode, FExt, FFastExt},
+		common::{FDomain, FEncode, FExt, FFastExt, DOMAIN_SEPARATOR},
 		exp,
 		verify::{get_flush_dedup_sumcheck_metas, FlushSumcheckMeta},
 	},
 	fiat_shamir::{CanSample, Challenger},
-	merkle_tree::BinaryMerkleTreeProver,
+	merkle_tree::{BinaryMerkleTree, BinaryMerkleTreeProver},
 	oracle::{Constraint, MultilinearOracleSet, MultilinearPolyVariant, OracleId},
-	piop,
+	piop::{self, CommitMeta, FRIBiniusPCS, PolyCommitScheme},
 	protocols::{
 		fri::CommitOutput,
 		gkr_exp,
@@ -61,13 +62,117 @@ use crate::{
 };
 
 /// Generates a proof that a witness satisfies a constraint system with the standard FRI PCS.
+///
+/// `Hash` is any [`Digest`](digest::Digest), or a [`MultiDigest`] that hashes the Merkle tree
+/// leaves in parallel lanes. The proof is verified with its [`MultiDigest::Digest`].
 #[instrument("constraint_system::prove", skip_all, level = "debug")]
 pub fn prove<U, Tower, DomainFactory, Hash, Compress, Challenger_, Backend>(
 	constraint_system: &ConstraintSystem<FExt<Tower>>,
 	log_inv_rate: usize,
 	security_bits: usize,
 	boundaries: &[Boundary<FExt<Tower>>],
+	witness: MultilinearExtensionIndex<U, FExt<Tower>>,
+	domain_factory: DomainFactory,
+	backend: &Backend,
+) -> Result<Proof, Error>
+where
+	U: ProverTowerUnderlier<Tower>,
+	Tower: ProverTowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	DomainFactory: EvaluationDomainFactory<FDomain<Tower>>,
+	Hash: MultiDigest<Digest: BlockSizeUser>,
+	Compress: PseudoCompressionFunction<Output<Hash::Digest>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+	Backend: ComputationBackend,
+	// REVIEW: Consider changing TowerFamily and associated traits to shorten/remove these bounds
+	PackedType<U, Tower::B128>: PackedTop<Tower>
+		+ PackedFieldIndexable
+		+ RepackedExtension<PackedType<U, Tower::B8>>
+		+ RepackedExtension<PackedType<U, Tower::B16>>
+		+ RepackedExtension<PackedType<U, Tower::B32>>
+		+ RepackedExtension<PackedType<U, Tower::B64>>
+		+ RepackedExtension<PackedType<U, Tower::B128>>
+		+ PackedTransformationFactory<PackedType<U, Tower::FastB128>>,
+	PackedType<U, Tower::FastB128>:
+		PackedFieldIndexable + PackedTransformationFactory<PackedType<U, Tower::B128>>,
+	PackedType<U, Tower::B8>: PackedFieldIndexable,
+	PackedType<U, Tower::B16>: PackedFieldIndexable,
+	PackedType<U, Tower::B32>: PackedFieldIndexable,
+	PackedType<U, Tower::B64>: PackedFieldIndexable,
+{
+	let (commit_meta, _) = piop::make_oracle_commit_meta(&constraint_system.oracles)?;
+	let pcs = make_fri_pcs::<Tower, Hash, Compress>(&commit_meta, log_inv_rate, security_bits)?;
+
+	prove_with_pcs::<U, Tower, _, _, Challenger_, _>(
+		constraint_system,
+		&pcs,
+		boundaries,
+		witness,
+		domain_factory,
+		backend,
+	)
+}
+
+/// Generates a proof that a witness satisfies a constraint system, committing the witness with the
+/// given polynomial commitment scheme.
+///
+/// The scheme must be set up for the commit metadata of the constraint system's oracles, see
+/// [`piop::make_oracle_commit_meta`]. The proof is verified by [`super::verify_with_pcs`] with the
+/// same scheme.
+#[instrument("constraint_system::prove_with_pcs", skip_all, level = "debug")]
+pub fn prove_with_pcs<U, Tower, PCS, DomainFactory, Challenger_, Backend>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	pcs: &PCS,
+	boundaries: &[Boundary<FExt<Tower>>],
+	witness: MultilinearExtensionIndex<U, FExt<Tower>>,
+	domain_factory: DomainFactory,
+	backend: &Backend,
+) -> Result<Proof, Error>
+where
+	U: ProverTowerUnderlier<Tower>,
+	Tower: ProverTowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	PCS: PolyCommitScheme<FExt<Tower>, PackedType<U, Tower::B128>>,
+	DomainFactory: EvaluationDomainFactory<FDomain<Tower>>,
+	Challenger_: Challenger + Default,
+	Backend: ComputationBackend,
+	// REVIEW: Consider changing TowerFamily and associated traits to shorten/remove these bounds
+	PackedType<U, Tower::B128>: PackedTop<Tower>
+		+ PackedFieldIndexable
+		+ RepackedExtension<PackedType<U, Tower::B8>>
+		+ RepackedExtension<PackedType<U, Tower::B16>>
+		+ RepackedExtension<PackedType<U, Tower::B32>>
+		+ RepackedExtension<PackedType<U, Tower::B64>>
+		+ RepackedExtension<PackedType<U, Tower::B128>>
+		+ PackedTransformationFactory<PackedType<U, Tower::FastB128>>,
+	PackedType<U, Tower::FastB128>:
+		PackedFieldIndexable + PackedTransformationFactory<PackedType<U, Tower::B128>>,
+	PackedType<U, Tower::B8>: PackedFieldIndexable,
+	PackedType<U, Tower::B16>: PackedFieldIndexable,
+	PackedType<U, Tower::B32>: PackedFieldIndexable,
+	PackedType<U, Tower::B64>: PackedFieldIndexable,
+{
+	prove_with_commit::<U, Tower, _, _, Challenger_, _>(
+		constraint_system,
+		pcs,
+		boundaries,
+		witness,
+		|committed_multilins| Ok(pcs.commit(committed_multilins)?),
+		domain_factory,
+		backend,
+	)
+}
+
+/// Generates a proof like [`prove_with_pcs`], committing the witness with the given function.
+#[allow(clippy::too_many_arguments)]
+fn prove_with_commit<U, Tower, PCS, DomainFactory, Challenger_, Backend>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	pcs: &PCS,
+	boundaries: &[Boundary<FExt<Tower>>],
 	mut witness: MultilinearExtensionIndex<U, FExt<Tower>>,
+	commit: impl FnOnce(
+		&[MultilinearWitness<PackedType<U, Tower::B128>>],
+	) -> Result<(PCS::Commitment, PCS::Committed), Error>,
 	domain_factory: DomainFactory,
 	backend: &Backend,
 ) -> Result<Proof, Error>
@@ -75,9 +180,8 @@ where
 	U: ProverTowerUnderlier<Tower>,
 	Tower: ProverTowerFamily,
 	Tower::B128: PackedTop<Tower>,
+	PCS: PolyCommitScheme<FExt<Tower>, PackedType<U, Tower::B128>>,
 	DomainFactory: EvaluationDomainFactory<FDomain<Tower>>,
-	Hash: Digest + BlockSizeUser + FixedOutputReset,
-	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
 	Challenger_: Challenger + Default,
 	Backend: ComputationBackend,
 	// REVIEW: Consider changing TowerFamily and associated traits to shorten/remove these bounds
@@ -104,8 +208,11 @@ where
 
 	let fast_domain_factory = IsomorphicEvaluationDomainFactory::<FFastExt<Tower>>::default();
 
-	let mut transcript = ProverTranscript::<Challenger_>::new();
-	transcript.observe().write_slice(boundaries);
+	let mut transcript =
+		ProverTranscript::<Challenger_>::new_with_domain_separator(DOMAIN_SEPARATOR);
+	transcript
+		.observe_labeled("boundaries")
+		.write_slice(boundaries);
 
 	let ConstraintSystem {
 		mut oracles,
@@ -126,9 +233,6 @@ where
 	table_constraints.sort_by_key(|constraint_set| Reverse(constraint_set.n_vars));
 
 	// Commit polynomials
-	let merkle_prover = BinaryMerkleTreeProver::<_, Hash, _>::new(Compress::default());
-	let merkle_scheme = merkle_prover.scheme();
-
 	let (commit_meta, oracle_to_commit_index) = piop::make_oracle_commit_meta(&oracles)?;
 	let committed_multilins = piop::collect_committed_witnesses(
 		&commit_meta,
@@ -137,23 +241,15 @@ where
 		&witness,
 	)?;
 
-	let fri_params = piop::make_commit_params_with_optimal_arity::<_, FEncode<Tower>, _>(
-		&commit_meta,
-		merkle_scheme,
-		security_bits,
-		log_inv_rate,
-	)?;
-	let CommitOutput {
-		commitment,
-		committed,
-		codeword,
-	} = piop::commit(&fri_params, &merkle_prover, &committed_multilins)?;
+	let (commitment, committed) = commit(&committed_multilins)?;
 
 	// Observe polynomial commitment
+	transcript.set_phase("commitment");
 	let mut writer = transcript.message();
 	writer.write(&commitment);
 
 	// GKR exp
+	transcript.set_phase("gkr_exp");
 	let exp_challenge = transcript.sample_vec(exp::max_n_vars(&exponents, &oracles));
 
 	let exp_evals = gkr_exp::get_evals_in_point_from_witnesses(&exp_witnesses, &exp_challenge)?
@@ -187,6 +283,7 @@ where
 	let exp_eval_claims = exp::make_eval_claims(&exponents, base_exp_output)?;
 
 	// Grand product arguments
+	transcript.set_phase("grand_product");
 	// Grand products for non-zero checking
 	let non_zero_fast_witnesses =
 		make_fast_masked_flush_witnesses(&oracles, &witness, &non_zero_oracle_ids, None)?;
@@ -277,6 +374,7 @@ where
 		gkr_gpa::make_eval_claims(non_zero_oracle_ids, non_zero_final_layer_claims)?;
 
 	// Reduce flush_final_layer_claims to sumcheck claims then evalcheck claims
+	transcript.set_phase("flush_sumcheck");
 	let (flush_oracle_ids, flush_selectors, flush_final_layer_claims) =
 		reorder_for_flushing_by_n_vars(
 			&oracles,
@@ -290,11 +388,11 @@ where
 		flush_selectors_unique_by_claim,
 		flush_oracle_ids_by_claim,
 	} = get_flush_sumcheck_provers::<_, _, FDomain<Tower>, _, _>(
-		&mut oracles,
+		&oracles,
 		&flush_oracle_ids,
 		&flush_selectors,
 		&flush_final_layer_claims,
-		&mut witness,
+		&witness,
 		&domain_factory,
 		backend,
 	)?;
@@ -309,6 +407,7 @@ where
 	)?;
 
 	// Zerocheck
+	transcript.set_phase("zerocheck");
 	let (zerocheck_claims, zerocheck_oracle_metas) = table_constraints
 		.iter()
 		.cloned()
@@ -446,6 +545,7 @@ where
 		sumcheck::make_eval_claims(zerocheck_oracle_metas, multilinear_zerocheck_output)?;
 
 	// Prove evaluation claims
+	transcript.set_phase("evalcheck");
 	let eval_claims = greedy_evalcheck::prove::<_, _, FDomain<Tower>, _, _>(
 		&mut oracles,
 		&mut witness,
@@ -461,6 +561,7 @@ where
 	)?;
 
 	// Reduce committed evaluation claims to PIOP sumcheck claims
+	transcript.set_phase("ring_switch");
 	let system = ring_switch::EvalClaimSystem::new(
 		&oracles,
 		&commit_meta,
@@ -479,13 +580,11 @@ where
 	)?;
 
 	// Prove evaluation claims using PIOP compiler
-	piop::prove::<_, FDomain<Tower>, _, _, _, _, _, _, _, _>(
-		&fri_params,
-		&merkle_prover,
+	transcript.set_phase("piop");
+	pcs.prove::<FDomain<Tower>, _, _, _, _>(
 		domain_factory,
 		&commit_meta,
 		committed,
-		&codeword,
 		&committed_multilins,
 		&transparent_multilins,
 		&piop_sumcheck_claims,
@@ -498,6 +597,212 @@ where
 	})
 }
 
+/// A commitment to the committed multilinears of a witness with the standard FRI PCS, made by
+/// [`commit`].
+///
+/// The commitment can be persisted with its [`SerializeBytes`] implementation and passed to
+/// [`prove_with_commitment`] in a later proving session, which then skips encoding and committing
+/// the witness again.
+#[derive(Debug)]
+pub struct PersistedCommitment<P, Digest> {
+	/// A digest of the evaluations of the committed multilinears, see [`prove_with_commitment`].
+	pub witness_digest: Digest,
+	pub output: CommitOutput<P, Digest, BinaryMerkleTree<Digest>>,
+}
+
+impl<P, Digest> SerializeBytes for PersistedCommitment<P, Digest>
+where
+	P: PackedField<Scalar: SerializeBytes>,
+	Digest: SerializeBytes,
+{
+	fn serialize(
+		&self,
+		mut write_buf: impl BufMut,
+		mode: SerializationMode,
+	) -> Result<(), SerializationError> {
+		SerializeBytes::serialize(&self.witness_digest, &mut write_buf, mode)?;
+		SerializeBytes::serialize(&self.output, &mut write_buf, mode)
+	}
+}
+
+impl<P, Digest> DeserializeBytes for PersistedCommitment<P, Digest>
+where
+	P: PackedField<Scalar: DeserializeBytes>,
+	Digest: DeserializeBytes,
+{
+	fn deserialize(
+		mut read_buf: impl Buf,
+		mode: SerializationMode,
+	) -> Result<Self, SerializationError> {
+		Ok(Self {
+			witness_digest: DeserializeBytes::deserialize(&mut read_buf, mode)?,
+			output: DeserializeBytes::deserialize(&mut read_buf, mode)?,
+		})
+	}
+}
+
+/// Commits the committed multilinears of a witness with the standard FRI PCS, so that the
+/// commitment can be persisted and reused by [`prove_with_commitment`].
+///
+/// Like [`prove`], this first adds the committed witnesses of the exponentiation results to the
+/// witness index.
+#[instrument("constraint_system::commit", skip_all, level = "debug")]
+#[allow(clippy::type_complexity)]
+pub fn commit<U, Tower, Hash, Compress>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	log_inv_rate: usize,
+	security_bits: usize,
+	witness: &mut MultilinearExtensionIndex<U, FExt<Tower>>,
+) -> Result<PersistedCommitment<PackedType<U, Tower::B128>, Output<Hash::Digest>>, Error>
+where
+	U: ProverTowerUnderlier<Tower>,
+	Tower: ProverTowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: MultiDigest<Digest: BlockSizeUser>,
+	Compress: PseudoCompressionFunction<Output<Hash::Digest>, 2> + Default + Sync,
+	PackedType<U, Tower::B128>: PackedTop<Tower>
+		+ PackedFieldIndexable
+		+ PackedTransformationFactory<PackedType<U, Tower::FastB128>>,
+	PackedType<U, Tower::FastB128>: PackedTransformationFactory<PackedType<U, Tower::B128>>,
+{
+	let ConstraintSystem {
+		oracles, exponents, ..
+	} = constraint_system;
+	exp::make_exp_witnesses(witness, oracles, exponents)?;
+
+	let (commit_meta, oracle_to_commit_index) = piop::make_oracle_commit_meta(oracles)?;
+	let committed_multilins =
+		piop::collect_committed_witnesses(&commit_meta, &oracle_to_commit_index, oracles, witness)?;
+	let pcs = make_fri_pcs::<Tower, Hash, Compress>(&commit_meta, log_inv_rate, security_bits)?;
+
+	Ok(PersistedCommitment {
+		witness_digest: witness_digest::<Hash::Digest, U, Tower::B128>(&committed_multilins)?,
+		output: piop::commit(pcs.fri_params(), pcs.merkle_prover(), &committed_multilins)?,
+	})
+}
+
+/// Generates a proof like [`prove`], reusing a commitment to the witness persisted by [`commit`]
+/// in an earlier proving session.
+///
+/// `commitment` is the expected root of the persisted commitment, which the caller must obtain
+/// from a trusted source, see [`piop::load_commitment`].
+///
+/// The committed multilinears often change in only a few columns between proving sessions, but
+/// any change invalidates the codeword of the whole committed batch. If the digest of the committed
+/// multilinears differs from the persisted one, the witness is committed again as in [`prove`], and
+/// the caller should persist a new commitment with [`commit`] for the next session.
+#[instrument("constraint_system::prove_with_commitment", skip_all, level = "debug")]
+#[allow(clippy::too_many_arguments)]
+pub fn prove_with_commitment<U, Tower, DomainFactory, Hash, Compress, Challenger_, Backend>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	log_inv_rate: usize,
+	security_bits: usize,
+	boundaries: &[Boundary<FExt<Tower>>],
+	witness: MultilinearExtensionIndex<U, FExt<Tower>>,
+	commitment: &Output<Hash::Digest>,
+	persisted: PersistedCommitment<PackedType<U, Tower::B128>, Output<Hash::Digest>>,
+	domain_factory: DomainFactory,
+	backend: &Backend,
+) -> Result<Proof, Error>
+where
+	U: ProverTowerUnderlier<Tower>,
+	Tower: ProverTowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	DomainFactory: EvaluationDomainFactory<FDomain<Tower>>,
+	Hash: MultiDigest<Digest: BlockSizeUser>,
+	Compress: PseudoCompressionFunction<Output<Hash::Digest>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+	Backend: ComputationBackend,
+	// REVIEW: Consider changing TowerFamily and associated traits to shorten/remove these bounds
+	PackedType<U, Tower::B128>: PackedTop<Tower>
+		+ PackedFieldIndexable
+		+ RepackedExtension<PackedType<U, Tower::B8>>
+		+ RepackedExtension<PackedType<U, Tower::B16>>
+		+ RepackedExtension<PackedType<U, Tower::B32>>
+		+ RepackedExtension<PackedType<U, Tower::B64>>
+		+ RepackedExtension<PackedType<U, Tower::B128>>
+		+ PackedTransformationFactory<PackedType<U, Tower::FastB128>>,
+	PackedType<U, Tower::FastB128>:
+		PackedFieldIndexable + PackedTransformationFactory<PackedType<U, Tower::B128>>,
+	PackedType<U, Tower::B8>: PackedFieldIndexable,
+	PackedType<U, Tower::B16>: PackedFieldIndexable,
+	PackedType<U, Tower::B32>: PackedFieldIndexable,
+	PackedType<U, Tower::B64>: PackedFieldIndexable,
+{
+	let (commit_meta, _) = piop::make_oracle_commit_meta(&constraint_system.oracles)?;
+	let pcs = make_fri_pcs::<Tower, Hash, Compress>(&commit_meta, log_inv_rate, security_bits)?;
+
+	prove_with_commit::<U, Tower, _, _, Challenger_, _>(
+		constraint_system,
+		&pcs,
+		boundaries,
+		witness,
+		|committed_multilins| {
+			let PersistedCommitment {
+				witness_digest: persisted_digest,
+				output,
+			} = persisted;
+			if witness_digest::<Hash::Digest, U, Tower::B128>(committed_multilins)?
+				== persisted_digest
+			{
+				Ok(pcs.load(commitment, output, false)?)
+			} else {
+				tracing::debug!(
+					"committed witness changed since it was persisted, committing again"
+				);
+				Ok(pcs.commit(committed_multilins)?)
+			}
+		},
+		domain_factory,
+		backend,
+	)
+}
+
+#[allow(clippy::type_complexity)]
+fn make_fri_pcs<Tower, Hash, Compress>(
+	commit_meta: &CommitMeta,
+	log_inv_rate: usize,
+	security_bits: usize,
+) -> Result<
+	FRIBiniusPCS<FExt<Tower>, FEncode<Tower>, BinaryMerkleTreeProver<FExt<Tower>, Hash, Compress>>,
+	Error,
+>
+where
+	Tower: ProverTowerFamily,
+	Hash: MultiDigest<Digest: BlockSizeUser>,
+	Compress: PseudoCompressionFunction<Output<Hash::Digest>, 2> + Default + Sync,
+{
+	let merkle_prover = BinaryMerkleTreeProver::<_, Hash, _>::new(Compress::default());
+	let fri_params = piop::make_commit_params_with_optimal_arity::<_, FEncode<Tower>, _>(
+		commit_meta,
+		merkle_prover.scheme(),
+		security_bits,
+		log_inv_rate,
+	)?;
+	Ok(FRIBiniusPCS::new(fri_params, merkle_prover))
+}
+
+/// Hashes the evaluations of the committed multilinears, to detect whether a witness changed since
+/// its commitment was persisted.
+fn witness_digest<D, U, F>(
+	multilins: &[MultilinearWitness<PackedType<U, F>>],
+) -> Result<Output<D>, Error>
+where
+	D: Digest,
+	U: UnderlierType + PackScalar<F>,
+	F: Field,
+{
+	let mut hasher = D::new();
+	for (id, multilin) in multilins.iter().enumerate() {
+		let evals = multilin
+			.packed_evals()
+			.ok_or(piop::Error::CommittedPackedEvaluationsMissing { id })?;
+		hasher.update((multilin.n_vars() as u64).to_le_bytes());
+		hasher.update(bytemuck::cast_slice::<U, u8>(PackedType::<U, F>::to_underliers_ref(evals)));
+	}
+	Ok(hasher.finalize())
+}
+
 type TypeErasedUnivariateZerocheck<'a, F> = Box<dyn UnivariateZerocheckProver<'a, F> + 'a>;
 type TypeErasedSumcheck<'a, F> = Box<dyn SumcheckProver<F> + 'a>;
 type TypeErasedProver<'a, F> =
@@ -717,11 +1022,11 @@ pub struct FlushSumcheckProvers<Prover> {
 
 #[instrument(skip_all, level = "debug")]
 fn get_flush_sumcheck_provers<'a, 'b, U, Tower, FDomain, DomainFactory, Backend>(
-	oracles: &mut MultilinearOracleSet<Tower::B128>,
+	oracles: &MultilinearOracleSet<Tower::B128>,
 	flush_oracle_ids: &[OracleId],
 	flush_selectors: &[OracleId],
 	final_layer_claims: &[LayerClaim<Tower::B128>],
-	witness: &mut MultilinearExtensionIndex<'a, U, Tower::B128>,
+	witness: &MultilinearExtensionIndex<'a, U, Tower::B128>,
 	domain_factory: DomainFactory,
 	backend: &'b Backend,
 ) -> Result<FlushSumcheckProvers<impl SumcheckProver<Tower::B128> + 'b>, Error>
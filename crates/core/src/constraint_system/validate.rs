@@ -41,20 +41,34 @@ where
 			.map(|id| witness.get_multilin_poly(*id))
 			.collect::<Result<Vec<_>, _>>()?;
 
-		let mut zero_claims = vec![];
 		for constraint in &constraint_set.constraints {
 			match constraint.predicate {
-				ConstraintPredicate::Zero => zero_claims.push((
-					constraint.name.clone(),
-					ArithCircuitPoly::with_n_vars(
-						multilinears.len(),
-						constraint.composition.clone(),
-					)?,
-				)),
+				ConstraintPredicate::Zero => {
+					// Evaluate every constraint on the multilinears it reads only, since
+					// constraint sets of large circuits span many thousands of oracles.
+					let usage = constraint.composition.vars_usage();
+					let mut used = (0..usage.len()).filter(|&i| usage[i]).collect::<Vec<_>>();
+					if used.is_empty() && !multilinears.is_empty() {
+						used.push(0);
+					}
+					let mut positions = vec![0; usage.len()];
+					for (position, &i) in used.iter().enumerate() {
+						positions[i] = position;
+					}
+					let composition = constraint.composition.clone().remap_vars(&positions)?;
+					let zero_claim = (
+						constraint.name.clone(),
+						ArithCircuitPoly::with_n_vars(used.len(), composition)?,
+					);
+					let used_multilinears = used
+						.iter()
+						.map(|&i| multilinears[i].clone())
+						.collect::<Vec<_>>();
+					zerocheck::validate_witness(&used_multilinears, [&zero_claim])?;
+				}
 				ConstraintPredicate::Sum(_) => unimplemented!(),
 			}
 		}
-		zerocheck::validate_witness(&multilinears, &zero_claims)?;
 	}
 
 	// Check that nonzero oracles are non-zero over the entire hypercube
@@ -2,7 +2,10 @@
 
 use std::{cmp::Reverse, iter};
 
-use binius_field::{BinaryField, PackedField, TowerField};
+use binius_field::{
+	as_packed_field::PackedType, BinaryField, PackedField, PackedFieldIndexable, RepackedExtension,
+	TowerField,
+};
 use binius_hash::PseudoCompressionFunction;
 use binius_math::{ArithExpr, CompositionPoly, EvaluationOrder};
 use binius_utils::{bail, checked_arithmetics::log2_ceil_usize};
@@ -19,12 +22,12 @@ use crate::{
 	composition::IndexComposition,
 	constraint_system::{
 		channel::{Flush, FlushDirection},
-		common::{FDomain, FEncode, FExt},
+		common::{FDomain, FEncode, FExt, DOMAIN_SEPARATOR},
 	},
 	fiat_shamir::{CanSample, Challenger},
-	merkle_tree::BinaryMerkleTreeScheme,
+	merkle_tree::BinaryMerkleTreeProver,
 	oracle::{MultilinearOracleSet, OracleId},
-	piop,
+	piop::{self, FRIBiniusPCS, PolyCommitScheme},
 	polynomial::MultivariatePoly,
 	protocols::{
 		evalcheck::EvalcheckMultilinearClaim,
@@ -42,7 +45,7 @@ use crate::{
 	transparent::eq_ind::EqIndPartialEval,
 };
 
-/// Verifies a proof against a constraint system.
+/// Verifies a proof against a constraint system with the standard FRI PCS.
 #[instrument("constraint_system::verify", skip_all, level = "debug")]
 pub fn verify<U, Tower, Hash, Compress, Challenger_>(
 	constraint_system: &ConstraintSystem<FExt<Tower>>,
@@ -58,6 +61,38 @@ where
 	Hash: Digest + BlockSizeUser,
 	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
 	Challenger_: Challenger + Default,
+	PackedType<U, Tower::B128>:
+		PackedTop<Tower> + PackedFieldIndexable + RepackedExtension<PackedType<U, Tower::B128>>,
+{
+	// The verifier only uses the Merkle tree scheme of the prover.
+	let merkle_prover = BinaryMerkleTreeProver::<_, Hash, _>::new(Compress::default());
+	let (commit_meta, _) = piop::make_oracle_commit_meta(&constraint_system.oracles)?;
+	let fri_params = piop::make_commit_params_with_optimal_arity::<_, FEncode<Tower>, _>(
+		&commit_meta,
+		merkle_prover.scheme(),
+		security_bits,
+		log_inv_rate,
+	)?;
+	let pcs = FRIBiniusPCS::new(fri_params, merkle_prover);
+
+	verify_with_pcs::<U, Tower, _, Challenger_>(constraint_system, &pcs, boundaries, proof)
+}
+
+/// Verifies a proof against a constraint system, generated by [`super::prove_with_pcs`] with the
+/// given polynomial commitment scheme.
+#[instrument("constraint_system::verify_with_pcs", skip_all, level = "debug")]
+pub fn verify_with_pcs<U, Tower, PCS, Challenger_>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	pcs: &PCS,
+	boundaries: &[Boundary<FExt<Tower>>],
+	proof: Proof,
+) -> Result<(), Error>
+where
+	U: TowerUnderlier<Tower>,
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	PCS: PolyCommitScheme<FExt<Tower>, PackedType<U, Tower::B128>>,
+	Challenger_: Challenger + Default,
 {
 	let ConstraintSystem {
 		mut oracles,
@@ -74,23 +109,21 @@ where
 
 	let Proof { transcript } = proof;
 
-	let mut transcript = VerifierTranscript::<Challenger_>::new(transcript);
-	transcript.observe().write_slice(boundaries);
+	let mut transcript =
+		VerifierTranscript::<Challenger_>::new_with_domain_separator(transcript, DOMAIN_SEPARATOR);
+	transcript
+		.observe_labeled("boundaries")
+		.write_slice(boundaries);
 
-	let merkle_scheme = BinaryMerkleTreeScheme::<_, Hash, _>::new(Compress::default());
 	let (commit_meta, oracle_to_commit_index) = piop::make_oracle_commit_meta(&oracles)?;
-	let fri_params = piop::make_commit_params_with_optimal_arity::<_, FEncode<Tower>, _>(
-		&commit_meta,
-		&merkle_scheme,
-		security_bits,
-		log_inv_rate,
-	)?;
 
 	// Read polynomial commitment polynomials
+	transcript.set_phase("commitment");
 	let mut reader = transcript.message();
-	let commitment = reader.read::<Output<Hash>>()?;
+	let commitment = reader.read::<PCS::Commitment>()?;
 
 	// GKR exp multiplication
+	transcript.set_phase("gkr_exp");
 	exponents.sort_by_key(|b| std::cmp::Reverse(b.n_vars(&oracles)));
 
 	let exp_challenge = transcript.sample_vec(exp::max_n_vars(&exponents, &oracles));
@@ -108,6 +141,7 @@ where
 	let exp_eval_claims = exp::make_eval_claims(&exponents, base_exp_output)?;
 
 	// Grand product arguments
+	transcript.set_phase("grand_product");
 	// Grand products for non-zero checks
 	let mut reader = transcript.message();
 	let non_zero_products = reader.read_scalar_slice(non_zero_oracle_ids.len())?;
@@ -166,6 +200,7 @@ where
 		gkr_gpa::make_eval_claims(non_zero_oracle_ids, non_zero_final_layer_claims)?;
 
 	// Reduce flush_final_layer_claims to sumcheck claims then evalcheck claims
+	transcript.set_phase("flush_sumcheck");
 	let (flush_oracle_ids, flush_selectors, flush_final_layer_claims) =
 		reorder_for_flushing_by_n_vars(
 			&oracles,
@@ -224,6 +259,7 @@ where
 	}
 
 	// Zerocheck
+	transcript.set_phase("zerocheck");
 	let (zerocheck_claims, zerocheck_oracle_metas) = table_constraints
 		.iter()
 		.cloned()
@@ -296,6 +332,7 @@ where
 		sumcheck::make_eval_claims(zerocheck_oracle_metas, multilinear_zerocheck_output)?;
 
 	// Evalcheck
+	transcript.set_phase("evalcheck");
 	let eval_claims = greedy_evalcheck::verify(
 		&mut oracles,
 		[non_zero_prodcheck_eval_claims, flush_eval_claims]
@@ -307,6 +344,7 @@ where
 	)?;
 
 	// Reduce committed evaluation claims to PIOP sumcheck claims
+	transcript.set_phase("ring_switch");
 	let system = ring_switch::EvalClaimSystem::new(
 		&oracles,
 		&commit_meta,
@@ -320,15 +358,8 @@ where
 	} = ring_switch::verify::<_, Tower, _>(&system, &mut transcript)?;
 
 	// Prove evaluation claims using PIOP compiler
-	piop::verify(
-		&commit_meta,
-		&merkle_scheme,
-		&fri_params,
-		&commitment,
-		&transparents,
-		&piop_sumcheck_claims,
-		&mut transcript,
-	)?;
+	transcript.set_phase("piop");
+	pcs.verify(&commit_meta, &commitment, &transparents, &piop_sumcheck_claims, &mut transcript)?;
 
 	transcript.finalize()?;
 
@@ -0,0 +1,454 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{cmp::min, mem::size_of};
+
+use binius_field::{AESTowerField32b, AESTowerField8b, ExtensionField, Field};
+use binius_hash::{
+	groestl::Groestl256PPermutation, permutation::CryptographicPermutation, Vision32bPermutation,
+};
+use bytemuck::Pod;
+use bytes::{buf::UninitSlice, Buf, BufMut};
+
+use super::{CanSample, CanSampleBits, Challenger};
+
+/// A duplex sponge challenger over the Vision Mark-32 permutation, with a rate of 16 elements.
+pub type VisionChallenger = DuplexChallenger<AESTowerField32b, Vision32bPermutation, 24, 16>;
+
+/// A duplex sponge challenger over the Grøstl-256 P permutation, with a rate of 32 bytes.
+pub type GroestlChallenger = DuplexChallenger<AESTowerField8b, Groestl256PPermutation, 64, 32>;
+
+/// Challenger over a cryptographic permutation of `WIDTH` field elements, in duplex sponge mode.
+///
+/// Unlike [`super::HasherChallenger`], the challenger observes and samples field elements without
+/// serializing them, so that it is cheap to arithmetize in recursive verification. Observed
+/// elements overwrite the first `RATE` elements of the state, and samples are taken from the
+/// first `RATE` elements of the state, in order. The state is permuted whenever the rate is
+/// filled with observed elements, or a sample is taken after an observation or from an exhausted
+/// rate.
+///
+/// The [`Challenger`] interface observes and samples the in-memory bytes of the elements. Observed
+/// bytes are padded with a single `0x01` byte followed by zeros up to the next element boundary
+/// when the next sample is taken or element is observed, so that distinct byte strings are
+/// absorbed as distinct elements.
+#[derive(Debug, Clone)]
+pub struct DuplexChallenger<F, Perm, const WIDTH: usize, const RATE: usize> {
+	inner: DuplexState<F, Perm, WIDTH, RATE>,
+}
+
+#[derive(Debug, Clone)]
+struct DuplexState<F, Perm, const WIDTH: usize, const RATE: usize> {
+	permutation: Perm,
+	state: [F; WIDTH],
+	/// Observed elements that are not absorbed into the state yet.
+	input_buffer: Vec<F>,
+	/// Elements available for sampling, in reverse order.
+	output_buffer: Vec<F>,
+	/// Observed bytes of an incomplete element.
+	observed_bytes: Vec<u8>,
+	observed_len: usize,
+	/// Whether bytes were observed since the last padding.
+	bytes_pending: bool,
+	/// Bytes of the last sampled element that have not been read yet.
+	sampled_bytes: Vec<u8>,
+	sampled_index: usize,
+}
+
+impl<F, Perm, const WIDTH: usize, const RATE: usize> DuplexChallenger<F, Perm, WIDTH, RATE>
+where
+	F: Field + Pod,
+	Perm: CryptographicPermutation<[F; WIDTH]>,
+{
+	pub fn new(permutation: Perm) -> Self {
+		assert!(RATE > 0 && RATE < WIDTH, "rate must be positive and below the width");
+		Self {
+			inner: DuplexState {
+				permutation,
+				state: [F::ZERO; WIDTH],
+				input_buffer: Vec::with_capacity(RATE),
+				output_buffer: Vec::with_capacity(RATE),
+				observed_bytes: vec![0; size_of::<F>()],
+				observed_len: 0,
+				bytes_pending: false,
+				sampled_bytes: Vec::new(),
+				sampled_index: 0,
+			},
+		}
+	}
+
+	pub fn observe(&mut self, value: F) {
+		self.inner.flush_observed_bytes();
+		self.inner.observe(value);
+	}
+
+	pub fn observe_slice(&mut self, values: &[F]) {
+		for &value in values {
+			self.observe(value);
+		}
+	}
+}
+
+impl<F, Perm, const WIDTH: usize, const RATE: usize> Default
+	for DuplexChallenger<F, Perm, WIDTH, RATE>
+where
+	F: Field + Pod,
+	Perm: CryptographicPermutation<[F; WIDTH]> + Default,
+{
+	fn default() -> Self {
+		Self::new(Perm::default())
+	}
+}
+
+impl<F, Perm, const WIDTH: usize, const RATE: usize> DuplexState<F, Perm, WIDTH, RATE>
+where
+	F: Field + Pod,
+	Perm: CryptographicPermutation<[F; WIDTH]>,
+{
+	fn observe(&mut self, value: F) {
+		// any pending samples are invalidated by the new observation
+		self.output_buffer.clear();
+		self.sampled_bytes.clear();
+		self.sampled_index = 0;
+
+		self.input_buffer.push(value);
+		if self.input_buffer.len() == RATE {
+			self.duplexing();
+		}
+	}
+
+	fn sample(&mut self) -> F {
+		self.flush_observed_bytes();
+		if !self.input_buffer.is_empty() || self.output_buffer.is_empty() {
+			self.duplexing();
+		}
+		self.output_buffer
+			.pop()
+			.expect("output buffer is refilled by duplexing")
+	}
+
+	fn duplexing(&mut self) {
+		for (state, input) in self.state.iter_mut().zip(self.input_buffer.drain(..)) {
+			*state = input;
+		}
+		self.permutation.permute_mut(&mut self.state);
+
+		self.output_buffer.clear();
+		self.output_buffer
+			.extend(self.state[..RATE].iter().rev().copied());
+	}
+
+	/// Pads the observed bytes with `10*` up to the next element boundary and absorbs them.
+	///
+	/// The padding is applied even if the bytes end at an element boundary, which makes it
+	/// injective.
+	fn flush_observed_bytes(&mut self) {
+		if self.bytes_pending {
+			self.bytes_pending = false;
+			self.observed_bytes[self.observed_len] = 0x01;
+			self.observed_bytes[self.observed_len + 1..].fill(0);
+			self.observed_len = 0;
+			let value = bytemuck::pod_read_unaligned(&self.observed_bytes);
+			self.observe(value);
+		}
+	}
+}
+
+impl<F, Perm, const WIDTH: usize, const RATE: usize> Challenger
+	for DuplexChallenger<F, Perm, WIDTH, RATE>
+where
+	F: Field + Pod,
+	Perm: CryptographicPermutation<[F; WIDTH]>,
+{
+	fn sampler(&mut self) -> &mut impl Buf {
+		self.inner.flush_observed_bytes();
+		&mut self.inner
+	}
+
+	fn observer(&mut self) -> &mut impl BufMut {
+		&mut self.inner
+	}
+}
+
+impl<F, Perm, const WIDTH: usize, const RATE: usize> Buf for DuplexState<F, Perm, WIDTH, RATE>
+where
+	F: Field + Pod,
+	Perm: CryptographicPermutation<[F; WIDTH]>,
+{
+	fn remaining(&self) -> usize {
+		usize::MAX
+	}
+
+	fn chunk(&self) -> &[u8] {
+		&self.sampled_bytes[self.sampled_index..]
+	}
+
+	fn advance(&mut self, mut cnt: usize) {
+		// Must handle the case when `cnt` is 0
+		if self.sampled_index == self.sampled_bytes.len() {
+			self.sampled_bytes = bytemuck::bytes_of(&self.sample()).to_vec();
+			self.sampled_index = 0;
+		}
+
+		while cnt > 0 {
+			let remaining = min(self.sampled_bytes.len() - self.sampled_index, cnt);
+			if remaining == 0 {
+				self.sampled_bytes = bytemuck::bytes_of(&self.sample()).to_vec();
+				self.sampled_index = 0;
+				continue;
+			}
+			cnt -= remaining;
+			self.sampled_index += remaining;
+		}
+	}
+}
+
+unsafe impl<F, Perm, const WIDTH: usize, const RATE: usize> BufMut
+	for DuplexState<F, Perm, WIDTH, RATE>
+where
+	F: Field + Pod,
+	Perm: CryptographicPermutation<[F; WIDTH]>,
+{
+	fn remaining_mut(&self) -> usize {
+		usize::MAX
+	}
+
+	unsafe fn advance_mut(&mut self, cnt: usize) {
+		assert!(cnt <= self.observed_bytes.len() - self.observed_len);
+		self.bytes_pending |= cnt > 0;
+		self.observed_len += cnt;
+		if self.observed_len == self.observed_bytes.len() {
+			self.observed_len = 0;
+			let value = bytemuck::pod_read_unaligned(&self.observed_bytes);
+			self.observe(value);
+		}
+	}
+
+	fn chunk_mut(&mut self) -> &mut UninitSlice {
+		let buffer = &mut self.observed_bytes[self.observed_len..];
+		buffer.into()
+	}
+}
+
+impl<F, FE, Perm, const WIDTH: usize, const RATE: usize> CanSample<FE>
+	for DuplexChallenger<F, Perm, WIDTH, RATE>
+where
+	F: Field + Pod,
+	FE: ExtensionField<F>,
+	Perm: CryptographicPermutation<[F; WIDTH]>,
+{
+	fn sample(&mut self) -> FE {
+		let bases = (0..FE::DEGREE)
+			.map(|_| self.inner.sample())
+			.collect::<Vec<_>>();
+		FE::from_bases(bases).expect("number of bases is the extension degree")
+	}
+}
+
+impl<F, Perm, const WIDTH: usize, const RATE: usize> CanSampleBits<usize>
+	for DuplexChallenger<F, Perm, WIDTH, RATE>
+where
+	F: Field + Pod,
+	Perm: CryptographicPermutation<[F; WIDTH]>,
+{
+	fn sample_bits(&mut self, bits: usize) -> usize {
+		let bits = bits.min(usize::BITS as usize);
+		let mut bytes = [0u8; size_of::<usize>()];
+		let mut filled = 0;
+		while filled < bits.div_ceil(8) {
+			let value = self.inner.sample();
+			let value_bytes = bytemuck::bytes_of(&value);
+			let count = min(value_bytes.len(), bytes.len() - filled);
+			bytes[filled..filled + count].copy_from_slice(&value_bytes[..count]);
+			filled += count;
+		}
+
+		let unmasked = usize::from_le_bytes(bytes);
+		match 1usize.checked_shl(bits as u32) {
+			Some(bound) => unmasked & (bound - 1),
+			None => unmasked,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::array;
+
+	use binius_field::{AESTowerField128b, BinaryField128b, BinaryField32b};
+	use binius_hash::{permutation::Permutation, VisionHasherDigest};
+	use digest::Digest;
+	use rand::{rngs::StdRng, Rng, SeedableRng};
+
+	use super::*;
+	use crate::transcript::ProverTranscript;
+
+	const VISION_WIDTH: usize = 24;
+	const VISION_RATE: usize = 16;
+
+	fn permute(mut state: [AESTowerField32b; VISION_WIDTH]) -> [AESTowerField32b; VISION_WIDTH] {
+		Vision32bPermutation::default().permute_mut(&mut state);
+		state
+	}
+
+	#[test]
+	fn test_sample_from_fresh_state() {
+		let mut challenger = VisionChallenger::default();
+		let expected = permute([AESTowerField32b::ZERO; VISION_WIDTH]);
+
+		let samples: Vec<AESTowerField32b> = challenger.sample_vec(VISION_RATE + 1);
+		assert_eq!(samples[..VISION_RATE], expected[..VISION_RATE]);
+		assert_eq!(samples[VISION_RATE], permute(expected)[0]);
+	}
+
+	#[test]
+	fn test_observe_overwrites_rate() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let values: [AESTowerField32b; 3] = array::from_fn(|_| AESTowerField32b::random(&mut rng));
+
+		let mut challenger = VisionChallenger::default();
+		let _: AESTowerField32b = challenger.sample();
+		challenger.observe_slice(&values);
+
+		let mut expected = permute([AESTowerField32b::ZERO; VISION_WIDTH]);
+		expected[..3].copy_from_slice(&values);
+		let expected = permute(expected);
+
+		let samples: Vec<AESTowerField32b> = challenger.sample_vec(2);
+		assert_eq!(samples, expected[..2]);
+	}
+
+	#[test]
+	fn test_vision_digest_known_answer() {
+		// A single block sponge absorption followed by a squeeze of 8 elements is the Vision
+		// digest of a 32-byte message.
+		let mut rng = StdRng::seed_from_u64(0);
+		let message: [u8; 32] = rng.gen();
+
+		let mut block = [0u8; 64];
+		block[..32].copy_from_slice(&message);
+		block[32] = 0x80;
+		block[63] = 0x01;
+
+		let mut challenger = VisionChallenger::default();
+		for word in block.chunks_exact(4) {
+			let word = BinaryField32b::new(u32::from_le_bytes(word.try_into().unwrap()));
+			challenger.observe(AESTowerField32b::from(word));
+		}
+		let digest = (0..8)
+			.flat_map(|_| {
+				let value: AESTowerField32b = challenger.sample();
+				BinaryField32b::from(value).val().to_le_bytes()
+			})
+			.collect::<Vec<_>>();
+
+		assert_eq!(digest, VisionHasherDigest::digest(message).to_vec());
+	}
+
+	#[test]
+	fn test_mixed_sampling() {
+		let mut challenger = VisionChallenger::default();
+		challenger.observe_slice(&[AESTowerField32b::new(1), AESTowerField32b::new(2)]);
+		let sample: AESTowerField32b = challenger.sample();
+		let bits = challenger.sample_bits(20);
+		let mut bytes = [0u8; 5];
+		challenger.sampler().copy_to_slice(&mut bytes);
+
+		let mut expected = [AESTowerField32b::ZERO; VISION_WIDTH];
+		expected[..2].copy_from_slice(&[AESTowerField32b::new(1), AESTowerField32b::new(2)]);
+		let expected = permute(expected);
+		assert_eq!(sample, expected[0]);
+		assert_eq!(bits, expected[1].val() as usize & ((1 << 20) - 1));
+		assert_eq!(bytes[..4], expected[2].val().to_le_bytes());
+		assert_eq!(bytes[4], expected[3].val().to_le_bytes()[0]);
+	}
+
+	#[test]
+	fn test_byte_and_field_observations_agree() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let values: [AESTowerField32b; 21] = array::from_fn(|_| AESTowerField32b::random(&mut rng));
+
+		let mut field_challenger = VisionChallenger::default();
+		field_challenger.observe_slice(&values);
+		field_challenger.observe(AESTowerField32b::new(0x01));
+
+		let mut byte_challenger = VisionChallenger::default();
+		let bytes = bytemuck::cast_slice::<_, u8>(&values);
+		byte_challenger.observer().put_slice(&bytes[..7]);
+		byte_challenger.observer().put_slice(&bytes[7..]);
+
+		let field_sample: AESTowerField128b = field_challenger.sample();
+		let byte_sample: AESTowerField128b = byte_challenger.sample();
+		assert_eq!(field_sample, byte_sample);
+	}
+
+	#[test]
+	fn test_padding_is_injective() {
+		let sample_bytes = |observed: &[u8]| {
+			let mut challenger = VisionChallenger::default();
+			challenger.observer().put_slice(observed);
+			let mut bytes = [0u8; 8];
+			challenger.sampler().copy_to_slice(&mut bytes);
+			bytes
+		};
+
+		assert_ne!(sample_bytes(&[1, 2]), sample_bytes(&[1, 2, 0, 0]));
+		assert_ne!(sample_bytes(&[1, 2]), sample_bytes(&[1, 2, 1, 0]));
+		assert_ne!(sample_bytes(&[]), sample_bytes(&[0, 0, 0, 0]));
+
+		// [1, 2] is padded to the element with bytes [1, 2, 1, 0]
+		let mut challenger = VisionChallenger::default();
+		challenger.observe(AESTowerField32b::new(u32::from_le_bytes([1, 2, 1, 0])));
+		let mut bytes = [0u8; 8];
+		challenger.sampler().copy_to_slice(&mut bytes);
+		assert_eq!(sample_bytes(&[1, 2]), bytes);
+	}
+
+	#[test]
+	fn test_known_answers() {
+		let mut vision = VisionChallenger::default();
+		vision.observer().put_slice(b"binius");
+		let samples: Vec<AESTowerField32b> = vision.sample_vec(4);
+		assert_eq!(
+			samples,
+			[0x6d03e12e, 0x98151a25, 0x971cd85f, 0x918962cb].map(AESTowerField32b::new)
+		);
+		assert_eq!(vision.sample_bits(20), 0x52f23);
+
+		let mut groestl = GroestlChallenger::default();
+		groestl.observer().put_slice(b"binius");
+		let samples: Vec<AESTowerField8b> = groestl.sample_vec(8);
+		assert_eq!(
+			samples,
+			[0xfb, 0x07, 0x72, 0xa2, 0x54, 0xb9, 0xb9, 0x94].map(AESTowerField8b::new)
+		);
+		assert_eq!(groestl.sample_bits(20), 0x75c90);
+	}
+
+	#[test]
+	fn test_groestl_challenger() {
+		let mut challenger = GroestlChallenger::default();
+		challenger.observe(AESTowerField8b::new(0x42));
+		let samples: Vec<AESTowerField8b> = challenger.sample_vec(33);
+
+		let mut state = [AESTowerField8b::ZERO; 64];
+		state[0] = AESTowerField8b::new(0x42);
+		let state = Groestl256PPermutation.permute(state);
+		assert_eq!(samples[..32], state[..32]);
+		assert_eq!(samples[32], Groestl256PPermutation.permute(state)[0]);
+	}
+
+	#[test]
+	fn test_transcript() {
+		let mut prover = ProverTranscript::<VisionChallenger>::new();
+		prover.message().write_scalar(BinaryField32b::new(7));
+		let prover_sample: BinaryField128b = prover.sample();
+		let prover_bits = prover.sample_bits(13);
+
+		let mut verifier = prover.into_verifier();
+		let value: BinaryField32b = verifier.message().read_scalar().unwrap();
+		let verifier_sample: BinaryField128b = verifier.sample();
+		assert_eq!(value, BinaryField32b::new(7));
+		assert_eq!(prover_sample, verifier_sample);
+		assert_eq!(prover_bits, verifier.sample_bits(13));
+		verifier.finalize().unwrap();
+	}
+}
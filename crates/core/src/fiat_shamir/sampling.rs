@@ -13,10 +13,12 @@ use std::array;
 pub trait CanSample<T> {
 	fn sample(&mut self) -> T;
 
+	#[track_caller]
 	fn sample_array<const N: usize>(&mut self) -> [T; N] {
 		array::from_fn(|_| self.sample())
 	}
 
+	#[track_caller]
 	fn sample_vec(&mut self, n: usize) -> Vec<T> {
 		(0..n).map(|_| self.sample()).collect()
 	}
@@ -1,40 +1,104 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{array, fmt::Debug, mem::MaybeUninit};
+use std::{array, fmt::Debug, iter, mem::MaybeUninit};
 
 use binius_field::TowerField;
-use binius_hash::{HashBuffer, PseudoCompressionFunction};
+use binius_hash::{MultiDigest, PseudoCompressionFunction};
 use binius_maybe_rayon::{prelude::*, slice::ParallelSlice};
 use binius_utils::{
-	bail, checked_arithmetics::log2_strict_usize, SerializationMode, SerializeBytes,
+	bail, checked_arithmetics::log2_strict_usize, DeserializeBytes, SerializationError,
+	SerializationMode, SerializeBytes,
 };
-use digest::{crypto_common::BlockSizeUser, Digest, FixedOutputReset, Output};
+use bytes::{Buf, BufMut};
+use digest::Output;
 use tracing::instrument;
 
 use super::errors::Error;
 
-/// A binary Merkle tree that commits batches of vectors.
+/// The number of nodes of a layer hashed together with [`MultiDigest::hash_many`] or
+/// [`PseudoCompressionFunction::compress_many`].
+const BATCH_SIZE: usize = 64;
+
+/// A Merkle tree that commits batches of vectors.
 ///
 /// The vector entries at each index in a batch are hashed together into leaf digests. Then a
 /// Merkle tree is constructed over the leaf digests. The implementation requires that the vector
 /// lengths are all equal to each other and a power of two.
+///
+/// Each inner node compresses `N` nodes of the layer below, where the arity `N` is a power of two
+/// and defaults to 2. When the base-2 logarithm of the number of leaves is not a multiple of
+/// `log2(N)`, the root compresses fewer nodes, padded with default digests. The layers of the tree
+/// are thus at the depths returned by [`layer_depths`].
 #[derive(Debug, Clone)]
-pub struct BinaryMerkleTree<D> {
+pub struct BinaryMerkleTree<D, const N: usize = 2> {
 	/// Base-2 logarithm of the number of leaves
 	pub log_len: usize,
 	/// The inner nodes, arranged as a flattened array of layers with the root at the end
 	pub inner_nodes: Vec<D>,
 }
 
-pub fn build<F, H, C>(
+/// Returns the depths of the layers of a Merkle tree with `1 << log_len` leaves, from the leaves
+/// to the root.
+///
+/// Every layer compresses groups of `1 << log_arity` nodes of the layer below, except for the
+/// root, which compresses all nodes of the layer below.
+pub fn layer_depths(log_len: usize, log_arity: usize) -> impl Iterator<Item = usize> {
+	iter::successors(Some(log_len), move |&depth| {
+		(depth > 0).then(|| depth.saturating_sub(log_arity))
+	})
+}
+
+/// Returns the base-2 logarithm of the arity `N`, which must be a power of two greater than one.
+pub(super) const fn log_arity<const N: usize>() -> usize {
+	assert!(N >= 2 && N.is_power_of_two(), "Merkle tree arity must be a power of two");
+	N.ilog2() as usize
+}
+
+/// Returns the depth of each layer from the leaves up to the one at `layer_depth` exclusive,
+/// along with the base-2 logarithm of the number of its nodes compressed into one node of the
+/// layer above.
+///
+/// Throws if there is no layer at `layer_depth`.
+pub(super) fn compressed_layers(
+	log_len: usize,
+	log_arity: usize,
+	layer_depth: usize,
+) -> Result<Vec<(usize, usize)>, Error> {
+	let depths = layer_depths(log_len, log_arity)
+		.take_while(|&depth| depth >= layer_depth)
+		.collect::<Vec<_>>();
+	if depths.last() != Some(&layer_depth) {
+		bail!(Error::IncorrectLayerDepth);
+	}
+
+	Ok(depths
+		.windows(2)
+		.map(|depths| (depths[0], depths[0] - depths[1]))
+		.collect())
+}
+
+/// Compresses up to `N` nodes into their parent, padding them with default digests.
+pub(super) fn compress_children<D, C, const N: usize>(compression: &C, children: &[D]) -> D
+where
+	D: Clone + Default,
+	C: PseudoCompressionFunction<D, N>,
+{
+	compression.compress(pad_children(children))
+}
+
+fn pad_children<D: Clone + Default, const N: usize>(children: &[D]) -> [D; N] {
+	array::from_fn(|i| children.get(i).cloned().unwrap_or_default())
+}
+
+pub fn build<F, H, C, const N: usize>(
 	compression: &C,
 	elements: &[F],
 	batch_size: usize,
-) -> Result<BinaryMerkleTree<Output<H>>, Error>
+) -> Result<BinaryMerkleTree<Output<H::Digest>, N>, Error>
 where
 	F: TowerField,
-	H: Digest + BlockSizeUser + FixedOutputReset,
-	C: PseudoCompressionFunction<Output<H>, 2> + Sync,
+	H: MultiDigest,
+	C: PseudoCompressionFunction<Output<H::Digest>, N> + Sync,
 {
 	if elements.len() % batch_size != 0 {
 		bail!(Error::IncorrectBatchSize);
@@ -55,17 +119,20 @@ where
 	)
 }
 
-fn internal_build<Digest, C>(
+fn internal_build<Digest, C, const N: usize>(
 	compression: &C,
 	// Must either successfully initialize the passed in slice or return error
 	hash_leaves: impl FnOnce(&mut [MaybeUninit<Digest>]) -> Result<(), Error>,
 	log_len: usize,
-) -> Result<BinaryMerkleTree<Digest>, Error>
+) -> Result<BinaryMerkleTree<Digest, N>, Error>
 where
-	Digest: Clone + Send + Sync,
-	C: PseudoCompressionFunction<Digest, 2> + Sync,
+	Digest: Clone + Default + Send + Sync,
+	C: PseudoCompressionFunction<Digest, N> + Sync,
 {
-	let total_length = (1 << (log_len + 1)) - 1;
+	let log_arity = log_arity::<N>();
+	let total_length = layer_depths(log_len, log_arity)
+		.map(|depth| 1 << depth)
+		.sum();
 	let mut inner_nodes = Vec::with_capacity(total_length);
 
 	hash_leaves(&mut inner_nodes.spare_capacity_mut()[..(1 << log_len)])?;
@@ -76,8 +143,8 @@ where
 		// SAFETY: prev-layer was initialized by hash_leaves
 		slice_assume_init_mut(prev_layer)
 	};
-	for i in 1..(log_len + 1) {
-		let (next_layer, next_remaining) = remaining.split_at_mut(1 << (log_len - i));
+	for depth in layer_depths(log_len, log_arity).skip(1) {
+		let (next_layer, next_remaining) = remaining.split_at_mut(1 << depth);
 		remaining = next_remaining;
 
 		compress_layer(compression, prev_layer, next_layer);
@@ -101,15 +168,15 @@ where
 }
 
 #[instrument("BinaryMerkleTree::build", skip_all, level = "debug")]
-pub fn build_from_iterator<F, H, C, ParIter>(
+pub fn build_from_iterator<F, H, C, ParIter, const N: usize>(
 	compression: &C,
 	iterated_chunks: ParIter,
 	log_len: usize,
-) -> Result<BinaryMerkleTree<Output<H>>, Error>
+) -> Result<BinaryMerkleTree<Output<H::Digest>, N>, Error>
 where
 	F: TowerField,
-	H: Digest + BlockSizeUser + FixedOutputReset,
-	C: PseudoCompressionFunction<Output<H>, 2> + Sync,
+	H: MultiDigest,
+	C: PseudoCompressionFunction<Output<H::Digest>, N> + Sync,
 	ParIter: IndexedParallelIterator<Item: IntoIterator<Item = F>>,
 {
 	internal_build(
@@ -119,7 +186,7 @@ where
 	)
 }
 
-impl<D: Clone> BinaryMerkleTree<D> {
+impl<D: Clone, const N: usize> BinaryMerkleTree<D, N> {
 	pub fn root(&self) -> D {
 		self.inner_nodes
 			.last()
@@ -127,47 +194,144 @@ impl<D: Clone> BinaryMerkleTree<D> {
 			.clone()
 	}
 
+	/// Returns the layer at the given depth
+	///
+	/// Throws if the tree has no layer at this depth, see [`layer_depths`]
 	pub fn layer(&self, layer_depth: usize) -> Result<&[D], Error> {
-		if layer_depth > self.log_len {
-			bail!(Error::IncorrectLayerDepth);
+		let mut range_start = 0;
+		for depth in layer_depths(self.log_len, log_arity::<N>()) {
+			if depth == layer_depth {
+				return Ok(&self.inner_nodes[range_start..range_start + (1 << layer_depth)]);
+			}
+			range_start += 1 << depth;
 		}
-		let range_start = self.inner_nodes.len() + 1 - (1 << (layer_depth + 1));
-
-		Ok(&self.inner_nodes[range_start..range_start + (1 << layer_depth)])
+		bail!(Error::IncorrectLayerDepth)
 	}
 
 	/// Get a Merkle branch for the given index
 	///
+	/// For every layer below the one at `layer_depth`, the branch contains the nodes compressed
+	/// together with the node on the path of the index, in increasing index order.
+	///
 	/// Throws if the index is out of range
 	pub fn branch(&self, index: usize, layer_depth: usize) -> Result<Vec<D>, Error> {
-		if index >= 1 << self.log_len || layer_depth > self.log_len {
+		self.multi_branch(&[index], layer_depth)
+	}
+
+	/// Get the Merkle branches for the given indices, sharing the nodes common to several branches
+	///
+	/// The indices must be sorted in strictly increasing order. Going up from the leaves to the
+	/// layer at `layer_depth`, the proof contains, for every group of nodes compressed together
+	/// that contains a node on a branch, the nodes of the group on no branch, layer by layer and
+	/// in increasing index order within each layer.
+	///
+	/// Throws if an index is out of range or the indices are not sorted
+	pub fn multi_branch(&self, indices: &[usize], layer_depth: usize) -> Result<Vec<D>, Error> {
+		if indices
+			.last()
+			.is_some_and(|&index| index >= 1 << self.log_len)
+			|| layer_depth > self.log_len
+		{
 			return Err(Error::IndexOutOfRange {
 				max: (1 << self.log_len) - 1,
 			});
 		}
+		if !indices.is_sorted_by(|a, b| a < b) {
+			bail!(Error::UnsortedIndices);
+		}
+
+		let mut proof = Vec::new();
+		let mut layer_indices = indices.to_vec();
+		for (depth, log_group_size) in
+			compressed_layers(self.log_len, log_arity::<N>(), layer_depth)?
+		{
+			let layer = self.layer(depth)?;
+			let mut next_layer_indices = Vec::with_capacity(layer_indices.len());
+			for group in layer_indices.chunk_by(|a, b| a >> log_group_size == b >> log_group_size) {
+				let group_index = group[0] >> log_group_size;
+				let group_start = group_index << log_group_size;
+				let mut group = group.iter().peekable();
+				for (index, node) in layer[group_start..group_start + (1 << log_group_size)]
+					.iter()
+					.enumerate()
+				{
+					if group.next_if_eq(&&(group_start + index)).is_none() {
+						proof.push(node.clone());
+					}
+				}
+				next_layer_indices.push(group_index);
+			}
+			layer_indices = next_layer_indices;
+		}
 
-		let branch = (0..self.log_len - layer_depth)
-			.map(|j| {
-				let node_index = (((1 << j) - 1) << (self.log_len + 1 - j)) | (index >> j) ^ 1;
-				self.inner_nodes[node_index].clone()
-			})
-			.collect();
+		Ok(proof)
+	}
+}
+
+/// Returns the total number of inner nodes of a tree with `1 << log_len` leaves, or `None` if it
+/// overflows.
+fn n_inner_nodes(log_len: usize, log_arity: usize) -> Option<usize> {
+	layer_depths(log_len, log_arity)
+		.try_fold(0usize, |total, depth| total.checked_add(1usize.checked_shl(depth as u32)?))
+}
+
+impl<D: SerializeBytes, const N: usize> SerializeBytes for BinaryMerkleTree<D, N> {
+	fn serialize(
+		&self,
+		mut write_buf: impl BufMut,
+		mode: SerializationMode,
+	) -> Result<(), SerializationError> {
+		SerializeBytes::serialize(&self.log_len, &mut write_buf, mode)?;
+		SerializeBytes::serialize(&self.inner_nodes, &mut write_buf, mode)
+	}
+}
 
-		Ok(branch)
+impl<D: DeserializeBytes, const N: usize> DeserializeBytes for BinaryMerkleTree<D, N> {
+	/// Deserializes a tree, checking that the number of inner nodes matches the number of leaves.
+	///
+	/// The digests are not recomputed, so the tree is only as trustworthy as its source.
+	fn deserialize(
+		mut read_buf: impl Buf,
+		mode: SerializationMode,
+	) -> Result<Self, SerializationError> {
+		let log_len: usize = DeserializeBytes::deserialize(&mut read_buf, mode)?;
+		let inner_nodes: Vec<D> = DeserializeBytes::deserialize(&mut read_buf, mode)?;
+		if n_inner_nodes(log_len, log_arity::<N>()) != Some(inner_nodes.len()) {
+			return Err(SerializationError::InvalidConstruction {
+				name: "BinaryMerkleTree",
+			});
+		}
+		Ok(Self {
+			log_len,
+			inner_nodes,
+		})
 	}
 }
 
 #[tracing::instrument("MerkleTree::compress_layer", skip_all, level = "debug")]
-fn compress_layer<D, C>(compression: &C, prev_layer: &[D], next_layer: &mut [MaybeUninit<D>])
-where
-	D: Clone + Send + Sync,
-	C: PseudoCompressionFunction<D, 2> + Sync,
+fn compress_layer<D, C, const N: usize>(
+	compression: &C,
+	prev_layer: &[D],
+	next_layer: &mut [MaybeUninit<D>],
+) where
+	D: Clone + Default + Send + Sync,
+	C: PseudoCompressionFunction<D, N> + Sync,
 {
+	let group_size = prev_layer.len() / next_layer.len();
 	prev_layer
-		.par_chunks_exact(2)
-		.zip(next_layer.par_iter_mut())
-		.for_each(|(prev_pair, next_digest)| {
-			next_digest.write(compression.compress(array::from_fn(|i| prev_pair[i].clone())));
+		.par_chunks(group_size * BATCH_SIZE)
+		.zip(next_layer.par_chunks_mut(BATCH_SIZE))
+		.for_each(|(prev_nodes, next_digests)| {
+			let inputs = prev_nodes
+				.chunks(group_size)
+				.map(pad_children)
+				.collect::<Vec<_>>();
+			for (next_digest, digest) in next_digests
+				.iter_mut()
+				.zip(compression.compress_many(&inputs))
+			{
+				next_digest.write(digest);
+			}
 		})
 }
 
@@ -177,10 +341,13 @@ where
 /// into N equal-sized chunks and hashes each chunks into the corresponding output digest. This
 /// returns the number of elements hashed into each digest.
 #[tracing::instrument("hash_interleaved", skip_all, level = "debug")]
-fn hash_interleaved<F, H>(elems: &[F], digests: &mut [MaybeUninit<Output<H>>]) -> Result<(), Error>
+fn hash_interleaved<F, H>(
+	elems: &[F],
+	digests: &mut [MaybeUninit<Output<H::Digest>>],
+) -> Result<(), Error>
 where
 	F: TowerField,
-	H: Digest + BlockSizeUser + FixedOutputReset,
+	H: MultiDigest,
 {
 	if elems.len() % digests.len() != 0 {
 		return Err(Error::IncorrectVectorLen {
@@ -198,26 +365,36 @@ where
 
 fn hash_iterated<F, H, ParIter>(
 	iterated_chunks: ParIter,
-	digests: &mut [MaybeUninit<Output<H>>],
+	digests: &mut [MaybeUninit<Output<H::Digest>>],
 ) -> Result<(), Error>
 where
 	F: TowerField,
-	H: Digest + BlockSizeUser + FixedOutputReset,
+	H: MultiDigest,
 	ParIter: IndexedParallelIterator<Item: IntoIterator<Item = F>>,
 {
 	digests
-		.par_iter_mut()
-		.zip(iterated_chunks)
-		.for_each_init(H::new, |hasher, (digest, elems)| {
-			{
-				let mut hash_buffer = HashBuffer::new(hasher);
+		.par_chunks_mut(BATCH_SIZE)
+		.zip(iterated_chunks.chunks(BATCH_SIZE))
+		.for_each_init(Vec::new, |buffer, (digests, leaves)| {
+			buffer.clear();
+			let mut leaf_ends = Vec::with_capacity(leaves.len());
+			for elems in leaves {
 				for elem in elems {
 					let mode = SerializationMode::CanonicalTower;
-					SerializeBytes::serialize(&elem, &mut hash_buffer, mode)
-						.expect("HashBuffer has infinite capacity");
+					SerializeBytes::serialize(&elem, &mut *buffer, mode)
+						.expect("Vec has infinite capacity");
 				}
+				leaf_ends.push(buffer.len());
+			}
+
+			let messages = iter::once(0)
+				.chain(leaf_ends.iter().copied())
+				.zip(&leaf_ends)
+				.map(|(start, &end)| &buffer[start..end])
+				.collect::<Vec<_>>();
+			for (digest, leaf_digest) in digests.iter_mut().zip(H::hash_many(&messages)) {
+				digest.write(leaf_digest);
 			}
-			digest.write(Digest::finalize_reset(hasher));
 		});
 	Ok(())
 }
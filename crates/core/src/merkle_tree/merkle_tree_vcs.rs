@@ -25,7 +25,7 @@ pub trait MerkleTreeScheme<T>: Sync {
 	/// Returns the optimal layer that the verifier should verify only once.
 	fn optimal_verify_layer(&self, n_queries: usize, tree_depth: usize) -> usize;
 
-	/// Returns the total byte-size of a proof for multiple opening queries.
+	/// Returns an upper bound on the total byte-size of a proof for multiple opening queries.
 	///
 	/// ## Arguments
 	///
@@ -63,6 +63,22 @@ pub trait MerkleTreeScheme<T>: Sync {
 		layer_digests: &[Self::Digest],
 		proof: &mut TranscriptReader<B>,
 	) -> Result<(), Error>;
+
+	/// Verify a single proof for the openings of entries in a committed vector at several indices.
+	///
+	/// ## Arguments
+	///
+	/// * `indices` - the entry indices, sorted in strictly increasing order
+	/// * `values` - the concatenated entries at the indices, all of the same length
+	fn verify_multi_opening<B: Buf>(
+		&self,
+		indices: &[usize],
+		values: &[T],
+		layer_depth: usize,
+		tree_depth: usize,
+		layer_digests: &[Self::Digest],
+		proof: &mut TranscriptReader<B>,
+	) -> Result<(), Error>;
 }
 
 /// A Merkle tree prover for a particular scheme.
@@ -116,4 +132,21 @@ pub trait MerkleTreeProver<T>: Sync {
 		index: usize,
 		proof: &mut TranscriptWriter<B>,
 	) -> Result<(), Error>;
+
+	/// Generate a single opening proof for the entries in a committed vector at several indices.
+	///
+	/// Nodes shared by the Merkle branches of several indices are written to the proof only once.
+	///
+	/// ## Arguments
+	///
+	/// * `committed` - helper data generated during commitment
+	/// * `layer_depth` - depth of the layer to prove inclusion in
+	/// * `indices` - the entry indices, sorted in strictly increasing order
+	fn prove_multi_opening<B: BufMut>(
+		&self,
+		committed: &Self::Committed,
+		layer_depth: usize,
+		indices: &[usize],
+		proof: &mut TranscriptWriter<B>,
+	) -> Result<(), Error>;
 }
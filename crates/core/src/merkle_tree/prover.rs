@@ -1,10 +1,10 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 use binius_field::TowerField;
-use binius_hash::PseudoCompressionFunction;
+use binius_hash::{MultiDigest, PseudoCompressionFunction};
 use binius_maybe_rayon::iter::IndexedParallelIterator;
 use bytes::BufMut;
-use digest::{core_api::BlockSizeUser, Digest, FixedOutputReset, Output};
+use digest::{core_api::BlockSizeUser, Output};
 use getset::Getters;
 use tracing::instrument;
 
@@ -17,12 +17,12 @@ use super::{
 use crate::transcript::TranscriptWriter;
 
 #[derive(Debug, Getters)]
-pub struct BinaryMerkleTreeProver<T, H, C> {
+pub struct BinaryMerkleTreeProver<T, H: MultiDigest, C, const N: usize = 2> {
 	#[getset(get = "pub")]
-	scheme: BinaryMerkleTreeScheme<T, H, C>,
+	scheme: BinaryMerkleTreeScheme<T, H::Digest, C, N>,
 }
 
-impl<T, C, H> BinaryMerkleTreeProver<T, H, C> {
+impl<T, C, H: MultiDigest, const N: usize> BinaryMerkleTreeProver<T, H, C, N> {
 	pub fn new(compression: C) -> Self {
 		Self {
 			scheme: BinaryMerkleTreeScheme::new(compression),
@@ -30,14 +30,14 @@ impl<T, C, H> BinaryMerkleTreeProver<T, H, C> {
 	}
 }
 
-impl<F, H, C> MerkleTreeProver<F> for BinaryMerkleTreeProver<F, H, C>
+impl<F, H, C, const N: usize> MerkleTreeProver<F> for BinaryMerkleTreeProver<F, H, C, N>
 where
 	F: TowerField,
-	H: Digest + BlockSizeUser + FixedOutputReset,
-	C: PseudoCompressionFunction<Output<H>, 2> + Sync,
+	H: MultiDigest<Digest: BlockSizeUser>,
+	C: PseudoCompressionFunction<Output<H::Digest>, N> + Sync,
 {
-	type Scheme = BinaryMerkleTreeScheme<F, H, C>;
-	type Committed = BinaryMerkleTree<Output<H>>;
+	type Scheme = BinaryMerkleTreeScheme<F, H::Digest, C, N>;
+	type Committed = BinaryMerkleTree<Output<H::Digest>, N>;
 
 	fn scheme(&self) -> &Self::Scheme {
 		&self.scheme
@@ -47,9 +47,9 @@ where
 		&self,
 		data: &[F],
 		batch_size: usize,
-	) -> Result<(Commitment<Output<H>>, Self::Committed), Error> {
+	) -> Result<(Commitment<Output<H::Digest>>, Self::Committed), Error> {
 		let tree =
-			binary_merkle_tree::build::<_, H, _>(self.scheme.compression(), data, batch_size)?;
+			binary_merkle_tree::build::<_, H, _, N>(self.scheme.compression(), data, batch_size)?;
 
 		let commitment = Commitment {
 			root: tree.root(),
@@ -63,7 +63,7 @@ where
 		&self,
 		committed: &'a Self::Committed,
 		depth: usize,
-	) -> Result<&'a [Output<H>], Error> {
+	) -> Result<&'a [Output<H::Digest>], Error> {
 		committed.layer(depth)
 	}
 
@@ -79,6 +79,18 @@ where
 		Ok(())
 	}
 
+	fn prove_multi_opening<B: BufMut>(
+		&self,
+		committed: &Self::Committed,
+		layer_depth: usize,
+		indices: &[usize],
+		proof: &mut TranscriptWriter<B>,
+	) -> Result<(), Error> {
+		let branches = committed.multi_branch(indices, layer_depth)?;
+		proof.write_slice(&branches);
+		Ok(())
+	}
+
 	#[instrument(skip_all, level = "debug")]
 	#[allow(clippy::type_complexity)]
 	fn commit_iterated<ParIter>(
@@ -92,7 +104,7 @@ where
 	where
 		ParIter: IndexedParallelIterator<Item: IntoIterator<Item = F>>,
 	{
-		let tree = binary_merkle_tree::build_from_iterator::<F, H, C, _>(
+		let tree = binary_merkle_tree::build_from_iterator::<F, H, C, _, N>(
 			self.scheme.compression(),
 			iterated_chunks,
 			log_len,
@@ -1,6 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{array, fmt::Debug, marker::PhantomData};
+use std::{fmt::Debug, marker::PhantomData};
 
 use binius_field::TowerField;
 use binius_hash::{HashBuffer, PseudoCompressionFunction};
@@ -14,13 +14,14 @@ use digest::{core_api::BlockSizeUser, Digest, Output};
 use getset::Getters;
 
 use super::{
+	binary_merkle_tree::{compress_children, compressed_layers, layer_depths, log_arity},
 	errors::{Error, VerificationError},
 	merkle_tree_vcs::MerkleTreeScheme,
 };
 use crate::transcript::TranscriptReader;
 
 #[derive(Debug, Getters)]
-pub struct BinaryMerkleTreeScheme<T, H, C> {
+pub struct BinaryMerkleTreeScheme<T, H, C, const N: usize = 2> {
 	#[getset(get = "pub")]
 	compression: C,
 	// This makes it so that `BinaryMerkleTreeScheme` remains Send + Sync
@@ -28,7 +29,7 @@ pub struct BinaryMerkleTreeScheme<T, H, C> {
 	_phantom: PhantomData<fn() -> (T, H)>,
 }
 
-impl<T, H, C> BinaryMerkleTreeScheme<T, H, C> {
+impl<T, H, C, const N: usize> BinaryMerkleTreeScheme<T, H, C, N> {
 	pub fn new(compression: C) -> Self {
 		Self {
 			compression,
@@ -37,17 +38,23 @@ impl<T, H, C> BinaryMerkleTreeScheme<T, H, C> {
 	}
 }
 
-impl<F, H, C> MerkleTreeScheme<F> for BinaryMerkleTreeScheme<F, H, C>
+impl<F, H, C, const N: usize> MerkleTreeScheme<F> for BinaryMerkleTreeScheme<F, H, C, N>
 where
 	F: TowerField,
 	H: Digest + BlockSizeUser,
-	C: PseudoCompressionFunction<Output<H>, 2> + Sync,
+	C: PseudoCompressionFunction<Output<H>, N> + Sync,
 {
 	type Digest = Output<H>;
 
 	/// This layer allows minimizing the proof size.
+	///
+	/// It is the layer closest to the root below which there are at least as many nodes as queries.
 	fn optimal_verify_layer(&self, n_queries: usize, tree_depth: usize) -> usize {
-		log2_ceil_usize(n_queries).min(tree_depth)
+		let min_depth = log2_ceil_usize(n_queries).min(tree_depth);
+		layer_depths(tree_depth, log_arity::<N>())
+			.take_while(|&depth| depth >= min_depth)
+			.last()
+			.expect("the leaves are at least as deep as min_depth")
 	}
 
 	fn proof_size(&self, len: usize, n_queries: usize, layer_depth: usize) -> Result<usize, Error> {
@@ -61,8 +68,12 @@ where
 			bail!(Error::IncorrectLayerDepth)
 		}
 
-		Ok(((log_len - layer_depth - 1) * n_queries + (1 << layer_depth))
-			* <H as Digest>::output_size())
+		let branch_len = compressed_layers(log_len, log_arity::<N>(), layer_depth)?
+			.into_iter()
+			.map(|(_, log_group_size)| (1 << log_group_size) - 1)
+			.sum::<usize>();
+
+		Ok((branch_len * n_queries + (1 << layer_depth)) * <H as Digest>::output_size())
 	}
 
 	fn verify_vector(
@@ -109,7 +120,25 @@ where
 
 	fn verify_opening<B: Buf>(
 		&self,
-		mut index: usize,
+		index: usize,
+		values: &[F],
+		layer_depth: usize,
+		tree_depth: usize,
+		layer_digests: &[Self::Digest],
+		proof: &mut TranscriptReader<B>,
+	) -> Result<(), Error> {
+		if index >= (1 << tree_depth) {
+			bail!(Error::IndexOutOfRange {
+				max: (1 << tree_depth) - 1
+			});
+		}
+
+		self.verify_multi_opening(&[index], values, layer_depth, tree_depth, layer_digests, proof)
+	}
+
+	fn verify_multi_opening<B: Buf>(
+		&self,
+		indices: &[usize],
 		values: &[F],
 		layer_depth: usize,
 		tree_depth: usize,
@@ -120,45 +149,84 @@ where
 			bail!(VerificationError::IncorrectVectorLength);
 		}
 
-		if index >= (1 << tree_depth) {
+		if indices
+			.last()
+			.is_some_and(|&index| index >= 1 << tree_depth)
+		{
 			bail!(Error::IndexOutOfRange {
 				max: (1 << tree_depth) - 1
 			});
 		}
 
-		let mut leaf_digest = hash_field_elems::<_, H>(values);
-		for branch_node in proof.read_vec(tree_depth - layer_depth)? {
-			leaf_digest = self.compression.compress(if index & 1 == 0 {
-				[leaf_digest, branch_node]
-			} else {
-				[branch_node, leaf_digest]
-			});
-			index >>= 1;
+		if !indices.is_sorted_by(|a, b| a < b) {
+			bail!(Error::UnsortedIndices);
+		}
+
+		let layers = compressed_layers(tree_depth, log_arity::<N>(), layer_depth)?;
+
+		if indices.is_empty() {
+			return Ok(());
+		}
+
+		if values.len() % indices.len() != 0 {
+			bail!(Error::IncorrectBatchSize);
+		}
+
+		let mut nodes = indices
+			.iter()
+			.zip(values.chunks_exact(values.len() / indices.len()))
+			.map(|(&index, chunk)| (index, hash_field_elems::<_, H>(chunk)))
+			.collect::<Vec<_>>();
+
+		for (_, log_group_size) in layers {
+			let mut next_nodes = Vec::with_capacity(nodes.len());
+			for group in nodes.chunk_by(|(a, _), (b, _)| a >> log_group_size == b >> log_group_size)
+			{
+				let group_index = group[0].0 >> log_group_size;
+				let mut group = group.iter().peekable();
+				let children = (group_index << log_group_size..(group_index + 1) << log_group_size)
+					.map(|index| match group.next_if(|(next, _)| *next == index) {
+						Some((_, digest)) => Ok(digest.clone()),
+						None => proof.read(),
+					})
+					.collect::<Result<Vec<_>, _>>()?;
+				next_nodes.push((group_index, compress_children(&self.compression, &children)));
+			}
+			nodes = next_nodes;
 		}
 
-		(leaf_digest == layer_digests[index])
+		nodes
+			.iter()
+			.all(|(index, digest)| *digest == layer_digests[*index])
 			.then_some(())
 			.ok_or_else(|| VerificationError::InvalidProof.into())
 	}
 }
 
 // Merkle-tree-like folding
-fn fold_digests_vector_inplace<C, D>(compression: &C, digests: &mut [D]) -> Result<(), Error>
+fn fold_digests_vector_inplace<C, D, const N: usize>(
+	compression: &C,
+	digests: &mut [D],
+) -> Result<(), Error>
 where
-	C: PseudoCompressionFunction<D, 2> + Sync,
+	C: PseudoCompressionFunction<D, N> + Sync,
 	D: Clone + Default + Send + Sync + Debug,
 {
 	if !digests.len().is_power_of_two() {
 		bail!(Error::PowerOfTwoLengthRequired);
 	}
 
-	let mut len = digests.len() / 2;
+	let log_len = log2_strict_usize(digests.len());
+	let mut len = digests.len();
 
-	while len != 0 {
+	for (_, log_group_size) in compressed_layers(log_len, log_arity::<N>(), 0)? {
+		len >>= log_group_size;
 		for i in 0..len {
-			digests[i] = compression.compress(array::from_fn(|j| digests[2 * i + j].clone()));
+			digests[i] = compress_children(
+				compression,
+				&digests[i << log_group_size..(i + 1) << log_group_size],
+			);
 		}
-		len /= 2;
 	}
 
 	Ok(())
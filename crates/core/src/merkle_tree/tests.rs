@@ -4,10 +4,17 @@ use core::slice;
 use std::iter::repeat_with;
 
 use binius_field::{BinaryField16b, Field};
-use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+use binius_hash::{
+	groestl::{Groestl256, Groestl256ByteCompression, Groestl256MultiCompression},
+	VisionByteCompression, VisionHasherDigest,
+};
+use binius_utils::{DeserializeBytes, SerializationMode, SerializeBytes};
+use digest::Output;
 use rand::{rngs::StdRng, SeedableRng};
 
-use super::{BinaryMerkleTreeProver, MerkleTreeProver, MerkleTreeScheme};
+use super::{
+	layer_depths, BinaryMerkleTree, BinaryMerkleTreeProver, MerkleTreeProver, MerkleTreeScheme,
+};
 use crate::{fiat_shamir::HasherChallenger, transcript::ProverTranscript};
 
 #[test]
@@ -44,6 +51,45 @@ fn test_binary_merkle_vcs_commit_prove_open_correctly() {
 	}
 }
 
+#[test]
+fn test_binary_merkle_vcs_vision_compression() {
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let mr_prover =
+		BinaryMerkleTreeProver::<_, VisionHasherDigest, _>::new(VisionByteCompression::default());
+
+	let data = repeat_with(|| Field::random(&mut rng))
+		.take(16)
+		.collect::<Vec<BinaryField16b>>();
+	let (commitment, tree) = mr_prover.commit(&data, 1).unwrap();
+
+	for (i, value) in data.iter().enumerate() {
+		let mut proof_writer = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		mr_prover
+			.prove_opening(&tree, 0, i, &mut proof_writer.message())
+			.unwrap();
+
+		let mut proof_reader = proof_writer.into_verifier();
+		mr_prover
+			.scheme()
+			.verify_opening(
+				i,
+				slice::from_ref(value),
+				0,
+				4,
+				&[commitment.root],
+				&mut proof_reader.message(),
+			)
+			.unwrap();
+	}
+
+	let (groestl_commitment, _) =
+		BinaryMerkleTreeProver::<_, VisionHasherDigest, _>::new(Groestl256ByteCompression)
+			.commit(&data, 1)
+			.unwrap();
+	assert_ne!(groestl_commitment.root, commitment.root);
+}
+
 #[test]
 fn test_binary_merkle_vcs_commit_layer_prove_open_correctly() {
 	let mut rng = StdRng::seed_from_u64(0);
@@ -100,3 +146,258 @@ fn test_binary_merkle_vcs_verify_vector() {
 		.verify_vector(&commitment.root, &data, 1)
 		.unwrap();
 }
+
+#[test]
+fn test_binary_merkle_vcs_multi_opening() {
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let mr_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+
+	let data = repeat_with(|| Field::random(&mut rng))
+		.take(64)
+		.collect::<Vec<BinaryField16b>>();
+	let (commitment, tree) = mr_prover.commit(&data, 2).unwrap();
+
+	let indices = [0, 1, 5, 6, 7, 20, 31];
+	let values = indices
+		.iter()
+		.flat_map(|&i| &data[2 * i..2 * i + 2])
+		.copied()
+		.collect::<Vec<_>>();
+	for layer_depth in 0..6 {
+		let layer = mr_prover.layer(&tree, layer_depth).unwrap();
+		mr_prover
+			.scheme()
+			.verify_layer(&commitment.root, layer_depth, layer)
+			.unwrap();
+
+		let mut proof_writer = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		mr_prover
+			.prove_multi_opening(&tree, layer_depth, &indices, &mut proof_writer.message())
+			.unwrap();
+
+		let mut proof_reader = proof_writer.into_verifier();
+		mr_prover
+			.scheme()
+			.verify_multi_opening(
+				&indices,
+				&values,
+				layer_depth,
+				5,
+				layer,
+				&mut proof_reader.message(),
+			)
+			.unwrap();
+		proof_reader.finalize().unwrap();
+	}
+}
+
+#[test]
+fn test_binary_merkle_vcs_multi_opening_shares_nodes() {
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let mr_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+
+	let data = repeat_with(|| Field::random(&mut rng))
+		.take(256)
+		.collect::<Vec<BinaryField16b>>();
+	let (_, tree) = mr_prover.commit(&data, 1).unwrap();
+
+	// Each pair of sibling leaves shares a branch, and all branches meet above depth 3.
+	let indices = (0..8)
+		.map(|i| i * 32)
+		.flat_map(|i| [i, i + 1])
+		.collect::<Vec<_>>();
+	let proof = tree.multi_branch(&indices, 0).unwrap();
+	assert_eq!(proof.len(), 8 * (8 - 1 - 3));
+
+	let independent_len = indices.len() * tree.branch(0, 0).unwrap().len();
+	assert!(proof.len() < independent_len / 2);
+
+	assert!(tree.multi_branch(&[0], 0).unwrap() == tree.branch(0, 0).unwrap());
+}
+
+#[test]
+fn test_binary_merkle_vcs_multi_opening_rejects_wrong_values() {
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let mr_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+
+	let data = repeat_with(|| Field::random(&mut rng))
+		.take(16)
+		.collect::<Vec<BinaryField16b>>();
+	let (commitment, tree) = mr_prover.commit(&data, 1).unwrap();
+
+	let indices = [2, 3, 9];
+	let mut values = indices.iter().map(|&i| data[i]).collect::<Vec<_>>();
+	values[2] += BinaryField16b::ONE;
+
+	let mut proof_writer = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	mr_prover
+		.prove_multi_opening(&tree, 0, &indices, &mut proof_writer.message())
+		.unwrap();
+
+	let mut proof_reader = proof_writer.into_verifier();
+	assert!(mr_prover
+		.scheme()
+		.verify_multi_opening(
+			&indices,
+			&values,
+			0,
+			4,
+			&[commitment.root],
+			&mut proof_reader.message()
+		)
+		.is_err());
+
+	assert!(tree.multi_branch(&[3, 2], 0).is_err());
+	assert!(tree.multi_branch(&[2, 2], 0).is_err());
+	assert!(tree.multi_branch(&[16], 0).is_err());
+}
+
+fn check_multiary_merkle_vcs<const N: usize>(log_len: usize) {
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let mr_prover =
+		BinaryMerkleTreeProver::<_, Groestl256, _, N>::new(Groestl256MultiCompression::<N>);
+
+	let data = repeat_with(|| Field::random(&mut rng))
+		.take(2 << log_len)
+		.collect::<Vec<BinaryField16b>>();
+	let (commitment, tree) = mr_prover.commit(&data, 2).unwrap();
+
+	assert_eq!(commitment.root, tree.root());
+	mr_prover
+		.scheme()
+		.verify_vector(&commitment.root, &data, 2)
+		.unwrap();
+
+	let log_arity = N.ilog2() as usize;
+	for layer_depth in 0..=log_len {
+		let is_layer = layer_depths(log_len, log_arity).any(|depth| depth == layer_depth);
+		assert_eq!(mr_prover.layer(&tree, layer_depth).is_ok(), is_layer);
+		if !is_layer {
+			continue;
+		}
+
+		let layer = mr_prover.layer(&tree, layer_depth).unwrap();
+		mr_prover
+			.scheme()
+			.verify_layer(&commitment.root, layer_depth, layer)
+			.unwrap();
+
+		for i in [0, 3, (1 << log_len) - 1] {
+			let mut proof_writer = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+			mr_prover
+				.prove_opening(&tree, layer_depth, i, &mut proof_writer.message())
+				.unwrap();
+
+			let mut proof_reader = proof_writer.into_verifier();
+			mr_prover
+				.scheme()
+				.verify_opening(
+					i,
+					&data[2 * i..2 * i + 2],
+					layer_depth,
+					log_len,
+					layer,
+					&mut proof_reader.message(),
+				)
+				.unwrap();
+			proof_reader.finalize().unwrap();
+		}
+
+		let indices = [1, 2, 3, 9, (1 << log_len) - 2];
+		let values = indices
+			.iter()
+			.flat_map(|&i| &data[2 * i..2 * i + 2])
+			.copied()
+			.collect::<Vec<_>>();
+		let mut proof_writer = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		mr_prover
+			.prove_multi_opening(&tree, layer_depth, &indices, &mut proof_writer.message())
+			.unwrap();
+
+		let mut proof_reader = proof_writer.into_verifier();
+		mr_prover
+			.scheme()
+			.verify_multi_opening(
+				&indices,
+				&values,
+				layer_depth,
+				log_len,
+				layer,
+				&mut proof_reader.message(),
+			)
+			.unwrap();
+		proof_reader.finalize().unwrap();
+	}
+
+	let optimal_layer = mr_prover.scheme().optimal_verify_layer(5, log_len);
+	assert!(mr_prover.layer(&tree, optimal_layer).is_ok());
+	assert!(optimal_layer >= 3);
+}
+
+#[test]
+fn test_multiary_merkle_vcs() {
+	check_multiary_merkle_vcs::<4>(6);
+	check_multiary_merkle_vcs::<4>(7);
+	check_multiary_merkle_vcs::<8>(6);
+	check_multiary_merkle_vcs::<8>(8);
+}
+
+#[test]
+fn test_multiary_merkle_vcs_binary_matches() {
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let data = repeat_with(|| Field::random(&mut rng))
+		.take(32)
+		.collect::<Vec<BinaryField16b>>();
+
+	let (commitment, _) =
+		BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression)
+			.commit(&data, 1)
+			.unwrap();
+	let (multi_commitment, _) =
+		BinaryMerkleTreeProver::<_, Groestl256, _, 2>::new(Groestl256MultiCompression::<2>)
+			.commit(&data, 1)
+			.unwrap();
+	assert_eq!(commitment, multi_commitment);
+}
+
+#[test]
+fn test_binary_merkle_tree_serialization_round_trip() {
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let data = repeat_with(|| Field::random(&mut rng))
+		.take(2 << 7)
+		.collect::<Vec<BinaryField16b>>();
+	let mr_prover =
+		BinaryMerkleTreeProver::<_, Groestl256, _, 4>::new(Groestl256MultiCompression::<4>);
+	let (commitment, tree) = mr_prover.commit(&data, 2).unwrap();
+
+	let mut bytes = Vec::new();
+	tree.serialize(&mut bytes, SerializationMode::Native)
+		.unwrap();
+	let loaded = BinaryMerkleTree::<Output<Groestl256>, 4>::deserialize(
+		bytes.as_slice(),
+		SerializationMode::Native,
+	)
+	.unwrap();
+
+	assert_eq!(loaded.log_len, tree.log_len);
+	assert_eq!(loaded.inner_nodes, tree.inner_nodes);
+	assert_eq!(loaded.root(), commitment.root);
+
+	// A tree with a different arity has a different number of inner nodes
+	assert!(BinaryMerkleTree::<Output<Groestl256>, 2>::deserialize(
+		bytes.as_slice(),
+		SerializationMode::Native
+	)
+	.is_err());
+	assert!(BinaryMerkleTree::<Output<Groestl256>, 4>::deserialize(
+		&bytes[..bytes.len() - 1],
+		SerializationMode::Native
+	)
+	.is_err());
+}
@@ -25,6 +25,18 @@ pub enum Error {
 	OracleToCommitIndexMalformed { id: OracleId },
 	#[error("the number of variables of the polynomials in sumcheck claim {index} do not match")]
 	SumcheckClaimVariablesMismatch { index: usize },
+	#[error("the persisted codeword has {actual} scalars, expected {expected}")]
+	PersistedCodewordLength { expected: usize, actual: usize },
+	#[error("the persisted Merkle tree does not match the expected commitment")]
+	PersistedCommitmentMismatch,
+	#[error("the persisted codeword does not hash to the expected commitment")]
+	PersistedCodewordMismatch,
+	#[error("conflicting or incorrect argument: {0}")]
+	InvalidArgs(String),
+	#[error("cannot calculate parameters satisfying the security target")]
+	ParameterError,
+	#[error("vector commit error: {0}")]
+	VectorCommit(#[source] Box<dyn std::error::Error + Send + Sync>),
 	#[error("binius_math error: {0}")]
 	Math(#[from] binius_math::Error),
 	#[error("Polynomial error: {0}")]
@@ -47,6 +59,8 @@ pub enum VerificationError {
 	IncorrectTransparentEvaluation { index: usize },
 	#[error("sumcheck final evaluation is incorrect")]
 	IncorrectSumcheckEvaluation,
+	#[error("opened column {index} is inconsistent with the combined row")]
+	IncorrectColumnOpening { index: usize },
 	#[error("Transcript error: {0}")]
 	Transcript(#[from] transcript::Error),
 }
@@ -29,11 +29,18 @@
 //! At the end of the interleaved sumcheck-FRI invocation, the verifier tests consistency of the
 //! claimed piecewise evaluations against the final FRI output.
 //!
+//! The compiler is abstracted by the [`PolyCommitScheme`] trait, which is implemented by
+//! [`FRIBiniusPCS`] for the protocol above and by [`TensorPCS`] for the tensor-code commitment
+//! scheme from [DP23]. The tensor-code scheme proves faster at the cost of larger proofs.
+//!
+//! [DP23]: <https://eprint.iacr.org/2023/1784>
 //! [DP24]: <https://eprint.iacr.org/2024/504>
 
 pub mod commit;
 mod error;
+mod pcs;
 mod prove;
+pub mod tensor;
 #[cfg(test)]
 mod tests;
 mod util;
@@ -41,5 +48,7 @@ mod verify;
 
 pub use commit::*;
 pub use error::*;
+pub use pcs::{FRIBiniusPCS, PolyCommitScheme};
 pub use prove::*;
+pub use tensor::{TensorPCS, TensorPCSParams};
 pub use verify::{make_commit_params_with_optimal_arity, verify, CommitMeta, PIOPSumcheckClaim};
@@ -0,0 +1,231 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::borrow::Borrow;
+
+use binius_field::{
+	BinaryField, ExtensionField, Field, PackedExtension, PackedField, PackedFieldIndexable,
+	TowerField,
+};
+use binius_hal::ComputationBackend;
+use binius_math::{EvaluationDomainFactory, MultilinearPoly};
+use binius_utils::{DeserializeBytes, SerializeBytes};
+use getset::Getters;
+
+use super::{
+	error::Error,
+	prove::{commit, load_commitment, prove},
+	verify::{verify, CommitMeta, PIOPSumcheckClaim},
+};
+use crate::{
+	fiat_shamir::Challenger,
+	merkle_tree::{MerkleTreeProver, MerkleTreeScheme},
+	polynomial::MultivariatePoly,
+	protocols::fri::{CommitOutput, FRIParams},
+	transcript::{ProverTranscript, VerifierTranscript},
+};
+
+/// A polynomial commitment scheme for batches of multilinears, which proves the sumcheck claims
+/// output by a multilinear polynomial IOP.
+///
+/// A batch is committed as the piecewise multilinear described by its [`CommitMeta`]. The claims
+/// are products of a committed multilinear and a transparent multilinear, see
+/// [`PIOPSumcheckClaim`].
+pub trait PolyCommitScheme<F, P>
+where
+	F: TowerField,
+	P: PackedField<Scalar = F>,
+{
+	/// The commitment sent to the verifier.
+	type Commitment: Clone + SerializeBytes + DeserializeBytes;
+	/// Data generated during commitment required to prove the claims.
+	type Committed;
+
+	/// Commits a batch of multilinear polynomials.
+	///
+	/// The multilinears may be defined over subfields of `F` and must be in ascending order by
+	/// the number of variables in the packed multilinear, like in [`commit`].
+	fn commit<M>(&self, multilins: &[M]) -> Result<(Self::Commitment, Self::Committed), Error>
+	where
+		M: MultilinearPoly<P>;
+
+	/// Proves a batch of sumcheck claims about the committed multilinears.
+	#[allow(clippy::too_many_arguments)]
+	fn prove<FDomain, M, DomainFactory, Challenger_, Backend>(
+		&self,
+		domain_factory: DomainFactory,
+		commit_meta: &CommitMeta,
+		committed: Self::Committed,
+		committed_multilins: &[M],
+		transparent_multilins: &[M],
+		claims: &[PIOPSumcheckClaim<F>],
+		transcript: &mut ProverTranscript<Challenger_>,
+		backend: &Backend,
+	) -> Result<(), Error>
+	where
+		FDomain: Field,
+		P: PackedExtension<FDomain>,
+		M: MultilinearPoly<P> + Send + Sync,
+		DomainFactory: EvaluationDomainFactory<FDomain>,
+		Challenger_: Challenger,
+		Backend: ComputationBackend;
+
+	/// Verifies a batch of sumcheck claims about the committed multilinears.
+	fn verify<'a, Transparent, Challenger_>(
+		&self,
+		commit_meta: &CommitMeta,
+		commitment: &Self::Commitment,
+		transparents: &[Transparent],
+		claims: &[PIOPSumcheckClaim<F>],
+		transcript: &mut VerifierTranscript<Challenger_>,
+	) -> Result<(), Error>
+	where
+		Transparent: Borrow<dyn MultivariatePoly<F> + 'a>,
+		Challenger_: Challenger;
+}
+
+/// The FRI-Binius polynomial commitment scheme from [DP24], see the [module](super)
+/// documentation.
+///
+/// [DP24]: <https://eprint.iacr.org/2024/504>
+#[derive(Debug, Getters)]
+pub struct FRIBiniusPCS<F, FEncode, MTProver>
+where
+	F: BinaryField,
+	FEncode: BinaryField,
+{
+	#[getset(get = "pub")]
+	fri_params: FRIParams<F, FEncode>,
+	#[getset(get = "pub")]
+	merkle_prover: MTProver,
+}
+
+impl<F, FEncode, MTProver> FRIBiniusPCS<F, FEncode, MTProver>
+where
+	F: BinaryField,
+	FEncode: BinaryField,
+{
+	pub const fn new(fri_params: FRIParams<F, FEncode>, merkle_prover: MTProver) -> Self {
+		Self {
+			fri_params,
+			merkle_prover,
+		}
+	}
+}
+
+impl<F, FEncode, MTScheme, MTProver> FRIBiniusPCS<F, FEncode, MTProver>
+where
+	F: BinaryField + ExtensionField<FEncode>,
+	FEncode: BinaryField,
+	MTScheme: MerkleTreeScheme<F>,
+	MTProver: MerkleTreeProver<F, Scheme = MTScheme>,
+{
+	/// Loads a persisted commitment output in place of [`PolyCommitScheme::commit`], checking it
+	/// against the expected `commitment`, see [`load_commitment`].
+	#[allow(clippy::type_complexity)]
+	pub fn load<P>(
+		&self,
+		commitment: &MTScheme::Digest,
+		persisted: CommitOutput<P, MTScheme::Digest, MTProver::Committed>,
+		rehash_codeword: bool,
+	) -> Result<(MTScheme::Digest, (MTProver::Committed, Vec<P>)), Error>
+	where
+		P: PackedFieldIndexable<Scalar = F>,
+	{
+		let CommitOutput {
+			commitment,
+			committed,
+			codeword,
+		} = load_commitment(
+			&self.fri_params,
+			&self.merkle_prover,
+			commitment,
+			persisted,
+			rehash_codeword,
+		)?;
+		Ok((commitment, (committed, codeword)))
+	}
+}
+
+impl<F, FEncode, P, MTScheme, MTProver> PolyCommitScheme<F, P>
+	for FRIBiniusPCS<F, FEncode, MTProver>
+where
+	F: TowerField + ExtensionField<FEncode>,
+	FEncode: BinaryField,
+	P: PackedFieldIndexable<Scalar = F>
+		+ PackedExtension<F, PackedSubfield = P>
+		+ PackedExtension<FEncode>,
+	MTScheme: MerkleTreeScheme<F, Digest: SerializeBytes + DeserializeBytes>,
+	MTProver: MerkleTreeProver<F, Scheme = MTScheme>,
+{
+	type Commitment = MTScheme::Digest;
+	type Committed = (MTProver::Committed, Vec<P>);
+
+	fn commit<M>(&self, multilins: &[M]) -> Result<(Self::Commitment, Self::Committed), Error>
+	where
+		M: MultilinearPoly<P>,
+	{
+		let CommitOutput {
+			commitment,
+			committed,
+			codeword,
+		} = commit(&self.fri_params, &self.merkle_prover, multilins)?;
+		Ok((commitment, (committed, codeword)))
+	}
+
+	fn prove<FDomain, M, DomainFactory, Challenger_, Backend>(
+		&self,
+		domain_factory: DomainFactory,
+		commit_meta: &CommitMeta,
+		(committed, codeword): Self::Committed,
+		committed_multilins: &[M],
+		transparent_multilins: &[M],
+		claims: &[PIOPSumcheckClaim<F>],
+		transcript: &mut ProverTranscript<Challenger_>,
+		backend: &Backend,
+	) -> Result<(), Error>
+	where
+		FDomain: Field,
+		P: PackedExtension<FDomain>,
+		M: MultilinearPoly<P> + Send + Sync,
+		DomainFactory: EvaluationDomainFactory<FDomain>,
+		Challenger_: Challenger,
+		Backend: ComputationBackend,
+	{
+		prove(
+			&self.fri_params,
+			&self.merkle_prover,
+			domain_factory,
+			commit_meta,
+			committed,
+			&codeword,
+			committed_multilins,
+			transparent_multilins,
+			claims,
+			transcript,
+			backend,
+		)
+	}
+
+	fn verify<'a, Transparent, Challenger_>(
+		&self,
+		commit_meta: &CommitMeta,
+		commitment: &Self::Commitment,
+		transparents: &[Transparent],
+		claims: &[PIOPSumcheckClaim<F>],
+		transcript: &mut VerifierTranscript<Challenger_>,
+	) -> Result<(), Error>
+	where
+		Transparent: Borrow<dyn MultivariatePoly<F> + 'a>,
+		Challenger_: Challenger,
+	{
+		verify(
+			commit_meta,
+			self.merkle_prover.scheme(),
+			&self.fri_params,
+			commitment,
+			transparents,
+			claims,
+			transcript,
+		)
+	}
+}
@@ -1,7 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 use binius_field::{
-	packed::set_packed_slice, BinaryField, Field, PackedExtension, PackedField,
+	packed::set_packed_slice, BinaryField, ExtensionField, Field, PackedExtension, PackedField,
 	PackedFieldIndexable, TowerField,
 };
 use binius_hal::ComputationBackend;
@@ -17,10 +17,10 @@ use itertools::{chain, Itertools};
 
 use super::{
 	error::Error,
-	verify::{make_sumcheck_claim_descs, PIOPSumcheckClaim},
+	verify::{make_sumcheck_claim_descs, PIOPSumcheckClaim, SumcheckClaimDesc},
 };
 use crate::{
-	fiat_shamir::{CanSample, Challenger},
+	fiat_shamir::Challenger,
 	merkle_tree::{MerkleTreeProver, MerkleTreeScheme},
 	piop::CommitMeta,
 	protocols::{
@@ -46,7 +46,7 @@ use crate::{
 // * multilinears are sorted in ascending order by number of packed variables
 // * `message_buffer` is initialized to all zeros
 // * `message_buffer` is larger than the total number of scalars in the multilinears
-fn merge_multilins<P, M>(multilins: &[M], message_buffer: &mut [P])
+pub(super) fn merge_multilins<P, M>(multilins: &[M], message_buffer: &mut [P])
 where
 	P: PackedField,
 	M: MultilinearPoly<P>,
@@ -85,6 +85,35 @@ where
 	}
 }
 
+/// Checks the preconditions of [`merge_multilins`] on a batch of multilinears to commit.
+pub(super) fn check_committed_multilins<P, M>(multilins: &[M]) -> Result<(), Error>
+where
+	P: PackedField,
+	M: MultilinearPoly<P>,
+{
+	for (i, multilin) in multilins.iter().enumerate() {
+		if multilin.n_vars() < multilin.log_extension_degree() {
+			return Err(Error::OracleTooSmall {
+				// i is not an OracleId, but whatever, that's a problem for whoever has to debug
+				// this
+				id: i,
+				min_vars: multilin.log_extension_degree(),
+			});
+		}
+		if multilin.packed_evals().is_none() {
+			return Err(Error::CommittedPackedEvaluationsMissing { id: i });
+		}
+	}
+
+	let n_packed_vars = multilins
+		.iter()
+		.map(|multilin| multilin.n_vars() - multilin.log_extension_degree());
+	if !is_sorted_ascending(n_packed_vars) {
+		return Err(Error::CommittedsNotSorted);
+	}
+	Ok(())
+}
+
 /// Commits a batch of multilinear polynomials.
 ///
 /// The multilinears this function accepts as arguments may be defined over subfields of `F`. In
@@ -113,26 +142,7 @@ where
 	MTScheme: MerkleTreeScheme<F>,
 	MTProver: MerkleTreeProver<F, Scheme = MTScheme>,
 {
-	for (i, multilin) in multilins.iter().enumerate() {
-		if multilin.n_vars() < multilin.log_extension_degree() {
-			return Err(Error::OracleTooSmall {
-				// i is not an OracleId, but whatever, that's a problem for whoever has to debug
-				// this
-				id: i,
-				min_vars: multilin.log_extension_degree(),
-			});
-		}
-		if multilin.packed_evals().is_none() {
-			return Err(Error::CommittedPackedEvaluationsMissing { id: i });
-		}
-	}
-
-	let n_packed_vars = multilins
-		.iter()
-		.map(|multilin| multilin.n_vars() - multilin.log_extension_degree());
-	if !is_sorted_ascending(n_packed_vars) {
-		return Err(Error::CommittedsNotSorted);
-	}
+	check_committed_multilins(multilins)?;
 
 	// TODO: this should be passed in to avoid recomputing twiddles
 	let rs_code = ReedSolomonCode::new(
@@ -151,6 +161,83 @@ where
 	Ok(output)
 }
 
+/// Loads the output of a previous call to [`commit`], such as one persisted to disk, so that a
+/// batch of unchanged multilinears can be proven without encoding and committing them again.
+///
+/// The `commitment` is the expected Merkle root, which the caller must obtain from a trusted
+/// source rather than from the persisted output. This checks that the shapes of the codeword and
+/// the Merkle tree match the FRI parameters and that the leaves of the tree hash up to the
+/// expected root.
+///
+/// If `rehash_codeword` is false, the codeword is not hashed again, so the caller must trust the
+/// source of the persisted output to hold the codeword of the multilinears that are later passed
+/// to [`prove`]. Otherwise, the Merkle tree is rebuilt from the codeword, which skips the encoding
+/// but not the hashing of [`commit`].
+pub fn load_commitment<F, FEncode, P, MTScheme, MTProver>(
+	fri_params: &FRIParams<F, FEncode>,
+	merkle_prover: &MTProver,
+	commitment: &MTScheme::Digest,
+	persisted: fri::CommitOutput<P, MTScheme::Digest, MTProver::Committed>,
+	rehash_codeword: bool,
+) -> Result<fri::CommitOutput<P, MTScheme::Digest, MTProver::Committed>, Error>
+where
+	F: BinaryField + ExtensionField<FEncode>,
+	FEncode: BinaryField,
+	P: PackedFieldIndexable<Scalar = F>,
+	MTScheme: MerkleTreeScheme<F>,
+	MTProver: MerkleTreeProver<F, Scheme = MTScheme>,
+{
+	let codeword_len = persisted.codeword.len() << P::LOG_WIDTH;
+	if codeword_len != 1 << fri_params.log_len() {
+		return Err(Error::PersistedCodewordLength {
+			expected: 1 << fri_params.log_len(),
+			actual: codeword_len,
+		});
+	}
+	if persisted.commitment != *commitment {
+		return Err(Error::PersistedCommitmentMismatch);
+	}
+
+	// The leaves of the tree are the cosets folded together in the first FRI round, as in
+	// `fri::commit_interleaved_with`.
+	let coset_log_len = fri_params
+		.fold_arities()
+		.first()
+		.copied()
+		.unwrap_or_else(|| fri_params.rs_code().log_inv_rate());
+
+	if rehash_codeword {
+		let (rehashed, committed) = merkle_prover
+			.commit(P::unpack_scalars(&persisted.codeword), 1 << coset_log_len)
+			.map_err(|err| Error::VectorCommit(Box::new(err)))?;
+		if rehashed.root != *commitment {
+			return Err(Error::PersistedCodewordMismatch);
+		}
+		return Ok(fri::CommitOutput {
+			committed,
+			..persisted
+		});
+	}
+
+	let tree_depth = fri_params.log_len() - coset_log_len;
+	let leaves = merkle_prover
+		.layer(&persisted.committed, tree_depth)
+		.map_err(|err| Error::VectorCommit(Box::new(err)))?;
+	let root = merkle_prover
+		.layer(&persisted.committed, 0)
+		.map_err(|err| Error::VectorCommit(Box::new(err)))?;
+	if root != [commitment.clone()]
+		|| merkle_prover
+			.scheme()
+			.verify_layer(commitment, tree_depth, leaves)
+			.is_err()
+	{
+		return Err(Error::PersistedCommitmentMismatch);
+	}
+
+	Ok(persisted)
+}
+
 /// Proves a batch of sumcheck claims that are products of committed polynomials from a committed
 /// batch and transparent polynomials.
 ///
@@ -191,11 +278,41 @@ where
 		transparent_multilins.iter().map(|poly| poly.n_vars()),
 		claims,
 	)?;
+	let packed_committed_multilins = pack_committed_multilins(committed_multilins)?;
+	let sumcheck_provers = make_sumcheck_provers(
+		&sumcheck_claim_descs,
+		&packed_committed_multilins,
+		transparent_multilins,
+		domain_factory,
+		backend,
+	)?;
+
+	prove_interleaved_fri_sumcheck(
+		commit_meta.total_vars(),
+		fri_params,
+		merkle_prover,
+		sumcheck_provers,
+		codeword,
+		&committed,
+		transcript,
+	)?;
+
+	Ok(())
+}
 
-	// The committed multilinears provided by argument are committed *small field* multilinears.
-	// Create multilinears representing the packed polynomials here. Eventually, we would like to
-	// refactor the calling code so that the PIOP only handles *big field* multilinear witnesses.
-	let packed_committed_multilins = committed_multilins
+/// Creates multilinears representing the packed polynomials of committed multilinears.
+///
+/// The committed multilinears provided by argument are committed *small field* multilinears.
+/// Eventually, we would like to refactor the calling code so that the PIOP only handles *big
+/// field* multilinear witnesses.
+pub(super) fn pack_committed_multilins<P, M>(
+	committed_multilins: &[M],
+) -> Result<Vec<MLEDirectAdapter<P, &[P]>>, Error>
+where
+	P: PackedField,
+	M: MultilinearPoly<P>,
+{
+	committed_multilins
 		.iter()
 		.enumerate()
 		.map(|(i, committed_multilin)| {
@@ -203,17 +320,32 @@ where
 				.packed_evals()
 				.ok_or(Error::CommittedPackedEvaluationsMissing { id: i })?;
 			let packed_multilin = MultilinearExtension::from_values_slice(packed_evals)?;
-			Ok::<_, Error>(MLEDirectAdapter::from(packed_multilin))
+			Ok(MLEDirectAdapter::from(packed_multilin))
 		})
-		.collect::<Result<Vec<_>, _>>()?;
+		.collect()
+}
 
-	let non_empty_sumcheck_descs = sumcheck_claim_descs
+/// Creates a sumcheck prover for each number of variables with claims, in ascending order.
+pub(super) fn make_sumcheck_provers<'a, F, FDomain, P, M, PackedM, DomainFactory, Backend>(
+	sumcheck_claim_descs: &[SumcheckClaimDesc<F>],
+	packed_committed_multilins: &'a [PackedM],
+	transparent_multilins: &'a [M],
+	domain_factory: DomainFactory,
+	backend: &'a Backend,
+) -> Result<Vec<impl SumcheckProver<F> + 'a>, Error>
+where
+	F: TowerField,
+	FDomain: Field,
+	P: PackedField<Scalar = F> + PackedExtension<F, PackedSubfield = P> + PackedExtension<FDomain>,
+	M: MultilinearPoly<P> + Send + Sync,
+	PackedM: MultilinearPoly<P> + Send + Sync,
+	DomainFactory: EvaluationDomainFactory<FDomain>,
+	Backend: ComputationBackend,
+{
+	sumcheck_claim_descs
 		.iter()
-		.enumerate()
-		.filter(|(_n_vars, desc)| !desc.composite_sums.is_empty());
-	let sumcheck_provers = non_empty_sumcheck_descs
-		.clone()
-		.map(|(_n_vars, desc)| {
+		.filter(|desc| !desc.composite_sums.is_empty())
+		.map(|desc| {
 			let multilins = chain!(
 				packed_committed_multilins[desc.committed_indices.clone()]
 					.iter()
@@ -223,28 +355,17 @@ where
 					.map(Either::Right),
 			)
 			.collect::<Vec<_>>();
-			RegularSumcheckProver::new(
+			let prover = RegularSumcheckProver::new(
 				EvaluationOrder::LowToHigh,
 				multilins,
 				desc.composite_sums.iter().cloned(),
 				&domain_factory,
 				immediate_switchover_heuristic,
 				backend,
-			)
+			)?;
+			Ok(prover)
 		})
-		.collect::<Result<Vec<_>, _>>()?;
-
-	prove_interleaved_fri_sumcheck(
-		commit_meta.total_vars(),
-		fri_params,
-		merkle_prover,
-		sumcheck_provers,
-		codeword,
-		&committed,
-		transcript,
-	)?;
-
-	Ok(())
+		.collect()
 }
 
 fn prove_interleaved_fri_sumcheck<F, FEncode, P, MTScheme, MTProver, Challenger_>(
@@ -270,19 +391,22 @@ where
 	let mut sumcheck_batch_prover = SumcheckBatchProver::new(sumcheck_provers, transcript)?;
 
 	for _ in 0..n_rounds {
-		sumcheck_batch_prover.send_round_proof(&mut transcript.message())?;
-		let challenge = transcript.sample();
+		sumcheck_batch_prover
+			.send_round_proof(&mut transcript.message_labeled("sumcheck.round"))?;
+		let challenge = transcript.sample_labeled("sumcheck.round_challenge");
 		sumcheck_batch_prover.receive_challenge(challenge)?;
 
 		match fri_prover.execute_fold_round(challenge)? {
 			FoldRoundOutput::NoCommitment => {}
 			FoldRoundOutput::Commitment(round_commitment) => {
-				transcript.message().write(&round_commitment);
+				transcript
+					.message_labeled("fri.round_commitment")
+					.write(&round_commitment);
 			}
 		}
 	}
 
-	sumcheck_batch_prover.finish(&mut transcript.message())?;
+	sumcheck_batch_prover.finish(&mut transcript.message_labeled("sumcheck.multilinear_evals"))?;
 	fri_prover.finish_proof(transcript)?;
 	Ok(())
 }
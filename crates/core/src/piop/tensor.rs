@@ -0,0 +1,656 @@
+// Copyright 2025 Irreducible Inc.
+
+//! The tensor-code polynomial commitment scheme from [DP23], in the style of Ligero and Brakedown.
+//!
+//! The committed batch is merged into a single message like in FRI-Binius, and the message is
+//! viewed as a matrix with `2^log_batch_size` rows, which are interleaved in the message. Each row
+//! is encoded with a Reed–Solomon code and the columns of the encoded matrix are committed as the
+//! leaves of a Merkle tree. This is exactly the interleaved codeword committed by FRI, with the
+//! leaves made of whole columns.
+//!
+//! The sumcheck claims of the PIOP are reduced to an evaluation of the merged multilinear at the
+//! sumcheck challenges by a batched sumcheck. To open the evaluation, the prover sends the
+//! combination of the rows by the tensor expansion of the low challenges, which the verifier
+//! evaluates at the high challenges. The verifier then encodes the combined row and checks it
+//! against the same combination of randomly sampled columns.
+//!
+//! Compared to FRI-Binius, the prover skips the FRI folding rounds and their commitments, while the
+//! proof grows with the square root of the message length instead of its logarithm.
+//!
+//! [DP23]: <https://eprint.iacr.org/2023/1784>
+
+use std::{borrow::Borrow, iter};
+
+use binius_field::{
+	util::inner_product_unchecked, BinaryField, ExtensionField, Field, PackedExtension,
+	PackedField, PackedFieldIndexable, TowerField,
+};
+use binius_hal::ComputationBackend;
+use binius_math::{eq_ind_partial_eval, EvaluationDomainFactory, MultilinearPoly};
+use binius_maybe_rayon::prelude::*;
+use binius_ntt::{AdditiveNTT, NTTOptions, ThreadingSettings};
+use binius_utils::{bail, DeserializeBytes, SerializeBytes};
+use bytemuck::zeroed_vec;
+use getset::{CopyGetters, Getters};
+use tracing::instrument;
+
+use super::{
+	error::{Error, VerificationError},
+	pcs::PolyCommitScheme,
+	prove::{
+		check_committed_multilins, make_sumcheck_provers, merge_multilins, pack_committed_multilins,
+	},
+	verify::{
+		make_sumcheck_claim_descs, make_sumcheck_claims, verify_sumcheck_outputs, CommitMeta,
+		PIOPSumcheckClaim,
+	},
+};
+use crate::{
+	fiat_shamir::Challenger,
+	merkle_tree::{MerkleTreeProver, MerkleTreeScheme},
+	polynomial::MultivariatePoly,
+	protocols::{
+		fri::{to_par_scalar_big_chunks, to_par_scalar_small_chunks, CommitOutput},
+		sumcheck::{
+			front_loaded::BatchVerifier as SumcheckBatchVerifier,
+			prove::front_loaded::BatchProver as SumcheckBatchProver,
+		},
+	},
+	reed_solomon::reed_solomon::ReedSolomonCode,
+	transcript::{ProverTranscript, VerifierTranscript},
+};
+
+/// Parameters of the tensor-code polynomial commitment scheme.
+#[derive(Debug, Getters, CopyGetters)]
+pub struct TensorPCSParams<FEncode>
+where
+	FEncode: BinaryField,
+{
+	/// The Reed–Solomon code encoding the rows.
+	#[getset(get = "pub")]
+	rs_code: ReedSolomonCode<FEncode>,
+	/// The binary logarithm of the number of rows.
+	#[getset(get_copy = "pub")]
+	log_batch_size: usize,
+	/// The number of columns opened to the verifier.
+	#[getset(get_copy = "pub")]
+	n_test_queries: usize,
+}
+
+impl<FEncode> TensorPCSParams<FEncode>
+where
+	FEncode: BinaryField,
+{
+	pub const fn new(
+		rs_code: ReedSolomonCode<FEncode>,
+		log_batch_size: usize,
+		n_test_queries: usize,
+	) -> Self {
+		Self {
+			rs_code,
+			log_batch_size,
+			n_test_queries,
+		}
+	}
+
+	/// The number of variables of the committed message.
+	pub const fn n_vars(&self) -> usize {
+		self.rs_code.log_dim() + self.log_batch_size
+	}
+}
+
+/// Calculates the number of column openings required to achieve a target security level.
+///
+/// Each opening catches a committed matrix that is far from the interleaved code with probability
+/// at least a third of the relative distance of the code.
+pub fn calculate_n_test_queries<F, FEncode>(
+	security_bits: usize,
+	log_batch_size: usize,
+	rs_code: &ReedSolomonCode<FEncode>,
+) -> Result<usize, Error>
+where
+	F: BinaryField + ExtensionField<FEncode>,
+	FEncode: BinaryField,
+{
+	let field_size = 2.0_f64.powi(F::N_BITS as i32);
+	let sumcheck_err = (2 * (rs_code.log_dim() + log_batch_size)) as f64 / field_size;
+	// ℓ ⋅ 2^{ℓ' + R} / |T_{τ}| for the tensor combination of the rows
+	let proximity_err = (log_batch_size * rs_code.len()) as f64 / field_size;
+	let per_query_err = 1.0 - (1.0 - 2.0_f64.powi(-(rs_code.log_inv_rate() as i32))) / 3.0;
+	let allowed_query_err = 2.0_f64.powi(-(security_bits as i32)) - sumcheck_err - proximity_err;
+	if allowed_query_err <= 0.0 {
+		return Err(Error::ParameterError);
+	}
+	let n_queries = allowed_query_err.log(per_query_err).ceil() as usize;
+	Ok(n_queries)
+}
+
+/// Chooses the number of rows of the committed matrix that minimizes the proof size.
+pub fn make_tensor_params<F, FEncode, MTScheme>(
+	commit_meta: &CommitMeta,
+	_merkle_scheme: &MTScheme,
+	security_bits: usize,
+	log_inv_rate: usize,
+) -> Result<TensorPCSParams<FEncode>, Error>
+where
+	F: BinaryField + ExtensionField<FEncode>,
+	FEncode: BinaryField,
+	MTScheme: MerkleTreeScheme<F>,
+{
+	let n_vars = commit_meta.total_vars();
+	let rs_code = ReedSolomonCode::<FEncode>::new(n_vars, log_inv_rate, &NTTOptions::default())?;
+	let n_test_queries = calculate_n_test_queries::<F, FEncode>(security_bits, 0, &rs_code)?;
+
+	// The proof consists of the combined row, the opened columns and their Merkle branches.
+	let proof_size = |log_batch_size: usize| {
+		let log_len = n_vars - log_batch_size + log_inv_rate;
+		(1 << (n_vars - log_batch_size)) * size_of::<F>()
+			+ n_test_queries
+				* ((1 << log_batch_size) * size_of::<F>() + log_len * size_of::<MTScheme::Digest>())
+	};
+	let log_batch_size = (0..=n_vars)
+		.min_by_key(|&log_batch_size| proof_size(log_batch_size))
+		.expect("range is not empty");
+
+	let rs_code = ReedSolomonCode::new(
+		n_vars - log_batch_size,
+		log_inv_rate,
+		&NTTOptions {
+			precompute_twiddles: true,
+			thread_settings: ThreadingSettings::MultithreadedDefault,
+		},
+	)?;
+	let n_test_queries =
+		calculate_n_test_queries::<F, FEncode>(security_bits, log_batch_size, &rs_code)?;
+	Ok(TensorPCSParams::new(rs_code, log_batch_size, n_test_queries))
+}
+
+/// Commits a batch of multilinear polynomials.
+///
+/// The arguments are the same as those of [`super::commit`], and the returned codeword is the
+/// encoded matrix with the rows interleaved.
+#[instrument("piop::tensor::commit", skip_all)]
+pub fn commit<F, FEncode, P, M, MTScheme, MTProver>(
+	params: &TensorPCSParams<FEncode>,
+	merkle_prover: &MTProver,
+	multilins: &[M],
+) -> Result<CommitOutput<P, MTScheme::Digest, MTProver::Committed>, Error>
+where
+	F: BinaryField + ExtensionField<FEncode>,
+	FEncode: BinaryField,
+	P: PackedField<Scalar = F> + PackedExtension<FEncode>,
+	M: MultilinearPoly<P>,
+	MTScheme: MerkleTreeScheme<F>,
+	MTProver: MerkleTreeProver<F, Scheme = MTScheme>,
+{
+	check_committed_multilins(multilins)?;
+
+	// The rows are encoded with the NTT directly, because the interleaved rows may fill packed
+	// elements even if a single row does not.
+	let rs_code = params.rs_code();
+	let log_batch_size = params.log_batch_size();
+	let log_elems = params.n_vars();
+	let mut encoded = tracing::debug_span!("allocate codeword")
+		.in_scope(|| zeroed_vec(1 << (log_elems - P::LOG_WIDTH + rs_code.log_inv_rate())));
+	let (message, parity) = encoded.split_at_mut(1 << (log_elems - P::LOG_WIDTH));
+	merge_multilins(multilins, message);
+	for chunk in parity.chunks_exact_mut(message.len()) {
+		chunk.copy_from_slice(message);
+	}
+	encoded
+		.par_chunks_exact_mut(1 << (log_elems - P::LOG_WIDTH))
+		.enumerate()
+		.try_for_each(|(coset, chunk)| {
+			rs_code.get_ntt().forward_transform_ext(
+				chunk,
+				coset as u32,
+				log_batch_size,
+				rs_code.log_dim(),
+			)
+		})?;
+
+	// Each leaf is a column of the encoded matrix.
+	let (commitment, committed) = if log_batch_size > P::LOG_WIDTH {
+		let columns = to_par_scalar_big_chunks(&encoded, 1 << log_batch_size);
+		merkle_prover.commit_iterated(columns, rs_code.log_len())
+	} else {
+		let columns = to_par_scalar_small_chunks(&encoded, 1 << log_batch_size);
+		merkle_prover.commit_iterated(columns, rs_code.log_len())
+	}
+	.map_err(|err| Error::VectorCommit(Box::new(err)))?;
+
+	Ok(CommitOutput {
+		commitment: commitment.root,
+		committed,
+		codeword: encoded,
+	})
+}
+
+/// Proves a batch of sumcheck claims that are products of committed polynomials from a committed
+/// batch and transparent polynomials.
+///
+/// The arguments corresponding to the committed multilinears must be the output of [`commit`].
+#[allow(clippy::too_many_arguments)]
+#[instrument("piop::tensor::prove", skip_all)]
+pub fn prove<F, FDomain, FEncode, P, M, DomainFactory, MTScheme, MTProver, Challenger_, Backend>(
+	params: &TensorPCSParams<FEncode>,
+	merkle_prover: &MTProver,
+	domain_factory: DomainFactory,
+	commit_meta: &CommitMeta,
+	committed: MTProver::Committed,
+	codeword: &[P],
+	committed_multilins: &[M],
+	transparent_multilins: &[M],
+	claims: &[PIOPSumcheckClaim<F>],
+	transcript: &mut ProverTranscript<Challenger_>,
+	backend: &Backend,
+) -> Result<(), Error>
+where
+	F: TowerField,
+	FDomain: Field,
+	FEncode: BinaryField,
+	P: PackedFieldIndexable<Scalar = F>
+		+ PackedExtension<F, PackedSubfield = P>
+		+ PackedExtension<FDomain>
+		+ PackedExtension<FEncode>,
+	M: MultilinearPoly<P> + Send + Sync,
+	DomainFactory: EvaluationDomainFactory<FDomain>,
+	MTScheme: MerkleTreeScheme<F, Digest: SerializeBytes>,
+	MTProver: MerkleTreeProver<F, Scheme = MTScheme>,
+	Challenger_: Challenger,
+	Backend: ComputationBackend,
+{
+	let n_vars = commit_meta.total_vars();
+	if n_vars != params.n_vars() {
+		bail!(Error::InvalidArgs(format!(
+			"committed batch has {n_vars} variables, the parameters are for {}",
+			params.n_vars()
+		)));
+	}
+
+	// Map of n_vars to sumcheck claim descriptions
+	let sumcheck_claim_descs = make_sumcheck_claim_descs(
+		commit_meta,
+		transparent_multilins.iter().map(|poly| poly.n_vars()),
+		claims,
+	)?;
+	let packed_committed_multilins = pack_committed_multilins(committed_multilins)?;
+	let sumcheck_provers = make_sumcheck_provers(
+		&sumcheck_claim_descs,
+		&packed_committed_multilins,
+		transparent_multilins,
+		domain_factory,
+		backend,
+	)?;
+
+	let mut sumcheck_batch_prover = SumcheckBatchProver::new(sumcheck_provers, transcript)?;
+	let mut challenges = Vec::with_capacity(n_vars);
+	for _ in 0..n_vars {
+		sumcheck_batch_prover
+			.send_round_proof(&mut transcript.message_labeled("sumcheck.round"))?;
+		let challenge = transcript.sample_labeled("sumcheck.round_challenge");
+		sumcheck_batch_prover.receive_challenge(challenge)?;
+		challenges.push(challenge);
+	}
+	sumcheck_batch_prover.finish(&mut transcript.message_labeled("sumcheck.multilinear_evals"))?;
+
+	let mut message = zeroed_vec(1 << (n_vars - P::LOG_WIDTH));
+	merge_multilins(committed_multilins, &mut message);
+	prove_opening(
+		params,
+		merkle_prover,
+		&challenges,
+		P::unpack_scalars(&message),
+		P::unpack_scalars(codeword),
+		&committed,
+		transcript,
+	)
+}
+
+/// Proves the evaluation of the committed message at a point.
+fn prove_opening<F, FEncode, MTScheme, MTProver, Challenger_>(
+	params: &TensorPCSParams<FEncode>,
+	merkle_prover: &MTProver,
+	point: &[F],
+	message: &[F],
+	codeword: &[F],
+	committed: &MTProver::Committed,
+	transcript: &mut ProverTranscript<Challenger_>,
+) -> Result<(), Error>
+where
+	F: TowerField,
+	FEncode: BinaryField,
+	MTScheme: MerkleTreeScheme<F, Digest: SerializeBytes>,
+	MTProver: MerkleTreeProver<F, Scheme = MTScheme>,
+	Challenger_: Challenger,
+{
+	let log_batch_size = params.log_batch_size();
+	let row_tensor = eq_ind_partial_eval::<F>(&point[..log_batch_size]);
+	let combined_row = message
+		.par_chunks_exact(1 << log_batch_size)
+		.map(|column| {
+			inner_product_unchecked::<F, F>(column.iter().copied(), row_tensor.iter().copied())
+		})
+		.collect::<Vec<_>>();
+	transcript
+		.message_labeled("tensor.combined_row")
+		.write_scalar_slice(&combined_row);
+
+	let tree_depth = params.rs_code().log_len();
+	let layer_depth = merkle_prover
+		.scheme()
+		.optimal_verify_layer(params.n_test_queries(), tree_depth);
+	let layer = merkle_prover
+		.layer(committed, layer_depth)
+		.map_err(|err| Error::VectorCommit(Box::new(err)))?;
+	transcript
+		.decommitment_labeled("tensor.merkle_layer")
+		.write_slice(layer);
+
+	let indices = sample_column_indices(params, tree_depth, |bits| {
+		transcript.sample_bits_labeled("tensor.query_index", bits)
+	});
+	let mut columns = transcript.decommitment_labeled("tensor.columns");
+	for &index in &indices {
+		columns
+			.write_scalar_slice(&codeword[index << log_batch_size..(index + 1) << log_batch_size]);
+	}
+	merkle_prover
+		.prove_multi_opening(
+			committed,
+			layer_depth,
+			&indices,
+			&mut transcript.decommitment_labeled("tensor.column_proofs"),
+		)
+		.map_err(|err| Error::VectorCommit(Box::new(err)))?;
+
+	Ok(())
+}
+
+/// Verifies a batch of sumcheck claims that are products of committed polynomials from a committed
+/// batch and transparent polynomials.
+///
+/// The arguments are the same as those of [`super::verify`], with the tensor PCS parameters
+/// instead of the FRI parameters.
+#[instrument("piop::tensor::verify", skip_all)]
+pub fn verify<'a, F, FEncode, Challenger_, MTScheme>(
+	commit_meta: &CommitMeta,
+	merkle_scheme: &MTScheme,
+	params: &TensorPCSParams<FEncode>,
+	commitment: &MTScheme::Digest,
+	transparents: &[impl Borrow<dyn MultivariatePoly<F> + 'a>],
+	claims: &[PIOPSumcheckClaim<F>],
+	transcript: &mut VerifierTranscript<Challenger_>,
+) -> Result<(), Error>
+where
+	F: TowerField + ExtensionField<FEncode>,
+	FEncode: BinaryField,
+	Challenger_: Challenger,
+	MTScheme: MerkleTreeScheme<F, Digest: DeserializeBytes>,
+{
+	let n_vars = commit_meta.total_vars();
+	if n_vars != params.n_vars() {
+		bail!(Error::InvalidArgs(format!(
+			"committed batch has {n_vars} variables, the parameters are for {}",
+			params.n_vars()
+		)));
+	}
+
+	// Map of n_vars to sumcheck claim descriptions
+	let sumcheck_claim_descs = make_sumcheck_claim_descs(
+		commit_meta,
+		transparents.iter().map(|poly| poly.borrow().n_vars()),
+		claims,
+	)?;
+	let sumcheck_claims = make_sumcheck_claims(&sumcheck_claim_descs)?;
+
+	let mut sumcheck_verifier = SumcheckBatchVerifier::new(&sumcheck_claims, transcript)?;
+	let mut multilinear_evals = Vec::with_capacity(sumcheck_claims.len());
+	let mut challenges = Vec::with_capacity(n_vars);
+	for _ in 0..n_vars {
+		let mut reader = transcript.message_labeled("sumcheck.round");
+		while let Some(claim_multilinear_evals) = sumcheck_verifier.try_finish_claim(&mut reader)? {
+			multilinear_evals.push(claim_multilinear_evals);
+		}
+		sumcheck_verifier.receive_round_proof(&mut reader)?;
+
+		let challenge = transcript.sample_labeled("sumcheck.round_challenge");
+		challenges.push(challenge);
+		sumcheck_verifier.finish_round(challenge)?;
+	}
+
+	let mut reader = transcript.message_labeled("sumcheck.multilinear_evals");
+	while let Some(claim_multilinear_evals) = sumcheck_verifier.try_finish_claim(&mut reader)? {
+		multilinear_evals.push(claim_multilinear_evals);
+	}
+	sumcheck_verifier.finish()?;
+
+	let piecewise_eval = verify_sumcheck_outputs(
+		commit_meta,
+		&sumcheck_claim_descs,
+		multilinear_evals,
+		transparents,
+		&challenges,
+	)?;
+	verify_opening(params, merkle_scheme, commitment, &challenges, piecewise_eval, transcript)
+}
+
+/// Verifies the evaluation of the committed message at a point.
+fn verify_opening<F, FEncode, MTScheme, Challenger_>(
+	params: &TensorPCSParams<FEncode>,
+	merkle_scheme: &MTScheme,
+	commitment: &MTScheme::Digest,
+	point: &[F],
+	eval: F,
+	transcript: &mut VerifierTranscript<Challenger_>,
+) -> Result<(), Error>
+where
+	F: TowerField + ExtensionField<FEncode>,
+	FEncode: BinaryField,
+	MTScheme: MerkleTreeScheme<F, Digest: DeserializeBytes>,
+	Challenger_: Challenger,
+{
+	let log_batch_size = params.log_batch_size();
+	let (row_point, column_point) = point.split_at(log_batch_size);
+
+	let rs_code = params.rs_code();
+	let combined_row = transcript
+		.message_labeled("tensor.combined_row")
+		.read_scalar_slice::<F>(rs_code.dim())
+		.map_err(VerificationError::Transcript)?;
+	let column_tensor = eq_ind_partial_eval::<F>(column_point);
+	if inner_product_unchecked::<F, F>(combined_row.iter().copied(), column_tensor) != eval {
+		return Err(VerificationError::IncorrectSumcheckEvaluation.into());
+	}
+
+	let encoded_row = encode_ext(rs_code, &combined_row)?;
+
+	let tree_depth = rs_code.log_len();
+	let layer_depth = merkle_scheme.optimal_verify_layer(params.n_test_queries(), tree_depth);
+	let layer = transcript
+		.decommitment_labeled("tensor.merkle_layer")
+		.read_vec(1 << layer_depth)
+		.map_err(VerificationError::Transcript)?;
+	merkle_scheme
+		.verify_layer(commitment, layer_depth, &layer)
+		.map_err(|err| Error::VectorCommit(Box::new(err)))?;
+
+	let indices = sample_column_indices(params, tree_depth, |bits| {
+		transcript.sample_bits_labeled("tensor.query_index", bits)
+	});
+	let columns = transcript
+		.decommitment_labeled("tensor.columns")
+		.read_scalar_slice::<F>(indices.len() << log_batch_size)
+		.map_err(VerificationError::Transcript)?;
+	merkle_scheme
+		.verify_multi_opening(
+			&indices,
+			&columns,
+			layer_depth,
+			tree_depth,
+			&layer,
+			&mut transcript.decommitment_labeled("tensor.column_proofs"),
+		)
+		.map_err(|err| Error::VectorCommit(Box::new(err)))?;
+
+	let row_tensor = eq_ind_partial_eval::<F>(row_point);
+	for (&index, column) in iter::zip(&indices, columns.chunks_exact(1 << log_batch_size)) {
+		let combined_column =
+			inner_product_unchecked::<F, F>(column.iter().copied(), row_tensor.iter().copied());
+		if combined_column != encoded_row[index] {
+			return Err(VerificationError::IncorrectColumnOpening { index }.into());
+		}
+	}
+
+	Ok(())
+}
+
+/// Encodes a message of extension field elements with a code over the subfield.
+fn encode_ext<F, FEncode>(
+	rs_code: &ReedSolomonCode<FEncode>,
+	message: &[F],
+) -> Result<Vec<F>, Error>
+where
+	F: ExtensionField<FEncode>,
+	FEncode: BinaryField,
+{
+	// The message is encoded as a batch of messages over the subfield, one for each coordinate.
+	let bases = message
+		.iter()
+		.flat_map(|elem| elem.iter_bases())
+		.collect::<Vec<_>>();
+	let mut codeword = bases.repeat(rs_code.inv_rate());
+	for (coset, chunk) in codeword.chunks_exact_mut(bases.len()).enumerate() {
+		rs_code.get_ntt().forward_transform(
+			chunk,
+			coset as u32,
+			F::LOG_DEGREE,
+			rs_code.log_dim(),
+		)?;
+	}
+	Ok(codeword
+		.chunks_exact(F::DEGREE)
+		.map(|bases| F::from_bases(bases.iter().copied()).expect("chunk has F::DEGREE elements"))
+		.collect())
+}
+
+/// Samples the indices of the opened columns, sorted in increasing order with duplicates removed.
+fn sample_column_indices<FEncode: BinaryField>(
+	params: &TensorPCSParams<FEncode>,
+	tree_depth: usize,
+	mut sample_bits: impl FnMut(usize) -> usize,
+) -> Vec<usize> {
+	let mut indices = (0..params.n_test_queries())
+		.map(|_| sample_bits(tree_depth))
+		.collect::<Vec<_>>();
+	indices.sort_unstable();
+	indices.dedup();
+	indices
+}
+
+/// The tensor-code polynomial commitment scheme, see the [module](self) documentation.
+#[derive(Debug, Getters)]
+pub struct TensorPCS<FEncode, MTProver>
+where
+	FEncode: BinaryField,
+{
+	#[getset(get = "pub")]
+	params: TensorPCSParams<FEncode>,
+	#[getset(get = "pub")]
+	merkle_prover: MTProver,
+}
+
+impl<FEncode, MTProver> TensorPCS<FEncode, MTProver>
+where
+	FEncode: BinaryField,
+{
+	pub const fn new(params: TensorPCSParams<FEncode>, merkle_prover: MTProver) -> Self {
+		Self {
+			params,
+			merkle_prover,
+		}
+	}
+}
+
+impl<F, FEncode, P, MTScheme, MTProver> PolyCommitScheme<F, P> for TensorPCS<FEncode, MTProver>
+where
+	F: TowerField + ExtensionField<FEncode>,
+	FEncode: BinaryField,
+	P: PackedFieldIndexable<Scalar = F>
+		+ PackedExtension<F, PackedSubfield = P>
+		+ PackedExtension<FEncode>,
+	MTScheme: MerkleTreeScheme<F, Digest: SerializeBytes + DeserializeBytes>,
+	MTProver: MerkleTreeProver<F, Scheme = MTScheme>,
+{
+	type Commitment = MTScheme::Digest;
+	type Committed = (MTProver::Committed, Vec<P>);
+
+	fn commit<M>(&self, multilins: &[M]) -> Result<(Self::Commitment, Self::Committed), Error>
+	where
+		M: MultilinearPoly<P>,
+	{
+		let CommitOutput {
+			commitment,
+			committed,
+			codeword,
+		} = commit(&self.params, &self.merkle_prover, multilins)?;
+		Ok((commitment, (committed, codeword)))
+	}
+
+	fn prove<FDomain, M, DomainFactory, Challenger_, Backend>(
+		&self,
+		domain_factory: DomainFactory,
+		commit_meta: &CommitMeta,
+		(committed, codeword): Self::Committed,
+		committed_multilins: &[M],
+		transparent_multilins: &[M],
+		claims: &[PIOPSumcheckClaim<F>],
+		transcript: &mut ProverTranscript<Challenger_>,
+		backend: &Backend,
+	) -> Result<(), Error>
+	where
+		FDomain: Field,
+		P: PackedExtension<FDomain>,
+		M: MultilinearPoly<P> + Send + Sync,
+		DomainFactory: EvaluationDomainFactory<FDomain>,
+		Challenger_: Challenger,
+		Backend: ComputationBackend,
+	{
+		prove(
+			&self.params,
+			&self.merkle_prover,
+			domain_factory,
+			commit_meta,
+			committed,
+			&codeword,
+			committed_multilins,
+			transparent_multilins,
+			claims,
+			transcript,
+			backend,
+		)
+	}
+
+	fn verify<'a, Transparent, Challenger_>(
+		&self,
+		commit_meta: &CommitMeta,
+		commitment: &Self::Commitment,
+		transparents: &[Transparent],
+		claims: &[PIOPSumcheckClaim<F>],
+		transcript: &mut VerifierTranscript<Challenger_>,
+	) -> Result<(), Error>
+	where
+		Transparent: Borrow<dyn MultivariatePoly<F> + 'a>,
+		Challenger_: Challenger,
+	{
+		verify(
+			commit_meta,
+			self.merkle_prover.scheme(),
+			&self.params,
+			commitment,
+			transparents,
+			claims,
+			transcript,
+		)
+	}
+}
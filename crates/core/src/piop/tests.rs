@@ -1,35 +1,41 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::iter::repeat_with;
+use std::{iter::repeat_with, ops::Range};
 
+use assert_matches::assert_matches;
 use binius_field::{
-	BinaryField, BinaryField16b, BinaryField8b, Field, PackedBinaryField2x128b, PackedExtension,
-	PackedField, PackedFieldIndexable, TowerField,
+	BinaryField, BinaryField128b, BinaryField16b, BinaryField8b, Field, PackedBinaryField2x128b,
+	PackedExtension, PackedField, PackedFieldIndexable, TowerField,
 };
 use binius_hal::make_portable_backend;
 use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
 use binius_math::{
 	DefaultEvaluationDomainFactory, MLEDirectAdapter, MultilinearExtension, MultilinearPoly,
 };
-use binius_utils::{DeserializeBytes, SerializeBytes};
+use binius_utils::{DeserializeBytes, SerializationMode, SerializeBytes};
+use digest::Output;
 use rand::{rngs::StdRng, Rng, SeedableRng};
 
 use super::{
-	prove,
-	prove::commit,
-	verify,
+	commit,
+	error::VerificationError,
+	tensor::{self, make_tensor_params},
 	verify::{make_commit_params_with_optimal_arity, CommitMeta},
-	PIOPSumcheckClaim,
+	Error, FRIBiniusPCS, PIOPSumcheckClaim, PolyCommitScheme, TensorPCS,
 };
 use crate::{
 	fiat_shamir::HasherChallenger,
-	merkle_tree::{BinaryMerkleTreeProver, MerkleTreeProver, MerkleTreeScheme},
+	merkle_tree::{BinaryMerkleTree, BinaryMerkleTreeProver, MerkleTreeProver},
 	polynomial::MultivariatePoly,
 	protocols::fri::CommitOutput,
-	transcript::ProverTranscript,
+	transcript::{LogEntry, LogEntryKind, ProverTranscript, VerifierTranscript},
 	transparent,
 };
 
+type MerkleProver = BinaryMerkleTreeProver<BinaryField128b, Groestl256, Groestl256ByteCompression>;
+type PersistedCommitOutput =
+	CommitOutput<PackedBinaryField2x128b, Output<Groestl256>, BinaryMerkleTree<Output<Groestl256>>>;
+
 const SECURITY_BITS: usize = 32;
 
 fn generate_multilin<P>(n_vars: usize, mut rng: impl Rng) -> MultilinearExtension<P>
@@ -97,31 +103,60 @@ where
 	sumcheck_claims
 }
 
-fn commit_prove_verify<F, FDomain, FEncode, P, MTScheme>(
+fn commit_prove_verify<F, FDomain, P, PCS>(
 	commit_meta: &CommitMeta,
 	n_transparents: usize,
-	merkle_prover: &impl MerkleTreeProver<F, Scheme = MTScheme>,
-	log_inv_rate: usize,
+	pcs: &PCS,
 ) where
 	F: TowerField,
 	FDomain: BinaryField,
-	FEncode: BinaryField,
-	P: PackedFieldIndexable<Scalar = F>
-		+ PackedExtension<FDomain>
-		+ PackedExtension<FEncode>
-		+ PackedExtension<F, PackedSubfield = P>,
-	MTScheme: MerkleTreeScheme<F, Digest: SerializeBytes + DeserializeBytes>,
+	P: PackedField<Scalar = F> + PackedExtension<FDomain> + PackedExtension<F, PackedSubfield = P>,
+	PCS: PolyCommitScheme<F, P>,
 {
-	let merkle_scheme = merkle_prover.scheme();
+	commit_prove_verify_with::<F, FDomain, P, PCS>(commit_meta, n_transparents, pcs, |multilins| {
+		pcs.commit(multilins).unwrap()
+	});
+}
 
-	let fri_params = make_commit_params_with_optimal_arity::<_, FEncode, _>(
+/// Like [`commit_prove_verify`], but commits the multilinears with the given function.
+fn commit_prove_verify_with<F, FDomain, P, PCS>(
+	commit_meta: &CommitMeta,
+	n_transparents: usize,
+	pcs: &PCS,
+	commit: impl FnOnce(&[MLEDirectAdapter<P>]) -> (PCS::Commitment, PCS::Committed),
+) where
+	F: TowerField,
+	FDomain: BinaryField,
+	P: PackedField<Scalar = F> + PackedExtension<FDomain> + PackedExtension<F, PackedSubfield = P>,
+	PCS: PolyCommitScheme<F, P>,
+{
+	commit_prove_verify_tampered::<F, FDomain, P, PCS>(
 		commit_meta,
-		merkle_scheme,
-		SECURITY_BITS,
-		log_inv_rate,
+		n_transparents,
+		pcs,
+		commit,
+		|_, _| {},
 	)
 	.unwrap();
+}
 
+/// Like [`commit_prove_verify_with`], but passes the proof bytes through `tamper` before verifying
+/// them and returns the verification result.
+///
+/// `tamper` also receives the log of all the prover's transcript operations, see [`proof_range`].
+fn commit_prove_verify_tampered<F, FDomain, P, PCS>(
+	commit_meta: &CommitMeta,
+	n_transparents: usize,
+	pcs: &PCS,
+	commit: impl FnOnce(&[MLEDirectAdapter<P>]) -> (PCS::Commitment, PCS::Committed),
+	tamper: impl FnOnce(&[LogEntry], &mut [u8]),
+) -> Result<(), Error>
+where
+	F: TowerField,
+	FDomain: BinaryField,
+	P: PackedField<Scalar = F> + PackedExtension<FDomain> + PackedExtension<F, PackedSubfield = P>,
+	PCS: PolyCommitScheme<F, P>,
+{
 	let backend = make_portable_backend();
 	let mut rng = StdRng::seed_from_u64(0);
 
@@ -129,11 +164,7 @@ fn commit_prove_verify<F, FDomain, FEncode, P, MTScheme>(
 		.into_iter()
 		.map(MLEDirectAdapter::from)
 		.collect::<Vec<_>>();
-	let CommitOutput {
-		commitment,
-		committed,
-		codeword,
-	} = commit(&fri_params, merkle_prover, &committed_multilins).unwrap();
+	let (commitment, committed) = commit(&committed_multilins);
 
 	let transparent_multilins_by_vars = commit_meta
 		.n_multilins_by_vars()
@@ -150,16 +181,14 @@ fn commit_prove_verify<F, FDomain, FEncode, P, MTScheme>(
 		make_sumcheck_claims(&committed_multilins, transparent_multilins.as_slice());
 
 	let mut proof = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	proof.set_trace(true);
 	proof.message().write(&commitment);
 
 	let domain_factory = DefaultEvaluationDomainFactory::<FDomain>::default();
-	prove(
-		&fri_params,
-		merkle_prover,
+	pcs.prove(
 		domain_factory,
 		commit_meta,
 		committed,
-		&codeword,
 		&committed_multilins,
 		&transparent_multilins,
 		&sumcheck_claims,
@@ -168,7 +197,10 @@ fn commit_prove_verify<F, FDomain, FEncode, P, MTScheme>(
 	)
 	.unwrap();
 
-	let mut proof = proof.into_verifier();
+	let log = proof.log().to_vec();
+	let mut bytes = proof.finalize();
+	tamper(&log, &mut bytes);
+	let mut proof = VerifierTranscript::<HasherChallenger<Groestl256>>::new(bytes);
 
 	let transparent_polys = transparent_mles
 		.iter()
@@ -186,16 +218,92 @@ fn commit_prove_verify<F, FDomain, FEncode, P, MTScheme>(
 		.collect::<Vec<_>>();
 
 	let commitment = proof.message().read().unwrap();
-	verify(
+	pcs.verify(commit_meta, &commitment, &transparent_polys, &sumcheck_claims, &mut proof)?;
+	proof.finalize().map_err(VerificationError::Transcript)?;
+	Ok(())
+}
+
+/// Returns the range of the proof bytes written by the first operation with the label in the log.
+fn proof_range(log: &[LogEntry], label: &str) -> Range<usize> {
+	let mut start = 0;
+	for entry in log {
+		if !matches!(entry.kind, LogEntryKind::Message | LogEntryKind::Decommitment) {
+			continue;
+		}
+		if entry.label.as_deref() == Some(label) {
+			return start..start + entry.bytes.len();
+		}
+		start += entry.bytes.len();
+	}
+	panic!("no transcript operation labeled {label}");
+}
+
+fn commit_prove_verify_fri(commit_meta: &CommitMeta, n_transparents: usize, log_inv_rate: usize) {
+	let pcs = make_fri_pcs(commit_meta, log_inv_rate);
+	commit_prove_verify::<_, BinaryField8b, PackedBinaryField2x128b, _>(
 		commit_meta,
-		merkle_scheme,
-		&fri_params,
-		&commitment,
-		&transparent_polys,
-		&sumcheck_claims,
-		&mut proof,
+		n_transparents,
+		&pcs,
+	);
+}
+
+fn make_fri_pcs(
+	commit_meta: &CommitMeta,
+	log_inv_rate: usize,
+) -> FRIBiniusPCS<BinaryField128b, BinaryField16b, MerkleProver> {
+	let merkle_prover = MerkleProver::new(Groestl256ByteCompression);
+	let fri_params = make_commit_params_with_optimal_arity::<_, BinaryField16b, _>(
+		commit_meta,
+		merkle_prover.scheme(),
+		SECURITY_BITS,
+		log_inv_rate,
 	)
 	.unwrap();
+	FRIBiniusPCS::new(fri_params, merkle_prover)
+}
+
+/// Commits with `pcs` and returns the commitment along with the commitment output after a round
+/// trip through bytes.
+fn commit_and_persist(
+	pcs: &FRIBiniusPCS<BinaryField128b, BinaryField16b, MerkleProver>,
+	multilins: &[MLEDirectAdapter<PackedBinaryField2x128b>],
+) -> (Output<Groestl256>, PersistedCommitOutput) {
+	let output = commit(pcs.fri_params(), pcs.merkle_prover(), multilins).unwrap();
+	let mut bytes = Vec::new();
+	output
+		.serialize(&mut bytes, SerializationMode::Native)
+		.unwrap();
+	let persisted =
+		PersistedCommitOutput::deserialize(bytes.as_slice(), SerializationMode::Native).unwrap();
+	(output.commitment, persisted)
+}
+
+fn make_tensor_pcs(
+	commit_meta: &CommitMeta,
+	log_inv_rate: usize,
+) -> TensorPCS<BinaryField16b, MerkleProver> {
+	let merkle_prover = MerkleProver::new(Groestl256ByteCompression);
+	let params = make_tensor_params::<_, BinaryField16b, _>(
+		commit_meta,
+		merkle_prover.scheme(),
+		SECURITY_BITS,
+		log_inv_rate,
+	)
+	.unwrap();
+	TensorPCS::new(params, merkle_prover)
+}
+
+fn commit_prove_verify_tensor(
+	commit_meta: &CommitMeta,
+	n_transparents: usize,
+	log_inv_rate: usize,
+) {
+	let pcs = make_tensor_pcs(commit_meta, log_inv_rate);
+	commit_prove_verify::<_, BinaryField8b, PackedBinaryField2x128b, _>(
+		commit_meta,
+		n_transparents,
+		&pcs,
+	);
 }
 
 #[test]
@@ -210,44 +318,186 @@ fn test_commit_meta_total_vars() {
 #[test]
 fn test_with_one_poly() {
 	let commit_meta = CommitMeta::with_vars([4]);
-	let merkle_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
-	let n_transparents = 1;
-	let log_inv_rate = 1;
-
-	commit_prove_verify::<_, BinaryField8b, BinaryField16b, PackedBinaryField2x128b, _>(
-		&commit_meta,
-		n_transparents,
-		&merkle_prover,
-		log_inv_rate,
-	);
+	commit_prove_verify_fri(&commit_meta, 1, 1);
 }
 
 #[test]
 fn test_with_one_n_vars() {
 	let commit_meta = CommitMeta::with_vars([4, 4]);
+	commit_prove_verify_fri(&commit_meta, 1, 1);
+}
+
+#[test]
+fn test_commit_prove_verify() {
+	let commit_meta = CommitMeta::with_vars([4, 4, 6, 7]);
+	commit_prove_verify_fri(&commit_meta, 2, 1);
+}
+
+#[test]
+fn test_tensor_with_one_poly() {
+	let commit_meta = CommitMeta::with_vars([4]);
+	commit_prove_verify_tensor(&commit_meta, 1, 1);
+}
+
+#[test]
+fn test_tensor_commit_prove_verify() {
+	let commit_meta = CommitMeta::with_vars([4, 4, 6, 7]);
+	commit_prove_verify_tensor(&commit_meta, 2, 1);
+}
+
+#[test]
+fn test_tensor_with_many_rows() {
+	// Large enough that the proof size is minimized with more than one row
+	let commit_meta = CommitMeta::with_vars([14]);
 	let merkle_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
-	let n_transparents = 1;
-	let log_inv_rate = 1;
+	let params = make_tensor_params::<_, BinaryField16b, _>(
+		&commit_meta,
+		merkle_prover.scheme(),
+		SECURITY_BITS,
+		2,
+	)
+	.unwrap();
+	assert!(params.log_batch_size() > 0);
+	let pcs = TensorPCS::new(params, merkle_prover);
+
+	commit_prove_verify::<_, BinaryField8b, PackedBinaryField2x128b, _>(&commit_meta, 1, &pcs);
+}
+
+#[test]
+fn test_prove_with_persisted_commitment() {
+	let commit_meta = CommitMeta::with_vars([4, 4, 6, 7]);
+	let pcs = make_fri_pcs(&commit_meta, 1);
 
-	commit_prove_verify::<_, BinaryField8b, BinaryField16b, PackedBinaryField2x128b, _>(
+	commit_prove_verify_with::<_, BinaryField8b, PackedBinaryField2x128b, _>(
 		&commit_meta,
-		n_transparents,
-		&merkle_prover,
-		log_inv_rate,
+		2,
+		&pcs,
+		|multilins| {
+			let (commitment, persisted) = commit_and_persist(&pcs, multilins);
+			pcs.load(&commitment, persisted, false).unwrap()
+		},
+	);
+
+	commit_prove_verify_with::<_, BinaryField8b, PackedBinaryField2x128b, _>(
+		&commit_meta,
+		2,
+		&pcs,
+		|multilins| {
+			let (commitment, persisted) = commit_and_persist(&pcs, multilins);
+			pcs.load(&commitment, persisted, true).unwrap()
+		},
 	);
 }
 
 #[test]
-fn test_commit_prove_verify() {
+fn test_load_commitment_rejects_inconsistent_output() {
 	let commit_meta = CommitMeta::with_vars([4, 4, 6, 7]);
-	let merkle_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
-	let n_transparents = 2;
-	let log_inv_rate = 1;
+	let pcs = make_fri_pcs(&commit_meta, 1);
+	let multilins = generate_multilins::<PackedBinaryField2x128b>(
+		commit_meta.n_multilins_by_vars(),
+		StdRng::seed_from_u64(0),
+	)
+	.into_iter()
+	.map(MLEDirectAdapter::from)
+	.collect::<Vec<_>>();
+
+	let (commitment, mut persisted) = commit_and_persist(&pcs, &multilins);
+	persisted.commitment[0] ^= 1;
+	assert_matches!(
+		pcs.load(&commitment, persisted, false),
+		Err(Error::PersistedCommitmentMismatch)
+	);
+
+	let (mut commitment, persisted) = commit_and_persist(&pcs, &multilins);
+	commitment[0] ^= 1;
+	assert_matches!(
+		pcs.load(&commitment, persisted, false),
+		Err(Error::PersistedCommitmentMismatch)
+	);
+
+	// A changed codeword is only detected when it is hashed again.
+	let (commitment, mut persisted) = commit_and_persist(&pcs, &multilins);
+	persisted.codeword[0] += PackedBinaryField2x128b::one();
+	assert_matches!(pcs.load(&commitment, persisted, true), Err(Error::PersistedCodewordMismatch));
+
+	let (commitment, mut persisted) = commit_and_persist(&pcs, &multilins);
+	persisted.codeword.pop();
+	assert_matches!(
+		pcs.load(&commitment, persisted, false),
+		Err(Error::PersistedCodewordLength { .. })
+	);
 
-	commit_prove_verify::<_, BinaryField8b, BinaryField16b, PackedBinaryField2x128b, _>(
+	let (commitment, persisted) = commit_and_persist(&pcs, &multilins);
+	let other_pcs = make_fri_pcs(&commit_meta, 2);
+	assert_matches!(
+		other_pcs.load(&commitment, persisted, false),
+		Err(Error::PersistedCodewordLength { .. })
+	);
+}
+
+/// Proves and verifies with the tensor PCS after flipping a bit in the proof bytes written by the
+/// transcript operation with the label.
+fn tensor_prove_verify_flipped(label: &str) -> Result<(), Error> {
+	let commit_meta = CommitMeta::with_vars([4, 4, 6, 7]);
+	let pcs = make_tensor_pcs(&commit_meta, 1);
+	commit_prove_verify_tampered::<_, BinaryField8b, PackedBinaryField2x128b, _>(
 		&commit_meta,
-		n_transparents,
-		&merkle_prover,
-		log_inv_rate,
+		2,
+		&pcs,
+		|multilins| pcs.commit(multilins).unwrap(),
+		|log, bytes| bytes[proof_range(log, label).start] ^= 1,
+	)
+}
+
+#[test]
+fn test_tensor_rejects_changed_combined_row() {
+	assert_matches!(
+		tensor_prove_verify_flipped("tensor.combined_row"),
+		Err(Error::VerificationError(VerificationError::IncorrectSumcheckEvaluation))
+	);
+}
+
+#[test]
+fn test_tensor_rejects_wrong_merkle_layer() {
+	assert_matches!(
+		tensor_prove_verify_flipped("tensor.merkle_layer"),
+		Err(Error::VectorCommit(_))
+	);
+}
+
+#[test]
+fn test_tensor_rejects_changed_opened_column() {
+	// Opened columns that do not match the Merkle tree are rejected by the Merkle tree scheme.
+	assert_matches!(tensor_prove_verify_flipped("tensor.columns"), Err(Error::VectorCommit(_)));
+
+	// Commit to a codeword with every column changed, so that the opened columns match the Merkle
+	// tree but not the encoding of the combined row.
+	let commit_meta = CommitMeta::with_vars([4, 4, 6, 7]);
+	let pcs = make_tensor_pcs(&commit_meta, 1);
+	let result = commit_prove_verify_tampered::<_, BinaryField8b, PackedBinaryField2x128b, _>(
+		&commit_meta,
+		2,
+		&pcs,
+		|multilins| {
+			let mut codeword = tensor::commit(pcs.params(), pcs.merkle_prover(), multilins)
+				.unwrap()
+				.codeword;
+			for packed in &mut codeword {
+				*packed += PackedBinaryField2x128b::one();
+			}
+			let (commitment, committed) = pcs
+				.merkle_prover()
+				.commit(
+					PackedBinaryField2x128b::unpack_scalars(&codeword),
+					1 << pcs.params().log_batch_size(),
+				)
+				.unwrap();
+			(commitment.root, (committed, codeword))
+		},
+		|_, _| {},
+	);
+	assert_matches!(
+		result,
+		Err(Error::VerificationError(VerificationError::IncorrectColumnOpening { .. }))
 	);
 }
@@ -12,7 +12,7 @@ use tracing::instrument;
 use super::error::{Error, VerificationError};
 use crate::{
 	composition::{BivariateProduct, IndexComposition},
-	fiat_shamir::{CanSample, Challenger},
+	fiat_shamir::Challenger,
 	merkle_tree::MerkleTreeScheme,
 	piop::util::ResizeableIndex,
 	polynomial::MultivariatePoly,
@@ -299,22 +299,7 @@ where
 		claims,
 	)?;
 
-	let non_empty_sumcheck_descs = sumcheck_claim_descs
-		.iter()
-		.enumerate()
-		.filter(|(_n_vars, desc)| !desc.composite_sums.is_empty());
-	let sumcheck_claims = non_empty_sumcheck_descs
-		.clone()
-		.map(|(n_vars, desc)| {
-			// Make a single sumcheck claim with compositions of the committed and transparent
-			// polynomials with `n_vars` variables
-			SumcheckClaim::new(
-				n_vars,
-				desc.committed_indices.len() + desc.transparent_indices.len(),
-				desc.composite_sums.clone(),
-			)
-		})
-		.collect::<Result<Vec<_>, _>>()?;
+	let sumcheck_claims = make_sumcheck_claims(&sumcheck_claim_descs)?;
 
 	// Interleaved front-loaded sumcheck
 	let BatchInterleavedSumcheckFRIOutput {
@@ -330,27 +315,71 @@ where
 		transcript,
 	)?;
 
+	// Verify the committed evals against the FRI final value.
+	let piecewise_eval = verify_sumcheck_outputs(
+		commit_meta,
+		&sumcheck_claim_descs,
+		multilinear_evals,
+		transparents,
+		&challenges,
+	)?;
+	if piecewise_eval != fri_final {
+		return Err(VerificationError::IncorrectSumcheckEvaluation.into());
+	}
+
+	Ok(())
+}
+
+/// Makes a single sumcheck claim for each number of variables with claims, in ascending order.
+pub(super) fn make_sumcheck_claims<F: Field>(
+	sumcheck_claim_descs: &[SumcheckClaimDesc<F>],
+) -> Result<Vec<SumcheckClaim<F, IndexComposition<BivariateProduct, 2>>>, Error> {
+	sumcheck_claim_descs
+		.iter()
+		.enumerate()
+		.filter(|(_n_vars, desc)| !desc.composite_sums.is_empty())
+		.map(|(n_vars, desc)| {
+			// Make a single sumcheck claim with compositions of the committed and transparent
+			// polynomials with `n_vars` variables
+			let claim = SumcheckClaim::new(
+				n_vars,
+				desc.committed_indices.len() + desc.transparent_indices.len(),
+				desc.composite_sums.clone(),
+			)?;
+			Ok(claim)
+		})
+		.collect()
+}
+
+/// Verifies the transparent evals output by the sumchecks and returns the evaluation of the
+/// committed piecewise multilinear at the sumcheck challenges.
+pub(super) fn verify_sumcheck_outputs<'a, F: Field>(
+	commit_meta: &CommitMeta,
+	sumcheck_claim_descs: &[SumcheckClaimDesc<F>],
+	multilinear_evals: Vec<Vec<F>>,
+	transparents: &[impl Borrow<dyn MultivariatePoly<F> + 'a>],
+	challenges: &[F],
+) -> Result<F, Error> {
+	let non_empty_sumcheck_descs = sumcheck_claim_descs
+		.iter()
+		.enumerate()
+		.filter(|(_n_vars, desc)| !desc.composite_sums.is_empty());
 	let mut piecewise_evals = verify_transparent_evals(
 		commit_meta,
 		non_empty_sumcheck_descs,
 		multilinear_evals,
 		transparents,
-		&challenges,
+		challenges,
 	)?;
 
-	// Verify the committed evals against the FRI final value.
 	piecewise_evals.reverse();
 	let n_pieces_by_vars = sumcheck_claim_descs
 		.iter()
 		.map(|desc| desc.n_committed())
 		.collect::<Vec<_>>();
 	let piecewise_eval =
-		evaluate_piecewise_multilinear(&challenges, &n_pieces_by_vars, &mut piecewise_evals)?;
-	if piecewise_eval != fri_final {
-		return Err(VerificationError::IncorrectSumcheckEvaluation.into());
-	}
-
-	Ok(())
+		evaluate_piecewise_multilinear(challenges, &n_pieces_by_vars, &mut piecewise_evals)?;
+	Ok(piecewise_eval)
 }
 
 // Verify the transparent evals and collect the committed evals.
@@ -421,13 +450,13 @@ where
 	let mut multilinear_evals = Vec::with_capacity(claims.len());
 	let mut challenges = Vec::with_capacity(n_rounds);
 	for round_no in 0..n_rounds {
-		let mut reader = proof.message();
+		let mut reader = proof.message_labeled("sumcheck.round");
 		while let Some(claim_multilinear_evals) = sumcheck_verifier.try_finish_claim(&mut reader)? {
 			multilinear_evals.push(claim_multilinear_evals);
 		}
 		sumcheck_verifier.receive_round_proof(&mut reader)?;
 
-		let challenge = proof.sample();
+		let challenge = proof.sample_labeled("sumcheck.round_challenge");
 		challenges.push(challenge);
 
 		sumcheck_verifier.finish_round(challenge)?;
@@ -435,7 +464,7 @@ where
 		let observe_fri_comm = next_commit_round.is_some_and(|round| round == round_no + 1);
 		if observe_fri_comm {
 			let comm = proof
-				.message()
+				.message_labeled("fri.round_commitment")
 				.read()
 				.map_err(VerificationError::Transcript)?;
 			fri_commitments.push(comm);
@@ -443,7 +472,7 @@ where
 		}
 	}
 
-	let mut reader = proof.message();
+	let mut reader = proof.message_labeled("sumcheck.multilinear_evals");
 	while let Some(claim_multilinear_evals) = sumcheck_verifier.try_finish_claim(&mut reader)? {
 		multilinear_evals.push(claim_multilinear_evals);
 	}
@@ -259,6 +259,16 @@ where
 		})
 }
 
+/// Returns the indices sorted in increasing order with duplicates removed.
+///
+/// This is the order in which the cosets opened by a batch of queries appear in the proof.
+pub fn sorted_unique(indices: &[usize]) -> Vec<usize> {
+	let mut indices = indices.to_vec();
+	indices.sort_unstable();
+	indices.dedup();
+	indices
+}
+
 /// The type of the termination round codeword in the FRI protocol.
 pub type TerminateCodeword<F> = Vec<F>;
 
@@ -3,19 +3,19 @@
 use binius_field::{BinaryField, ExtensionField, PackedExtension, PackedField, TowerField};
 use binius_hal::{make_portable_backend, ComputationBackend};
 use binius_maybe_rayon::prelude::*;
-use binius_utils::{bail, SerializeBytes};
+use binius_utils::{bail, DeserializeBytes, SerializationError, SerializationMode, SerializeBytes};
 use bytemuck::zeroed_vec;
-use bytes::BufMut;
+use bytes::{Buf, BufMut};
 use itertools::izip;
 use tracing::instrument;
 
 use super::{
-	common::{vcs_optimal_layers_depths_iter, FRIParams},
+	common::{sorted_unique, vcs_optimal_layers_depths_iter, FRIParams},
 	error::Error,
 	TerminateCodeword,
 };
 use crate::{
-	fiat_shamir::{CanSampleBits, Challenger},
+	fiat_shamir::Challenger,
 	merkle_tree::{MerkleTreeProver, MerkleTreeScheme},
 	protocols::fri::common::{fold_chunk, fold_interleaved_chunk},
 	reed_solomon::reed_solomon::ReedSolomonCode,
@@ -126,6 +126,65 @@ pub struct CommitOutput<P, VCSCommitment, VCSCommitted> {
 	pub codeword: Vec<P>,
 }
 
+/// Serializes the commitment, the vector commitment data and the codeword, so that the output of a
+/// commitment can be persisted and reused instead of encoding and committing the message again.
+///
+/// The codeword is serialized as its number of scalars followed by the scalars, so that it does not
+/// depend on the packing width.
+impl<P, VCSCommitment, VCSCommitted> SerializeBytes for CommitOutput<P, VCSCommitment, VCSCommitted>
+where
+	P: PackedField<Scalar: SerializeBytes>,
+	VCSCommitment: SerializeBytes,
+	VCSCommitted: SerializeBytes,
+{
+	fn serialize(
+		&self,
+		mut write_buf: impl BufMut,
+		mode: SerializationMode,
+	) -> Result<(), SerializationError> {
+		SerializeBytes::serialize(&self.commitment, &mut write_buf, mode)?;
+		SerializeBytes::serialize(&self.committed, &mut write_buf, mode)?;
+		SerializeBytes::serialize(&(self.codeword.len() << P::LOG_WIDTH), &mut write_buf, mode)?;
+		P::iter_slice(&self.codeword)
+			.try_for_each(|scalar| SerializeBytes::serialize(&scalar, &mut write_buf, mode))
+	}
+}
+
+impl<P, VCSCommitment, VCSCommitted> DeserializeBytes
+	for CommitOutput<P, VCSCommitment, VCSCommitted>
+where
+	P: PackedField<Scalar: DeserializeBytes>,
+	VCSCommitment: DeserializeBytes,
+	VCSCommitted: DeserializeBytes,
+{
+	fn deserialize(
+		mut read_buf: impl Buf,
+		mode: SerializationMode,
+	) -> Result<Self, SerializationError> {
+		let commitment = DeserializeBytes::deserialize(&mut read_buf, mode)?;
+		let committed = DeserializeBytes::deserialize(&mut read_buf, mode)?;
+		let len: usize = DeserializeBytes::deserialize(&mut read_buf, mode)?;
+		if len % P::WIDTH != 0 {
+			return Err(SerializationError::InvalidConstruction {
+				name: "CommitOutput::codeword",
+			});
+		}
+		let codeword = (0..len >> P::LOG_WIDTH)
+			.map(|_| {
+				let scalars = (0..P::WIDTH)
+					.map(|_| DeserializeBytes::deserialize(&mut read_buf, mode))
+					.collect::<Result<Vec<P::Scalar>, _>>()?;
+				Ok(P::from_scalars(scalars))
+			})
+			.collect::<Result<_, SerializationError>>()?;
+		Ok(Self {
+			commitment,
+			committed,
+			codeword,
+		})
+	}
+}
+
 /// Creates a parallel iterator over scalars of subfield elementsAssumes chunk_size to be a power of two
 pub fn to_par_scalar_big_chunks<P>(
 	packed_slice: &[P],
@@ -443,20 +502,22 @@ where
 		Challenger_: Challenger,
 	{
 		let (terminate_codeword, query_prover) = self.finalize()?;
-		let mut advice = transcript.decommitment();
-		advice.write_scalar_slice(&terminate_codeword);
+		transcript
+			.decommitment_labeled("fri.terminate_codeword")
+			.write_scalar_slice(&terminate_codeword);
 
 		let layers = query_prover.vcs_optimal_layers()?;
+		let mut advice = transcript.decommitment_labeled("fri.layers");
 		for layer in layers {
 			advice.write_slice(&layer);
 		}
 
 		let params = query_prover.params;
 
-		for _ in 0..params.n_test_queries() {
-			let index = transcript.sample_bits(params.index_bits());
-			query_prover.prove_query(index, transcript.decommitment())?;
-		}
+		let indices = (0..params.n_test_queries())
+			.map(|_| transcript.sample_bits_labeled("fri.query_index", params.index_bits()))
+			.collect::<Vec<_>>();
+		query_prover.prove_queries(&indices, transcript.decommitment_labeled("fri.queries"))?;
 
 		Ok(())
 	}
@@ -489,55 +550,69 @@ where
 		self.params.n_oracles()
 	}
 
-	/// Proves a FRI challenge query.
+	/// Proves a single FRI challenge query.
+	///
+	/// This is [`Self::prove_queries`] with one index, so the proof is a multi-opening of one
+	/// coset per oracle.
 	///
 	/// ## Arguments
 	///
 	/// * `index` - an index into the original codeword domain
-	#[instrument(skip_all, name = "fri::FRIQueryProver::prove_query", level = "debug")]
-	pub fn prove_query<B>(
+	#[deprecated(note = "use `prove_queries`, which deduplicates the openings of all queries")]
+	pub fn prove_query<B>(&self, index: usize, advice: TranscriptWriter<B>) -> Result<(), Error>
+	where
+		B: BufMut,
+	{
+		self.prove_queries(&[index], advice)
+	}
+
+	/// Proves a batch of FRI challenge queries.
+	///
+	/// For every oracle, the cosets opened by the queries are written once each, in increasing
+	/// order of their index, followed by a single Merkle multi-opening proof for all of them.
+	///
+	/// ## Arguments
+	///
+	/// * `indices` - the indices into the original codeword domain, in any order and possibly
+	///   repeated
+	#[instrument(skip_all, name = "fri::FRIQueryProver::prove_queries", level = "debug")]
+	pub fn prove_queries<B>(
 		&self,
-		mut index: usize,
+		indices: &[usize],
 		mut advice: TranscriptWriter<B>,
 	) -> Result<(), Error>
 	where
 		B: BufMut,
 	{
-		let mut arities_and_optimal_layers_depths = self
-			.params
-			.fold_arities()
-			.iter()
-			.copied()
-			.zip(vcs_optimal_layers_depths_iter(self.params, self.merkle_prover.scheme()));
-
-		let Some((first_fold_arity, first_optimal_layer_depth)) =
-			arities_and_optimal_layers_depths.next()
-		else {
-			// If there are no query proofs, that means that no oracles were sent during the FRI
-			// fold rounds. In that case, the original interleaved codeword is decommitted and
-			// the only checks that need to be performed are in `verify_last_oracle`.
-			return Ok(());
-		};
-
-		prove_coset_opening(
-			self.merkle_prover,
-			self.codeword,
-			self.codeword_committed,
-			index,
-			first_fold_arity,
-			first_optimal_layer_depth,
-			&mut advice,
-		)?;
-
-		for ((codeword, committed), (arity, optimal_layer_depth)) in
-			izip!(self.round_committed.iter(), arities_and_optimal_layers_depths)
+		// If there are no fold arities, that means that no oracles were sent during the FRI fold
+		// rounds. In that case, the original interleaved codeword is decommitted and the only
+		// checks that need to be performed are in `verify_last_oracle`.
+		let codewords_and_committed = std::iter::once((self.codeword, self.codeword_committed))
+			.chain(
+				self.round_committed
+					.iter()
+					.map(|(codeword, committed)| (codeword.as_slice(), committed)),
+			);
+
+		let mut coset_indices = indices.to_vec();
+		for (i, ((codeword, committed), arity, optimal_layer_depth)) in izip!(
+			codewords_and_committed,
+			self.params.fold_arities().iter().copied(),
+			vcs_optimal_layers_depths_iter(self.params, self.merkle_prover.scheme())
+		)
+		.enumerate()
 		{
-			index >>= arity;
-			prove_coset_opening(
+			if i != 0 {
+				for index in &mut coset_indices {
+					*index >>= arity;
+				}
+			}
+
+			prove_coset_openings(
 				self.merkle_prover,
 				codeword,
 				committed,
-				index,
+				&sorted_unique(&coset_indices),
 				arity,
 				optimal_layer_depth,
 				&mut advice,
@@ -563,11 +638,11 @@ where
 	}
 }
 
-fn prove_coset_opening<F, MTProver, B>(
+fn prove_coset_openings<F, MTProver, B>(
 	merkle_prover: &MTProver,
 	codeword: &[F],
 	committed: &MTProver::Committed,
-	coset_index: usize,
+	coset_indices: &[usize],
 	log_coset_size: usize,
 	optimal_layer_depth: usize,
 	advice: &mut TranscriptWriter<B>,
@@ -577,11 +652,14 @@ where
 	MTProver: MerkleTreeProver<F>,
 	B: BufMut,
 {
-	let values = &codeword[(coset_index << log_coset_size)..((coset_index + 1) << log_coset_size)];
-	advice.write_scalar_slice(values);
+	for &coset_index in coset_indices {
+		let values =
+			&codeword[(coset_index << log_coset_size)..((coset_index + 1) << log_coset_size)];
+		advice.write_scalar_slice(values);
+	}
 
 	merkle_prover
-		.prove_opening(committed, optimal_layer_depth, coset_index, advice)
+		.prove_multi_opening(committed, optimal_layer_depth, coset_indices, advice)
 		.map_err(|err| Error::VectorCommit(Box::new(err)))?;
 
 	Ok(())
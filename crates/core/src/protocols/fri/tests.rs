@@ -6,20 +6,24 @@ use binius_field::{
 	arch::{packed_64::PackedBinaryField4x16b, OptimalUnderlier128b},
 	as_packed_field::{PackScalar, PackedType},
 	underlier::UnderlierType,
-	BinaryField, BinaryField128b, BinaryField16b, BinaryField32b, ExtensionField,
+	BinaryField, BinaryField128b, BinaryField16b, BinaryField32b, ExtensionField, Field,
 	PackedBinaryField16x16b, PackedField, PackedFieldIndexable, TowerField,
 };
 use binius_hal::{make_portable_backend, ComputationBackendExt};
-use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+use binius_hash::{
+	groestl::{Groestl256, Groestl256ByteCompression, Groestl256MultiCompression},
+	PseudoCompressionFunction,
+};
 use binius_math::MultilinearExtension;
 use binius_maybe_rayon::prelude::ParallelIterator;
 use binius_ntt::NTTOptions;
+use digest::Output;
 use rand::prelude::*;
 
 use super::to_par_scalar_big_chunks;
 use crate::{
 	fiat_shamir::{CanSample, HasherChallenger},
-	merkle_tree::BinaryMerkleTreeProver,
+	merkle_tree::{BinaryMerkleTreeProver, MerkleTreeProver},
 	protocols::fri::{
 		self, to_par_scalar_small_chunks, CommitOutput, FRIFolder, FRIParams, FRIVerifier,
 		FoldRoundOutput,
@@ -39,6 +43,29 @@ fn test_commit_prove_verify_success<U, F, FA>(
 	FA: BinaryField,
 	PackedType<U, F>: PackedFieldIndexable,
 	PackedType<U, FA>: PackedFieldIndexable,
+{
+	test_commit_prove_verify_success_with_compression::<U, F, FA, _, 2>(
+		Groestl256ByteCompression,
+		log_dimension,
+		log_inv_rate,
+		log_batch_size,
+		arities,
+	);
+}
+
+fn test_commit_prove_verify_success_with_compression<U, F, FA, C, const N: usize>(
+	compression: C,
+	log_dimension: usize,
+	log_inv_rate: usize,
+	log_batch_size: usize,
+	arities: &[usize],
+) where
+	U: UnderlierType + PackScalar<F> + PackScalar<FA>,
+	F: TowerField + ExtensionField<FA> + PackedField<Scalar = F>,
+	FA: BinaryField,
+	PackedType<U, F>: PackedFieldIndexable,
+	PackedType<U, FA>: PackedFieldIndexable,
+	C: PseudoCompressionFunction<Output<Groestl256>, N> + Sync,
 {
 	let mut rng = StdRng::seed_from_u64(0);
 
@@ -49,7 +76,7 @@ fn test_commit_prove_verify_success<U, F, FA>(
 	)
 	.unwrap();
 
-	let merkle_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+	let merkle_prover = BinaryMerkleTreeProver::<_, Groestl256, _, N>::new(compression);
 
 	let committed_rs_code =
 		ReedSolomonCode::<FA>::new(log_dimension, log_inv_rate, &NTTOptions::default()).unwrap();
@@ -155,6 +182,23 @@ fn test_commit_prove_verify_success_128b_full() {
 	);
 }
 
+#[test]
+fn test_commit_prove_verify_success_128b_quaternary_merkle_tree() {
+	// Some of the committed codewords have a number of leaves that is not a power of four
+	let log_dimension = 8;
+	let log_final_dimension = 1;
+	let log_inv_rate = 2;
+	let arities = vec![1; log_dimension - log_final_dimension];
+
+	test_commit_prove_verify_success_with_compression::<
+		OptimalUnderlier128b,
+		BinaryField128b,
+		BinaryField16b,
+		_,
+		4,
+	>(Groestl256MultiCompression::<4>, log_dimension, log_inv_rate, 0, &arities);
+}
+
 #[test]
 fn test_commit_prove_verify_success_128b_higher_arity() {
 	let log_dimension = 8;
@@ -213,6 +257,76 @@ fn test_commit_prove_verify_success_without_folding() {
 	);
 }
 
+#[test]
+#[allow(deprecated)]
+fn test_single_query_prove_verify() {
+	type P = PackedType<OptimalUnderlier128b, BinaryField128b>;
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let log_dimension = 6;
+	let log_inv_rate = 2;
+	let arities = [2, 2];
+
+	let committed_rs_code_packed = ReedSolomonCode::<
+		PackedType<OptimalUnderlier128b, BinaryField16b>,
+	>::new(log_dimension, log_inv_rate, &NTTOptions::default())
+	.unwrap();
+	let committed_rs_code =
+		ReedSolomonCode::<BinaryField16b>::new(log_dimension, log_inv_rate, &NTTOptions::default())
+			.unwrap();
+	let params = FRIParams::new(committed_rs_code, 0, arities.to_vec(), 3).unwrap();
+	let merkle_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+
+	let msg = repeat_with(|| P::random(&mut rng))
+		.take(committed_rs_code_packed.dim() >> P::LOG_WIDTH)
+		.collect::<Vec<_>>();
+	let CommitOutput {
+		commitment,
+		committed,
+		codeword,
+	} = fri::commit_interleaved(&committed_rs_code_packed, &params, &merkle_prover, &msg).unwrap();
+
+	let mut round_prover =
+		FRIFolder::new(&params, &merkle_prover, P::unpack_scalars(&codeword), &committed).unwrap();
+	let challenges = repeat_with(|| <BinaryField128b as Field>::random(&mut rng))
+		.take(params.n_fold_rounds())
+		.collect::<Vec<_>>();
+	let mut round_commitments = Vec::new();
+	for &challenge in &challenges {
+		if let FoldRoundOutput::Commitment(round_commitment) =
+			round_prover.execute_fold_round(challenge).unwrap()
+		{
+			round_commitments.push(round_commitment);
+		}
+	}
+	let (terminate_codeword, query_prover) = round_prover.finalize().unwrap();
+	let layers = query_prover.vcs_optimal_layers().unwrap();
+
+	let indices = [5, 0, 5, (1 << params.index_bits()) - 1];
+	let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	for &index in &indices {
+		query_prover
+			.prove_query(index, transcript.decommitment())
+			.unwrap();
+	}
+
+	let verifier = FRIVerifier::new(
+		&params,
+		merkle_prover.scheme(),
+		&commitment,
+		&round_commitments,
+		&challenges,
+	)
+	.unwrap();
+	let mut transcript = transcript.into_verifier();
+	for &index in &indices {
+		verifier
+			.verify_query(index, &terminate_codeword, &layers, &mut transcript.decommitment())
+			.unwrap();
+	}
+	transcript.finalize().unwrap();
+}
+
 #[test]
 fn test_parallel_iterator_for_commitments() {
 	// Compare results for small and large chunk sizes to ensure that theyre identical
@@ -260,3 +374,138 @@ fn test_parallel_iterator_for_commitments() {
 
 	assert_eq!(collected_smaller, collected_bigger);
 }
+
+/// Runs FRI on a Reed–Solomon codeword with `n_errors` corrupted symbols and returns whether the
+/// verifier rejects. The `trial` index is observed first to vary the Fiat-Shamir challenges.
+fn fri_rejects_corrupted_codeword(
+	log_dimension: usize,
+	log_inv_rate: usize,
+	arities: &[usize],
+	n_test_queries: usize,
+	n_errors: usize,
+	trial: u64,
+) -> bool {
+	type F = BinaryField128b;
+
+	let mut rng = StdRng::seed_from_u64(trial);
+
+	let rs_code =
+		ReedSolomonCode::<F>::new(log_dimension, log_inv_rate, &NTTOptions::default()).unwrap();
+	let msg = repeat_with(|| <F as Field>::random(&mut rng))
+		.take(rs_code.dim())
+		.collect::<Vec<_>>();
+
+	let mut codeword = rs_code.encode_scalars(&msg).unwrap();
+	for index in rand::seq::index::sample(&mut rng, codeword.len(), n_errors) {
+		codeword[index] += <F as Field>::random(&mut rng);
+	}
+	assert_eq!(
+		rs_code.distance_to_code(&codeword).unwrap(),
+		Some(n_errors).filter(|&n_errors| n_errors <= rs_code.unique_decoding_radius())
+	);
+
+	let params = FRIParams::<F, F>::new(rs_code, 0, arities.to_vec(), n_test_queries).unwrap();
+
+	// Commit to the corrupted codeword directly, bypassing the encoding
+	let merkle_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+	let coset_log_len = arities[0];
+	let (commitment, committed) = merkle_prover
+		.commit_iterated(
+			to_par_scalar_big_chunks(&codeword, 1 << coset_log_len),
+			params.log_len() - coset_log_len,
+		)
+		.unwrap();
+
+	let mut round_prover = FRIFolder::new(&params, &merkle_prover, &codeword, &committed).unwrap();
+
+	let mut prover_challenger = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	prover_challenger.observe().write(&trial);
+	prover_challenger.message().write(&commitment.root);
+	let mut round_commitments = Vec::with_capacity(params.n_oracles());
+	for _ in 0..params.n_fold_rounds() {
+		let challenge = prover_challenger.sample();
+		if let FoldRoundOutput::Commitment(round_commitment) =
+			round_prover.execute_fold_round(challenge).unwrap()
+		{
+			prover_challenger.message().write(&round_commitment);
+			round_commitments.push(round_commitment);
+		}
+	}
+	round_prover.finish_proof(&mut prover_challenger).unwrap();
+
+	let mut verifier_challenger = prover_challenger.into_verifier();
+	verifier_challenger.observe().write(&trial);
+	let codeword_commitment = verifier_challenger.message().read().unwrap();
+	let mut verifier_challenges = Vec::with_capacity(params.n_fold_rounds());
+	for (&arity, round_commitment) in params.fold_arities().iter().zip(&round_commitments) {
+		verifier_challenges.append(&mut verifier_challenger.sample_vec(arity));
+		let read_commitment: digest::Output<Groestl256> =
+			verifier_challenger.message().read().unwrap();
+		assert_eq!(&read_commitment, round_commitment);
+	}
+	verifier_challenges.append(&mut verifier_challenger.sample_vec(params.n_final_challenges()));
+
+	let verifier = FRIVerifier::new(
+		&params,
+		merkle_prover.scheme(),
+		&codeword_commitment,
+		&round_commitments,
+		&verifier_challenges,
+	)
+	.unwrap();
+
+	verifier.verify(&mut verifier_challenger).is_err()
+}
+
+#[test]
+fn test_verify_rejects_codeword_beyond_unique_decoding_radius() {
+	let log_dimension = 5;
+	let log_inv_rate = 2;
+	let arities = [2, 1];
+	let n_test_queries = 4;
+
+	let n_errors = 3 << (log_dimension + log_inv_rate - 2);
+	let n_rejected = (0..8)
+		.filter(|&trial| {
+			fri_rejects_corrupted_codeword(
+				log_dimension,
+				log_inv_rate,
+				&arities,
+				n_test_queries,
+				n_errors,
+				trial,
+			)
+		})
+		.count();
+	assert_eq!(n_rejected, 8);
+}
+
+#[test]
+fn test_verify_rejects_codeword_within_unique_decoding_radius() {
+	let log_dimension = 5;
+	let log_inv_rate = 2;
+	let arities = [2, 1];
+	let n_test_queries = 2;
+
+	// The corruption is folded into the terminal codeword, which the verifier reads in full and
+	// checks to fold to a repetition codeword. The rejection thus does not depend on the queries
+	// hitting a corrupted symbol, and every trial is rejected except with probability about
+	// 1/|F|. The trials are seeded, so this is deterministic.
+	for n_errors in [1, 4, 1 << (log_dimension + log_inv_rate - 2)] {
+		for trial in 0..8 {
+			assert!(fri_rejects_corrupted_codeword(
+				log_dimension,
+				log_inv_rate,
+				&arities,
+				n_test_queries,
+				n_errors,
+				trial,
+			));
+		}
+	}
+}
+
+#[test]
+fn test_verify_accepts_codeword_without_errors() {
+	assert!(!fri_rejects_corrupted_codeword(5, 2, &[2, 1], 4, 0, 0));
+}
@@ -9,9 +9,13 @@ use bytes::Buf;
 use itertools::izip;
 use tracing::instrument;
 
-use super::{common::vcs_optimal_layers_depths_iter, error::Error, VerificationError};
+use super::{
+	common::{sorted_unique, vcs_optimal_layers_depths_iter},
+	error::Error,
+	VerificationError,
+};
 use crate::{
-	fiat_shamir::{CanSampleBits, Challenger},
+	fiat_shamir::Challenger,
 	merkle_tree::MerkleTreeScheme,
 	protocols::fri::common::{fold_chunk, fold_interleaved_chunk, FRIParams},
 	transcript::{TranscriptReader, VerifierTranscript},
@@ -102,13 +106,14 @@ where
 		// Verify that the last oracle sent is a codeword.
 		let terminate_codeword_len =
 			1 << (self.params.n_final_challenges() + self.params.rs_code().log_inv_rate());
-		let mut advice = transcript.decommitment();
-		let terminate_codeword = advice
+		let terminate_codeword = transcript
+			.decommitment_labeled("fri.terminate_codeword")
 			.read_scalar_slice(terminate_codeword_len)
 			.map_err(Error::TranscriptError)?;
 		let final_value = self.verify_last_oracle(&terminate_codeword)?;
 
 		// Verify that the provided layers match the commitments.
+		let mut advice = transcript.decommitment_labeled("fri.layers");
 		let layers = vcs_optimal_layers_depths_iter(self.params, self.vcs)
 			.map(|layer_depth| advice.read_vec(1 << layer_depth))
 			.collect::<Result<Vec<_>, _>>()?;
@@ -124,17 +129,15 @@ where
 
 		// Verify the random openings against the decommitted layers.
 
-		let mut scratch_buffer = self.create_scratch_buffer();
-		for _ in 0..self.params.n_test_queries() {
-			let index = transcript.sample_bits(self.params.index_bits());
-			self.verify_query_internal(
-				index,
-				&terminate_codeword,
-				&layers,
-				&mut transcript.decommitment(),
-				&mut scratch_buffer,
-			)?
-		}
+		let indices = (0..self.params.n_test_queries())
+			.map(|_| transcript.sample_bits_labeled("fri.query_index", self.params.index_bits()))
+			.collect::<Vec<_>>();
+		self.verify_queries(
+			&indices,
+			&terminate_codeword,
+			&layers,
+			&mut transcript.decommitment_labeled("fri.queries"),
+		)?;
 
 		Ok(final_value)
 	}
@@ -209,16 +212,16 @@ where
 		Ok(final_value)
 	}
 
-	/// Verifies a FRI challenge query.
+	/// Verifies a single FRI challenge query.
 	///
-	/// A FRI challenge query tests for consistency between all consecutive oracles sent by the
-	/// prover. The verifier has full access to the last oracle sent, and this is probabilistically
-	/// verified to be a codeword by `Self::verify_last_oracle`.
+	/// This is [`Self::verify_queries`] with one index, reading a proof written by
+	/// [`FRIQueryProver::prove_query`](super::FRIQueryProver::prove_query).
 	///
 	/// ## Arguments
 	///
 	/// * `index` - an index into the original codeword domain
-	/// * `proof` - a query proof
+	/// * `advice` - the openings of the coset queried in each oracle
+	#[deprecated(note = "use `verify_queries`, which deduplicates the openings of all queries")]
 	pub fn verify_query<B: Buf>(
 		&self,
 		index: usize,
@@ -226,83 +229,118 @@ where
 		layers: &[Vec<VCS::Digest>],
 		advice: &mut TranscriptReader<B>,
 	) -> Result<(), Error> {
-		self.verify_query_internal(
-			index,
-			terminate_codeword,
+		self.verify_queries(&[index], terminate_codeword, layers, advice)
+	}
+
+	/// Verifies a batch of FRI challenge queries.
+	///
+	/// A FRI challenge query tests for consistency between all consecutive oracles sent by the
+	/// prover. The verifier has full access to the last oracle sent, and this is probabilistically
+	/// verified to be a codeword by `Self::verify_last_oracle`.
+	///
+	/// ## Arguments
+	///
+	/// * `indices` - the indices into the original codeword domain, in any order and possibly
+	///   repeated
+	/// * `advice` - the openings of the cosets queried in each oracle, as written by
+	///   [`FRIQueryProver::prove_queries`](super::FRIQueryProver::prove_queries)
+	pub fn verify_queries<B: Buf>(
+		&self,
+		indices: &[usize],
+		terminate_codeword: &[F],
+		layers: &[Vec<VCS::Digest>],
+		advice: &mut TranscriptReader<B>,
+	) -> Result<(), Error> {
+		let openings = self.verify_openings(indices, layers, advice)?;
+
+		let mut scratch_buffer = self.create_scratch_buffer();
+		for &index in indices {
+			self.verify_query_internal(index, terminate_codeword, &openings, &mut scratch_buffer)?;
+		}
+		Ok(())
+	}
+
+	/// Reads the cosets opened by the queries in each oracle and verifies them against the
+	/// decommitted layers.
+	#[instrument(skip_all, name = "fri::FRIVerifier::verify_openings", level = "debug")]
+	fn verify_openings<B: Buf>(
+		&self,
+		indices: &[usize],
+		layers: &[Vec<VCS::Digest>],
+		advice: &mut TranscriptReader<B>,
+	) -> Result<Vec<CosetOpenings<F>>, Error> {
+		let mut coset_indices = indices.to_vec();
+		let mut log_n_cosets = self.params.index_bits();
+
+		izip!(
+			self.params.fold_arities().iter().copied(),
 			layers,
-			advice,
-			&mut self.create_scratch_buffer(),
+			vcs_optimal_layers_depths_iter(self.params, self.vcs)
 		)
+		.enumerate()
+		.map(|(i, (arity, layer, optimal_layer_depth))| {
+			if i != 0 {
+				for index in &mut coset_indices {
+					*index >>= arity;
+				}
+				log_n_cosets -= arity;
+			}
+
+			let coset_indices = sorted_unique(&coset_indices);
+			let values = verify_coset_openings(
+				self.vcs,
+				&coset_indices,
+				arity,
+				optimal_layer_depth,
+				log_n_cosets,
+				layer,
+				advice,
+			)?;
+			Ok(CosetOpenings {
+				coset_indices,
+				log_coset_size: arity,
+				values,
+			})
+		})
+		.collect()
 	}
 
 	#[instrument(skip_all, name = "fri::FRIVerifier::verify_query", level = "debug")]
-	fn verify_query_internal<B: Buf>(
+	fn verify_query_internal(
 		&self,
 		mut index: usize,
 		terminate_codeword: &[F],
-		layers: &[Vec<VCS::Digest>],
-		advice: &mut TranscriptReader<B>,
+		openings: &[CosetOpenings<F>],
 		scratch_buffer: &mut [F],
 	) -> Result<(), Error> {
-		let mut arities_iter = self.params.fold_arities().iter().copied();
-
-		let mut layer_digest_and_optimal_layer_depth =
-			iter::zip(layers, vcs_optimal_layers_depths_iter(self.params, self.vcs));
-
-		let Some(first_fold_arity) = arities_iter.next() else {
+		let Some((first_openings, openings)) = openings.split_first() else {
 			// If there are no query proofs, that means that no oracles were sent during the FRI
 			// fold rounds. In that case, the original interleaved codeword is decommitted and
 			// the only checks that need to be performed are in `verify_last_oracle`.
 			return Ok(());
 		};
 
-		let (first_layer, first_optimal_layer_depth) = layer_digest_and_optimal_layer_depth
-			.next()
-			.expect("The length should be the same as the amount of proofs.");
-
 		// This is the round of the folding phase that the codeword to be folded is committed to.
 		let mut fold_round = 0;
-		let mut log_n_cosets = self.params.index_bits();
 
 		// Check the first fold round before the main loop. It is special because in the first
 		// round we need to fold as an interleaved chunk instead of a regular coset.
-		let log_coset_size = first_fold_arity - self.params.log_batch_size();
-		let values = verify_coset_opening(
-			self.vcs,
-			index,
-			first_fold_arity,
-			first_optimal_layer_depth,
-			log_n_cosets,
-			first_layer,
-			advice,
-		)?;
+		let log_coset_size = first_openings.log_coset_size - self.params.log_batch_size();
 		let mut next_value = fold_interleaved_chunk(
 			self.params.rs_code(),
 			self.params.log_batch_size(),
 			index,
-			&values,
+			first_openings.coset(index),
 			&self.interleave_tensor,
 			&self.fold_challenges[fold_round..fold_round + log_coset_size],
 			scratch_buffer,
 		);
 		fold_round += log_coset_size;
 
-		for (i, (arity, (layer, optimal_layer_depth))) in
-			izip!(arities_iter, layer_digest_and_optimal_layer_depth).enumerate()
-		{
+		for (i, coset_openings) in openings.iter().enumerate() {
+			let arity = coset_openings.log_coset_size;
 			let coset_index = index >> arity;
-
-			log_n_cosets -= arity;
-
-			let values = verify_coset_opening(
-				self.vcs,
-				coset_index,
-				arity,
-				optimal_layer_depth,
-				log_n_cosets,
-				layer,
-				advice,
-			)?;
+			let values = coset_openings.coset(coset_index);
 
 			if next_value != values[index % (1 << arity)] {
 				return Err(VerificationError::IncorrectFold {
@@ -316,7 +354,7 @@ where
 				self.params.rs_code(),
 				fold_round,
 				coset_index,
-				&values,
+				values,
 				&self.fold_challenges[fold_round..fold_round + arity],
 				scratch_buffer,
 			);
@@ -349,11 +387,31 @@ where
 	}
 }
 
-/// Verifies that the coset opening provided in the proof is consistent with the VCS commitment.
+/// The cosets of an oracle opened by a batch of queries.
+struct CosetOpenings<F> {
+	/// The indices of the opened cosets, sorted in strictly increasing order.
+	coset_indices: Vec<usize>,
+	log_coset_size: usize,
+	/// The concatenated values of the opened cosets.
+	values: Vec<F>,
+}
+
+impl<F> CosetOpenings<F> {
+	/// Returns the values of an opened coset.
+	fn coset(&self, coset_index: usize) -> &[F] {
+		let position = self
+			.coset_indices
+			.binary_search(&coset_index)
+			.expect("the cosets of all queries are opened");
+		&self.values[position << self.log_coset_size..(position + 1) << self.log_coset_size]
+	}
+}
+
+/// Verifies that the coset openings provided in the proof are consistent with the VCS commitment.
 #[allow(clippy::too_many_arguments)]
-fn verify_coset_opening<F, MTScheme, B>(
+fn verify_coset_openings<F, MTScheme, B>(
 	vcs: &MTScheme,
-	coset_index: usize,
+	coset_indices: &[usize],
 	log_coset_size: usize,
 	optimal_layer_depth: usize,
 	tree_depth: usize,
@@ -365,9 +423,9 @@ where
 	MTScheme: MerkleTreeScheme<F>,
 	B: Buf,
 {
-	let values = advice.read_scalar_slice::<F>(1 << log_coset_size)?;
-	vcs.verify_opening(
-		coset_index,
+	let values = advice.read_scalar_slice::<F>(coset_indices.len() << log_coset_size)?;
+	vcs.verify_multi_opening(
+		coset_indices,
 		&values,
 		optimal_layer_depth,
 		tree_depth,
@@ -26,6 +26,7 @@ mod error;
 mod gkr_gpa;
 mod oracles;
 mod packed_field_storage;
+mod proof;
 mod prove;
 #[cfg(test)]
 mod tests;
@@ -36,5 +37,6 @@ pub use gkr_gpa::{
 	GrandProductBatchProveOutput, GrandProductClaim, GrandProductWitness, LayerClaim,
 };
 pub use oracles::*;
+pub use proof::*;
 pub use prove::*;
 pub use verify::*;
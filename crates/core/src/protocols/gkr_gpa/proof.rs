@@ -0,0 +1,121 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::{Field, TowerField};
+use binius_macros::{DeserializeBytes, SerializeBytes};
+
+use super::{Error, GrandProductClaim};
+use crate::{
+	composition::{BivariateProduct, IndexComposition},
+	fiat_shamir::Challenger,
+	polynomial::Error as PolynomialError,
+	protocols::sumcheck::{
+		eq_ind::reduce_to_regular_sumchecks, CompositeSumClaim, EqIndSumcheckClaim,
+		SumcheckChallenges, SumcheckProof,
+	},
+	transcript::{ProverTranscript, VerifierTranscript},
+};
+
+/// A batched grand product proof.
+#[derive(Debug, Default, Clone, PartialEq, Eq, SerializeBytes, DeserializeBytes)]
+pub struct GrandProductProof<F: Field> {
+	/// The layer sumcheck proofs, starting from the layer right below the output.
+	pub layers: Vec<SumcheckProof<F>>,
+}
+
+/// The verifier challenges of a batched grand product protocol execution.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct GrandProductChallenges<F: Field> {
+	/// The sumcheck challenges $r'_k$ of each layer.
+	pub layers: Vec<SumcheckChallenges<F>>,
+	/// The GPA challenges $\mu_k$ sampled after each layer sumcheck.
+	pub gpa_challenges: Vec<F>,
+}
+
+impl<F: TowerField> GrandProductProof<F> {
+	/// Reads a batched grand product proof for the given claims from the verifier transcript.
+	///
+	/// The transcript is advanced exactly like [`batch_verify`](super::batch_verify) would.
+	pub fn read<Challenger_: Challenger>(
+		claims: &[GrandProductClaim<F>],
+		transcript: &mut VerifierTranscript<Challenger_>,
+	) -> Result<(Self, GrandProductChallenges<F>), Error> {
+		let max_n_vars = claims.iter().map(|claim| claim.n_vars).max().unwrap_or(0);
+
+		let mut layers = Vec::with_capacity(max_n_vars);
+		let mut challenges = GrandProductChallenges {
+			layers: Vec::with_capacity(max_n_vars),
+			gpa_challenges: Vec::with_capacity(max_n_vars),
+		};
+		for layer_no in 0..max_n_vars {
+			let eq_ind_sumcheck_claims = layer_sumcheck_claims(claims, layer_no)?;
+			let regular_sumcheck_claims = reduce_to_regular_sumchecks(&eq_ind_sumcheck_claims)?;
+
+			let (layer, layer_challenges) =
+				SumcheckProof::read(&regular_sumcheck_claims, transcript)?;
+			layers.push(layer);
+			challenges.layers.push(layer_challenges);
+			challenges
+				.gpa_challenges
+				.push(transcript.sample_labeled("gpa.layer_challenge"));
+		}
+
+		Ok((Self { layers }, challenges))
+	}
+
+	/// Writes the batched grand product proof for the given claims into the prover transcript.
+	pub fn write<Challenger_: Challenger>(
+		&self,
+		claims: &[GrandProductClaim<F>],
+		transcript: &mut ProverTranscript<Challenger_>,
+	) -> Result<GrandProductChallenges<F>, Error> {
+		let max_n_vars = claims.iter().map(|claim| claim.n_vars).max().unwrap_or(0);
+
+		if self.layers.len() != max_n_vars {
+			return Err(Error::MismatchedClaimsAndProofs);
+		}
+
+		let mut challenges = GrandProductChallenges {
+			layers: Vec::with_capacity(max_n_vars),
+			gpa_challenges: Vec::with_capacity(max_n_vars),
+		};
+		for (layer_no, layer) in self.layers.iter().enumerate() {
+			let eq_ind_sumcheck_claims = layer_sumcheck_claims(claims, layer_no)?;
+			let regular_sumcheck_claims = reduce_to_regular_sumchecks(&eq_ind_sumcheck_claims)?;
+
+			let layer_challenges = layer.write(&regular_sumcheck_claims, transcript)?;
+			challenges.layers.push(layer_challenges);
+			challenges
+				.gpa_challenges
+				.push(transcript.sample_labeled("gpa.layer_challenge"));
+		}
+
+		Ok(challenges)
+	}
+}
+
+/// Constructs the eq-ind sumcheck claim of a layer with the same shape as the one verified by
+/// `reduce_layer_claim_batch`; the claimed sums are irrelevant for the proof layout.
+fn layer_sumcheck_claims<F: Field>(
+	claims: &[GrandProductClaim<F>],
+	layer_no: usize,
+) -> Result<Vec<EqIndSumcheckClaim<F, IndexComposition<BivariateProduct, 2>>>, Error> {
+	let n_active_claims = claims
+		.iter()
+		.filter(|claim| claim.n_vars > layer_no)
+		.count();
+	let n_multilinears = 2 * n_active_claims;
+
+	let composite_sums = (0..n_active_claims)
+		.map(|i| {
+			let composition =
+				IndexComposition::new(n_multilinears, [2 * i, 2 * i + 1], BivariateProduct {})?;
+			Ok(CompositeSumClaim {
+				composition,
+				sum: F::ZERO,
+			})
+		})
+		.collect::<Result<Vec<_>, PolynomialError>>()?;
+
+	let eq_ind_sumcheck_claim = EqIndSumcheckClaim::new(layer_no, n_multilinears, composite_sums)?;
+	Ok(vec![eq_ind_sumcheck_claim])
+}
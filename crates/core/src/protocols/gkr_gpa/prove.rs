@@ -22,7 +22,7 @@ use super::{
 };
 use crate::{
 	composition::{BivariateProduct, IndexComposition},
-	fiat_shamir::{CanSample, Challenger},
+	fiat_shamir::Challenger,
 	protocols::sumcheck::{
 		self, equal_n_vars_check, immediate_switchover_heuristic,
 		prove::eq_ind::{eq_ind_expand, EqIndSumcheckProver, EqIndSumcheckProverBuilder},
@@ -103,7 +103,7 @@ where
 		};
 
 		// Step 3: Sample a challenge for the next layer
-		let gpa_challenge = transcript.sample();
+		let gpa_challenge = transcript.sample_labeled("gpa.layer_challenge");
 
 		// Step 4: Finalize each prover to update its internal current_layer_claim
 		for (i, prover) in sorted_provers.iter_mut().enumerate() {
@@ -15,12 +15,12 @@ use binius_math::{EvaluationOrder, IsomorphicEvaluationDomainFactory, Multilinea
 use bytemuck::zeroed_vec;
 use rand::{rngs::StdRng, SeedableRng};
 
-use super::{GrandProductClaim, GrandProductWitness};
+use super::{GrandProductClaim, GrandProductProof, GrandProductWitness};
 use crate::{
 	fiat_shamir::HasherChallenger,
 	oracle::MultilinearOracleSet,
 	protocols::gkr_gpa::{batch_prove, batch_verify, GrandProductBatchProveOutput},
-	transcript::ProverTranscript,
+	transcript::{ProverTranscript, VerifierTranscript},
 	witness::MultilinearExtensionIndex,
 };
 
@@ -239,3 +239,51 @@ fn test_prove_verify_batch_512b() {
 
 	run_prove_verify_batch_test::<U, F, FS, P>();
 }
+
+#[test]
+fn test_extract_grand_product_proof() {
+	type F = BinaryField128b;
+	type U = <F as WithUnderlier>::Underlier;
+	type P = PackedType<U, F>;
+	type FS = BinaryField32b;
+
+	let evaluation_order = EvaluationOrder::LowToHigh;
+	let domain_factory = IsomorphicEvaluationDomainFactory::<FS>::default();
+	let backend = binius_hal::make_portable_backend();
+
+	let CreateClaimsWitnessesOutput {
+		new_claims: claims,
+		new_witnesses: witnesses,
+		..
+	} = create_claims_witnesses_helper::<U, P, F>(
+		StdRng::seed_from_u64(0),
+		MultilinearOracleSet::new(),
+		MultilinearExtensionIndex::new(),
+		5,
+		3,
+	);
+
+	let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	batch_prove::<_, _, FS, _, _>(
+		evaluation_order,
+		witnesses,
+		&claims,
+		domain_factory,
+		&mut prover_transcript,
+		&backend,
+	)
+	.unwrap();
+	let proof_bytes = prover_transcript.finalize();
+
+	// Extract the proof and re-inject it into a fresh transcript
+	let mut extraction_transcript =
+		VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof_bytes.clone());
+	let (proof, challenges) = GrandProductProof::read(&claims, &mut extraction_transcript).unwrap();
+	extraction_transcript.finalize().unwrap();
+	assert_eq!(proof.layers.len(), 5);
+
+	let mut reinjected_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let reinjected_challenges = proof.write(&claims, &mut reinjected_transcript).unwrap();
+	assert_eq!(reinjected_challenges, challenges);
+	assert_eq!(reinjected_transcript.finalize(), proof_bytes);
+}
@@ -11,7 +11,7 @@ use tracing::instrument;
 use super::{gkr_gpa::LayerClaim, Error, GrandProductClaim};
 use crate::{
 	composition::{BivariateProduct, IndexComposition},
-	fiat_shamir::{CanSample, Challenger},
+	fiat_shamir::Challenger,
 	polynomial::Error as PolynomialError,
 	protocols::sumcheck::{self, CompositeSumClaim, EqIndSumcheckClaim},
 	transcript::VerifierTranscript,
@@ -159,7 +159,7 @@ where
 
 	// Create the new (k+1)th layer LayerClaims for each grand product circuit
 	let sumcheck_challenge = batch_sumcheck_output.challenges.clone();
-	let gpa_challenge = transcript.sample();
+	let gpa_challenge = transcript.sample_labeled("gpa.layer_challenge");
 	let new_layer_challenge = sumcheck_challenge
 		.into_iter()
 		.chain(Some(gpa_challenge))
@@ -6,6 +6,7 @@ use binius_field::{
 	util::{inner_product_unchecked, powers},
 	ExtensionField, Field, PackedField,
 };
+use binius_macros::{DeserializeBytes, SerializeBytes};
 use binius_math::{CompositionPoly, EvaluationDomainFactory, InterpolationDomain, MultilinearPoly};
 use binius_utils::bail;
 use getset::{CopyGetters, Getters};
@@ -93,7 +94,7 @@ where
 /// A univariate polynomial in monomial basis.
 ///
 /// The coefficient at position `i` in the inner vector corresponds to the term $X^i$.
-#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, SerializeBytes, DeserializeBytes)]
 pub struct RoundCoeffs<F: Field>(pub Vec<F>);
 
 impl<F: Field> RoundCoeffs<F> {
@@ -153,7 +154,7 @@ impl<F: Field> MulAssign<F> for RoundCoeffs<F> {
 /// Since the verifier knows the claimed sum of the polynomial values at the points 0 and 1, the
 /// high-degree term coefficient can be easily recovered. Truncating the coefficient off saves a
 /// small amount of proof data.
-#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, SerializeBytes, DeserializeBytes)]
 pub struct RoundProof<F: Field>(pub RoundCoeffs<F>);
 
 impl<F: Field> RoundProof<F> {
@@ -196,20 +197,6 @@ impl<F: Field> RoundProof<F> {
 	}
 }
 
-/// A sumcheck batch proof.
-#[derive(Debug, Default, Clone, PartialEq, Eq)]
-pub struct Proof<F: Field> {
-	/// The round proofs for each round.
-	pub rounds: Vec<RoundProof<F>>,
-	/// The claimed evaluations of all multilinears at the point defined by the sumcheck verifier
-	/// challenges.
-	///
-	/// The structure is a vector of vectors of field elements. Each entry of the outer vector
-	/// corresponds to one [`SumcheckClaim`] in a batch. Each inner vector contains the evaluations
-	/// of the multilinears referenced by that claim.
-	pub multilinear_evals: Vec<Vec<F>>,
-}
-
 #[derive(Debug, PartialEq, Eq)]
 pub struct BatchSumcheckOutput<F: Field> {
 	pub challenges: Vec<F>,
@@ -237,6 +224,32 @@ pub fn standard_switchover_heuristic(k: isize) -> impl Fn(usize) -> usize + Copy
 	}
 }
 
+/// Constructs a switchover function for [`SmallFieldSumcheckProver`] that returns the number of
+/// rounds to be computed over the small field.
+///
+/// A small field round over compositions of degree $d$ costs $((d + 1) / 2)^i$ times more small
+/// field operations than the first round, while an extension field multiplication is roughly as
+/// expensive as `extension_degree` small field ones. The function returns the largest number of
+/// rounds $i$ such that $(d + 1)^i < 2^{i + k} \cdot \mathrm{extension\_degree}$, capped at
+/// $\log_2(\mathrm{extension\_degree}) + k$ rounds, which coincides with
+/// [`standard_switchover_heuristic`] shifted by one round for multilinear (degree one) compositions.
+///
+/// [`SmallFieldSumcheckProver`]: super::prove::SmallFieldSumcheckProver
+pub fn small_field_switchover_heuristic(k: isize) -> impl Fn(usize, usize) -> usize + Copy {
+	move |extension_degree: usize, composition_degree: usize| {
+		let log_budget = (extension_degree.ilog2() as isize + k).max(0) as usize;
+		let grid_size = composition_degree as u128 + 1;
+		(0..log_budget)
+			.take_while(|&round| {
+				let round = round as u32 + 1;
+				grid_size
+					.checked_pow(round)
+					.is_some_and(|cost| cost >> (round as usize + log_budget).min(127) == 0)
+			})
+			.count()
+	}
+}
+
 /// Sumcheck switchover heuristic that begins folding immediately in the first round.
 pub const fn immediate_switchover_heuristic(_extension_degree: usize) -> usize {
 	0
@@ -11,6 +11,7 @@ pub mod eq_ind;
 mod error;
 pub mod front_loaded;
 mod oracles;
+mod proof;
 pub mod prove;
 #[cfg(test)]
 mod tests;
@@ -23,6 +24,7 @@ pub use common::*;
 pub use eq_ind::EqIndSumcheckClaim;
 pub use error::*;
 pub use oracles::*;
+pub use proof::*;
 pub use prove::batch_prove;
 pub use univariate_zerocheck::batch_verify_zerocheck_univariate_round;
 pub use verify::{batch_verify, batch_verify_with_start};
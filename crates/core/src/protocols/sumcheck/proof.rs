@@ -0,0 +1,562 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Standalone sumcheck and zerocheck proof artifacts.
+//!
+//! [`batch_prove`] and [`batch_verify`] exchange messages through a Fiat-Shamir transcript. The
+//! types in this module capture the prover messages of a single protocol execution as plain data,
+//! so that they can be extracted from a transcript, (de)serialized, checked against externally
+//! supplied challenges with [`verify_sumcheck_proof`], and written back into a transcript,
+//! reproducing exactly the same proof bytes.
+//!
+//! [`batch_prove`]: super::batch_prove
+//! [`batch_verify`]: super::batch_verify
+
+use std::iter;
+
+use binius_field::{Field, TowerField};
+use binius_macros::{DeserializeBytes, SerializeBytes};
+use binius_math::{CompositionPoly, EvaluationOrder};
+use binius_utils::{bail, sorting::is_sorted_ascending};
+use itertools::izip;
+
+use super::{
+	common::{batch_weighted_value, BatchSumcheckOutput, RoundCoeffs, RoundProof, SumcheckClaim},
+	eq_ind::reduce_to_regular_sumchecks,
+	error::{Error, VerificationError},
+	univariate_zerocheck::univariate_round_domain,
+	verify::{compute_expected_batch_composite_evaluation_multi_claim, interpolate_round_proof},
+	zerocheck::{reduce_to_eq_ind_sumchecks, ZerocheckClaim},
+};
+use crate::{
+	fiat_shamir::Challenger,
+	transcript::{ProverTranscript, VerifierTranscript},
+};
+
+/// A sumcheck batch proof.
+#[derive(Debug, Default, Clone, PartialEq, Eq, SerializeBytes, DeserializeBytes)]
+pub struct SumcheckProof<F: Field> {
+	/// The round proofs for each round.
+	pub rounds: Vec<RoundProof<F>>,
+	/// The claimed evaluations of all multilinears at the point defined by the sumcheck verifier
+	/// challenges.
+	///
+	/// The structure is a vector of vectors of field elements. Each entry of the outer vector
+	/// corresponds to one [`SumcheckClaim`] in a batch. Each inner vector contains the evaluations
+	/// of the multilinears referenced by that claim.
+	pub multilinear_evals: Vec<Vec<F>>,
+}
+
+/// A sumcheck batch proof.
+#[deprecated(note = "renamed to `SumcheckProof`")]
+pub type Proof<F> = SumcheckProof<F>;
+
+/// The verifier challenges of a batched sumcheck protocol execution.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SumcheckChallenges<F: Field> {
+	/// The batching coefficients, one per claim in the batch.
+	pub batch_coeffs: Vec<F>,
+	/// The round challenges, in the order they were sampled.
+	pub round_challenges: Vec<F>,
+}
+
+impl<F: TowerField> SumcheckProof<F> {
+	/// Reads a batched sumcheck proof for the given claims from the verifier transcript.
+	///
+	/// The transcript is advanced exactly like [`batch_verify`] would, but no checks beyond the
+	/// shape of the claims are performed; use [`verify_sumcheck_proof`] on the output to that end.
+	///
+	/// [`batch_verify`]: super::batch_verify
+	pub fn read<Composition, Challenger_>(
+		claims: &[SumcheckClaim<F, Composition>],
+		transcript: &mut VerifierTranscript<Challenger_>,
+	) -> Result<(Self, SumcheckChallenges<F>), Error>
+	where
+		Composition: CompositionPoly<F>,
+		Challenger_: Challenger,
+	{
+		Schedule::new(&claim_shapes(claims), ScheduleStart::default())?.read(transcript)
+	}
+
+	/// Writes the batched sumcheck proof for the given claims into the prover transcript.
+	///
+	/// The transcript is advanced exactly like [`batch_prove`] would, so a proof extracted with
+	/// [`Self::read`] reproduces the original proof bytes.
+	///
+	/// [`batch_prove`]: super::batch_prove
+	pub fn write<Composition, Challenger_>(
+		&self,
+		claims: &[SumcheckClaim<F, Composition>],
+		transcript: &mut ProverTranscript<Challenger_>,
+	) -> Result<SumcheckChallenges<F>, Error>
+	where
+		Composition: CompositionPoly<F>,
+		Challenger_: Challenger,
+	{
+		Schedule::new(&claim_shapes(claims), ScheduleStart::default())?.write(self, transcript)
+	}
+}
+
+/// Verify a batched sumcheck proof against explicitly provided challenges.
+///
+/// This performs the same checks as [`batch_verify`], except that the batching coefficients and
+/// round challenges are taken from `challenges` instead of being sampled from a Fiat-Shamir
+/// transcript.
+///
+/// [`batch_verify`]: super::batch_verify
+pub fn verify_sumcheck_proof<F, Composition>(
+	evaluation_order: EvaluationOrder,
+	claims: &[SumcheckClaim<F, Composition>],
+	proof: &SumcheckProof<F>,
+	challenges: &SumcheckChallenges<F>,
+) -> Result<BatchSumcheckOutput<F>, Error>
+where
+	F: Field,
+	Composition: CompositionPoly<F>,
+{
+	let schedule = Schedule::new(&claim_shapes(claims), ScheduleStart::default())?;
+	schedule.check_proof_shape(proof)?;
+
+	let SumcheckChallenges {
+		batch_coeffs,
+		round_challenges,
+	} = challenges;
+
+	if batch_coeffs.len() != claims.len() || round_challenges.len() != schedule.rounds.len() {
+		bail!(Error::IncorrectNumberOfChallenges);
+	}
+
+	let mut sum = F::ZERO;
+	let mut active_index = 0;
+	for (&(n_new_claims, _), round_proof, &challenge) in
+		izip!(&schedule.rounds, &proof.rounds, round_challenges)
+	{
+		let active_range = active_index..active_index + n_new_claims;
+		sum += batched_sum(&claims[active_range.clone()], &batch_coeffs[active_range]);
+		active_index += n_new_claims;
+
+		sum = interpolate_round_proof(round_proof.clone(), sum, challenge);
+	}
+
+	// Batch in any claims for 0-variate (ie. constant) polynomials.
+	sum += batched_sum(&claims[active_index..], &batch_coeffs[active_index..]);
+
+	let expected_sum = compute_expected_batch_composite_evaluation_multi_claim(
+		batch_coeffs.clone(),
+		claims,
+		&proof.multilinear_evals,
+	)?;
+
+	if sum != expected_sum {
+		return Err(VerificationError::IncorrectBatchEvaluation.into());
+	}
+
+	let mut challenges = round_challenges.clone();
+	if EvaluationOrder::HighToLow == evaluation_order {
+		challenges.reverse();
+	}
+
+	Ok(BatchSumcheckOutput {
+		challenges,
+		multilinear_evals: proof.multilinear_evals.clone(),
+	})
+}
+
+/// A batched zerocheck proof, consisting of the univariate skip round followed by the sumcheck
+/// over the remaining variables.
+///
+/// The univariatizing reduction that follows a zerocheck is a regular batched sumcheck and is
+/// represented by a separate [`SumcheckProof`].
+#[derive(Debug, Default, Clone, PartialEq, Eq, SerializeBytes, DeserializeBytes)]
+pub struct ZerocheckProof<F: Field> {
+	/// Batched univariatized round evaluations, excluding the zero prefix of the domain.
+	pub univariate_round_evals: Vec<F>,
+	/// Sumcheck over the eq-ind reduced zerocheck claims, starting after the skipped rounds.
+	pub sumcheck: SumcheckProof<F>,
+}
+
+/// The verifier challenges of a batched zerocheck protocol execution, not including the zerocheck
+/// challenges themselves.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ZerocheckChallenges<F: Field> {
+	/// The univariate round challenge.
+	pub univariate_challenge: F,
+	/// The sumcheck challenges; batching coefficients of the claims participating in the
+	/// univariate round come first.
+	pub sumcheck: SumcheckChallenges<F>,
+}
+
+impl<F: TowerField> ZerocheckProof<F> {
+	/// Reads a batched zerocheck proof for the given claims from the verifier transcript.
+	///
+	/// The transcript is expected to be positioned right after the zerocheck challenges have been
+	/// sampled, and is advanced exactly like [`batch_verify_zerocheck_univariate_round`] followed
+	/// by [`batch_verify_with_start`] would.
+	///
+	/// [`batch_verify_zerocheck_univariate_round`]: super::batch_verify_zerocheck_univariate_round
+	/// [`batch_verify_with_start`]: super::batch_verify_with_start
+	pub fn read<Composition, Challenger_>(
+		claims: &[ZerocheckClaim<F, Composition>],
+		skip_rounds: usize,
+		transcript: &mut VerifierTranscript<Challenger_>,
+	) -> Result<(Self, ZerocheckChallenges<F>), Error>
+	where
+		Composition: CompositionPoly<F>,
+		Challenger_: Challenger,
+	{
+		let ZerocheckSchedule {
+			univariate_cnt,
+			univariate_evals_len,
+			sumcheck,
+		} = ZerocheckSchedule::new(claims, skip_rounds)?;
+
+		let univariate_batch_coeffs = (0..univariate_cnt)
+			.map(|_| transcript.sample_labeled("zerocheck.batch_coeff"))
+			.collect();
+		let univariate_round_evals = transcript
+			.message_labeled("zerocheck.univariate_round_evals")
+			.read_scalar_slice(univariate_evals_len)?;
+		let univariate_challenge = transcript.sample_labeled("zerocheck.univariate_challenge");
+
+		let (sumcheck, sumcheck_challenges) = sumcheck.read(transcript)?;
+
+		let proof = Self {
+			univariate_round_evals,
+			sumcheck,
+		};
+		let challenges = ZerocheckChallenges {
+			univariate_challenge,
+			sumcheck: prepend_batch_coeffs(univariate_batch_coeffs, sumcheck_challenges),
+		};
+		Ok((proof, challenges))
+	}
+
+	/// Writes the batched zerocheck proof for the given claims into the prover transcript.
+	pub fn write<Composition, Challenger_>(
+		&self,
+		claims: &[ZerocheckClaim<F, Composition>],
+		skip_rounds: usize,
+		transcript: &mut ProverTranscript<Challenger_>,
+	) -> Result<ZerocheckChallenges<F>, Error>
+	where
+		Composition: CompositionPoly<F>,
+		Challenger_: Challenger,
+	{
+		let ZerocheckSchedule {
+			univariate_cnt,
+			univariate_evals_len,
+			sumcheck,
+		} = ZerocheckSchedule::new(claims, skip_rounds)?;
+
+		if self.univariate_round_evals.len() != univariate_evals_len {
+			bail!(VerificationError::IncorrectLagrangeRoundEvalsLen);
+		}
+		sumcheck.check_proof_shape(&self.sumcheck)?;
+
+		let univariate_batch_coeffs = (0..univariate_cnt)
+			.map(|_| transcript.sample_labeled("zerocheck.batch_coeff"))
+			.collect();
+		transcript
+			.message_labeled("zerocheck.univariate_round_evals")
+			.write_scalar_slice(&self.univariate_round_evals);
+		let univariate_challenge = transcript.sample_labeled("zerocheck.univariate_challenge");
+
+		let sumcheck_challenges = sumcheck.write(&self.sumcheck, transcript)?;
+
+		Ok(ZerocheckChallenges {
+			univariate_challenge,
+			sumcheck: prepend_batch_coeffs(univariate_batch_coeffs, sumcheck_challenges),
+		})
+	}
+}
+
+fn prepend_batch_coeffs<F: Field>(
+	mut batch_coeffs: Vec<F>,
+	challenges: SumcheckChallenges<F>,
+) -> SumcheckChallenges<F> {
+	batch_coeffs.extend(challenges.batch_coeffs);
+	SumcheckChallenges {
+		batch_coeffs,
+		round_challenges: challenges.round_challenges,
+	}
+}
+
+fn batched_sum<F: Field, Composition: CompositionPoly<F>>(
+	claims: &[SumcheckClaim<F, Composition>],
+	batch_coeffs: &[F],
+) -> F {
+	iter::zip(claims, batch_coeffs)
+		.map(|(claim, &batch_coeff)| {
+			batch_weighted_value(
+				batch_coeff,
+				claim
+					.composite_sums()
+					.iter()
+					.map(|inner_claim| inner_claim.sum),
+			)
+		})
+		.sum()
+}
+
+/// The part of a sumcheck claim that determines the layout of its proof.
+#[derive(Debug, Clone, Copy)]
+struct ClaimShape {
+	n_vars: usize,
+	n_multilinears: usize,
+	max_degree: usize,
+}
+
+fn claim_shapes<F: Field, Composition: CompositionPoly<F>>(
+	claims: &[SumcheckClaim<F, Composition>],
+) -> Vec<ClaimShape> {
+	claims
+		.iter()
+		.map(|claim| ClaimShape {
+			n_vars: claim.n_vars(),
+			n_multilinears: claim.n_multilinears(),
+			max_degree: claim.max_individual_degree(),
+		})
+		.collect()
+}
+
+/// Mirrors [`BatchVerifyStart`](super::verify::BatchVerifyStart) without the claimed sum.
+#[derive(Debug, Default, Clone, Copy)]
+struct ScheduleStart {
+	n_prebatched: usize,
+	max_degree: usize,
+	skip_rounds: usize,
+}
+
+/// The order in which a batched sumcheck samples challenges and exchanges messages.
+#[derive(Debug)]
+struct Schedule {
+	/// For each round, the number of batching coefficients sampled before the round message and
+	/// the number of coefficients in the round message.
+	rounds: Vec<(usize, usize)>,
+	/// The number of batching coefficients sampled for 0-variate claims after the last round.
+	n_trailing_batch_coeffs: usize,
+	/// The number of multilinear evaluations of each claim.
+	n_multilinears: Vec<usize>,
+}
+
+impl Schedule {
+	fn new(shapes: &[ClaimShape], start: ScheduleStart) -> Result<Self, Error> {
+		let ScheduleStart {
+			n_prebatched,
+			mut max_degree,
+			skip_rounds,
+		} = start;
+
+		// Check that the claims are in descending order by n_vars
+		if !is_sorted_ascending(shapes.iter().map(|shape| shape.n_vars).rev()) {
+			bail!(Error::ClaimsOutOfOrder);
+		}
+
+		if n_prebatched > shapes.len() {
+			bail!(Error::TooManyPrebatchedCoeffs);
+		}
+
+		let n_rounds = shapes.iter().map(|shape| shape.n_vars).max().unwrap_or(0);
+
+		if skip_rounds > n_rounds {
+			bail!(VerificationError::IncorrectSkippedRoundsCount);
+		}
+
+		let mut active_index = n_prebatched;
+		let rounds = (skip_rounds..n_rounds)
+			.map(|round_no| {
+				let n_vars = n_rounds - round_no;
+				let n_new_claims = shapes[active_index..]
+					.iter()
+					.take_while(|shape| shape.n_vars == n_vars)
+					.count();
+				max_degree = shapes[active_index..active_index + n_new_claims]
+					.iter()
+					.map(|shape| shape.max_degree)
+					.fold(max_degree, usize::max);
+				active_index += n_new_claims;
+				(n_new_claims, max_degree)
+			})
+			.collect();
+
+		Ok(Self {
+			rounds,
+			n_trailing_batch_coeffs: shapes.len() - active_index,
+			n_multilinears: shapes.iter().map(|shape| shape.n_multilinears).collect(),
+		})
+	}
+
+	fn check_proof_shape<F: Field>(&self, proof: &SumcheckProof<F>) -> Result<(), Error> {
+		if proof.rounds.len() != self.rounds.len() {
+			bail!(VerificationError::NumberOfRounds);
+		}
+
+		for (round, (round_proof, &(_, n_coeffs))) in
+			iter::zip(&proof.rounds, &self.rounds).enumerate()
+		{
+			if round_proof.coeffs().len() != n_coeffs {
+				bail!(VerificationError::NumberOfCoefficients {
+					round,
+					expected: n_coeffs,
+				});
+			}
+		}
+
+		if proof.multilinear_evals.len() != self.n_multilinears.len() {
+			bail!(VerificationError::NumberOfFinalEvaluations);
+		}
+
+		if iter::zip(&proof.multilinear_evals, &self.n_multilinears)
+			.any(|(evals, &n_multilinears)| evals.len() != n_multilinears)
+		{
+			bail!(VerificationError::NumberOfMultilinearEvals);
+		}
+
+		Ok(())
+	}
+
+	fn read<F, Challenger_>(
+		&self,
+		transcript: &mut VerifierTranscript<Challenger_>,
+	) -> Result<(SumcheckProof<F>, SumcheckChallenges<F>), Error>
+	where
+		F: TowerField,
+		Challenger_: Challenger,
+	{
+		// An empty batch has an empty proof, see `batch_prove_with_start`
+		if self.n_multilinears.is_empty() {
+			return Ok((SumcheckProof::default(), SumcheckChallenges::default()));
+		}
+
+		let mut batch_coeffs = Vec::new();
+		let mut round_challenges = Vec::with_capacity(self.rounds.len());
+		let mut rounds = Vec::with_capacity(self.rounds.len());
+		for &(n_new_claims, n_coeffs) in &self.rounds {
+			batch_coeffs.extend(
+				(0..n_new_claims).map(|_| transcript.sample_labeled::<F>("sumcheck.batch_coeff")),
+			);
+
+			let coeffs = transcript
+				.message_labeled("sumcheck.round_coeffs")
+				.read_scalar_slice(n_coeffs)?;
+			rounds.push(RoundProof(RoundCoeffs(coeffs)));
+
+			round_challenges.push(transcript.sample_labeled("sumcheck.round_challenge"));
+		}
+
+		batch_coeffs.extend(
+			(0..self.n_trailing_batch_coeffs)
+				.map(|_| transcript.sample_labeled::<F>("sumcheck.batch_coeff")),
+		);
+
+		let mut reader = transcript.message_labeled("sumcheck.multilinear_evals");
+		let multilinear_evals = self
+			.n_multilinears
+			.iter()
+			.map(|&n_multilinears| reader.read_scalar_slice(n_multilinears))
+			.collect::<Result<_, _>>()?;
+
+		let proof = SumcheckProof {
+			rounds,
+			multilinear_evals,
+		};
+		let challenges = SumcheckChallenges {
+			batch_coeffs,
+			round_challenges,
+		};
+		Ok((proof, challenges))
+	}
+
+	fn write<F, Challenger_>(
+		&self,
+		proof: &SumcheckProof<F>,
+		transcript: &mut ProverTranscript<Challenger_>,
+	) -> Result<SumcheckChallenges<F>, Error>
+	where
+		F: TowerField,
+		Challenger_: Challenger,
+	{
+		self.check_proof_shape(proof)?;
+
+		if self.n_multilinears.is_empty() {
+			return Ok(SumcheckChallenges::default());
+		}
+
+		let mut batch_coeffs = Vec::new();
+		let mut round_challenges = Vec::with_capacity(self.rounds.len());
+		for (&(n_new_claims, _), round_proof) in iter::zip(&self.rounds, &proof.rounds) {
+			batch_coeffs.extend(
+				(0..n_new_claims).map(|_| transcript.sample_labeled::<F>("sumcheck.batch_coeff")),
+			);
+
+			transcript
+				.message_labeled("sumcheck.round_coeffs")
+				.write_scalar_slice(round_proof.coeffs());
+
+			round_challenges.push(transcript.sample_labeled("sumcheck.round_challenge"));
+		}
+
+		batch_coeffs.extend(
+			(0..self.n_trailing_batch_coeffs)
+				.map(|_| transcript.sample_labeled::<F>("sumcheck.batch_coeff")),
+		);
+
+		let mut writer = transcript.message_labeled("sumcheck.multilinear_evals");
+		for multilinear_evals in &proof.multilinear_evals {
+			writer.write_scalar_slice(multilinear_evals);
+		}
+
+		Ok(SumcheckChallenges {
+			batch_coeffs,
+			round_challenges,
+		})
+	}
+}
+
+/// The layout of a batched zerocheck proof, see [`ZerocheckProof`].
+struct ZerocheckSchedule {
+	univariate_cnt: usize,
+	univariate_evals_len: usize,
+	sumcheck: Schedule,
+}
+
+impl ZerocheckSchedule {
+	fn new<F: Field, Composition: CompositionPoly<F>>(
+		claims: &[ZerocheckClaim<F, Composition>],
+		skip_rounds: usize,
+	) -> Result<Self, Error> {
+		let max_n_vars = claims.first().map(|claim| claim.n_vars()).unwrap_or(0);
+
+		if skip_rounds > max_n_vars {
+			bail!(VerificationError::IncorrectSkippedRoundsCount);
+		}
+
+		let univariate_cnt =
+			claims.partition_point(|claim| claim.n_vars() > max_n_vars - skip_rounds);
+		let univariate_claims = &claims[..univariate_cnt];
+
+		let (max_domain_size, zeros_prefix_len) =
+			univariate_round_domain(univariate_claims, skip_rounds)?;
+
+		let max_degree = univariate_claims
+			.iter()
+			.map(|claim| claim.max_individual_degree() + 1)
+			.max()
+			.unwrap_or(0);
+
+		let eq_ind_sumcheck_claims = reduce_to_eq_ind_sumchecks(claims)?;
+		let regular_sumcheck_claims = reduce_to_regular_sumchecks(&eq_ind_sumcheck_claims)?;
+
+		let start = ScheduleStart {
+			n_prebatched: univariate_cnt,
+			max_degree,
+			skip_rounds,
+		};
+		let sumcheck = Schedule::new(&claim_shapes(&regular_sumcheck_claims), start)?;
+
+		Ok(Self {
+			univariate_cnt,
+			univariate_evals_len: max_domain_size - zeros_prefix_len,
+			sumcheck,
+		})
+	}
+}
@@ -8,7 +8,7 @@ use binius_utils::{bail, sorting::is_sorted_ascending};
 use tracing::instrument;
 
 use crate::{
-	fiat_shamir::{CanSample, Challenger},
+	fiat_shamir::Challenger,
 	protocols::sumcheck::{
 		common::{BatchSumcheckOutput, RoundCoeffs},
 		error::Error,
@@ -186,7 +186,7 @@ where
 				break;
 			}
 
-			let next_batch_coeff: F = transcript.sample();
+			let next_batch_coeff: F = transcript.sample_labeled("sumcheck.batch_coeff");
 			batch_coeffs.push(next_batch_coeff);
 			active_index += 1;
 		}
@@ -202,10 +202,10 @@ where
 
 		let round_proof = round_coeffs.truncate();
 		transcript
-			.message()
+			.message_labeled("sumcheck.round_coeffs")
 			.write_scalar_slice(round_proof.coeffs());
 
-		let challenge = transcript.sample();
+		let challenge = transcript.sample_labeled("sumcheck.round_challenge");
 		challenges.push(challenge);
 
 		for prover in &mut provers[..active_index] {
@@ -217,7 +217,7 @@ where
 	while let Some(prover) = provers.get(active_index) {
 		debug_assert_eq!(prover.n_vars(), 0);
 
-		let _next_batch_coeff: F = transcript.sample();
+		let _next_batch_coeff: F = transcript.sample_labeled("sumcheck.batch_coeff");
 		active_index += 1;
 	}
 
@@ -226,7 +226,7 @@ where
 		.map(|prover| Box::new(prover).finish())
 		.collect::<Result<Vec<_>, _>>()?;
 
-	let mut writer = transcript.message();
+	let mut writer = transcript.message_labeled("sumcheck.multilinear_evals");
 	for multilinear_evals in &multilinear_evals {
 		writer.write_scalar_slice(multilinear_evals);
 	}
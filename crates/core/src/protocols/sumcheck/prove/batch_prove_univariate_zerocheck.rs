@@ -5,7 +5,7 @@ use binius_utils::{bail, sorting::is_sorted_ascending};
 use tracing::instrument;
 
 use crate::{
-	fiat_shamir::{CanSample, Challenger},
+	fiat_shamir::Challenger,
 	protocols::sumcheck::{
 		prove::{batch_prove::BatchProveStart, SumcheckProver},
 		univariate::LagrangeRoundEvals,
@@ -132,7 +132,7 @@ where
 	let mut batch_coeffs = Vec::with_capacity(provers.len());
 	let mut round_evals = LagrangeRoundEvals::zeros(max_domain_size);
 	for prover in &mut provers {
-		let next_batch_coeff = transcript.sample();
+		let next_batch_coeff = transcript.sample_labeled("zerocheck.batch_coeff");
 		batch_coeffs.push(next_batch_coeff);
 
 		let prover_round_evals = prover.execute_univariate_round(
@@ -149,8 +149,10 @@ where
 		bail!(Error::IncorrectZerosPrefixLen);
 	}
 
-	transcript.message().write_scalar_slice(&round_evals.evals);
-	let univariate_challenge = transcript.sample();
+	transcript
+		.message_labeled("zerocheck.univariate_round_evals")
+		.write_scalar_slice(&round_evals.evals);
+	let univariate_challenge = transcript.sample_labeled("zerocheck.univariate_challenge");
 
 	let mut reduction_provers = Vec::with_capacity(provers.len());
 	for prover in provers {
@@ -1,14 +1,115 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+use std::iter;
+
 use binius_field::{
 	packed::{get_packed_slice, packed_from_fn_with_offset},
-	PackedField,
+	BinaryField, PackedField,
 };
 use binius_hal::ComputationBackend;
-use binius_math::EvaluationOrder;
+use binius_math::{BinarySubspace, EvaluationOrder, InterpolationDomain};
 use binius_maybe_rayon::prelude::*;
+use binius_ntt::{NovelPolyArithmetic, OddInterpolate, SingleThreadedNTT};
+use binius_utils::{bail, checked_arithmetics::log2_ceil_usize};
 use tracing::instrument;
 
+use crate::protocols::sumcheck::Error;
+
+/// Domain size from which [`NTTInterpolationDomain`] is used instead of the Vandermonde inverse of
+/// an [`InterpolationDomain`]. Below it, the quadratic matrix-vector product with subfield
+/// entries is cheaper than the NTT and the basis conversion.
+pub const NTT_INTERPOLATION_MIN_DOMAIN_SIZE: usize = 64;
+
+/// Interpolation of round polynomials over large domains using an additive NTT.
+///
+/// [`InterpolationDomain::interpolate`] multiplies by a precomputed inverse Vandermonde matrix,
+/// which takes quadratic time in the domain size. When the finite points of the domain are the
+/// lexicographic prefix of the canonical `FDomain` subspace, as produced by
+/// [`DefaultEvaluationDomainFactory`](binius_math::DefaultEvaluationDomainFactory), the values
+/// can instead be interpolated into the novel polynomial basis with [`OddInterpolate`] and
+/// converted to monomial coefficients with [`NovelPolyArithmetic`].
+pub struct NTTInterpolationDomain<F: BinaryField> {
+	ntt: SingleThreadedNTT<F>,
+	odd_interpolate: OddInterpolate<F>,
+	n_finite_points: usize,
+	/// Values of $X^m$ on the finite points, where $m$ is their number, if the domain contains
+	/// the Karatsuba infinity point.
+	infinity_monomial_evals: Option<Vec<F>>,
+}
+
+impl<F: BinaryField> NTTInterpolationDomain<F> {
+	/// Returns `None` if the finite points of `domain` are not a prefix of the canonical subspace.
+	pub fn new<FDomain>(domain: &InterpolationDomain<FDomain>) -> Result<Option<Self>, Error>
+	where
+		FDomain: BinaryField,
+		F: From<FDomain>,
+	{
+		let finite_points = domain.finite_points();
+		let n_finite_points = finite_points.len();
+		// Additive NTTs are defined over subspaces of positive dimension.
+		let log_n = log2_ceil_usize(n_finite_points).max(1);
+
+		let Ok(subspace) = BinarySubspace::<FDomain>::with_dim(log_n) else {
+			return Ok(None);
+		};
+
+		if !iter::zip(subspace.iter(), finite_points)
+			.all(|(point, &finite_point)| point == finite_point)
+		{
+			return Ok(None);
+		}
+
+		let ntt = SingleThreadedNTT::with_domain_field::<FDomain>(log_n)?;
+
+		let ell = n_finite_points.trailing_zeros() as usize;
+		let odd_interpolate = OddInterpolate::with_ntt(n_finite_points >> ell, ell, &ntt)?;
+
+		let infinity_monomial_evals = domain.with_infinity().then(|| {
+			finite_points
+				.iter()
+				.map(|&point| F::from(point).pow(n_finite_points as u64))
+				.collect()
+		});
+
+		Ok(Some(Self {
+			ntt,
+			odd_interpolate,
+			n_finite_points,
+			infinity_monomial_evals,
+		}))
+	}
+
+	/// Interpolates the monomial coefficients from values in the layout of
+	/// [`InterpolationDomain::interpolate`]: the finite points first, followed by the value at
+	/// infinity if present.
+	pub fn interpolate(&self, values: &[F]) -> Result<Vec<F>, Error> {
+		let size = self.n_finite_points + usize::from(self.infinity_monomial_evals.is_some());
+		if values.len() != size {
+			bail!(binius_math::Error::ExtrapolateNumberOfEvaluations);
+		}
+
+		let (finite_values, infinity_value) = values.split_at(self.n_finite_points);
+
+		// The value at infinity is the leading coefficient; subtracting its monomial leaves a
+		// polynomial of degree less than the number of finite points.
+		let mut coeffs = finite_values.to_vec();
+		if let (Some(&leading_coeff), Some(monomial_evals)) =
+			(infinity_value.first(), &self.infinity_monomial_evals)
+		{
+			for (coeff, &monomial_eval) in iter::zip(&mut coeffs, monomial_evals) {
+				*coeff -= leading_coeff * monomial_eval;
+			}
+		}
+
+		self.odd_interpolate
+			.inverse_transform(&self.ntt, &mut coeffs)?;
+
+		let mut coeffs = NovelPolyArithmetic::new(&self.ntt).novel_to_monomial(&coeffs)?;
+		coeffs.extend_from_slice(infinity_value);
+		Ok(coeffs)
+	}
+}
+
 #[instrument(skip_all, level = "debug")]
 pub fn fold_partial_eq_ind<P, Backend>(
 	evaluation_order: EvaluationOrder,
@@ -71,3 +172,64 @@ pub fn fold_partial_eq_ind<P, Backend>(
 		*partial_eq_ind_evals = Backend::to_hal_slice(updated_evals);
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use binius_field::{AESTowerField8b, BinaryField128b, BinaryField8b, Field};
+	use binius_math::{
+		DefaultEvaluationDomainFactory, EvaluationDomain, EvaluationDomainFactory,
+		IsomorphicEvaluationDomainFactory,
+	};
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+
+	#[test]
+	fn test_ntt_interpolation_matches_vandermonde() {
+		type F = BinaryField128b;
+		type FDomain = BinaryField8b;
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let factory = DefaultEvaluationDomainFactory::<FDomain>::default();
+
+		for size in (1..20).chain([63, 64, 65, 96]) {
+			let domain = InterpolationDomain::from(factory.create(size).unwrap());
+			let ntt_domain = NTTInterpolationDomain::<F>::new(&domain).unwrap().unwrap();
+
+			let values = repeat_with(|| <F as Field>::random(&mut rng))
+				.take(size)
+				.collect::<Vec<_>>();
+			assert_eq!(
+				ntt_domain.interpolate(&values).unwrap(),
+				domain.interpolate(&values).unwrap()
+			);
+		}
+
+		// Domains without the infinity point.
+		for size in [5, 64, 100] {
+			let points = factory.create(size + 1).unwrap().finite_points()[..size].to_vec();
+			let domain =
+				InterpolationDomain::from(EvaluationDomain::from_points(points, false).unwrap());
+			let ntt_domain = NTTInterpolationDomain::<F>::new(&domain).unwrap().unwrap();
+
+			let values = repeat_with(|| <F as Field>::random(&mut rng))
+				.take(size)
+				.collect::<Vec<_>>();
+			assert_eq!(
+				ntt_domain.interpolate(&values).unwrap(),
+				domain.interpolate(&values).unwrap()
+			);
+		}
+	}
+
+	#[test]
+	fn test_ntt_interpolation_requires_subspace_prefix() {
+		let factory = IsomorphicEvaluationDomainFactory::<BinaryField8b>::default();
+		let domain: InterpolationDomain<AESTowerField8b> = factory.create(64).unwrap().into();
+		assert!(NTTInterpolationDomain::<AESTowerField8b>::new(&domain)
+			.unwrap()
+			.is_none());
+	}
+}
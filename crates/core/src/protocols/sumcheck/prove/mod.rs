@@ -8,6 +8,7 @@ pub mod front_loaded;
 pub mod oracles;
 pub mod prover_state;
 pub mod regular_sumcheck;
+pub mod small_field_sumcheck;
 pub mod univariate;
 pub mod zerocheck;
 
@@ -20,5 +21,6 @@ pub use oracles::{
 };
 pub use prover_state::{ProverState, SumcheckInterpolator};
 pub use regular_sumcheck::RegularSumcheckProver;
+pub use small_field_sumcheck::SmallFieldSumcheckProver;
 pub use univariate::{reduce_to_skipped_projection, univariatizing_reduction_prover};
 pub use zerocheck::UnivariateZerocheck;
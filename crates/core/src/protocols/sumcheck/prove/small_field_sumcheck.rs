@@ -0,0 +1,695 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{iter, marker::PhantomData};
+
+use binius_field::{
+	packed::{get_packed_slice, set_packed_slice},
+	util::powers,
+	BinaryField, ExtensionField, Field, PackedExtension, PackedField, PackedSubfield, TowerField,
+};
+use binius_hal::ComputationBackend;
+use binius_math::{
+	evaluate_univariate, CompositionPoly, EvaluationDomain, EvaluationDomainFactory,
+	EvaluationOrder, InterpolationDomain, MLEDirectAdapter, MultilinearPoly, MultilinearQuery,
+};
+use binius_maybe_rayon::prelude::*;
+use binius_utils::bail;
+use bytemuck::zeroed_vec;
+use itertools::izip;
+use stackalloc::stackalloc_with_iter;
+use tracing::instrument;
+
+use super::{
+	batch_prove::SumcheckProver,
+	common::{NTTInterpolationDomain, NTT_INTERPOLATION_MIN_DOMAIN_SIZE},
+	regular_sumcheck::RegularSumcheckProver,
+};
+use crate::protocols::sumcheck::{
+	common::{
+		equal_n_vars_check, immediate_switchover_heuristic, small_field_embedding_degree_check,
+		CompositeSumClaim, RoundCoeffs,
+	},
+	error::Error,
+};
+
+/// A regular sumcheck prover that keeps the first rounds in a small field.
+///
+/// When all multilinears have evaluations in a small field `FBase`, the first rounds of the
+/// regular sumcheck can be computed without ever folding the witness over the extension field.
+/// Let $r_0, \ldots, r_{i-1}$ be the challenges sampled so far, then the round polynomial
+///
+/// $$
+/// R_i(X) = \sum_{x \in B_{n - i - 1}} C(M_0(r_0, \ldots, r_{i-1}, X, x), \ldots)
+/// $$
+///
+/// is a polynomial of individual degree at most $d$ in each of the challenges. The prover
+/// precomputes its values over the $(d + 1)^{i + 1}$ grid of small domain points (using only
+/// `FBase` arithmetic on the hypercube evaluations) and recovers the evaluations at the actual
+/// challenges by taking an inner product with the tensor product of Lagrange basis polynomials
+/// evaluated at the challenges. This is the "small value" optimization of
+/// [Bagad, Dao, Domb & Thaler 2025][BDDT25], building upon the delayed folding of [Gruen24].
+///
+/// The cost of a small field round grows geometrically, as $((d + 1) / 2)^i$, so after a number of
+/// rounds determined by a switchover function (see
+/// [`small_field_switchover_heuristic`](crate::protocols::sumcheck::small_field_switchover_heuristic))
+/// the multilinears are partially evaluated at the sampled challenges and the remaining rounds
+/// are delegated to a [`RegularSumcheckProver`] over the extension field.
+///
+/// The round polynomials are identical to the ones computed by [`RegularSumcheckProver`], which
+/// means that this prover is a drop-in replacement that does not affect the verifier.
+///
+/// [BDDT25]: <https://eprint.iacr.org/2025/1117>
+/// [Gruen24]: <https://eprint.iacr.org/2024/108>
+pub struct SmallFieldSumcheckProver<'a, FBase, FDomain, DomainFactory, P, Composition, M, Backend>
+where
+	FDomain: Field,
+	P: PackedField<Scalar: BinaryField>,
+	M: MultilinearPoly<P> + Send + Sync,
+	Backend: ComputationBackend,
+{
+	n_vars: usize,
+	evaluation_order: EvaluationOrder,
+	state: SmallFieldProverState<'a, FDomain, DomainFactory, P, Composition, M, Backend>,
+	_base_marker: PhantomData<FBase>,
+}
+
+enum SmallFieldProverState<'a, FDomain, DomainFactory, P, Composition, M, Backend>
+where
+	FDomain: Field,
+	P: PackedField<Scalar: BinaryField>,
+	M: MultilinearPoly<P> + Send + Sync,
+	Backend: ComputationBackend,
+{
+	SmallField(SmallFieldRounds<'a, FDomain, DomainFactory, P, Composition, M, Backend>),
+	Regular(RegularSumcheckProver<'a, FDomain, P, Composition, MLEDirectAdapter<P>, Backend>),
+	// Transient placeholder used while switching between the two states.
+	Switching,
+}
+
+struct SmallFieldRounds<'a, FDomain, DomainFactory, P, Composition, M, Backend>
+where
+	FDomain: Field,
+	P: PackedField<Scalar: BinaryField>,
+	Backend: ComputationBackend,
+{
+	small_field_rounds: usize,
+	multilinears: Vec<M>,
+	compositions: Vec<Composition>,
+	/// Small field grids of `degree + 1` points, one per composition.
+	grids: Vec<InterpolationDomain<FDomain>>,
+	/// NTT-based interpolation for the grids of large degree compositions.
+	ntt_grids: Vec<Option<NTTInterpolationDomain<P::Scalar>>>,
+	challenges: Vec<P::Scalar>,
+	sums: Vec<P::Scalar>,
+	round_coeffs: Option<Vec<RoundCoeffs<P::Scalar>>>,
+	evaluation_domain_factory: DomainFactory,
+	backend: &'a Backend,
+}
+
+impl<'a, F, FBase, FDomain, DomainFactory, P, Composition, M, Backend>
+	SmallFieldSumcheckProver<'a, FBase, FDomain, DomainFactory, P, Composition, M, Backend>
+where
+	F: TowerField + ExtensionField<FBase> + ExtensionField<FDomain>,
+	FBase: ExtensionField<FDomain>,
+	FDomain: BinaryField,
+	DomainFactory: EvaluationDomainFactory<FDomain>,
+	P: PackedField<Scalar = F>
+		+ PackedExtension<F, PackedSubfield = P>
+		+ PackedExtension<FBase>
+		+ PackedExtension<FDomain>,
+	Composition: CompositionPoly<P> + CompositionPoly<PackedSubfield<P, FBase>>,
+	M: MultilinearPoly<P> + Send + Sync,
+	Backend: ComputationBackend,
+{
+	/// Constructs a new small field sumcheck prover.
+	///
+	/// The `switchover_fn` maps the extension degree of `F` over `FBase` and the maximum
+	/// composition degree to the number of rounds to be computed in the small field.
+	#[instrument(skip_all, level = "debug", name = "SmallFieldSumcheckProver::new")]
+	pub fn new(
+		evaluation_order: EvaluationOrder,
+		multilinears: Vec<M>,
+		composite_claims: impl IntoIterator<Item = CompositeSumClaim<F, Composition>>,
+		evaluation_domain_factory: DomainFactory,
+		switchover_fn: impl Fn(usize, usize) -> usize,
+		backend: &'a Backend,
+	) -> Result<Self, Error> {
+		let n_vars = equal_n_vars_check(&multilinears)?;
+		small_field_embedding_degree_check::<_, FBase, P, _>(&multilinears)?;
+
+		let composite_claims = composite_claims.into_iter().collect::<Vec<_>>();
+
+		for claim in &composite_claims {
+			let composition_n_vars = CompositionPoly::<P>::n_vars(&claim.composition);
+			if composition_n_vars != multilinears.len() {
+				bail!(Error::InvalidComposition {
+					actual: composition_n_vars,
+					expected: multilinears.len(),
+				});
+			}
+		}
+
+		let max_degree = composite_claims
+			.iter()
+			.map(|claim| CompositionPoly::<P>::degree(&claim.composition))
+			.max()
+			.unwrap_or(0);
+
+		let small_field_rounds =
+			switchover_fn(<F as ExtensionField<FBase>>::DEGREE, max_degree).min(n_vars);
+
+		let grids = composite_claims
+			.iter()
+			.map(|claim| {
+				let degree = CompositionPoly::<P>::degree(&claim.composition);
+				small_field_grid(&evaluation_domain_factory, degree)
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+
+		let ntt_grids = grids
+			.iter()
+			.map(|grid| {
+				if grid.size() >= NTT_INTERPOLATION_MIN_DOMAIN_SIZE {
+					NTTInterpolationDomain::new(grid)
+				} else {
+					Ok(None)
+				}
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+
+		let (compositions, sums) = composite_claims
+			.into_iter()
+			.map(|claim| (claim.composition, claim.sum))
+			.unzip();
+
+		let mut prover = Self {
+			n_vars,
+			evaluation_order,
+			state: SmallFieldProverState::SmallField(SmallFieldRounds {
+				small_field_rounds,
+				multilinears,
+				compositions,
+				grids,
+				ntt_grids,
+				challenges: Vec::new(),
+				sums,
+				round_coeffs: None,
+				evaluation_domain_factory,
+				backend,
+			}),
+			_base_marker: PhantomData,
+		};
+
+		if small_field_rounds == 0 {
+			prover.switch_to_regular()?;
+		}
+
+		Ok(prover)
+	}
+
+	/// Partially evaluates the multilinears at the sampled challenges and hands over the
+	/// remaining rounds to a [`RegularSumcheckProver`].
+	#[instrument(
+		skip_all,
+		level = "debug",
+		name = "SmallFieldSumcheckProver::switch_to_regular"
+	)]
+	fn switch_to_regular(&mut self) -> Result<(), Error> {
+		let SmallFieldProverState::SmallField(rounds) =
+			std::mem::replace(&mut self.state, SmallFieldProverState::Switching)
+		else {
+			bail!(Error::ExpectedFinish);
+		};
+
+		let SmallFieldRounds {
+			multilinears,
+			compositions,
+			challenges,
+			sums,
+			evaluation_domain_factory,
+			backend,
+			..
+		} = rounds;
+
+		let query = match self.evaluation_order {
+			EvaluationOrder::LowToHigh => MultilinearQuery::<P>::expand(&challenges),
+			EvaluationOrder::HighToLow => {
+				let reversed_challenges = challenges.iter().rev().copied().collect::<Vec<_>>();
+				MultilinearQuery::<P>::expand(&reversed_challenges)
+			}
+		};
+
+		let folded_multilinears = multilinears
+			.par_iter()
+			.map(|multilinear| {
+				let folded = match self.evaluation_order {
+					EvaluationOrder::LowToHigh => multilinear.evaluate_partial_low(query.to_ref()),
+					EvaluationOrder::HighToLow => multilinear.evaluate_partial_high(query.to_ref()),
+				}?;
+				Ok(MLEDirectAdapter::from(folded))
+			})
+			.collect::<Result<Vec<_>, Error>>()?;
+
+		let composite_claims = iter::zip(compositions, sums)
+			.map(|(composition, sum)| CompositeSumClaim { composition, sum });
+
+		let regular_prover = RegularSumcheckProver::new(
+			self.evaluation_order,
+			folded_multilinears,
+			composite_claims,
+			evaluation_domain_factory,
+			immediate_switchover_heuristic,
+			backend,
+		)?;
+
+		self.state = SmallFieldProverState::Regular(regular_prover);
+		Ok(())
+	}
+}
+
+impl<F, FBase, FDomain, DomainFactory, P, Composition, M, Backend> SumcheckProver<F>
+	for SmallFieldSumcheckProver<'_, FBase, FDomain, DomainFactory, P, Composition, M, Backend>
+where
+	F: TowerField + ExtensionField<FBase> + ExtensionField<FDomain>,
+	FBase: ExtensionField<FDomain>,
+	FDomain: BinaryField,
+	DomainFactory: EvaluationDomainFactory<FDomain>,
+	P: PackedField<Scalar = F>
+		+ PackedExtension<F, PackedSubfield = P>
+		+ PackedExtension<FBase>
+		+ PackedExtension<FDomain>,
+	Composition: CompositionPoly<P> + CompositionPoly<PackedSubfield<P, FBase>>,
+	M: MultilinearPoly<P> + Send + Sync,
+	Backend: ComputationBackend,
+{
+	fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	fn evaluation_order(&self) -> EvaluationOrder {
+		self.evaluation_order
+	}
+
+	#[instrument("SmallFieldSumcheckProver::fold", skip_all, level = "debug")]
+	fn fold(&mut self, challenge: F) -> Result<(), Error> {
+		let rounds = match &mut self.state {
+			SmallFieldProverState::SmallField(rounds) => rounds,
+			SmallFieldProverState::Regular(regular_prover) => {
+				return regular_prover.fold(challenge);
+			}
+			SmallFieldProverState::Switching => unreachable!("switching is never left incomplete"),
+		};
+
+		let Some(round_coeffs) = rounds.round_coeffs.take() else {
+			bail!(Error::ExpectedExecution);
+		};
+
+		rounds.sums = round_coeffs
+			.iter()
+			.map(|coeffs| evaluate_univariate(&coeffs.0, challenge))
+			.collect();
+		rounds.challenges.push(challenge);
+
+		if rounds.challenges.len() == rounds.small_field_rounds {
+			self.switch_to_regular()?;
+		}
+
+		Ok(())
+	}
+
+	#[instrument("SmallFieldSumcheckProver::execute", skip_all, level = "debug")]
+	fn execute(&mut self, batch_coeff: F) -> Result<RoundCoeffs<F>, Error> {
+		let rounds = match &mut self.state {
+			SmallFieldProverState::SmallField(rounds) => rounds,
+			SmallFieldProverState::Regular(regular_prover) => {
+				return regular_prover.execute(batch_coeff);
+			}
+			SmallFieldProverState::Switching => unreachable!("switching is never left incomplete"),
+		};
+
+		if rounds.round_coeffs.is_some() {
+			bail!(Error::ExpectedFold);
+		}
+
+		let grid_evals = calculate_small_field_round_evals::<FBase, _, _, _, _, _>(
+			self.evaluation_order,
+			self.n_vars,
+			rounds.challenges.len(),
+			&rounds.multilinears,
+			&rounds.compositions,
+			&rounds.grids,
+		)?;
+
+		let round_coeffs = izip!(&rounds.grids, &rounds.ntt_grids, grid_evals)
+			.map(|(grid, ntt_grid, grid_evals)| {
+				let lagrange_tensor = lagrange_evals_tensor(grid, &rounds.challenges);
+
+				// Grid evaluations are laid out with the current round variable at the
+				// slowest stride, thus each chunk corresponds to a single evaluation point.
+				let round_evals = grid_evals
+					.chunks_exact(lagrange_tensor.len())
+					.map(|chunk| {
+						iter::zip(&lagrange_tensor, chunk)
+							.map(|(&weight, &eval)| weight * eval)
+							.sum::<F>()
+					})
+					.collect::<Vec<_>>();
+
+				let coeffs = match ntt_grid {
+					Some(ntt_grid) => ntt_grid.interpolate(&round_evals)?,
+					None => grid.interpolate(&round_evals)?,
+				};
+				Ok(RoundCoeffs(coeffs))
+			})
+			.collect::<Result<Vec<_>, Error>>()?;
+
+		let batched_coeffs = iter::zip(&round_coeffs, powers(batch_coeff))
+			.map(|(coeffs, scalar)| coeffs.clone() * scalar)
+			.fold(RoundCoeffs::default(), |accum, coeffs| accum + &coeffs);
+
+		rounds.round_coeffs = Some(round_coeffs);
+		Ok(batched_coeffs)
+	}
+
+	fn finish(self: Box<Self>) -> Result<Vec<F>, Error> {
+		match self.state {
+			SmallFieldProverState::SmallField(rounds) => {
+				if rounds.round_coeffs.is_some() {
+					bail!(Error::ExpectedFold);
+				}
+				bail!(Error::ExpectedExecution);
+			}
+			SmallFieldProverState::Regular(regular_prover) => Box::new(regular_prover).finish(),
+			SmallFieldProverState::Switching => unreachable!("switching is never left incomplete"),
+		}
+	}
+}
+
+/// Creates a domain of `degree + 1` finite points, which starts with zero and one.
+fn small_field_grid<FDomain: Field>(
+	evaluation_domain_factory: &impl EvaluationDomainFactory<FDomain>,
+	degree: usize,
+) -> Result<InterpolationDomain<FDomain>, Error> {
+	// The factory appends the Karatsuba infinity point to domains of size three and larger.
+	let domain = evaluation_domain_factory.create(if degree > 1 { degree + 2 } else { 2 })?;
+	let finite_points = domain.finite_points()[..degree + 1].to_vec();
+	Ok(EvaluationDomain::from_points(finite_points, false)?.into())
+}
+
+/// Tensor product of Lagrange basis evaluations over `grid`, with the first challenge
+/// corresponding to the fastest stride.
+fn lagrange_evals_tensor<F, FDomain>(
+	grid: &InterpolationDomain<FDomain>,
+	challenges: &[F],
+) -> Vec<F>
+where
+	F: ExtensionField<FDomain>,
+	FDomain: Field,
+{
+	let grid = EvaluationDomain::from_points(grid.finite_points().to_vec(), false)
+		.expect("grid points are distinct by construction");
+
+	challenges.iter().fold(vec![F::ONE], |tensor, &challenge| {
+		grid.lagrange_evals(challenge)
+			.into_iter()
+			.flat_map(|lagrange_eval| tensor.iter().map(move |&weight| weight * lagrange_eval))
+			.collect()
+	})
+}
+
+/// Extrapolate the evaluations of a multilinear over the `n_coords`-dimensional boolean hypercube
+/// to the `n_coords`-dimensional grid of points, with the first coordinate at the fastest stride.
+fn extrapolate_to_grid<FBase, FDomain>(
+	grid: &[FDomain],
+	n_coords: usize,
+	hypercube_evals: &[FBase],
+	scratch: &mut Vec<FBase>,
+	grid_evals: &mut Vec<FBase>,
+) where
+	FBase: ExtensionField<FDomain>,
+	FDomain: Field,
+{
+	debug_assert_eq!(hypercube_evals.len(), 1 << n_coords);
+
+	grid_evals.clear();
+	grid_evals.extend_from_slice(hypercube_evals);
+
+	let grid_size = grid.len();
+	let mut stride = 1;
+	for coord in 0..n_coords {
+		scratch.clear();
+		let outer_count = 1 << (n_coords - coord - 1);
+		for outer in 0..outer_count {
+			let evals_0 = &grid_evals[2 * outer * stride..][..stride];
+			let evals_1 = &grid_evals[(2 * outer + 1) * stride..][..stride];
+			for &point in grid {
+				scratch.extend(
+					iter::zip(evals_0, evals_1)
+						.map(|(&eval_0, &eval_1)| eval_0 + (eval_1 - eval_0) * point),
+				);
+			}
+		}
+
+		std::mem::swap(scratch, grid_evals);
+		stride *= grid_size;
+	}
+}
+
+/// Calculate the small field composition sums over the grid for a given small field round.
+///
+/// Returns a vector of `(degree + 1)^(round + 1)` small field values per composition; the value
+/// at the grid point $(v_0, \ldots, v_{round})$ is stored at a mixed radix index with $v_0$ at
+/// the fastest stride, and equals
+///
+/// $$
+/// \sum_{x \in B_{n - round - 1}} C(M_0(v_0, \ldots, v_{round}, x), \ldots)
+/// $$
+///
+/// where the variables are substituted in the sumcheck evaluation order.
+#[instrument(skip_all, level = "debug")]
+fn calculate_small_field_round_evals<FBase, FDomain, F, P, M, Composition>(
+	evaluation_order: EvaluationOrder,
+	n_vars: usize,
+	round: usize,
+	multilinears: &[M],
+	compositions: &[Composition],
+	grids: &[InterpolationDomain<FDomain>],
+) -> Result<Vec<Vec<FBase>>, Error>
+where
+	FBase: ExtensionField<FDomain>,
+	FDomain: Field,
+	F: ExtensionField<FBase>,
+	P: PackedField<Scalar = F> + PackedExtension<FBase>,
+	M: MultilinearPoly<P> + Send + Sync,
+	Composition: CompositionPoly<PackedSubfield<P, FBase>>,
+{
+	// Small subcubes are batched together to amortize composition evaluation overhead.
+	const MAX_LOG_BATCH: usize = 8;
+
+	assert!(round < n_vars);
+
+	let log_embedding_degree = <F as ExtensionField<FBase>>::LOG_DEGREE;
+	let cube_vars = round + 1;
+	let remaining_vars = n_vars - cube_vars;
+	let log_batch = MAX_LOG_BATCH.min(remaining_vars);
+	let batch = 1 << log_batch;
+
+	let subcube_vars = match evaluation_order {
+		EvaluationOrder::LowToHigh => cube_vars + log_batch,
+		EvaluationOrder::HighToLow => log_batch,
+	};
+
+	let grid_lens = grids
+		.iter()
+		.map(|grid| grid.finite_points().len().pow(cube_vars as u32))
+		.collect::<Vec<_>>();
+
+	let zero_accumulators = || {
+		grid_lens
+			.iter()
+			.map(|&grid_len| vec![FBase::ZERO; grid_len])
+			.collect::<Vec<_>>()
+	};
+
+	let packed_len =
+		|grid_len: usize| (grid_len << log_batch).div_ceil(PackedSubfield::<P, FBase>::WIDTH);
+	let max_packed_len = grid_lens.iter().copied().map(packed_len).max().unwrap_or(0);
+
+	(0..1 << (remaining_vars - log_batch))
+		.into_par_iter()
+		.try_fold(
+			|| {
+				ParFoldStates::<FBase, P>::new(
+					multilinears.len(),
+					subcube_vars,
+					cube_vars,
+					log_batch,
+					log_embedding_degree,
+					max_packed_len,
+					&grid_lens,
+				)
+			},
+			|mut par_fold_states, chunk_index| -> Result<_, Error> {
+				let ParFoldStates {
+					subcube_evals,
+					hypercube_evals,
+					grid_evals,
+					composition_evals,
+					scratch,
+					point_evals,
+					accumulators,
+				} = &mut par_fold_states;
+
+				// Gather the small field hypercube evaluations, laid out as `batch` consecutive
+				// subcubes of `cube_vars` variables, in the sumcheck round order.
+				for (multilinear, hypercube_evals) in
+					izip!(multilinears, hypercube_evals.iter_mut())
+				{
+					match evaluation_order {
+						EvaluationOrder::LowToHigh => {
+							multilinear.subcube_evals(
+								subcube_vars,
+								chunk_index,
+								log_embedding_degree,
+								subcube_evals,
+							)?;
+							let subcube_evals_base =
+								<P as PackedExtension<FBase>>::cast_bases(subcube_evals);
+							for (i, eval) in hypercube_evals.iter_mut().enumerate() {
+								*eval = get_packed_slice(subcube_evals_base, i);
+							}
+						}
+						EvaluationOrder::HighToLow => {
+							for cube_index in 0..1 << cube_vars {
+								// The first round variable is the highest one.
+								let high_index = reverse_bits(cube_index, cube_vars);
+								multilinear.subcube_evals(
+									subcube_vars,
+									high_index << (remaining_vars - log_batch) | chunk_index,
+									log_embedding_degree,
+									subcube_evals,
+								)?;
+								let subcube_evals_base =
+									<P as PackedExtension<FBase>>::cast_bases(subcube_evals);
+								for x in 0..batch {
+									hypercube_evals[x << cube_vars | cube_index] =
+										get_packed_slice(subcube_evals_base, x);
+								}
+							}
+						}
+					}
+				}
+
+				for (composition, grid, &grid_len, accumulators) in
+					izip!(compositions, grids, &grid_lens, accumulators.iter_mut())
+				{
+					let packed_len = packed_len(grid_len);
+
+					// Extrapolate each multilinear onto the grid, laid out as `[grid_point][x]`.
+					for (hypercube_evals, grid_evals) in
+						izip!(&*hypercube_evals, grid_evals.iter_mut())
+					{
+						for (x, hypercube_evals) in
+							hypercube_evals.chunks_exact(1 << cube_vars).enumerate()
+						{
+							extrapolate_to_grid(
+								grid.finite_points(),
+								cube_vars,
+								hypercube_evals,
+								scratch,
+								point_evals,
+							);
+							for (point_index, &eval) in point_evals.iter().enumerate() {
+								set_packed_slice(grid_evals, point_index << log_batch | x, eval);
+							}
+						}
+					}
+
+					// Evaluate the small field composition. Padding scalars past the end of the
+					// grid are evaluated as well, but never accumulated.
+					let batch_query = grid_evals.iter().map(|evals| &evals[..packed_len]);
+					stackalloc_with_iter(multilinears.len(), batch_query, |batch_query| {
+						composition
+							.batch_evaluate(batch_query, &mut composition_evals[..packed_len])
+					})?;
+
+					for (point_index, accumulator) in accumulators.iter_mut().enumerate() {
+						*accumulator += (0..batch)
+							.map(|x| {
+								get_packed_slice(composition_evals, point_index << log_batch | x)
+							})
+							.sum::<FBase>();
+					}
+				}
+
+				Ok(par_fold_states)
+			},
+		)
+		.map(|states| -> Result<_, Error> { Ok(states?.accumulators) })
+		.try_reduce(zero_accumulators, |mut lhs, rhs| {
+			for (lhs, rhs) in izip!(&mut lhs, rhs) {
+				for (lhs, rhs) in izip!(lhs, rhs) {
+					*lhs += rhs;
+				}
+			}
+			Ok(lhs)
+		})
+}
+
+#[derive(Debug)]
+struct ParFoldStates<FBase: Field, P: PackedExtension<FBase>> {
+	/// Evaluations of a multilinear subcube, embedded into P (see MultilinearPoly::subcube_evals). Scratch space.
+	subcube_evals: Vec<P>,
+	/// Small field hypercube evaluations in round variable order, per multilinear.
+	hypercube_evals: Vec<Vec<FBase>>,
+	/// Hypercube evaluations extrapolated onto the grid, per multilinear.
+	grid_evals: Vec<Vec<PackedSubfield<P, FBase>>>,
+	/// Evals of a single composition over the grid. Scratch space.
+	composition_evals: Vec<PackedSubfield<P, FBase>>,
+	/// Scratch space for grid extrapolation.
+	scratch: Vec<FBase>,
+	/// Grid extrapolation of a single subcube. Scratch space.
+	point_evals: Vec<FBase>,
+	/// Grid sums accumulators, per composition.
+	accumulators: Vec<Vec<FBase>>,
+}
+
+impl<FBase: Field, P: PackedExtension<FBase>> ParFoldStates<FBase, P> {
+	fn new(
+		n_multilinears: usize,
+		subcube_vars: usize,
+		cube_vars: usize,
+		log_batch: usize,
+		log_embedding_degree: usize,
+		max_packed_len: usize,
+		grid_lens: &[usize],
+	) -> Self {
+		let subcube_evals =
+			zeroed_vec(1 << subcube_vars.saturating_sub(log_embedding_degree + P::LOG_WIDTH));
+		let hypercube_evals = vec![vec![FBase::ZERO; 1 << (cube_vars + log_batch)]; n_multilinears];
+		let grid_evals = vec![zeroed_vec(max_packed_len); n_multilinears];
+		let composition_evals = zeroed_vec(max_packed_len);
+		let accumulators = grid_lens
+			.iter()
+			.map(|&grid_len| vec![FBase::ZERO; grid_len])
+			.collect();
+
+		Self {
+			subcube_evals,
+			hypercube_evals,
+			grid_evals,
+			composition_evals,
+			scratch: Vec::new(),
+			point_evals: Vec::new(),
+			accumulators,
+		}
+	}
+}
+
+const fn reverse_bits(index: usize, n_bits: usize) -> usize {
+	if n_bits == 0 {
+		0
+	} else {
+		index.reverse_bits() >> (usize::BITS as usize - n_bits)
+	}
+}
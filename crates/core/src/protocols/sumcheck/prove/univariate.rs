@@ -12,7 +12,7 @@ use binius_math::{
 	IsomorphicEvaluationDomainFactory, MLEDirectAdapter, MultilinearPoly,
 };
 use binius_maybe_rayon::prelude::*;
-use binius_ntt::{AdditiveNTT, OddInterpolate, SingleThreadedNTT};
+use binius_ntt::{AdditiveNTT, NovelPolyArithmetic, OddInterpolate, SingleThreadedNTT};
 use binius_utils::{bail, checked_arithmetics::log2_ceil_usize};
 use bytemuck::zeroed_vec;
 use itertools::izip;
@@ -596,6 +596,7 @@ fn extrapolate_round_evals<F: TowerField>(
 	// Instantiate a large enough NTT over F to be able to forward transform to full domain size.
 	// REVIEW: should be possible to use an existing FDomain NTT with striding.
 	let ntt = SingleThreadedNTT::with_canonical_field(log2_ceil_usize(max_domain_size))?;
+	let novel_poly_arithmetic = NovelPolyArithmetic::new(&ntt);
 
 	// Cache OddInterpolate instances, which, albeit small in practice, take cubic time to create.
 	let mut odd_interpolates = HashMap::new();
@@ -611,18 +612,15 @@ fn extrapolate_round_evals<F: TowerField>(
 			let ell = n.trailing_zeros() as usize;
 			assert!(ell >= skip_rounds);
 
-			OddInterpolate::new(n >> ell, ell, ntt.twiddles())
+			OddInterpolate::with_ntt(n >> ell, ell, &ntt)
 				.expect("domain large enough by construction")
 		});
 
 		// Obtain novel polynomial basis representation of round evaluations.
 		odd_interpolate.inverse_transform(&ntt, round_evals)?;
 
-		// Use forward NTT to extrapolate novel representation to the max domain size.
-		let next_log_n = log2_ceil_usize(max_domain_size);
-		round_evals.resize(1 << next_log_n, F::ZERO);
-
-		ntt.forward_transform(round_evals, 0, 0, next_log_n)?;
+		// Evaluate the novel representation over the max domain size.
+		*round_evals = novel_poly_arithmetic.evaluate_on_domain(round_evals, max_domain_size)?;
 
 		// Sanity check: first 1 << skip_rounds evals are still zeros.
 		debug_assert!(round_evals[..1 << skip_rounds]
@@ -630,7 +628,6 @@ fn extrapolate_round_evals<F: TowerField>(
 			.all(|&coeff| coeff == F::ZERO));
 
 		// Trim the result.
-		round_evals.resize(max_domain_size, F::ZERO);
 		round_evals.drain(..1 << skip_rounds);
 	}
 
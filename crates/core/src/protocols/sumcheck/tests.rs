@@ -11,7 +11,7 @@ use binius_field::{
 	packed::set_packed_slice,
 	underlier::UnderlierType,
 	BinaryField, BinaryField128b, BinaryField32b, BinaryField8b, ExtensionField, Field,
-	PackedBinaryField1x128b, PackedBinaryField4x32b, PackedExtension, PackedField,
+	PackedBinaryField1x128b, PackedBinaryField4x32b, PackedExtension, PackedField, PackedSubfield,
 	RepackedExtension, TowerField,
 };
 use binius_hal::{make_portable_backend, ComputationBackend, ComputationBackendExt};
@@ -22,18 +22,23 @@ use binius_math::{
 	MultilinearQuery,
 };
 use binius_maybe_rayon::{current_num_threads, prelude::*};
-use binius_utils::checked_arithmetics::log2_ceil_usize;
+use binius_utils::{
+	checked_arithmetics::log2_ceil_usize, DeserializeBytes, SerializationMode, SerializeBytes,
+};
 use itertools::izip;
 use rand::{rngs::StdRng, Rng, SeedableRng};
 
 use super::{
-	common::CompositeSumClaim,
+	common::{immediate_switchover_heuristic, small_field_switchover_heuristic, CompositeSumClaim},
 	front_loaded::BatchVerifier as FrontLoadedBatchVerifier,
 	prove::{
-		batch_prove, front_loaded::BatchProver as FrontLoadedBatchProver, RegularSumcheckProver,
+		batch_prove, batch_prove_with_start, batch_prove_zerocheck_univariate_round,
+		front_loaded::BatchProver as FrontLoadedBatchProver, RegularSumcheckProver,
+		SmallFieldSumcheckProver, UnivariateZerocheck,
 	},
 	verify::batch_verify,
-	BatchSumcheckOutput, SumcheckClaim,
+	verify_sumcheck_proof, BatchSumcheckOutput, Error, SumcheckClaim, SumcheckProof,
+	VerificationError, ZerocheckClaim, ZerocheckProof,
 };
 use crate::{
 	composition::index_composition,
@@ -41,9 +46,11 @@ use crate::{
 	polynomial::{IdentityCompositionPoly, MultilinearComposite},
 	protocols::{
 		sumcheck::prove::SumcheckProver,
-		test_utils::{AddOneComposition, TestProductComposition},
+		test_utils::{
+			generate_zero_product_multilinears, AddOneComposition, TestProductComposition,
+		},
 	},
-	transcript::ProverTranscript,
+	transcript::{LogEntry, ProverTranscript, VerifierTranscript},
 };
 
 #[derive(Debug, Clone)]
@@ -275,6 +282,143 @@ fn test_sumcheck_prove_verify_with_nontrivial_packing() {
 	>(n_vars, n_multilinears, switchover_rd);
 }
 
+fn test_small_field_prove_verify_product_helper<U, F, FDomain, FExt>(
+	evaluation_order: EvaluationOrder,
+	n_vars: usize,
+	n_multilinears: usize,
+	small_field_rounds: usize,
+) where
+	U: UnderlierType + PackScalar<F> + PackScalar<FDomain> + PackScalar<FExt>,
+	F: TowerField + ExtensionField<FDomain>,
+	FDomain: BinaryField,
+	FExt: TowerField + ExtensionField<F> + ExtensionField<FDomain>,
+	BinaryField128b: From<FExt> + Into<FExt>,
+{
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let multilins =
+		generate_random_multilinears::<PackedType<U, F>>(&mut rng, n_vars, n_multilinears)
+			.into_iter()
+			.map(MLEEmbeddingAdapter::<_, PackedType<U, FExt>, _>::from)
+			.collect::<Vec<_>>();
+	let product_composition = TestProductComposition::new(n_multilinears);
+	let composition = AddOneComposition::new(product_composition);
+	let sum = compute_composite_sum(&multilins, &composition);
+
+	let claim = SumcheckClaim::new(
+		n_vars,
+		n_multilinears,
+		vec![CompositeSumClaim {
+			composition: &composition,
+			sum,
+		}],
+	)
+	.unwrap();
+
+	let backend = make_portable_backend();
+	let domain_factory = IsomorphicEvaluationDomainFactory::<FDomain>::default();
+
+	let regular_prover = RegularSumcheckProver::<FDomain, _, _, _, _>::new(
+		evaluation_order,
+		multilins.iter().collect(),
+		[CompositeSumClaim {
+			composition: &composition,
+			sum,
+		}],
+		domain_factory.clone(),
+		immediate_switchover_heuristic,
+		&backend,
+	)
+	.unwrap();
+
+	let small_field_prover = SmallFieldSumcheckProver::<F, FDomain, _, _, _, _, _>::new(
+		evaluation_order,
+		multilins.iter().collect(),
+		[CompositeSumClaim {
+			composition: &composition,
+			sum,
+		}],
+		domain_factory,
+		|_, _| small_field_rounds,
+		&backend,
+	)
+	.unwrap();
+
+	let mut regular_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let regular_output = batch_prove(vec![regular_prover], &mut regular_transcript).unwrap();
+
+	let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let prover_output = batch_prove(vec![small_field_prover], &mut prover_transcript).unwrap();
+
+	// The small field prover sends exactly the same round polynomials as the regular one.
+	assert_eq!(prover_output, regular_output);
+	assert_eq!(prover_transcript.finalize(), regular_transcript.finalize());
+
+	let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let small_field_prover = SmallFieldSumcheckProver::<F, FDomain, _, _, _, _, _>::new(
+		evaluation_order,
+		multilins.iter().collect(),
+		[CompositeSumClaim {
+			composition: &composition,
+			sum,
+		}],
+		IsomorphicEvaluationDomainFactory::<FDomain>::default(),
+		|_, _| small_field_rounds,
+		&backend,
+	)
+	.unwrap();
+	let prover_output = batch_prove(vec![small_field_prover], &mut prover_transcript).unwrap();
+
+	let mut verifier_transcript = prover_transcript.into_verifier();
+	let verifier_output =
+		batch_verify(evaluation_order, &[claim], &mut verifier_transcript).unwrap();
+	verifier_transcript.finalize().unwrap();
+
+	assert_eq!(verifier_output, prover_output);
+}
+
+#[test]
+fn test_small_field_sumcheck_prove_verify() {
+	for evaluation_order in [EvaluationOrder::LowToHigh, EvaluationOrder::HighToLow] {
+		for n_vars in 1..7 {
+			for n_multilinears in 1..4 {
+				for small_field_rounds in 0..=n_vars.min(3) {
+					test_small_field_prove_verify_product_helper::<
+						OptimalUnderlier128b,
+						BinaryField8b,
+						BinaryField8b,
+						BinaryField128b,
+					>(evaluation_order, n_vars, n_multilinears, small_field_rounds);
+				}
+			}
+		}
+	}
+}
+
+#[test]
+fn test_small_field_sumcheck_prove_verify_with_nontrivial_packing() {
+	for evaluation_order in [EvaluationOrder::LowToHigh, EvaluationOrder::HighToLow] {
+		test_small_field_prove_verify_product_helper::<
+			OptimalUnderlier512b,
+			BinaryField32b,
+			BinaryField8b,
+			BinaryField128b,
+		>(evaluation_order, 10, 3, 3);
+	}
+}
+
+#[test]
+fn test_small_field_switchover_heuristic() {
+	let heuristic = small_field_switchover_heuristic(0);
+	// Multilinear compositions are capped by the logarithm of the extension degree.
+	assert_eq!(heuristic(16, 1), 4);
+	// Quadratic compositions trade off grid growth against cheaper multiplications.
+	assert_eq!(heuristic(16, 2), 4);
+	assert_eq!(heuristic(16, 3), 3);
+	assert_eq!(heuristic(16, 7), 1);
+	assert_eq!(heuristic(1, 3), 0);
+}
+
 #[derive(Clone)]
 struct TestSumcheckClaimShape {
 	n_vars: usize,
@@ -411,17 +555,40 @@ fn prove_verify_batch_with_evaluation_order(
 	}
 
 	let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	prover_transcript.set_log(true);
 	let prover_output =
 		batch_prove(provers, &mut prover_transcript).expect("failed to prove sumcheck");
+	let prover_log = prover_transcript.log().to_vec();
 
 	let prover_sample = CanSample::<FE>::sample(&mut prover_transcript);
 
 	let mut verifier_transcript = prover_transcript.into_verifier();
+	verifier_transcript.set_log(true);
 	let verifier_output =
 		batch_verify(evaluation_order, &claims, &mut verifier_transcript).unwrap();
 
 	assert_eq!(prover_output, verifier_output);
 
+	// Check that both sides label the same operations, one round message per round
+	let labels = |log: &[LogEntry]| {
+		log.iter()
+			.map(|entry| (entry.kind, entry.label.clone(), entry.bytes.clone()))
+			.collect::<Vec<_>>()
+	};
+	assert_eq!(labels(&prover_log), labels(verifier_transcript.log()));
+	let n_rounds = claim_shapes
+		.iter()
+		.map(|shape| shape.n_vars)
+		.max()
+		.unwrap_or(0);
+	assert_eq!(
+		prover_log
+			.iter()
+			.filter(|entry| entry.label.as_deref() == Some("sumcheck.round_coeffs"))
+			.count(),
+		n_rounds
+	);
+
 	// Check that challengers are in the same state
 	assert_eq!(prover_sample, CanSample::<FE>::sample(&mut verifier_transcript));
 }
@@ -458,6 +625,184 @@ fn test_prove_verify_batch_constant_polys() {
 	]);
 }
 
+#[test]
+fn test_prove_verify_batch_empty() {
+	prove_verify_batch(&[]);
+}
+
+fn extract_verify_batch_proof(claim_shapes: &[TestSumcheckClaimShape]) {
+	for evaluation_order in [EvaluationOrder::LowToHigh, EvaluationOrder::HighToLow] {
+		extract_verify_batch_proof_with_evaluation_order(evaluation_order, claim_shapes);
+	}
+}
+
+fn extract_verify_batch_proof_with_evaluation_order(
+	evaluation_order: EvaluationOrder,
+	claim_shapes: &[TestSumcheckClaimShape],
+) {
+	type P = PackedBinaryField4x32b;
+	type FDomain = BinaryField8b;
+	type FE = BinaryField128b;
+	type PE = PackedBinaryField1x128b;
+
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let backend = make_portable_backend();
+	let domain_factory = IsomorphicEvaluationDomainFactory::<FDomain>::default();
+
+	let mut claims = Vec::with_capacity(claim_shapes.len());
+	let mut provers = Vec::with_capacity(claim_shapes.len());
+	for claim_shape in claim_shapes {
+		let (_, claim, prover) = make_test_sumcheck::<FE, FDomain, P, PE, _>(
+			evaluation_order,
+			claim_shape,
+			&mut rng,
+			&domain_factory,
+			&backend,
+		);
+		claims.push(claim);
+		provers.push(prover);
+	}
+
+	let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let prover_output =
+		batch_prove(provers, &mut prover_transcript).expect("failed to prove sumcheck");
+	let proof_bytes = prover_transcript.finalize();
+
+	// Extract the proof from the transcript
+	let mut verifier_transcript =
+		VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof_bytes.clone());
+	let (proof, challenges) = SumcheckProof::read(&claims, &mut verifier_transcript).unwrap();
+	verifier_transcript.finalize().unwrap();
+
+	// Serialization round trip
+	let mut buffer = Vec::new();
+	proof
+		.serialize(&mut buffer, SerializationMode::CanonicalTower)
+		.unwrap();
+	let deserialized =
+		SumcheckProof::<FE>::deserialize(buffer.as_slice(), SerializationMode::CanonicalTower)
+			.unwrap();
+	assert_eq!(deserialized, proof);
+
+	// Verification without a transcript
+	let verifier_output =
+		verify_sumcheck_proof(evaluation_order, &claims, &deserialized, &challenges).unwrap();
+	assert_eq!(prover_output, verifier_output);
+
+	// Re-injection reproduces the original proof bytes
+	let mut reinjected_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let reinjected_challenges = proof.write(&claims, &mut reinjected_transcript).unwrap();
+	assert_eq!(reinjected_challenges, challenges);
+	assert_eq!(reinjected_transcript.finalize(), proof_bytes);
+
+	// Tampered proofs are rejected
+	let mut tampered = proof;
+	tampered.multilinear_evals[0][0] += FE::ONE;
+	assert!(verify_sumcheck_proof(evaluation_order, &claims, &tampered, &challenges).is_err());
+	tampered.multilinear_evals[0].pop();
+	assert!(matches!(
+		verify_sumcheck_proof(evaluation_order, &claims, &tampered, &challenges),
+		Err(Error::Verification(VerificationError::NumberOfMultilinearEvals))
+	));
+}
+
+#[test]
+fn test_extract_verify_batch_proof() {
+	extract_verify_batch_proof(&[
+		TestSumcheckClaimShape {
+			n_vars: 8,
+			degree: 3,
+		},
+		TestSumcheckClaimShape {
+			n_vars: 6,
+			degree: 2,
+		},
+		TestSumcheckClaimShape {
+			n_vars: 0,
+			degree: 3,
+		},
+	]);
+}
+
+#[test]
+fn test_extract_zerocheck_proof() {
+	type P = PackedBinaryField1x128b;
+	type FBase = BinaryField32b;
+	type F = BinaryField128b;
+	type FDomain = BinaryField8b;
+
+	let n_vars = 5;
+	let n_multilinears = 3;
+	let backend = make_portable_backend();
+	let domain_factory = IsomorphicEvaluationDomainFactory::<FDomain>::default();
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let composition = TestProductComposition::new(n_multilinears);
+	let claims =
+		[ZerocheckClaim::<F, _>::new(n_vars, n_multilinears, vec![composition.clone()]).unwrap()];
+
+	for skip_rounds in 0..=n_vars {
+		let multilins = generate_zero_product_multilinears::<PackedSubfield<P, FBase>, P>(
+			&mut rng,
+			n_vars,
+			n_multilinears,
+		);
+
+		let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		let zerocheck_challenges: Vec<F> = prover_transcript.sample_vec(n_vars - skip_rounds);
+		let prover = UnivariateZerocheck::<FDomain, FBase, P, _, _, _, _, _, _>::new(
+			multilins,
+			[("test_product".into(), composition.clone(), composition.clone())],
+			&zerocheck_challenges,
+			domain_factory.clone(),
+			immediate_switchover_heuristic,
+			&backend,
+		)
+		.unwrap();
+
+		// The claim takes part in the univariate round only if it has skipped rounds
+		let (univariate_provers, tail_provers) = if skip_rounds > 0 {
+			(vec![prover], vec![])
+		} else {
+			(
+				vec![],
+				vec![Box::new(prover.into_regular_zerocheck().unwrap())
+					as Box<dyn SumcheckProver<_>>],
+			)
+		};
+		let univariate_output = batch_prove_zerocheck_univariate_round(
+			univariate_provers,
+			skip_rounds,
+			&mut prover_transcript,
+		)
+		.unwrap();
+		batch_prove_with_start(
+			univariate_output.batch_prove_start,
+			tail_provers,
+			&mut prover_transcript,
+		)
+		.unwrap();
+		let proof_bytes = prover_transcript.finalize();
+
+		// Extract the proof and re-inject it into a fresh transcript
+		let mut extraction_transcript =
+			VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof_bytes.clone());
+		let _: Vec<F> = extraction_transcript.sample_vec(n_vars - skip_rounds);
+		let (proof, challenges) =
+			ZerocheckProof::read(&claims, skip_rounds, &mut extraction_transcript).unwrap();
+		extraction_transcript.finalize().unwrap();
+
+		let mut reinjected_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		let _: Vec<F> = reinjected_transcript.sample_vec(n_vars - skip_rounds);
+		let reinjected_challenges = proof
+			.write(&claims, skip_rounds, &mut reinjected_transcript)
+			.unwrap();
+		assert_eq!(reinjected_challenges, challenges);
+		assert_eq!(reinjected_transcript.finalize(), proof_bytes);
+	}
+}
+
 fn prove_verify_batch_front_loaded(claim_shapes: &[TestSumcheckClaimShape]) {
 	for evaluation_order in [EvaluationOrder::LowToHigh, EvaluationOrder::HighToLow] {
 		prove_verify_batch_front_loaded_with_evaluation_order(evaluation_order, claim_shapes);
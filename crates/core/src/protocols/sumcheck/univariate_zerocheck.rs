@@ -10,10 +10,7 @@ use super::{
 	verify::BatchVerifyStart,
 	zerocheck::ZerocheckClaim,
 };
-use crate::{
-	fiat_shamir::{CanSample, Challenger},
-	transcript::VerifierTranscript,
-};
+use crate::{fiat_shamir::Challenger, transcript::VerifierTranscript};
 
 #[derive(Debug)]
 pub struct BatchZerocheckUnivariateOutput<F: Field> {
@@ -53,39 +50,20 @@ where
 	Composition: CompositionPoly<F>,
 	Challenger_: Challenger,
 {
-	// Check that the claims are in descending order by n_vars
-	if !is_sorted_ascending(claims.iter().map(|claim| claim.n_vars()).rev()) {
-		bail!(Error::ClaimsOutOfOrder);
-	}
-
-	let max_n_vars = claims.first().map(|claim| claim.n_vars()).unwrap_or(0);
-	let min_n_vars = claims.last().map(|claim| claim.n_vars()).unwrap_or(0);
-
-	if max_n_vars - min_n_vars > skip_rounds {
-		bail!(VerificationError::IncorrectSkippedRoundsCount);
-	}
-
-	let max_domain_size = claims
-		.iter()
-		.map(|claim| {
-			domain_size(claim.max_individual_degree(), skip_rounds + claim.n_vars() - max_n_vars)
-		})
-		.max()
-		.unwrap_or(0);
-	let zeros_prefix_len = (1 << (skip_rounds + min_n_vars - max_n_vars)).min(max_domain_size);
+	let (max_domain_size, zeros_prefix_len) = univariate_round_domain(claims, skip_rounds)?;
 
 	let mut batch_coeffs = Vec::with_capacity(claims.len());
 	let mut max_degree = 0;
 	for claim in claims {
-		let next_batch_coeff = transcript.sample();
+		let next_batch_coeff = transcript.sample_labeled("zerocheck.batch_coeff");
 		batch_coeffs.push(next_batch_coeff);
 		max_degree = max_degree.max(claim.max_individual_degree() + 1);
 	}
 
 	let round_evals = transcript
-		.message()
+		.message_labeled("zerocheck.univariate_round_evals")
 		.read_scalar_slice(max_domain_size - zeros_prefix_len)?;
-	let univariate_challenge = transcript.sample();
+	let univariate_challenge = transcript.sample_labeled("zerocheck.univariate_challenge");
 
 	// REVIEW: consider using novel basis for the univariate round representation
 	//         (instead of Lagrange)
@@ -116,3 +94,39 @@ where
 
 	Ok(output)
 }
+
+/// Returns the size of the univariate round evaluation domain and the length of its zero prefix.
+///
+/// The prover sends `max_domain_size - zeros_prefix_len` round evaluations, as the honest
+/// univariatized round polynomial is known to vanish on the zero prefix.
+pub(super) fn univariate_round_domain<F, Composition>(
+	claims: &[ZerocheckClaim<F, Composition>],
+	skip_rounds: usize,
+) -> Result<(usize, usize), Error>
+where
+	F: Field,
+	Composition: CompositionPoly<F>,
+{
+	// Check that the claims are in descending order by n_vars
+	if !is_sorted_ascending(claims.iter().map(|claim| claim.n_vars()).rev()) {
+		bail!(Error::ClaimsOutOfOrder);
+	}
+
+	let max_n_vars = claims.first().map(|claim| claim.n_vars()).unwrap_or(0);
+	let min_n_vars = claims.last().map(|claim| claim.n_vars()).unwrap_or(0);
+
+	if max_n_vars - min_n_vars > skip_rounds {
+		bail!(VerificationError::IncorrectSkippedRoundsCount);
+	}
+
+	let max_domain_size = claims
+		.iter()
+		.map(|claim| {
+			domain_size(claim.max_individual_degree(), skip_rounds + claim.n_vars() - max_n_vars)
+		})
+		.max()
+		.unwrap_or(0);
+	let zeros_prefix_len = (1 << (skip_rounds + min_n_vars - max_n_vars)).min(max_domain_size);
+
+	Ok((max_domain_size, zeros_prefix_len))
+}
@@ -11,10 +11,7 @@ use super::{
 	error::{Error, VerificationError},
 	RoundCoeffs,
 };
-use crate::{
-	fiat_shamir::{CanSample, Challenger},
-	transcript::VerifierTranscript,
-};
+use crate::{fiat_shamir::Challenger, transcript::VerifierTranscript};
 
 /// Verify a batched sumcheck protocol execution.
 ///
@@ -90,6 +87,14 @@ where
 		bail!(Error::TooManyPrebatchedCoeffs);
 	}
 
+	// The prover sends nothing for an empty batch, see `batch_prove_with_start`
+	if claims.is_empty() {
+		return Ok(BatchSumcheckOutput {
+			challenges: Vec::new(),
+			multilinear_evals: Vec::new(),
+		});
+	}
+
 	let n_rounds = claims.iter().map(|claim| claim.n_vars()).max().unwrap_or(0);
 
 	if skip_rounds > n_rounds {
@@ -108,7 +113,7 @@ where
 				break;
 			}
 
-			let next_batch_coeff = transcript.sample();
+			let next_batch_coeff = transcript.sample_labeled("sumcheck.batch_coeff");
 			batch_coeffs.push(next_batch_coeff);
 
 			// Batch the next claimed sum into the batched sum.
@@ -123,10 +128,12 @@ where
 			active_index += 1;
 		}
 
-		let coeffs = transcript.message().read_scalar_slice(max_degree)?;
+		let coeffs = transcript
+			.message_labeled("sumcheck.round_coeffs")
+			.read_scalar_slice(max_degree)?;
 		let round_proof = RoundProof(RoundCoeffs(coeffs));
 
-		let challenge = transcript.sample();
+		let challenge = transcript.sample_labeled("sumcheck.round_challenge");
 		challenges.push(challenge);
 
 		sum = interpolate_round_proof(round_proof, sum, challenge);
@@ -136,7 +143,7 @@ where
 	while let Some(claim) = claims.get(active_index) {
 		debug_assert_eq!(claim.n_vars(), 0);
 
-		let next_batch_coeff = transcript.sample();
+		let next_batch_coeff = transcript.sample_labeled("sumcheck.batch_coeff");
 		batch_coeffs.push(next_batch_coeff);
 
 		// Batch the next claimed sum into the batched sum.
@@ -151,7 +158,7 @@ where
 	}
 
 	let mut multilinear_evals = Vec::with_capacity(claims.len());
-	let mut reader = transcript.message();
+	let mut reader = transcript.message_labeled("sumcheck.multilinear_evals");
 	for claim in claims {
 		let evals = reader.read_scalar_slice::<F>(claim.n_multilinears())?;
 		multilinear_evals.push(evals);
@@ -193,7 +200,7 @@ where
 	Ok(batch_weighted_value(batch_coeff, composite_evals.into_iter()))
 }
 
-fn compute_expected_batch_composite_evaluation_multi_claim<F: Field, Composition>(
+pub(super) fn compute_expected_batch_composite_evaluation_multi_claim<F: Field, Composition>(
 	batch_coeffs: Vec<F>,
 	claims: &[SumcheckClaim<F, Composition>],
 	multilinear_evals: &[Vec<F>],
@@ -0,0 +1,390 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Decoding algorithms for [`ReedSolomonCode`].
+//!
+//! Decoding is not needed by any protocol, it exists for testing proximity protocols and debugging
+//! commitments. The algorithms operate on unpacked scalars and use dense univariate polynomial
+//! arithmetic in the monomial basis, with quadratic complexity in the block length. Decoded
+//! messages are returned in the novel polynomial basis, matching the encoding convention of the
+//! additive NTT.
+
+use binius_field::{BinaryField, Field, PackedField};
+use binius_ntt::AdditiveNTT;
+use binius_utils::bail;
+
+use super::reed_solomon::ReedSolomonCode;
+
+#[derive(Debug, thiserror::Error)]
+pub enum DecodingError {
+	#[error("the received word has length {actual}, expected the block length {expected}")]
+	IncorrectWordLength { expected: usize, actual: usize },
+	#[error("the message has length {actual}, expected the code dimension {expected}")]
+	IncorrectMessageLength { expected: usize, actual: usize },
+	#[error("only {n_known} symbols are not erased, at least {dim} are required")]
+	TooManyErasures { n_known: usize, dim: usize },
+	#[error("the received word is not within the unique decoding radius of the code")]
+	DecodingFailure,
+	#[error("NTT error: {0}")]
+	Ntt(#[from] binius_ntt::Error),
+}
+
+impl<P> ReedSolomonCode<P>
+where
+	P: PackedField<Scalar: BinaryField>,
+{
+	/// The largest number of errors that can be uniquely corrected, $\lfloor (n - k) / 2 \rfloor$.
+	pub const fn unique_decoding_radius(&self) -> usize {
+		(self.len() - self.dim()) / 2
+	}
+
+	/// The evaluation point of the codeword symbol at `index`.
+	///
+	/// Codeword symbols are ordered as the elements of the NTT evaluation domain, where the coset
+	/// `i` of the encoding NTT occupies the `i`-th block of `dim()` symbols.
+	pub fn evaluation_point(&self, index: usize) -> P::Scalar {
+		self.get_ntt().subspace(0).get(index)
+	}
+
+	/// Encode a single message of unpacked scalars given in the novel polynomial basis.
+	pub fn encode_scalars(&self, message: &[P::Scalar]) -> Result<Vec<P::Scalar>, DecodingError> {
+		if message.len() != self.dim() {
+			bail!(DecodingError::IncorrectMessageLength {
+				expected: self.dim(),
+				actual: message.len(),
+			});
+		}
+
+		let mut codeword = message.repeat(self.inv_rate());
+		for (coset, chunk) in codeword.chunks_exact_mut(self.dim()).enumerate() {
+			self.get_ntt()
+				.forward_transform(chunk, coset as u32, 0, self.log_dim())?;
+		}
+		Ok(codeword)
+	}
+
+	/// Recover the message from a codeword with erased symbols.
+	///
+	/// Erased symbols are represented by `None`. Decoding succeeds when at least `dim()` symbols
+	/// are present and all of them agree with a single codeword.
+	pub fn decode_erasures(
+		&self,
+		word: &[Option<P::Scalar>],
+	) -> Result<Vec<P::Scalar>, DecodingError> {
+		self.check_word_len(word.len())?;
+
+		let (points, values): (Vec<_>, Vec<_>) = word
+			.iter()
+			.enumerate()
+			.filter_map(|(index, &symbol)| Some((self.evaluation_point(index), symbol?)))
+			.unzip();
+
+		if points.len() < self.dim() {
+			bail!(DecodingError::TooManyErasures {
+				n_known: points.len(),
+				dim: self.dim(),
+			});
+		}
+
+		let poly = interpolate(&points[..self.dim()], &values[..self.dim()]);
+		if points[self.dim()..]
+			.iter()
+			.zip(&values[self.dim()..])
+			.any(|(&point, &value)| evaluate(&poly, point) != value)
+		{
+			bail!(DecodingError::DecodingFailure);
+		}
+
+		self.message_from_monomial(&poly)
+	}
+
+	/// Recover the message from a received word with errors, using Gao's algorithm.
+	///
+	/// Decoding succeeds if the word differs from a codeword in at most
+	/// [`Self::unique_decoding_radius`] positions.
+	///
+	/// See [Gao03] for the description of the algorithm.
+	///
+	/// [Gao03]: <https://doi.org/10.1007/978-1-4757-3789-9_3>
+	pub fn decode(&self, word: &[P::Scalar]) -> Result<Vec<P::Scalar>, DecodingError> {
+		let (message, _) = self.decode_with_distance(word)?;
+		Ok(message)
+	}
+
+	/// The Hamming distance from `word` to the nearest codeword.
+	///
+	/// Returns `None` if the distance exceeds [`Self::unique_decoding_radius`], in which case
+	/// the nearest codeword cannot be efficiently determined.
+	pub fn distance_to_code(&self, word: &[P::Scalar]) -> Result<Option<usize>, DecodingError> {
+		match self.decode_with_distance(word) {
+			Ok((_, distance)) => Ok(Some(distance)),
+			Err(DecodingError::DecodingFailure) => Ok(None),
+			Err(err) => Err(err),
+		}
+	}
+
+	fn decode_with_distance(
+		&self,
+		word: &[P::Scalar],
+	) -> Result<(Vec<P::Scalar>, usize), DecodingError> {
+		self.check_word_len(word.len())?;
+
+		let n = self.len();
+		let k = self.dim();
+		let points = (0..n)
+			.map(|index| self.evaluation_point(index))
+			.collect::<Vec<_>>();
+
+		// g_0 is the vanishing polynomial of the evaluation domain, g_1 interpolates the word.
+		let mut g_prev = points
+			.iter()
+			.fold(vec![P::Scalar::ONE], |poly, &point| mul(&poly, &[point, P::Scalar::ONE]));
+		let mut g = interpolate(&points, word);
+		let mut v_prev = Vec::new();
+		let mut v = vec![P::Scalar::ONE];
+
+		// Partial extended Euclidean algorithm, maintaining the invariant g = u g_0 + v g_1.
+		while degree(&g).is_some_and(|deg| 2 * deg >= n + k) {
+			let (quotient, remainder) = div_rem(&g_prev, &g);
+			let v_next = add(&v_prev, &mul(&quotient, &v));
+			g_prev = std::mem::replace(&mut g, remainder);
+			v_prev = std::mem::replace(&mut v, v_next);
+		}
+
+		let (poly, remainder) = div_rem(&g, &v);
+		if !remainder.is_empty() || degree(&poly).is_some_and(|deg| deg >= k) {
+			bail!(DecodingError::DecodingFailure);
+		}
+
+		let distance = points
+			.iter()
+			.zip(word)
+			.filter(|(&point, &value)| evaluate(&poly, point) != value)
+			.count();
+		if distance > self.unique_decoding_radius() {
+			bail!(DecodingError::DecodingFailure);
+		}
+
+		Ok((self.message_from_monomial(&poly)?, distance))
+	}
+
+	/// Convert a polynomial of degree less than `dim()` from the monomial basis into the message,
+	/// ie. its coefficients in the novel polynomial basis.
+	fn message_from_monomial(&self, poly: &[P::Scalar]) -> Result<Vec<P::Scalar>, DecodingError> {
+		debug_assert!(poly.len() <= self.dim());
+
+		let mut message = (0..self.dim())
+			.map(|index| evaluate(poly, self.evaluation_point(index)))
+			.collect::<Vec<_>>();
+		self.get_ntt()
+			.inverse_transform(&mut message, 0, 0, self.log_dim())?;
+		Ok(message)
+	}
+
+	fn check_word_len(&self, len: usize) -> Result<(), DecodingError> {
+		if len != self.len() {
+			bail!(DecodingError::IncorrectWordLength {
+				expected: self.len(),
+				actual: len,
+			});
+		}
+		Ok(())
+	}
+}
+
+// Dense univariate polynomials in the monomial basis, with coefficients in ascending degree order
+// and no trailing zeros. The zero polynomial is the empty vector.
+
+fn trim<F: BinaryField>(mut poly: Vec<F>) -> Vec<F> {
+	while poly.last() == Some(&F::ZERO) {
+		poly.pop();
+	}
+	poly
+}
+
+fn degree<F: BinaryField>(poly: &[F]) -> Option<usize> {
+	poly.len().checked_sub(1)
+}
+
+fn evaluate<F: BinaryField>(poly: &[F], point: F) -> F {
+	poly.iter()
+		.rev()
+		.fold(F::ZERO, |acc, &coeff| acc * point + coeff)
+}
+
+// Addition and subtraction coincide in characteristic 2.
+fn add<F: BinaryField>(lhs: &[F], rhs: &[F]) -> Vec<F> {
+	let (long, short) = if lhs.len() >= rhs.len() {
+		(lhs, rhs)
+	} else {
+		(rhs, lhs)
+	};
+	let mut sum = long.to_vec();
+	for (sum_i, &short_i) in sum.iter_mut().zip(short) {
+		*sum_i += short_i;
+	}
+	trim(sum)
+}
+
+fn mul<F: BinaryField>(lhs: &[F], rhs: &[F]) -> Vec<F> {
+	if lhs.is_empty() || rhs.is_empty() {
+		return Vec::new();
+	}
+
+	let mut product = vec![F::ZERO; lhs.len() + rhs.len() - 1];
+	for (i, &lhs_i) in lhs.iter().enumerate() {
+		for (j, &rhs_j) in rhs.iter().enumerate() {
+			product[i + j] += lhs_i * rhs_j;
+		}
+	}
+	trim(product)
+}
+
+/// Polynomial long division; `divisor` must be non-zero.
+fn div_rem<F: BinaryField>(dividend: &[F], divisor: &[F]) -> (Vec<F>, Vec<F>) {
+	let divisor_deg = degree(divisor).expect("divisor must be non-zero");
+	let lead_inv = divisor[divisor_deg]
+		.invert()
+		.expect("leading coefficient is non-zero");
+
+	let mut remainder = dividend.to_vec();
+	let Some(quotient_deg) = dividend.len().checked_sub(divisor.len()) else {
+		return (Vec::new(), remainder);
+	};
+
+	let mut quotient = vec![F::ZERO; quotient_deg + 1];
+	for i in (0..=quotient_deg).rev() {
+		let coeff = remainder[i + divisor_deg] * lead_inv;
+		quotient[i] = coeff;
+		for (remainder_j, &divisor_j) in remainder[i..].iter_mut().zip(divisor) {
+			*remainder_j -= coeff * divisor_j;
+		}
+	}
+	remainder.truncate(divisor_deg);
+	(trim(quotient), trim(remainder))
+}
+
+/// Newton interpolation of the polynomial of degree less than `points.len()` through the given
+/// points, which must be distinct.
+fn interpolate<F: BinaryField>(points: &[F], values: &[F]) -> Vec<F> {
+	debug_assert_eq!(points.len(), values.len());
+
+	let mut divided_diffs = values.to_vec();
+	for level in 1..points.len() {
+		for i in (level..points.len()).rev() {
+			let denominator = (points[i] - points[i - level])
+				.invert()
+				.expect("interpolation points are distinct");
+			divided_diffs[i] = (divided_diffs[i] - divided_diffs[i - 1]) * denominator;
+		}
+	}
+
+	let mut poly = Vec::with_capacity(points.len());
+	for (&point, &coeff) in points.iter().zip(&divided_diffs).rev() {
+		poly = add(&mul(&poly, &[point, F::ONE]), &[coeff]);
+	}
+	poly
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use binius_field::{BinaryField32b, Field, PackedBinaryField4x32b};
+	use binius_ntt::NTTOptions;
+	use rand::{rngs::StdRng, seq::index::sample, SeedableRng};
+
+	use super::*;
+
+	type F = BinaryField32b;
+
+	fn random_message(code: &ReedSolomonCode<F>, rng: &mut StdRng) -> Vec<F> {
+		repeat_with(|| <F as Field>::random(&mut *rng))
+			.take(code.dim())
+			.collect()
+	}
+
+	fn corrupt(word: &mut [F], n_errors: usize, rng: &mut StdRng) {
+		for index in sample(rng, word.len(), n_errors) {
+			word[index] += <F as Field>::random(&mut *rng);
+		}
+	}
+
+	#[test]
+	fn test_encode_scalars_matches_encode_batch() {
+		type P = PackedBinaryField4x32b;
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let code = ReedSolomonCode::<P>::new(5, 2, &NTTOptions::default()).unwrap();
+
+		let message = repeat_with(|| <F as Field>::random(&mut rng))
+			.take(code.dim())
+			.collect::<Vec<_>>();
+		let mut codeword = message
+			.chunks_exact(P::WIDTH)
+			.map(|chunk| P::from_scalars(chunk.iter().copied()))
+			.collect::<Vec<_>>();
+		codeword.resize(code.len() / P::WIDTH, P::zero());
+		code.encode_ext_batch_inplace(&mut codeword, 0).unwrap();
+
+		assert_eq!(
+			code.encode_scalars(&message).unwrap(),
+			PackedField::iter_slice(&codeword).collect::<Vec<_>>()
+		);
+	}
+
+	#[test]
+	fn test_decode_up_to_unique_decoding_radius() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let code = ReedSolomonCode::<F>::new(4, 2, &NTTOptions::default()).unwrap();
+		let radius = code.unique_decoding_radius();
+		assert_eq!(radius, 24);
+
+		for n_errors in [0, 1, radius / 2, radius] {
+			let message = random_message(&code, &mut rng);
+			let mut word = code.encode_scalars(&message).unwrap();
+			corrupt(&mut word, n_errors, &mut rng);
+
+			assert_eq!(code.decode(&word).unwrap(), message);
+			assert_eq!(code.distance_to_code(&word).unwrap(), Some(n_errors));
+		}
+	}
+
+	#[test]
+	fn test_decode_beyond_unique_decoding_radius_fails() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let code = ReedSolomonCode::<F>::new(4, 2, &NTTOptions::default()).unwrap();
+
+		let message = random_message(&code, &mut rng);
+		let mut word = code.encode_scalars(&message).unwrap();
+		corrupt(&mut word, code.len() / 2, &mut rng);
+
+		assert!(matches!(code.decode(&word), Err(DecodingError::DecodingFailure)));
+		assert_eq!(code.distance_to_code(&word).unwrap(), None);
+	}
+
+	#[test]
+	fn test_decode_erasures() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let code = ReedSolomonCode::<F>::new(4, 2, &NTTOptions::default()).unwrap();
+
+		let message = random_message(&code, &mut rng);
+		let codeword = code.encode_scalars(&message).unwrap();
+
+		let mut word = codeword.iter().copied().map(Some).collect::<Vec<_>>();
+		for index in sample(&mut rng, code.len(), code.len() - code.dim()) {
+			word[index] = None;
+		}
+		assert_eq!(code.decode_erasures(&word).unwrap(), message);
+
+		// One more erasure makes the message unrecoverable
+		let index = word.iter().position(Option::is_some).unwrap();
+		word[index] = None;
+		assert!(matches!(code.decode_erasures(&word), Err(DecodingError::TooManyErasures { .. })));
+
+		// An error among the known symbols is detected
+		let mut word = codeword.into_iter().map(Some).collect::<Vec<_>>();
+		word[0] = None;
+		*word[1].as_mut().unwrap() += F::ONE;
+		assert!(matches!(code.decode_erasures(&word), Err(DecodingError::DecodingFailure)));
+	}
+}
@@ -1,3 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+mod decoding;
 pub mod reed_solomon;
+
+pub use decoding::DecodingError;
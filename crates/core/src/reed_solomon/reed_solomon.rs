@@ -120,7 +120,7 @@ where
 			symbol_bits = P::Scalar::N_BITS,
 		)
 		.entered();
-		if (code.len() << log_batch_size) < self.len() {
+		if code.len() * P::WIDTH < self.len() << log_batch_size {
 			bail!(Error::BufferTooSmall {
 				log_code_len: self.len(),
 			});
@@ -174,3 +174,33 @@ where
 		self.encode_batch_inplace(PE::cast_bases_mut(code), log_batch_size + PE::Scalar::LOG_DEGREE)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use assert_matches::assert_matches;
+	use binius_field::PackedBinaryField4x32b;
+
+	use super::*;
+
+	#[test]
+	fn test_encode_checks_buffer_len_in_scalars() {
+		type P = PackedBinaryField4x32b;
+
+		let rs_code = ReedSolomonCode::<P>::new(3, 1, &NTTOptions::default()).unwrap();
+
+		// A buffer of exactly `len() << log_batch_size` scalars is large enough
+		let log_batch_size = 0;
+		let mut code = vec![P::default(); (rs_code.len() << log_batch_size) / P::WIDTH];
+		rs_code
+			.encode_ext_batch_inplace(&mut code, log_batch_size)
+			.unwrap();
+
+		// The batch size scales the required length, not the provided one
+		let log_batch_size = 4;
+		let mut code = vec![P::default(); rs_code.len() / P::WIDTH];
+		assert_matches!(
+			rs_code.encode_ext_batch_inplace(&mut code, log_batch_size),
+			Err(Error::BufferTooSmall { .. })
+		);
+	}
+}
@@ -16,7 +16,7 @@ use super::{
 	tower_tensor_algebra::TowerTensorAlgebra,
 };
 use crate::{
-	fiat_shamir::{CanSample, Challenger},
+	fiat_shamir::Challenger,
 	piop::PIOPSumcheckClaim,
 	ring_switch::{common::EvalClaimSuffixDesc, eq_ind::RingSwitchEqInd},
 	tower::{PackedTop, TowerFamily},
@@ -40,11 +40,10 @@ pub fn prove<F, P, M, Tower, Challenger_, Backend>(
 	backend: &Backend,
 ) -> Result<ReducedWitness<P>, Error>
 where
-	F: TowerField,
+	F: TowerField + PackedTop<Tower>,
 	P: PackedFieldIndexable<Scalar = F>,
 	M: MultilinearPoly<P> + Sync,
 	Tower: TowerFamily<B128 = F>,
-	F: PackedTop<Tower>,
 	Challenger_: Challenger,
 	Backend: ComputationBackend,
 {
@@ -57,7 +56,9 @@ where
 	// Sample enough randomness to batch tensor elements corresponding to claims that share an
 	// evaluation point prefix.
 	let n_mixing_challenges = log2_ceil_usize(system.sumcheck_claim_descs.len());
-	let mixing_challenges = transcript.sample_vec(n_mixing_challenges);
+	let mixing_challenges = (0..n_mixing_challenges)
+		.map(|_| transcript.sample_labeled::<F>("ring_switch.mixing_challenge"))
+		.collect::<Vec<_>>();
 	let mixing_coeffs = MultilinearQuery::expand(&mixing_challenges).into_expansion();
 
 	// For each evaluation point prefix, send one batched partial evaluation.
@@ -68,21 +69,25 @@ where
 		&system.prefix_descs,
 		&system.eval_claim_to_prefix_desc_index,
 	)?;
-	let mut writer = transcript.message();
+	let mut writer = transcript.message_labeled("ring_switch.tensor_elems");
 	for (mixed_tensor_elem, prefix_desc) in iter::zip(mixed_tensor_elems, &system.prefix_descs) {
 		debug_assert_eq!(mixed_tensor_elem.vertical_elems().len(), 1 << prefix_desc.kappa());
 		writer.write_scalar_slice(mixed_tensor_elem.vertical_elems());
 	}
 
 	// Sample the row-batching randomness.
-	let row_batch_challenges = transcript.sample_vec(system.max_claim_kappa());
+	let row_batch_challenges = (0..system.max_claim_kappa())
+		.map(|_| transcript.sample_labeled::<F>("ring_switch.row_batch_challenge"))
+		.collect::<Vec<_>>();
 	let row_batch_coeffs = Arc::new(RowBatchCoeffs::new(
 		MultilinearQuery::<F, _>::expand(&row_batch_challenges).into_expansion(),
 	));
 
 	let row_batched_evals =
 		compute_row_batched_sumcheck_evals(scaled_tensor_elems, row_batch_coeffs.coeffs());
-	transcript.message().write_scalar_slice(&row_batched_evals);
+	transcript
+		.message_labeled("ring_switch.row_batched_evals")
+		.write_scalar_slice(&row_batched_evals);
 
 	// Create the reduced PIOP sumcheck witnesses.
 	let ring_switch_eq_inds = make_ring_switch_eq_inds::<_, P, Tower>(
@@ -205,9 +210,8 @@ fn compute_row_batched_sumcheck_evals<F, Tower>(
 	row_batch_coeffs: &[F],
 ) -> Vec<F>
 where
-	F: TowerField,
+	F: TowerField + PackedTop<Tower>,
 	Tower: TowerFamily<B128 = F>,
-	F: PackedTop<Tower>,
 {
 	tensor_elems
 		.into_par_iter()
@@ -223,10 +227,9 @@ fn make_ring_switch_eq_inds<F, P, Tower>(
 	mixing_coeffs: &[F],
 ) -> Result<Vec<MultilinearWitness<'static, P>>, Error>
 where
-	F: TowerField,
+	F: TowerField + PackedTop<Tower>,
 	P: PackedFieldIndexable<Scalar = F>,
 	Tower: TowerFamily<B128 = F>,
-	F: PackedTop<Tower>,
 {
 	sumcheck_claim_descs
 		.par_iter()
@@ -10,7 +10,7 @@ use itertools::izip;
 
 use super::eq_ind::RowBatchCoeffs;
 use crate::{
-	fiat_shamir::{CanSample, Challenger},
+	fiat_shamir::Challenger,
 	piop::PIOPSumcheckClaim,
 	polynomial::MultivariatePoly,
 	ring_switch::{
@@ -41,23 +41,30 @@ where
 	// Sample enough randomness to batch tensor elements corresponding to claims that share an
 	// evaluation point prefix.
 	let n_mixing_challenges = log2_ceil_usize(system.sumcheck_claim_descs.len());
-	let mixing_challenges = transcript.sample_vec(n_mixing_challenges);
+	let mixing_challenges = (0..n_mixing_challenges)
+		.map(|_| transcript.sample_labeled::<F>("ring_switch.mixing_challenge"))
+		.collect::<Vec<_>>();
 	let mixing_coeffs = MultilinearQuery::expand(&mixing_challenges).into_expansion();
 
 	// For each evaluation point prefix, receive one batched tensor algebra element and verify
 	// that it is consistent with the evaluation claims.
-	let tensor_elems =
-		verify_receive_tensor_elems(system, &mixing_coeffs, &mut transcript.message())?;
+	let tensor_elems = verify_receive_tensor_elems(
+		system,
+		&mixing_coeffs,
+		&mut transcript.message_labeled("ring_switch.tensor_elems"),
+	)?;
 
 	// Sample the row-batching randomness.
-	let row_batch_challenges = transcript.sample_vec(system.max_claim_kappa());
+	let row_batch_challenges = (0..system.max_claim_kappa())
+		.map(|_| transcript.sample_labeled::<F>("ring_switch.row_batch_challenge"))
+		.collect::<Vec<_>>();
 	let row_batch_coeffs = Arc::new(RowBatchCoeffs::new(
 		MultilinearQuery::<F, _>::expand(&row_batch_challenges).into_expansion(),
 	));
 
 	// For each original evaluation claim, receive the row-batched evaluation claim.
 	let row_batched_evals = transcript
-		.message()
+		.message_labeled("ring_switch.row_batched_evals")
 		.read_scalar_slice(system.sumcheck_claim_descs.len())?;
 
 	// Check that the row-batched evaluation claims sent by the prover are consistent with the
@@ -0,0 +1,131 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{fmt, panic::Location, slice};
+
+use bytes::{buf::UninitSlice, Buf, BufMut};
+
+/// Maximum number of bytes of a log entry that are displayed.
+const MAX_DISPLAYED_BYTES: usize = 64;
+
+/// The kind of a transcript operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogEntryKind {
+	/// Data written to the proof tape and observed.
+	Message,
+	/// Data observed without being written to the proof tape.
+	Observe,
+	/// Advice written to the proof tape without being observed.
+	Decommitment,
+	/// Verifier randomness sampled from the challenger.
+	Sample,
+}
+
+/// A transcript operation and the bytes it wrote, read, observed or sampled.
+///
+/// The bytes of a writer or reader are those that went through its methods.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogEntry {
+	pub kind: LogEntryKind,
+	/// The label of a labeled operation.
+	pub label: Option<String>,
+	/// The call site of the operation.
+	pub location: &'static Location<'static>,
+	/// The protocol phase set with `set_phase` before the operation.
+	pub phase: Option<String>,
+	pub bytes: Vec<u8>,
+}
+
+impl fmt::Display for LogEntry {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if let Some(phase) = &self.phase {
+			write!(f, "[{phase}] ")?;
+		}
+		write!(f, "{:?} ", self.kind)?;
+		if let Some(label) = &self.label {
+			write!(f, "{label} ")?;
+		}
+		write!(f, "({}): ", self.location)?;
+		for byte in self.bytes.iter().take(MAX_DISPLAYED_BYTES) {
+			write!(f, "{byte:02x}")?;
+		}
+		if self.bytes.len() > MAX_DISPLAYED_BYTES {
+			write!(f, "... ({} bytes)", self.bytes.len())?;
+		}
+		Ok(())
+	}
+}
+
+/// The log of a transcript.
+#[derive(Debug, Default)]
+pub(super) struct Log {
+	pub(super) entries: Vec<LogEntry>,
+	/// Whether unlabeled operations are recorded too.
+	pub(super) trace: bool,
+	pub(super) phase: Option<String>,
+}
+
+/// Starts a log entry if the operation is logged, returning the buffer of its bytes.
+pub(super) fn log_entry<'a>(
+	log: &'a mut Option<Log>,
+	kind: LogEntryKind,
+	label: Option<&str>,
+	location: &'static Location<'static>,
+) -> Option<&'a mut Vec<u8>> {
+	let log = log.as_mut()?;
+	if label.is_none() && !log.trace {
+		return None;
+	}
+	log.entries.push(LogEntry {
+		kind,
+		label: label.map(ToString::to_string),
+		location,
+		phase: log.phase.clone(),
+		bytes: Vec::new(),
+	});
+	log.entries.last_mut().map(|entry| &mut entry.bytes)
+}
+
+/// A buffer that copies the bytes passing through it into an optional log entry.
+pub(super) struct Recorded<'a, B> {
+	pub(super) inner: &'a mut B,
+	pub(super) bytes: Option<&'a mut Vec<u8>>,
+}
+
+impl<B: Buf> Buf for Recorded<'_, B> {
+	fn remaining(&self) -> usize {
+		self.inner.remaining()
+	}
+
+	fn chunk(&self) -> &[u8] {
+		self.inner.chunk()
+	}
+
+	fn advance(&mut self, cnt: usize) {
+		if let Some(bytes) = &mut self.bytes {
+			let readable = self.inner.chunk();
+			assert!(cnt <= readable.len());
+			bytes.extend_from_slice(&readable[..cnt]);
+		}
+		self.inner.advance(cnt);
+	}
+}
+
+unsafe impl<B: BufMut> BufMut for Recorded<'_, B> {
+	fn remaining_mut(&self) -> usize {
+		self.inner.remaining_mut()
+	}
+
+	unsafe fn advance_mut(&mut self, cnt: usize) {
+		if let Some(bytes) = &mut self.bytes {
+			let written = self.inner.chunk_mut();
+			assert!(cnt <= written.len());
+			// SAFETY: the caller guarantees that the next cnt bytes are initialized.
+			bytes.extend_from_slice(slice::from_raw_parts(written.as_mut_ptr(), cnt));
+		}
+		self.inner.advance_mut(cnt);
+	}
+
+	fn chunk_mut(&mut self) -> &mut UninitSlice {
+		self.inner.chunk_mut()
+	}
+}
@@ -11,15 +11,26 @@
 //! used for values that were previously committed to in the transcript tape.** For example, it is
 //! secure to write a Merkle tree root to the transcript tape, sample a random index, then provide
 //! the Merkle leaf opening at that index in the advice tape.
+//!
+//! Transcripts may be created with a domain separator that identifies the protocol, and prover
+//! messages and verifier challenges may be labeled. Both are absorbed into the Fiat-Shamir state,
+//! so that the challenges of composed protocols cannot collide. When logging is enabled, the
+//! labeled operations are recorded with their bytes as a readable [`LogEntry`] sequence, which can
+//! be compared across implementations. When tracing is enabled, unlabeled operations are recorded
+//! too, and the [`trace`] module compares the traces of a prover and a verifier.
 
 mod error;
+mod log;
+pub mod trace;
 
-use std::{iter::repeat_with, slice};
+use std::{iter::repeat_with, panic::Location, slice};
 
 use binius_field::{PackedField, TowerField};
 use binius_utils::{DeserializeBytes, SerializationMode, SerializeBytes};
 use bytes::{buf::UninitSlice, Buf, BufMut, Bytes, BytesMut};
 pub use error::Error;
+use log::{log_entry, Log, Recorded};
+pub use log::{LogEntry, LogEntryKind};
 use tracing::warn;
 
 use crate::fiat_shamir::{CanSample, CanSampleBits, Challenger};
@@ -31,6 +42,8 @@ use crate::fiat_shamir::{CanSample, CanSampleBits, Challenger};
 #[derive(Debug)]
 pub struct ProverTranscript<Challenger> {
 	combined: FiatShamirBuf<BytesMut, Challenger>,
+	domain_separator: Option<String>,
+	log: Option<Log>,
 	debug_assertions: bool,
 }
 
@@ -41,6 +54,7 @@ pub struct ProverTranscript<Challenger> {
 #[derive(Debug)]
 pub struct VerifierTranscript<Challenger> {
 	combined: FiatShamirBuf<Bytes, Challenger>,
+	log: Option<Log>,
 	debug_assertions: bool,
 }
 
@@ -50,6 +64,53 @@ struct FiatShamirBuf<Inner, Challenger> {
 	challenger: Challenger,
 }
 
+/// Prefixes of the absorbed domain separators and labels, which distinguish them from each other.
+const DOMAIN_SEPARATOR_TAG: u8 = 0;
+const LABEL_TAG: u8 = 1;
+
+impl<Inner, Challenger_: Challenger> FiatShamirBuf<Inner, Challenger_> {
+	/// Observes a tagged and length-prefixed string.
+	fn observe_tagged(&mut self, tag: u8, value: &str) {
+		let observer = self.challenger.observer();
+		observer.put_u8(tag);
+		observer.put_u64_le(value.len() as u64);
+		observer.put_slice(value.as_bytes());
+	}
+}
+
+/// Returns the initial log of a transcript, which traces all operations while a trace is recorded.
+fn initial_log() -> Option<Log> {
+	trace::is_recording().then(|| Log {
+		trace: true,
+		..Default::default()
+	})
+}
+
+/// Logs a sampled value if the operation is logged.
+fn log_sample<F: TowerField>(
+	log: &mut Option<Log>,
+	label: Option<&str>,
+	location: &'static Location<'static>,
+	value: F,
+) {
+	if let Some(bytes) = log_entry(log, LogEntryKind::Sample, label, location) {
+		value
+			.serialize(bytes, SerializationMode::CanonicalTower)
+			.expect("vector has infinite capacity");
+	}
+}
+
+/// Enables or disables the logging of labeled operations, or of all operations when `trace` is
+/// set, preserving the current phase.
+fn set_log(log: &mut Option<Log>, enabled: bool, trace: bool) {
+	let phase = log.take().and_then(|log| log.phase);
+	*log = enabled.then(|| Log {
+		entries: Vec::new(),
+		trace,
+		phase,
+	});
+}
+
 impl<Inner: Buf, Challenger_: Challenger> Buf for FiatShamirBuf<Inner, Challenger_> {
 	fn remaining(&self) -> usize {
 		self.buffer.remaining()
@@ -102,12 +163,31 @@ impl<Challenger_: Default + Challenger> ProverTranscript<Challenger_> {
 	pub fn new() -> Self {
 		Self {
 			combined: Default::default(),
+			domain_separator: None,
+			log: initial_log(),
 			debug_assertions: cfg!(debug_assertions),
 		}
 	}
 
+	/// Creates a new prover transcript that first observes a domain separator of the protocol.
+	///
+	/// The verifier transcript must be created with the same domain separator.
+	pub fn new_with_domain_separator(domain_separator: &str) -> Self {
+		let mut transcript = Self::new();
+		transcript
+			.combined
+			.observe_tagged(DOMAIN_SEPARATOR_TAG, domain_separator);
+		transcript.domain_separator = Some(domain_separator.to_string());
+		transcript
+	}
+
 	pub fn into_verifier(self) -> VerifierTranscript<Challenger_> {
-		VerifierTranscript::new(self.finalize())
+		match self.domain_separator.clone() {
+			Some(domain_separator) => {
+				VerifierTranscript::new_with_domain_separator(self.finalize(), &domain_separator)
+			}
+			None => VerifierTranscript::new(self.finalize()),
+		}
 	}
 }
 
@@ -130,16 +210,60 @@ impl<Challenger_: Challenger> ProverTranscript<Challenger_> {
 		self.debug_assertions = debug;
 	}
 
+	/// Enables or disables the log of labeled operations.
+	///
+	/// Logging is disabled by default, unless a trace is recorded with [`trace::record_trace`].
+	/// Changing the logging discards the recorded entries.
+	pub fn set_log(&mut self, enabled: bool) {
+		set_log(&mut self.log, enabled, false);
+	}
+
+	/// Enables or disables the log of all operations, including unlabeled ones.
+	pub fn set_trace(&mut self, enabled: bool) {
+		set_log(&mut self.log, enabled, true);
+	}
+
+	/// Sets the protocol phase of the following logged operations.
+	pub fn set_phase(&mut self, phase: &str) {
+		if let Some(log) = &mut self.log {
+			log.phase = Some(phase.to_string());
+		}
+	}
+
+	/// Returns the operations recorded since logging was enabled.
+	pub fn log(&self) -> &[LogEntry] {
+		self.log.as_ref().map_or(&[], |log| log.entries.as_slice())
+	}
+
 	/// Returns a writeable buffer that only observes the data written, without writing it to the
 	/// proof tape.
 	///
 	/// This method should be used to observe the input statement.
+	#[track_caller]
 	pub fn observe<'a, 'b>(&'a mut self) -> TranscriptWriter<'b, impl BufMut + 'b>
 	where
 		'a: 'b,
 	{
 		TranscriptWriter {
 			buffer: self.combined.challenger.observer(),
+			log: log_entry(&mut self.log, LogEntryKind::Observe, None, Location::caller()),
+			debug_assertions: self.debug_assertions,
+		}
+	}
+
+	/// Like [`Self::observe`], but first observes the label and records the data in the log.
+	#[track_caller]
+	pub fn observe_labeled<'a, 'b>(
+		&'a mut self,
+		label: &str,
+	) -> TranscriptWriter<'b, impl BufMut + 'b>
+	where
+		'a: 'b,
+	{
+		self.combined.observe_tagged(LABEL_TAG, label);
+		TranscriptWriter {
+			buffer: self.combined.challenger.observer(),
+			log: log_entry(&mut self.log, LogEntryKind::Observe, Some(label), Location::caller()),
 			debug_assertions: self.debug_assertions,
 		}
 	}
@@ -151,9 +275,28 @@ impl<Challenger_: Challenger> ProverTranscript<Challenger_> {
 	/// a Merkle tree root as a commitment, and later sends leaf openings. The leaf openings should
 	/// be written using [`Self::decommitment`] because they are verified with respect to the
 	/// previously sent Merkle root.
+	#[track_caller]
 	pub fn decommitment(&mut self) -> TranscriptWriter<impl BufMut> {
 		TranscriptWriter {
 			buffer: &mut self.combined.buffer,
+			log: log_entry(&mut self.log, LogEntryKind::Decommitment, None, Location::caller()),
+			debug_assertions: self.debug_assertions,
+		}
+	}
+
+	/// Like [`Self::decommitment`], but records the data in the log.
+	///
+	/// The label is not observed, as decommitments do not affect the Fiat-Shamir state.
+	#[track_caller]
+	pub fn decommitment_labeled(&mut self, label: &str) -> TranscriptWriter<impl BufMut> {
+		TranscriptWriter {
+			buffer: &mut self.combined.buffer,
+			log: log_entry(
+				&mut self.log,
+				LogEntryKind::Decommitment,
+				Some(label),
+				Location::caller(),
+			),
 			debug_assertions: self.debug_assertions,
 		}
 	}
@@ -161,15 +304,49 @@ impl<Challenger_: Challenger> ProverTranscript<Challenger_> {
 	/// Returns a writeable buffer that observes the data written and writes it to the proof tape.
 	///
 	/// This method should be used by default to write prover messages in an interactive protocol.
+	#[track_caller]
 	pub fn message<'a, 'b>(&'a mut self) -> TranscriptWriter<'b, impl BufMut>
 	where
 		'a: 'b,
 	{
 		TranscriptWriter {
 			buffer: &mut self.combined,
+			log: log_entry(&mut self.log, LogEntryKind::Message, None, Location::caller()),
 			debug_assertions: self.debug_assertions,
 		}
 	}
+
+	/// Like [`Self::message`], but first observes the label and records the data in the log.
+	#[track_caller]
+	pub fn message_labeled<'a, 'b>(&'a mut self, label: &str) -> TranscriptWriter<'b, impl BufMut>
+	where
+		'a: 'b,
+	{
+		self.combined.observe_tagged(LABEL_TAG, label);
+		TranscriptWriter {
+			buffer: &mut self.combined,
+			log: log_entry(&mut self.log, LogEntryKind::Message, Some(label), Location::caller()),
+			debug_assertions: self.debug_assertions,
+		}
+	}
+
+	/// Observes the label and samples a verifier challenge, recording it in the log.
+	#[track_caller]
+	pub fn sample_labeled<F: TowerField>(&mut self, label: &str) -> F {
+		self.combined.observe_tagged(LABEL_TAG, label);
+		let value = sample_value(&mut self.combined.challenger);
+		log_sample(&mut self.log, Some(label), Location::caller(), value);
+		value
+	}
+
+	/// Observes the label and samples `bits` random bits, recording them in the log.
+	#[track_caller]
+	pub fn sample_bits_labeled(&mut self, label: &str, bits: usize) -> usize {
+		self.combined.observe_tagged(LABEL_TAG, label);
+		let value = sample_bits_reader(self.combined.challenger.sampler(), bits);
+		log_sample_bits(&mut self.log, Some(label), Location::caller(), bits, value);
+		value
+	}
 }
 
 impl<Challenger_: Default + Challenger> VerifierTranscript<Challenger_> {
@@ -179,9 +356,19 @@ impl<Challenger_: Default + Challenger> VerifierTranscript<Challenger_> {
 				challenger: Challenger_::default(),
 				buffer: Bytes::from(vec),
 			},
+			log: initial_log(),
 			debug_assertions: cfg!(debug_assertions),
 		}
 	}
+
+	/// Creates a new verifier transcript that first observes a domain separator of the protocol.
+	pub fn new_with_domain_separator(vec: Vec<u8>, domain_separator: &str) -> Self {
+		let mut transcript = Self::new(vec);
+		transcript
+			.combined
+			.observe_tagged(DOMAIN_SEPARATOR_TAG, domain_separator);
+		transcript
+	}
 }
 
 impl<Challenger_: Challenger> VerifierTranscript<Challenger_> {
@@ -198,16 +385,60 @@ impl<Challenger_: Challenger> VerifierTranscript<Challenger_> {
 		self.debug_assertions = debug;
 	}
 
+	/// Enables or disables the log of labeled operations.
+	///
+	/// Logging is disabled by default, unless a trace is recorded with [`trace::record_trace`].
+	/// Changing the logging discards the recorded entries.
+	pub fn set_log(&mut self, enabled: bool) {
+		set_log(&mut self.log, enabled, false);
+	}
+
+	/// Enables or disables the log of all operations, including unlabeled ones.
+	pub fn set_trace(&mut self, enabled: bool) {
+		set_log(&mut self.log, enabled, true);
+	}
+
+	/// Sets the protocol phase of the following logged operations.
+	pub fn set_phase(&mut self, phase: &str) {
+		if let Some(log) = &mut self.log {
+			log.phase = Some(phase.to_string());
+		}
+	}
+
+	/// Returns the operations recorded since logging was enabled.
+	pub fn log(&self) -> &[LogEntry] {
+		self.log.as_ref().map_or(&[], |log| log.entries.as_slice())
+	}
+
 	/// Returns a writable buffer that only observes the data written, without reading it from the
 	/// proof tape.
 	///
 	/// This method should be used to observe the input statement.
+	#[track_caller]
 	pub fn observe<'a, 'b>(&'a mut self) -> TranscriptWriter<'b, impl BufMut + 'b>
 	where
 		'a: 'b,
 	{
 		TranscriptWriter {
 			buffer: self.combined.challenger.observer(),
+			log: log_entry(&mut self.log, LogEntryKind::Observe, None, Location::caller()),
+			debug_assertions: self.debug_assertions,
+		}
+	}
+
+	/// Like [`Self::observe`], but first observes the label and records the data in the log.
+	#[track_caller]
+	pub fn observe_labeled<'a, 'b>(
+		&'a mut self,
+		label: &str,
+	) -> TranscriptWriter<'b, impl BufMut + 'b>
+	where
+		'a: 'b,
+	{
+		self.combined.observe_tagged(LABEL_TAG, label);
+		TranscriptWriter {
+			buffer: self.combined.challenger.observer(),
+			log: log_entry(&mut self.log, LogEntryKind::Observe, Some(label), Location::caller()),
 			debug_assertions: self.debug_assertions,
 		}
 	}
@@ -215,9 +446,28 @@ impl<Challenger_: Challenger> VerifierTranscript<Challenger_> {
 	/// Returns a readable buffer that only reads the data from the proof tape, without observing it.
 	///
 	/// This method should only be used to read advice that was previously written to the transcript as an observed message.
+	#[track_caller]
 	pub fn decommitment(&mut self) -> TranscriptReader<impl Buf + '_> {
 		TranscriptReader {
 			buffer: &mut self.combined.buffer,
+			log: log_entry(&mut self.log, LogEntryKind::Decommitment, None, Location::caller()),
+			debug_assertions: self.debug_assertions,
+		}
+	}
+
+	/// Like [`Self::decommitment`], but records the data in the log.
+	///
+	/// The label is not observed, as decommitments do not affect the Fiat-Shamir state.
+	#[track_caller]
+	pub fn decommitment_labeled(&mut self, label: &str) -> TranscriptReader<impl Buf + '_> {
+		TranscriptReader {
+			buffer: &mut self.combined.buffer,
+			log: log_entry(
+				&mut self.log,
+				LogEntryKind::Decommitment,
+				Some(label),
+				Location::caller(),
+			),
 			debug_assertions: self.debug_assertions,
 		}
 	}
@@ -225,20 +475,65 @@ impl<Challenger_: Challenger> VerifierTranscript<Challenger_> {
 	/// Returns a readable buffer that observes the data read.
 	///
 	/// This method should be used by default to read verifier messages in an interactive protocol.
+	#[track_caller]
 	pub fn message<'a, 'b>(&'a mut self) -> TranscriptReader<'b, impl Buf>
 	where
 		'a: 'b,
 	{
 		TranscriptReader {
 			buffer: &mut self.combined,
+			log: log_entry(&mut self.log, LogEntryKind::Message, None, Location::caller()),
 			debug_assertions: self.debug_assertions,
 		}
 	}
+
+	/// Like [`Self::message`], but first observes the label and records the data in the log.
+	#[track_caller]
+	pub fn message_labeled<'a, 'b>(&'a mut self, label: &str) -> TranscriptReader<'b, impl Buf>
+	where
+		'a: 'b,
+	{
+		self.combined.observe_tagged(LABEL_TAG, label);
+		TranscriptReader {
+			buffer: &mut self.combined,
+			log: log_entry(&mut self.log, LogEntryKind::Message, Some(label), Location::caller()),
+			debug_assertions: self.debug_assertions,
+		}
+	}
+
+	/// Observes the label and samples a verifier challenge, recording it in the log.
+	#[track_caller]
+	pub fn sample_labeled<F: TowerField>(&mut self, label: &str) -> F {
+		self.combined.observe_tagged(LABEL_TAG, label);
+		let value = sample_value(&mut self.combined.challenger);
+		log_sample(&mut self.log, Some(label), Location::caller(), value);
+		value
+	}
+
+	/// Observes the label and samples `bits` random bits, recording them in the log.
+	#[track_caller]
+	pub fn sample_bits_labeled(&mut self, label: &str, bits: usize) -> usize {
+		self.combined.observe_tagged(LABEL_TAG, label);
+		let value = sample_bits_reader(self.combined.challenger.sampler(), bits);
+		log_sample_bits(&mut self.log, Some(label), Location::caller(), bits, value);
+		value
+	}
+}
+
+impl<Challenger> Drop for ProverTranscript<Challenger> {
+	fn drop(&mut self) {
+		if let Some(log) = self.log.take() {
+			trace::submit(log.entries);
+		}
+	}
 }
 
 // Useful warnings to see if we are neglecting to read any advice or transcript entirely
 impl<Challenger> Drop for VerifierTranscript<Challenger> {
 	fn drop(&mut self) {
+		if let Some(log) = self.log.take() {
+			trace::submit(log.entries);
+		}
 		if self.combined.buffer.has_remaining() {
 			warn!(
 				"Transcript reader is not fully read out: {:?} bytes left",
@@ -250,29 +545,39 @@ impl<Challenger> Drop for VerifierTranscript<Challenger> {
 
 pub struct TranscriptReader<'a, B: Buf> {
 	buffer: &'a mut B,
+	/// The bytes of the log entry of a labeled reader.
+	log: Option<&'a mut Vec<u8>>,
 	debug_assertions: bool,
 }
 
 impl<B: Buf> TranscriptReader<'_, B> {
+	/// Returns the underlying buffer, whose reads are not recorded in the log.
 	pub const fn buffer(&mut self) -> &mut B {
 		self.buffer
 	}
 
+	fn recorded(&mut self) -> Recorded<'_, B> {
+		Recorded {
+			inner: self.buffer,
+			bytes: self.log.as_deref_mut(),
+		}
+	}
+
 	pub fn read<T: DeserializeBytes>(&mut self) -> Result<T, Error> {
 		let mode = SerializationMode::CanonicalTower;
-		T::deserialize(self.buffer(), mode).map_err(Into::into)
+		T::deserialize(self.recorded(), mode).map_err(Into::into)
 	}
 
 	pub fn read_vec<T: DeserializeBytes>(&mut self, n: usize) -> Result<Vec<T>, Error> {
 		let mode = SerializationMode::CanonicalTower;
-		let mut buffer = self.buffer();
+		let mut buffer = self.recorded();
 		repeat_with(move || T::deserialize(&mut buffer, mode).map_err(Into::into))
 			.take(n)
 			.collect()
 	}
 
 	pub fn read_bytes(&mut self, buf: &mut [u8]) -> Result<(), Error> {
-		let buffer = self.buffer();
+		let mut buffer = self.recorded();
 		if buffer.remaining() < buf.len() {
 			return Err(Error::NotEnoughBytes);
 		}
@@ -287,7 +592,7 @@ impl<B: Buf> TranscriptReader<'_, B> {
 	}
 
 	pub fn read_scalar_slice_into<F: TowerField>(&mut self, buf: &mut [F]) -> Result<(), Error> {
-		let mut buffer = self.buffer();
+		let mut buffer = self.recorded();
 		for elem in buf {
 			let mode = SerializationMode::CanonicalTower;
 			*elem = DeserializeBytes::deserialize(&mut buffer, mode)?;
@@ -328,10 +633,13 @@ impl<B: Buf> TranscriptReader<'_, B> {
 
 pub struct TranscriptWriter<'a, B: BufMut> {
 	buffer: &'a mut B,
+	/// The bytes of the log entry of a labeled writer.
+	log: Option<&'a mut Vec<u8>>,
 	debug_assertions: bool,
 }
 
 impl<B: BufMut> TranscriptWriter<'_, B> {
+	/// Returns the underlying buffer, whose writes are not recorded in the log.
 	pub const fn buffer(&mut self) -> &mut B {
 		self.buffer
 	}
@@ -391,24 +699,34 @@ impl<B: BufMut> TranscriptWriter<'_, B> {
 		}
 	}
 
-	fn proof_size_event_wrapper<F: Fn(&mut B)>(&mut self, f: F) {
-		let buffer = self.buffer();
+	fn proof_size_event_wrapper<F: FnOnce(&mut Recorded<B>)>(&mut self, f: F) {
+		let mut buffer = Recorded {
+			inner: self.buffer,
+			bytes: self.log.as_deref_mut(),
+		};
 		let start_bytes = buffer.remaining_mut();
-		f(buffer);
+		f(&mut buffer);
 		let end_bytes = buffer.remaining_mut();
 		tracing::event!(name: "proof_size", tracing::Level::INFO, counter=true, incremental=true, value=start_bytes - end_bytes);
 	}
 }
 
+fn sample_value<F: TowerField>(challenger: &mut impl Challenger) -> F {
+	let mode = SerializationMode::CanonicalTower;
+	DeserializeBytes::deserialize(challenger.sampler(), mode)
+		.expect("challenger has infinite buffer")
+}
+
 impl<F, Challenger_> CanSample<F> for VerifierTranscript<Challenger_>
 where
 	F: TowerField,
 	Challenger_: Challenger,
 {
+	#[track_caller]
 	fn sample(&mut self) -> F {
-		let mode = SerializationMode::CanonicalTower;
-		DeserializeBytes::deserialize(self.combined.challenger.sampler(), mode)
-			.expect("challenger has infinite buffer")
+		let value = sample_value(&mut self.combined.challenger);
+		log_sample(&mut self.log, None, Location::caller(), value);
+		value
 	}
 }
 
@@ -417,10 +735,11 @@ where
 	F: TowerField,
 	Challenger_: Challenger,
 {
+	#[track_caller]
 	fn sample(&mut self) -> F {
-		let mode = SerializationMode::CanonicalTower;
-		DeserializeBytes::deserialize(self.combined.challenger.sampler(), mode)
-			.expect("challenger has infinite buffer")
+		let value = sample_value(&mut self.combined.challenger);
+		log_sample(&mut self.log, None, Location::caller(), value);
+		value
 	}
 }
 
@@ -442,12 +761,29 @@ fn sample_bits_reader<Reader: Buf>(mut reader: Reader, bits: usize) -> usize {
 	mask & unmasked
 }
 
+/// Logs sampled bits if the operation is logged.
+fn log_sample_bits(
+	log: &mut Option<Log>,
+	label: Option<&str>,
+	location: &'static Location<'static>,
+	bits: usize,
+	value: usize,
+) {
+	if let Some(bytes) = log_entry(log, LogEntryKind::Sample, label, location) {
+		let n_bytes = bits.min(usize::BITS as usize).div_ceil(8);
+		bytes.extend_from_slice(&value.to_le_bytes()[..n_bytes]);
+	}
+}
+
 impl<Challenger_> CanSampleBits<usize> for VerifierTranscript<Challenger_>
 where
 	Challenger_: Challenger,
 {
+	#[track_caller]
 	fn sample_bits(&mut self, bits: usize) -> usize {
-		sample_bits_reader(self.combined.challenger.sampler(), bits)
+		let value = sample_bits_reader(self.combined.challenger.sampler(), bits);
+		log_sample_bits(&mut self.log, None, Location::caller(), bits, value);
+		value
 	}
 }
 
@@ -455,8 +791,11 @@ impl<Challenger_> CanSampleBits<usize> for ProverTranscript<Challenger_>
 where
 	Challenger_: Challenger,
 {
+	#[track_caller]
 	fn sample_bits(&mut self, bits: usize) -> usize {
-		sample_bits_reader(self.combined.challenger.sampler(), bits)
+		let value = sample_bits_reader(self.combined.challenger.sampler(), bits);
+		log_sample_bits(&mut self.log, None, Location::caller(), bits, value);
+		value
 	}
 }
 
@@ -656,4 +995,94 @@ mod tests {
 			.message()
 			.read_debug("test_transcript_debug_should_fail");
 	}
+
+	#[test]
+	fn test_labeled_transcript() {
+		let mut prover_transcript =
+			ProverTranscript::<HasherChallenger<Groestl256>>::new_with_domain_separator("test");
+		prover_transcript.set_log(true);
+		prover_transcript
+			.message_labeled("round.coeffs")
+			.write_scalar_slice(&[BinaryField32b::new(1), BinaryField32b::new(2)]);
+		let challenge: BinaryField128b = prover_transcript.sample_labeled("round.challenge");
+		prover_transcript
+			.decommitment_labeled("opening")
+			.write_bytes(&[3, 4]);
+		let index = prover_transcript.sample_bits_labeled("query", 10);
+		assert!(index < 1 << 10);
+		prover_transcript
+			.message()
+			.write_scalar(BinaryField8b::new(5));
+		let prover_log = prover_transcript.log().to_vec();
+		assert_eq!(
+			prover_log
+				.iter()
+				.map(|entry| (entry.kind, entry.label.as_deref(), entry.bytes.len()))
+				.collect::<Vec<_>>(),
+			[
+				(LogEntryKind::Message, Some("round.coeffs"), 8),
+				(LogEntryKind::Sample, Some("round.challenge"), 16),
+				(LogEntryKind::Decommitment, Some("opening"), 2),
+				(LogEntryKind::Sample, Some("query"), 2),
+			]
+		);
+		let display = prover_log[2].to_string();
+		assert!(display.starts_with("Decommitment opening (crates/core/src/transcript/mod.rs:"));
+		assert!(display.ends_with("): 0304"));
+
+		let mut verifier_transcript = prover_transcript.into_verifier();
+		verifier_transcript.set_log(true);
+		let coeffs: Vec<BinaryField32b> = verifier_transcript
+			.message_labeled("round.coeffs")
+			.read_scalar_slice(2)
+			.unwrap();
+		assert_eq!(coeffs, [BinaryField32b::new(1), BinaryField32b::new(2)]);
+		assert_eq!(
+			verifier_transcript.sample_labeled::<BinaryField128b>("round.challenge"),
+			challenge
+		);
+		let mut opening = [0; 2];
+		verifier_transcript
+			.decommitment_labeled("opening")
+			.read_bytes(&mut opening)
+			.unwrap();
+		assert_eq!(verifier_transcript.sample_bits_labeled("query", 10), index);
+		let _: BinaryField8b = verifier_transcript.message().read_scalar().unwrap();
+		assert_eq!(trace::diff_traces(&prover_log, verifier_transcript.log()), None);
+		verifier_transcript.finalize().unwrap();
+	}
+
+	#[test]
+	fn test_domain_separation() {
+		fn challenge(domain_separator: Option<&str>, label: Option<&str>) -> BinaryField128b {
+			let mut transcript = match domain_separator {
+				Some(domain_separator) => {
+					ProverTranscript::<HasherChallenger<Groestl256>>::new_with_domain_separator(
+						domain_separator,
+					)
+				}
+				None => ProverTranscript::<HasherChallenger<Groestl256>>::new(),
+			};
+			match label {
+				Some(label) => transcript.message_labeled(label).write_bytes(b"message"),
+				None => transcript.message().write_bytes(b"message"),
+			}
+			transcript.sample()
+		}
+
+		let challenges = [
+			challenge(None, None),
+			challenge(Some("protocol_a"), None),
+			challenge(Some("protocol_b"), None),
+			challenge(None, Some("protocol_a")),
+			challenge(Some("protocol_a"), Some("message")),
+			challenge(Some("protocol_a"), Some("other")),
+		];
+		for i in 0..challenges.len() {
+			for j in 0..i {
+				assert_ne!(challenges[i], challenges[j]);
+			}
+		}
+		assert_eq!(challenge(Some("protocol_a"), Some("other")), challenges[5]);
+	}
 }
@@ -0,0 +1,244 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Recording of transcript traces and comparison of the prover and verifier traces.
+//!
+//! Transcripts created within [`record_trace`] record every operation, labeled with its call site
+//! and protocol phase. When a verifier rejects a proof, comparing its trace with the prover's
+//! locates the first operation where the two sides disagree, such as a message read with a
+//! different length than it was written or a challenge sampled from a different state.
+
+use std::{cell::RefCell, fmt, mem};
+
+use super::log::{LogEntry, LogEntryKind};
+
+thread_local! {
+	static RECORDED: RefCell<Option<Vec<LogEntry>>> = const { RefCell::new(None) };
+}
+
+/// Runs `f`, recording the traces of the transcripts it creates and drops on this thread.
+///
+/// The entries of each transcript are appended to the returned trace when it is dropped.
+pub fn record_trace<R>(f: impl FnOnce() -> R) -> (R, Vec<LogEntry>) {
+	let outer = RECORDED.with(|recorded| recorded.replace(Some(Vec::new())));
+	let result = f();
+	let trace = RECORDED
+		.with(|recorded| mem::replace(&mut *recorded.borrow_mut(), outer))
+		.expect("trace is recorded until f returns");
+	(result, trace)
+}
+
+/// Replays a proof by running the verification `verify`, and compares its trace with the trace
+/// of the prover.
+pub fn replay<R>(prover_trace: &[LogEntry], verify: impl FnOnce() -> R) -> (R, Option<Divergence>) {
+	let (result, verifier_trace) = record_trace(verify);
+	(result, diff_traces(prover_trace, &verifier_trace))
+}
+
+pub(super) fn is_recording() -> bool {
+	RECORDED.with(|recorded| recorded.borrow().is_some())
+}
+
+pub(super) fn submit(entries: Vec<LogEntry>) {
+	RECORDED.with(|recorded| {
+		if let Some(trace) = recorded.borrow_mut().as_mut() {
+			trace.extend(entries);
+		}
+	});
+}
+
+/// The first operation where a prover and a verifier trace disagree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Divergence {
+	/// The protocol phase of the diverging operation.
+	pub phase: Option<String>,
+	/// The diverging operation of the prover, if the prover trace did not end before.
+	pub prover: Option<LogEntry>,
+	/// The diverging operation of the verifier, if the verifier trace did not end before.
+	pub verifier: Option<LogEntry>,
+	/// The offset of the first differing byte in the diverging operation of the verifier.
+	pub offset: usize,
+}
+
+impl fmt::Display for Divergence {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "transcripts diverge")?;
+		if let Some(phase) = &self.phase {
+			write!(f, " in phase {phase}")?;
+		}
+		writeln!(f, " at byte {} of the verifier operation", self.offset)?;
+		for (side, entry) in [("prover", &self.prover), ("verifier", &self.verifier)] {
+			match entry {
+				Some(entry) => writeln!(f, "  {side}: {entry}")?,
+				None => writeln!(f, "  {side}: end of trace")?,
+			}
+		}
+		Ok(())
+	}
+}
+
+/// Returns the first divergence of two traces, or `None` if they agree.
+///
+/// Consecutive operations of the same kind and label are compared as a single operation, so that
+/// data written in one call and read in several calls, or the other way around, agrees. The call
+/// sites and phases are not compared.
+pub fn diff_traces(prover: &[LogEntry], verifier: &[LogEntry]) -> Option<Divergence> {
+	let prover = segments(prover);
+	let verifier = segments(verifier);
+	for i in 0..prover.len().max(verifier.len()) {
+		let (prover, verifier) = match (prover.get(i), verifier.get(i)) {
+			(Some(prover), Some(verifier)) => (prover, verifier),
+			(prover, verifier) => {
+				return Some(divergence(
+					prover.map(|segment| segment.entries[0]),
+					verifier.map(|segment| segment.entries[0]),
+					0,
+				))
+			}
+		};
+		if prover.kind != verifier.kind || prover.label != verifier.label {
+			return Some(divergence(Some(prover.entries[0]), Some(verifier.entries[0]), 0));
+		}
+		let offset = prover
+			.bytes
+			.iter()
+			.zip(&verifier.bytes)
+			.position(|(a, b)| a != b)
+			.unwrap_or_else(|| prover.bytes.len().min(verifier.bytes.len()));
+		if offset < prover.bytes.len().max(verifier.bytes.len()) {
+			let (prover_entry, _) = prover.entry_at(offset);
+			let (verifier_entry, verifier_offset) = verifier.entry_at(offset);
+			return Some(divergence(Some(prover_entry), Some(verifier_entry), verifier_offset));
+		}
+	}
+	None
+}
+
+fn divergence(prover: Option<&LogEntry>, verifier: Option<&LogEntry>, offset: usize) -> Divergence {
+	Divergence {
+		phase: verifier.or(prover).and_then(|entry| entry.phase.clone()),
+		prover: prover.cloned(),
+		verifier: verifier.cloned(),
+		offset,
+	}
+}
+
+/// Consecutive non-empty entries of the same kind and label.
+struct Segment<'a> {
+	kind: LogEntryKind,
+	label: Option<&'a str>,
+	bytes: Vec<u8>,
+	entries: Vec<&'a LogEntry>,
+}
+
+impl<'a> Segment<'a> {
+	/// Returns the entry containing the byte at `offset`, and the offset within the entry.
+	///
+	/// Offsets past the end belong to the last entry.
+	fn entry_at(&self, mut offset: usize) -> (&'a LogEntry, usize) {
+		for (i, entry) in self.entries.iter().enumerate() {
+			if offset < entry.bytes.len() || i == self.entries.len() - 1 {
+				return (entry, offset);
+			}
+			offset -= entry.bytes.len();
+		}
+		unreachable!("segments are not empty")
+	}
+}
+
+fn segments(trace: &[LogEntry]) -> Vec<Segment<'_>> {
+	let mut segments = Vec::<Segment>::new();
+	for entry in trace.iter().filter(|entry| !entry.bytes.is_empty()) {
+		match segments.last_mut() {
+			Some(segment)
+				if segment.kind == entry.kind && segment.label == entry.label.as_deref() =>
+			{
+				segment.bytes.extend_from_slice(&entry.bytes);
+				segment.entries.push(entry);
+			}
+			_ => segments.push(Segment {
+				kind: entry.kind,
+				label: entry.label.as_deref(),
+				bytes: entry.bytes.clone(),
+				entries: vec![entry],
+			}),
+		}
+	}
+	segments
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField128b, BinaryField32b};
+	use binius_hash::groestl::Groestl256;
+
+	use super::*;
+	use crate::{
+		fiat_shamir::{CanSample, HasherChallenger},
+		transcript::{ProverTranscript, VerifierTranscript},
+	};
+
+	type F = BinaryField32b;
+
+	fn prove() -> Vec<u8> {
+		let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		transcript.set_phase("commit");
+		let mut writer = transcript.message();
+		writer.write_scalar(F::new(1));
+		writer.write_scalar(F::new(2));
+		let _: BinaryField128b = transcript.sample();
+		transcript.set_phase("open");
+		transcript.message().write_scalar(F::new(3));
+		transcript.decommitment().write_bytes(&[4, 5, 6]);
+		transcript.finalize()
+	}
+
+	fn verify(proof: Vec<u8>, n_commitments: usize) -> Result<(), crate::transcript::Error> {
+		let mut transcript = VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof);
+		transcript.set_phase("commit");
+		for _ in 0..n_commitments {
+			transcript.message().read_scalar::<F>()?;
+		}
+		let _: BinaryField128b = transcript.sample();
+		transcript.set_phase("open");
+		transcript.message().read_scalar::<F>()?;
+		let mut opening = [0; 3];
+		transcript.decommitment().read_bytes(&mut opening)?;
+		transcript.finalize()
+	}
+
+	#[test]
+	fn test_matching_traces() {
+		let (proof, prover_trace) = record_trace(prove);
+		assert_eq!(prover_trace.len(), 4);
+		assert_eq!(prover_trace[0].bytes.len(), 8);
+		assert_eq!(prover_trace[3].kind, LogEntryKind::Decommitment);
+
+		// the commitments are read in two calls, but were written in one
+		let (result, divergence) = replay(&prover_trace, || verify(proof, 2));
+		result.unwrap();
+		assert_eq!(divergence, None);
+	}
+
+	#[test]
+	fn test_divergence() {
+		let (proof, prover_trace) = record_trace(prove);
+
+		let (result, divergence) = replay(&prover_trace, || verify(proof, 1));
+		assert!(result.is_err());
+		let divergence = divergence.unwrap();
+		assert_eq!(divergence.phase.as_deref(), Some("commit"));
+		assert_eq!(divergence.offset, 4);
+		assert_eq!(divergence.prover.unwrap().kind, LogEntryKind::Message);
+		assert_eq!(divergence.verifier.unwrap().kind, LogEntryKind::Message);
+	}
+
+	#[test]
+	fn test_not_recording() {
+		let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		transcript.message().write_scalar(F::new(1));
+		assert!(transcript.log().is_empty());
+		transcript.set_trace(true);
+		transcript.message().write_scalar(F::new(1));
+		assert_eq!(transcript.log().len(), 1);
+	}
+}
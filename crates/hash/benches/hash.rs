@@ -1,6 +1,9 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use binius_hash::{groestl::Groestl256, VisionHasherDigest};
+use binius_hash::{
+	groestl::{Groestl256, Groestl256Parallel},
+	MultiDigest, VisionHasherDigest,
+};
 use criterion::{criterion_group, criterion_main, Criterion, Throughput};
 use digest::Digest;
 use rand::{thread_rng, RngCore};
@@ -22,6 +25,16 @@ fn bench_groestl(c: &mut Criterion) {
 		bench.iter(|| groestl_crypto::Groestl256::digest(data));
 	});
 
+	// Hash the data as many short messages, like the leaves of a Merkle tree
+	const MESSAGE_LEN: usize = 256;
+	let messages = data.chunks(MESSAGE_LEN).collect::<Vec<_>>();
+	group.bench_function("Groestl256-256B-messages", |bench| {
+		bench.iter(|| messages.iter().map(Groestl256::digest).collect::<Vec<_>>());
+	});
+	group.bench_function("Groestl256-256B-messages-hash_many", |bench| {
+		bench.iter(|| Groestl256Parallel::hash_many(&messages));
+	});
+
 	group.finish()
 }
 
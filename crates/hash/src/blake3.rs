@@ -0,0 +1,26 @@
+// Copyright 2025 Irreducible Inc.
+
+use digest::{Digest, Output};
+
+use crate::{CompressionFunction, PseudoCompressionFunction};
+
+/// The BLAKE3 hash function with a 32-byte output.
+pub type Blake3 = blake3::Hasher;
+
+/// A two-to-one compression function for BLAKE3 digests.
+///
+/// The compression is the BLAKE3 hash of the concatenated digests. The 64-byte message fits in a
+/// single block, so a compression takes one call of the BLAKE3 compression function.
+#[derive(Debug, Default, Clone)]
+pub struct Blake3Compression;
+
+impl PseudoCompressionFunction<Output<Blake3>, 2> for Blake3Compression {
+	fn compress(&self, input: [Output<Blake3>; 2]) -> Output<Blake3> {
+		<Blake3 as Digest>::new()
+			.chain_update(input[0].as_slice())
+			.chain_update(input[1].as_slice())
+			.finalize()
+	}
+}
+
+impl CompressionFunction<Output<Blake3>, 2> for Blake3Compression {}
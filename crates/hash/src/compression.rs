@@ -12,6 +12,19 @@
 /// the preimage of a non-leaf node must consist of compression outputs.
 pub trait PseudoCompressionFunction<T, const N: usize>: Clone {
 	fn compress(&self, input: [T; N]) -> T;
+
+	/// Compresses several independent inputs.
+	///
+	/// Implementations may process the inputs in parallel lanes.
+	fn compress_many(&self, inputs: &[[T; N]]) -> Vec<T>
+	where
+		T: Clone,
+	{
+		inputs
+			.iter()
+			.map(|input| self.compress(input.clone()))
+			.collect()
+	}
 }
 
 /// An `N`-to-1 compression function.
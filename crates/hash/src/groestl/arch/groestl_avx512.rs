@@ -179,6 +179,47 @@ fn combined_perm_m512i(p_block: __m512i, q_block: __m512i) -> (__m512i, __m512i)
 	(q_block, p_block)
 }
 
+fn combined_perm_lanes_m512i<const LANES: usize>(
+	p_blocks: &mut [__m512i; LANES],
+	q_blocks: &mut [__m512i; LANES],
+) {
+	for r in 0..ROUND_SIZE {
+		for (p_block, q_block) in p_blocks.iter_mut().zip(q_blocks.iter_mut()) {
+			*p_block = add_round_constants_p(*p_block, r as u8);
+			*q_block = add_round_constants_q(*q_block, r as u8);
+		}
+		for (p_block, q_block) in p_blocks.iter_mut().zip(q_blocks.iter_mut()) {
+			*p_block = sub_bytes(*p_block);
+			*q_block = sub_bytes(*q_block);
+		}
+		for (p_block, q_block) in p_blocks.iter_mut().zip(q_blocks.iter_mut()) {
+			*p_block = shift_bytes(*p_block, &SHIFT_ARRAY_P);
+			*q_block = shift_bytes(*q_block, &SHIFT_ARRAY_Q);
+		}
+		for (p_block, q_block) in p_blocks.iter_mut().zip(q_blocks.iter_mut()) {
+			*p_block = mix_bytes(*p_block);
+			*q_block = mix_bytes(*q_block);
+		}
+	}
+}
+
+fn perm_p_lanes_m512i<const LANES: usize>(blocks: &mut [__m512i; LANES]) {
+	for r in 0..ROUND_SIZE {
+		for block in blocks.iter_mut() {
+			*block = add_round_constants_p(*block, r as u8);
+		}
+		for block in blocks.iter_mut() {
+			*block = sub_bytes(*block);
+		}
+		for block in blocks.iter_mut() {
+			*block = shift_bytes(*block, &SHIFT_ARRAY_P);
+		}
+		for block in blocks.iter_mut() {
+			*block = mix_bytes(*block);
+		}
+	}
+}
+
 impl GroestlShortInternal for GroestlShortImpl {
 	type State = __m512i;
 
@@ -211,4 +252,17 @@ impl GroestlShortInternal for GroestlShortImpl {
 		let (p, q) = combined_perm_m512i(p, q);
 		Self::xor_state(h, &xor_blocks(p, q));
 	}
+
+	fn compress_lanes<const LANES: usize>(h: &mut [Self::State; LANES], m: &[[u8; 64]; LANES]) {
+		let mut q = m.map(|m| Self::state_from_bytes(&m));
+		let mut p = std::array::from_fn(|i| xor_blocks(h[i], q[i]));
+		combined_perm_lanes_m512i(&mut p, &mut q);
+		for ((h, p), q) in h.iter_mut().zip(p).zip(q) {
+			*h = xor_blocks(*h, xor_blocks(p, q));
+		}
+	}
+
+	fn p_perm_lanes<const LANES: usize>(h: &mut [Self::State; LANES]) {
+		perm_p_lanes_m512i(h);
+	}
 }
@@ -2,8 +2,8 @@
 
 use digest::{Digest, Output};
 
-use super::digest::Groestl256;
-use crate::PseudoCompressionFunction;
+use super::digest::{Groestl256, Groestl256Parallel};
+use crate::{MultiDigest, PseudoCompressionFunction};
 
 /// One-way compression function that compresses two 32-byte strings into a single 32-byte string.
 #[derive(Debug, Default, Clone)]
@@ -17,4 +17,47 @@ impl PseudoCompressionFunction<Output<Groestl256>, 2> for Groestl256ByteCompress
 			.chain_update(input[1].as_slice())
 			.finalize()
 	}
+
+	fn compress_many(&self, inputs: &[[Output<Groestl256>; 2]]) -> Vec<Output<Groestl256>> {
+		hash_concatenations(inputs)
+	}
+}
+
+/// One-way compression function that compresses `N` 32-byte strings into a single 32-byte string.
+///
+/// The strings are hashed with Grøstl-256 in a single message, so that compressing 4 strings
+/// takes three Grøstl compression function calls rather than the six of three 2-to-1
+/// compressions.
+#[derive(Debug, Default, Clone)]
+pub struct Groestl256MultiCompression<const N: usize>;
+
+impl<const N: usize> PseudoCompressionFunction<Output<Groestl256>, N>
+	for Groestl256MultiCompression<N>
+{
+	fn compress(&self, input: [Output<Groestl256>; N]) -> Output<Groestl256> {
+		let mut hasher = Groestl256::new();
+		for digest in &input {
+			hasher.update(digest.as_slice());
+		}
+		hasher.finalize()
+	}
+
+	fn compress_many(&self, inputs: &[[Output<Groestl256>; N]]) -> Vec<Output<Groestl256>> {
+		hash_concatenations(inputs)
+	}
+}
+
+/// Hashes the concatenation of the digests of each input with [`Groestl256Parallel`].
+fn hash_concatenations<const N: usize>(
+	inputs: &[[Output<Groestl256>; N]],
+) -> Vec<Output<Groestl256>> {
+	let bytes = inputs
+		.iter()
+		.flatten()
+		.flat_map(|digest| digest.iter().copied())
+		.collect::<Vec<_>>();
+	let messages = bytes
+		.chunks_exact(N * <Groestl256 as Digest>::output_size())
+		.collect::<Vec<_>>();
+	Groestl256Parallel::hash_many(&messages)
 }
@@ -3,7 +3,7 @@
 
 // Implementation is copied from <https://github.com/RustCrypto/hashes>, with some modifications.
 
-use core::fmt;
+use core::{array, fmt};
 
 pub use digest;
 use digest::{
@@ -17,6 +17,7 @@ use digest::{
 };
 
 use super::{GroestlShortImpl, GroestlShortInternal};
+use crate::MultiDigest;
 
 /// Lowest-level core hasher state of the short Groestl variant.
 #[derive(Clone)]
@@ -98,3 +99,83 @@ impl fmt::Debug for GroestlShortVarCore<GroestlShortImpl> {
 		f.write_str("GroestlShortVarCore { ... }")
 	}
 }
+
+/// Grøstl-256 computed on several messages in parallel lanes.
+///
+/// Runs of messages with the same number of blocks are hashed in 8, 4 or 2 lanes. The digests are
+/// the same as those of [`Groestl256`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Groestl256Parallel;
+
+impl MultiDigest for Groestl256Parallel {
+	type Digest = Groestl256;
+
+	fn hash_many(messages: &[&[u8]]) -> Vec<Output<Groestl256>> {
+		let mut digests = Vec::with_capacity(messages.len());
+		let mut remaining = messages;
+		while let Some(first) = remaining.first() {
+			let n_blocks = n_padded_blocks(first.len());
+			let run_len = remaining
+				.iter()
+				.take(8)
+				.take_while(|message| n_padded_blocks(message.len()) == n_blocks)
+				.count();
+			let lanes = 1 << run_len.ilog2();
+			let (lane_messages, rest) = remaining.split_at(lanes);
+			match lanes {
+				8 => hash_lanes::<GroestlShortImpl, 8>(lane_messages, n_blocks, &mut digests),
+				4 => hash_lanes::<GroestlShortImpl, 4>(lane_messages, n_blocks, &mut digests),
+				2 => hash_lanes::<GroestlShortImpl, 2>(lane_messages, n_blocks, &mut digests),
+				_ => hash_lanes::<GroestlShortImpl, 1>(lane_messages, n_blocks, &mut digests),
+			}
+			remaining = rest;
+		}
+		digests
+	}
+}
+
+/// The number of blocks of a message of `len` bytes after padding.
+const fn n_padded_blocks(len: usize) -> usize {
+	// The padding appends at least one byte and the 8-byte block count
+	(len + 9).div_ceil(64)
+}
+
+/// Returns the block at index `i` of the padded message.
+fn padded_block(message: &[u8], n_blocks: usize, i: usize) -> [u8; 64] {
+	let mut block = [0u8; 64];
+	let start = (64 * i).min(message.len());
+	let end = (64 * (i + 1)).min(message.len());
+	block[..end - start].copy_from_slice(&message[start..end]);
+	if (64 * i..64 * (i + 1)).contains(&message.len()) {
+		block[message.len() - 64 * i] = 0x80;
+	}
+	if i == n_blocks - 1 {
+		block[56..].copy_from_slice(&(n_blocks as u64).to_be_bytes());
+	}
+	block
+}
+
+/// Hashes messages of the same number of padded blocks with Grøstl-256 in parallel lanes.
+fn hash_lanes<G: GroestlShortInternal, const LANES: usize>(
+	messages: &[&[u8]],
+	n_blocks: usize,
+	digests: &mut Vec<Output<Groestl256>>,
+) {
+	const OUTPUT_SIZE: usize = 32;
+
+	let mut initial = [0u8; 64];
+	initial[56..64].copy_from_slice(&(8 * OUTPUT_SIZE as u64).to_be_bytes());
+	let mut states: [G::State; LANES] = array::from_fn(|_| G::state_from_bytes(&initial));
+	for i in 0..n_blocks {
+		let blocks = array::from_fn(|lane| padded_block(messages[lane], n_blocks, i));
+		G::compress_lanes(&mut states, &blocks);
+	}
+
+	let mut outputs = states.clone();
+	G::p_perm_lanes(&mut outputs);
+	for (state, output) in states.iter_mut().zip(&outputs) {
+		G::xor_state(state, output);
+		let block = G::state_to_bytes(state);
+		digests.push(Output::<Groestl256>::clone_from_slice(&block[64 - OUTPUT_SIZE..]));
+	}
+}
@@ -3,6 +3,7 @@
 mod arch;
 mod compression;
 mod digest;
+mod permutation;
 #[cfg(test)]
 mod tests;
 
@@ -32,8 +33,25 @@ pub trait GroestlShortInternal {
 		Self::xor_state(h, &p);
 		Self::xor_state(h, &q);
 	}
+
+	/// Compresses a message block into the state of each of several independent lanes.
+	///
+	/// Implementations may interleave the lanes to make use of instruction-level parallelism.
+	fn compress_lanes<const LANES: usize>(h: &mut [Self::State; LANES], m: &[[u8; 64]; LANES]) {
+		for (h, m) in h.iter_mut().zip(m) {
+			Self::compress(h, m);
+		}
+	}
+
+	/// Applies the P permutation to the state of each of several independent lanes.
+	fn p_perm_lanes<const LANES: usize>(h: &mut [Self::State; LANES]) {
+		for h in h {
+			Self::p_perm(h);
+		}
+	}
 }
 
 pub use arch::GroestlShortImpl;
 pub use compression::*;
-pub use digest::Groestl256;
+pub use digest::{Groestl256, Groestl256Parallel};
+pub use permutation::Groestl256PPermutation;
@@ -0,0 +1,22 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::AESTowerField8b;
+
+use super::{GroestlShortImpl, GroestlShortInternal};
+use crate::permutation::{CryptographicPermutation, Permutation};
+
+/// The P permutation of Grøstl-256 over its 64-byte state, with bytes as elements of the AES field.
+///
+/// The state is in the byte order of the Grøstl specification, that is column-major.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Groestl256PPermutation;
+
+impl Permutation<[AESTowerField8b; 64]> for Groestl256PPermutation {
+	fn permute_mut(&self, input: &mut [AESTowerField8b; 64]) {
+		let mut state = GroestlShortImpl::state_from_bytes(bytemuck::must_cast_ref(input));
+		GroestlShortImpl::p_perm(&mut state);
+		*input = bytemuck::must_cast(GroestlShortImpl::state_to_bytes(&state));
+	}
+}
+
+impl CryptographicPermutation<[AESTowerField8b; 64]> for Groestl256PPermutation {}
@@ -1,9 +1,19 @@
 // Copyright 2025 Irreducible Inc.
 
+use std::array;
+
+use binius_field::AESTowerField8b;
 use digest::Digest;
 use proptest::prelude::*;
 
-use crate::groestl::digest::Groestl256;
+use crate::{
+	groestl::{
+		digest::Groestl256, Groestl256ByteCompression, Groestl256MultiCompression,
+		Groestl256PPermutation, Groestl256Parallel, GroestlShortImpl, GroestlShortInternal,
+	},
+	permutation::Permutation,
+	MultiDigest, PseudoCompressionFunction,
+};
 
 proptest! {
 	#[test]
@@ -16,3 +26,75 @@ proptest! {
 		);
 	}
 }
+
+proptest! {
+	#[test]
+	fn test_p_permutation_vs_compression(
+		h1 in any::<[u8; 64]>(),
+		h2 in any::<[u8; 64]>(),
+		m in any::<[u8; 64]>(),
+	) {
+		// compress(h, m) = P(h ^ m) ^ Q(m) ^ h, so Q(m) cancels out between two chaining values
+		let compress = |h: [u8; 64]| {
+			let mut state = GroestlShortImpl::state_from_bytes(&h);
+			GroestlShortImpl::compress(&mut state, &m);
+			GroestlShortImpl::state_to_bytes(&state)
+		};
+		let p = |h: [u8; 64]| {
+			let input = array::from_fn(|i| AESTowerField8b::new(h[i] ^ m[i]));
+			Groestl256PPermutation.permute(input).map(|x| x.val())
+		};
+
+		let (c1, c2, p1, p2) = (compress(h1), compress(h2), p(h1), p(h2));
+		for i in 0..64 {
+			prop_assert_eq!(c1[i] ^ c2[i] ^ h1[i] ^ h2[i], p1[i] ^ p2[i]);
+		}
+	}
+}
+
+proptest! {
+	#[test]
+	fn test_hash_many_vs_digest(
+		lens in prop::collection::vec(prop_oneof![Just(32usize), Just(55), Just(56), Just(64), 0..300usize], 0..20),
+		seed in any::<u8>(),
+	) {
+		let messages = lens
+			.iter()
+			.enumerate()
+			.map(|(i, &len)| (0..len).map(|j| (i * 31 + j) as u8 ^ seed).collect::<Vec<_>>())
+			.collect::<Vec<_>>();
+		let message_slices = messages.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+		let digests = Groestl256Parallel::hash_many(&message_slices);
+		prop_assert_eq!(digests.len(), messages.len());
+		for (digest, message) in digests.iter().zip(&messages) {
+			prop_assert_eq!(digest, &Groestl256::digest(message));
+		}
+	}
+}
+
+proptest! {
+	#[test]
+	fn test_compress_many_vs_compress(
+		inputs in prop::collection::vec(any::<[[u8; 32]; 4]>(), 0..20),
+	) {
+		let inputs = inputs
+			.iter()
+			.map(|input| input.map(|digest| digest.into()))
+			.collect::<Vec<_>>();
+		let pairs = inputs
+			.iter()
+			.map(|input| [input[0], input[1]])
+			.collect::<Vec<_>>();
+
+		let compressed = Groestl256ByteCompression.compress_many(&pairs);
+		for (pair, output) in pairs.iter().zip(&compressed) {
+			prop_assert_eq!(output, &Groestl256ByteCompression.compress(*pair));
+		}
+
+		let compressed = Groestl256MultiCompression::<4>.compress_many(&inputs);
+		for (input, output) in inputs.iter().zip(&compressed) {
+			prop_assert_eq!(output, &Groestl256MultiCompression::<4>.compress(*input));
+		}
+	}
+}
@@ -0,0 +1,24 @@
+// Copyright 2025 Irreducible Inc.
+
+use digest::{Digest, Output};
+pub use sha3::Keccak256;
+
+use crate::{CompressionFunction, PseudoCompressionFunction};
+
+/// A two-to-one compression function for Keccak-256 digests.
+///
+/// The compression is the Keccak-256 hash of the concatenated digests, which matches the node
+/// hashing of Merkle trees verified on the EVM.
+#[derive(Debug, Default, Clone)]
+pub struct Keccak256Compression;
+
+impl PseudoCompressionFunction<Output<Keccak256>, 2> for Keccak256Compression {
+	fn compress(&self, input: [Output<Keccak256>; 2]) -> Output<Keccak256> {
+		Keccak256::new()
+			.chain_update(input[0].as_slice())
+			.chain_update(input[1].as_slice())
+			.finalize()
+	}
+}
+
+impl CompressionFunction<Output<Keccak256>, 2> for Keccak256Compression {}
@@ -10,6 +10,10 @@
 //! This crate also provides an implementation of [Vision Mark-32], a cryptographic sponge function
 //! designed for efficient Binius arithmetization.
 //!
+//! For interoperability with external verifiers, the [`keccak`] and [`blake3`] modules provide
+//! Keccak-256 and BLAKE3 compression functions for Merkle trees, which can be used together with
+//! the corresponding hashers for Fiat-Shamir transcripts.
+//!
 //! [Grøstl-256]: <https://www.groestl.info/>
 //! [Vision Mark-32]: <https://eprint.iacr.org/2024/633>
 
@@ -18,9 +22,12 @@
 	feature(stdarch_x86_avx512)
 )]
 
+pub mod blake3;
 pub mod compression;
 pub mod groestl;
 pub mod hasher;
+pub mod keccak;
+mod multi_digest;
 pub mod permutation;
 mod serialization;
 pub mod sha2;
@@ -28,5 +35,6 @@ mod vision;
 
 pub use compression::*;
 pub use hasher::*;
+pub use multi_digest::*;
 pub use serialization::*;
 pub use vision::*;
@@ -0,0 +1,25 @@
+// Copyright 2025 Irreducible Inc.
+
+use digest::{Digest, Output};
+
+/// A hash function that computes the digests of several independent messages at once.
+///
+/// Every [`Digest`] is a `MultiDigest` that hashes the messages one after another. Faster
+/// implementations, like [`Groestl256Parallel`](crate::groestl::Groestl256Parallel), are separate
+/// types that process several messages in parallel lanes, which is most effective when the
+/// messages have the same length, like the leaves of a Merkle tree.
+pub trait MultiDigest {
+	/// The hash function computed on each message.
+	type Digest: Digest;
+
+	/// Returns the digests of the messages, in order.
+	fn hash_many(messages: &[&[u8]]) -> Vec<Output<Self::Digest>>;
+}
+
+impl<D: Digest> MultiDigest for D {
+	type Digest = D;
+
+	fn hash_many(messages: &[&[u8]]) -> Vec<Output<D>> {
+		messages.iter().map(D::digest).collect()
+	}
+}
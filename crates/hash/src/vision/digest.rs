@@ -6,7 +6,11 @@ use binius_field::{
 	BinaryField8b, BinaryToAesTransformation, PackedAESBinaryField8x32b, PackedBinaryField8x32b,
 	PackedExtensionIndexable, PackedField, PackedFieldIndexable,
 };
-use digest::{consts::U32, FixedOutput, HashMarker, OutputSizeUser, Update};
+use digest::{
+	consts::{U32, U64},
+	core_api::BlockSizeUser,
+	FixedOutput, HashMarker, OutputSizeUser, Update,
+};
 use lazy_static::lazy_static;
 
 use super::permutation::PERMUTATION;
@@ -104,6 +108,10 @@ impl Update for VisionHasherDigest {
 	}
 }
 
+impl BlockSizeUser for VisionHasherDigest {
+	type BlockSize = U64;
+}
+
 impl OutputSizeUser for VisionHasherDigest {
 	type OutputSize = U32;
 }
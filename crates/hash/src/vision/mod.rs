@@ -3,7 +3,11 @@
 pub mod constants;
 pub mod digest;
 pub mod permutation;
+mod sponge;
 
 pub use constants::*;
 pub use digest::*;
 pub use permutation::{Vision32MDSTransform, Vision32bPermutation, INV_PACKED_TRANS_AES};
+pub use sponge::{
+	VisionByteCompression, VisionCompression, VisionSponge, VisionSpongeReader, VISION_DIGEST_SIZE,
+};
@@ -20,7 +20,7 @@ use super::constants::{
 	AFFINE_FWD_AES, AFFINE_FWD_CONST_AES, AFFINE_INV_AES, AFFINE_INV_CONST_AES, NUM_ROUNDS,
 	ROUND_KEYS,
 };
-use crate::permutation::Permutation;
+use crate::permutation::{CryptographicPermutation, Permutation};
 
 type PackedTransformationType8x32bAES = <PackedAESBinaryField8x32b as PackedTransformationFactory<
 	PackedAESBinaryField8x32b,
@@ -85,7 +85,7 @@ lazy_static! {
 }
 
 /// This is the complete permutation function for the Vision hash which implements `Permutation`
-/// and `CryptographicPermutation` traits over `PackedAESBinary8x32b` as well as `BinaryField32b`
+/// and `CryptographicPermutation` traits over `PackedAESBinary8x32b` as well as `AESTowerField32b`
 #[derive(Clone, Default)]
 pub struct Vision32bPermutation {
 	mds: Vision32MDSTransform,
@@ -129,6 +129,23 @@ impl Permutation<[ByteSlicedAES32x32b; 24]> for Vision32bPermutation {
 	}
 }
 
+impl Permutation<[AESTowerField32b; 24]> for Vision32bPermutation {
+	fn permute_mut(&self, input: &mut [AESTowerField32b; 24]) {
+		let mut packed: [PackedAESBinaryField8x32b; 3] =
+			array::from_fn(|i| PackedAESBinaryField8x32b::from_fn(|j| input[i * 8 + j]));
+		self.permute_mut(&mut packed);
+		for (i, value) in input.iter_mut().enumerate() {
+			*value = packed[i / 8].get(i % 8);
+		}
+	}
+}
+
+impl CryptographicPermutation<[PackedAESBinaryField8x32b; 3]> for Vision32bPermutation {}
+
+impl CryptographicPermutation<[ByteSlicedAES32x32b; 24]> for Vision32bPermutation {}
+
+impl CryptographicPermutation<[AESTowerField32b; 24]> for Vision32bPermutation {}
+
 impl Vision32bPermutation {
 	/// Apply the S-box transformation to a packed chunk of field elements.
 	#[inline]
@@ -745,4 +762,17 @@ mod tests {
 			assert_eq!(*single_permutation, get_single_permutation(i, &data));
 		}
 	}
+
+	#[test]
+	fn test_scalar_permutation_consistency() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let mut scalars: [AESTowerField32b; 24] =
+			array::from_fn(|_| AESTowerField32b::random(&mut rng));
+		let mut packed = from_u32_to_packed_768(&scalars);
+
+		Vision32bPermutation::default().permute_mut(&mut scalars);
+		Vision32bPermutation::default().permute_mut(&mut packed);
+
+		assert_eq!(from_u32_to_packed_768(&scalars), packed);
+	}
 }
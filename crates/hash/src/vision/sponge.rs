@@ -0,0 +1,290 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::array;
+
+use binius_field::{AESTowerField32b, BinaryField32b, Field};
+use digest::Output;
+
+use super::{permutation::Vision32bPermutation, VisionHasherDigest};
+use crate::{permutation::Permutation, CompressionFunction, PseudoCompressionFunction};
+
+/// Number of elements in the state of the Vision Mark-32 permutation.
+const WIDTH: usize = 24;
+
+/// Number of elements in a digest, for 128 bits of collision resistance.
+pub const VISION_DIGEST_SIZE: usize = 8;
+
+/// A sponge over the Vision Mark-32 permutation that absorbs and squeezes [`BinaryField32b`]
+/// elements directly.
+///
+/// The sponge absorbs `RATE` elements per permutation and keeps a capacity of `24 - RATE`
+/// elements. Absorbed elements overwrite the rate part of the state, like in
+/// [`super::VisionHasherDigest`]. The message is padded with a one element followed by the zero
+/// elements that fill the last block, so that messages of different lengths never collide.
+///
+/// The default rate of 16 elements leaves a capacity of 8 elements, or 256 bits.
+#[derive(Clone)]
+pub struct VisionSponge<const RATE: usize = 16> {
+	permutation: Vision32bPermutation,
+	state: [AESTowerField32b; WIDTH],
+	/// Number of elements of the current block absorbed into the state.
+	filled: usize,
+}
+
+impl<const RATE: usize> Default for VisionSponge<RATE> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<const RATE: usize> VisionSponge<RATE> {
+	pub fn new() -> Self {
+		assert!(RATE > 0 && RATE < WIDTH, "rate must be positive and below the width");
+		Self {
+			permutation: Vision32bPermutation::default(),
+			state: [AESTowerField32b::ZERO; WIDTH],
+			filled: 0,
+		}
+	}
+
+	/// The number of capacity elements, which are never overwritten by the message.
+	pub const fn capacity() -> usize {
+		WIDTH - RATE
+	}
+
+	pub fn absorb(&mut self, elements: &[BinaryField32b]) {
+		for &element in elements {
+			self.absorb_element(element.into());
+		}
+	}
+
+	pub fn chain_absorb(mut self, elements: &[BinaryField32b]) -> Self {
+		self.absorb(elements);
+		self
+	}
+
+	/// Pads the message and returns the first [`VISION_DIGEST_SIZE`] elements of the output.
+	pub fn finalize(self) -> [BinaryField32b; VISION_DIGEST_SIZE] {
+		let mut reader = self.finalize_xof();
+		let mut digest = [BinaryField32b::ZERO; VISION_DIGEST_SIZE];
+		reader.squeeze(&mut digest);
+		digest
+	}
+
+	/// Pads the message and returns a reader of an output of any length.
+	pub fn finalize_xof(mut self) -> VisionSpongeReader<RATE> {
+		self.absorb_element(AESTowerField32b::ONE);
+		if self.filled != 0 {
+			self.state[self.filled..RATE].fill(AESTowerField32b::ZERO);
+			self.permutation.permute_mut(&mut self.state);
+		}
+		VisionSpongeReader {
+			permutation: self.permutation,
+			state: self.state,
+			squeezed: 0,
+		}
+	}
+
+	/// Returns the digest of `elements`.
+	pub fn hash(elements: &[BinaryField32b]) -> [BinaryField32b; VISION_DIGEST_SIZE] {
+		Self::new().chain_absorb(elements).finalize()
+	}
+
+	fn absorb_element(&mut self, element: AESTowerField32b) {
+		self.state[self.filled] = element;
+		self.filled += 1;
+		if self.filled == RATE {
+			self.permutation.permute_mut(&mut self.state);
+			self.filled = 0;
+		}
+	}
+}
+
+/// The output of a finalized [`VisionSponge`], squeezed `RATE` elements per permutation.
+#[derive(Clone)]
+pub struct VisionSpongeReader<const RATE: usize = 16> {
+	permutation: Vision32bPermutation,
+	state: [AESTowerField32b; WIDTH],
+	/// Number of elements of the rate already squeezed.
+	squeezed: usize,
+}
+
+impl<const RATE: usize> VisionSpongeReader<RATE> {
+	/// Fills `out` with the next elements of the output.
+	pub fn squeeze(&mut self, out: &mut [BinaryField32b]) {
+		for out in out {
+			if self.squeezed == RATE {
+				self.permutation.permute_mut(&mut self.state);
+				self.squeezed = 0;
+			}
+			*out = self.state[self.squeezed].into();
+			self.squeezed += 1;
+		}
+	}
+}
+
+/// A two-to-one compression function for digests of [`BinaryField32b`] elements.
+///
+/// The compression applies the Vision Mark-32 permutation once to the concatenated digests
+/// followed by zero elements, and truncates the output to the digest size. Unlike
+/// [`super::VisionHasherDigest`] over the serialized digests, it takes a single permutation and no
+/// conversion between bytes and field elements, which makes it cheap to verify in a circuit.
+#[derive(Clone, Default)]
+pub struct VisionCompression {
+	permutation: Vision32bPermutation,
+}
+
+impl PseudoCompressionFunction<[BinaryField32b; VISION_DIGEST_SIZE], 2> for VisionCompression {
+	fn compress(
+		&self,
+		input: [[BinaryField32b; VISION_DIGEST_SIZE]; 2],
+	) -> [BinaryField32b; VISION_DIGEST_SIZE] {
+		let [left, right] = input;
+		let mut state = array::from_fn(|i| match i {
+			i if i < VISION_DIGEST_SIZE => left[i].into(),
+			i if i < 2 * VISION_DIGEST_SIZE => right[i - VISION_DIGEST_SIZE].into(),
+			_ => AESTowerField32b::ZERO,
+		});
+		self.permutation.permute_mut(&mut state);
+		array::from_fn(|i| state[i].into())
+	}
+}
+
+impl CompressionFunction<[BinaryField32b; VISION_DIGEST_SIZE], 2> for VisionCompression {}
+
+/// [`VisionCompression`] over the bytes of [`VisionHasherDigest`] digests.
+///
+/// A digest is read as [`VISION_DIGEST_SIZE`] little-endian 32-bit elements, the encoding
+/// [`VisionHasherDigest`] outputs, so that Merkle trees over byte digests can be compressed with a
+/// single permutation.
+#[derive(Clone, Default)]
+pub struct VisionByteCompression {
+	compression: VisionCompression,
+}
+
+impl PseudoCompressionFunction<Output<VisionHasherDigest>, 2> for VisionByteCompression {
+	fn compress(&self, input: [Output<VisionHasherDigest>; 2]) -> Output<VisionHasherDigest> {
+		let digest = self
+			.compression
+			.compress(input.map(|bytes| digest_to_elements(&bytes)));
+		elements_to_digest(digest)
+	}
+}
+
+impl CompressionFunction<Output<VisionHasherDigest>, 2> for VisionByteCompression {}
+
+fn digest_to_elements(bytes: &Output<VisionHasherDigest>) -> [BinaryField32b; VISION_DIGEST_SIZE] {
+	array::from_fn(|i| {
+		let word = bytes[4 * i..4 * i + 4]
+			.try_into()
+			.expect("slice is 4 bytes");
+		BinaryField32b::new(u32::from_le_bytes(word))
+	})
+}
+
+fn elements_to_digest(
+	elements: [BinaryField32b; VISION_DIGEST_SIZE],
+) -> Output<VisionHasherDigest> {
+	let mut bytes = Output::<VisionHasherDigest>::default();
+	for (bytes, element) in bytes.chunks_exact_mut(4).zip(elements) {
+		bytes.copy_from_slice(&element.val().to_le_bytes());
+	}
+	bytes
+}
+
+#[cfg(test)]
+mod tests {
+	use rand::{rngs::StdRng, Rng, SeedableRng};
+
+	use super::*;
+
+	fn random_elements(rng: &mut StdRng, len: usize) -> Vec<BinaryField32b> {
+		(0..len).map(|_| BinaryField32b::new(rng.gen())).collect()
+	}
+
+	#[test]
+	fn test_absorb_in_parts() {
+		let mut rng = StdRng::seed_from_u64(0);
+		for len in [0, 1, 15, 16, 17, 40] {
+			let message = random_elements(&mut rng, len);
+			let expected = VisionSponge::<16>::hash(&message);
+
+			let mut sponge = VisionSponge::<16>::new();
+			for part in message.chunks(7) {
+				sponge.absorb(part);
+			}
+			assert_eq!(sponge.finalize(), expected);
+		}
+	}
+
+	#[test]
+	fn test_padding_separates_lengths() {
+		let zero = BinaryField32b::ZERO;
+		let digests = [0, 1, 15, 16, 17].map(|len| VisionSponge::<16>::hash(&vec![zero; len]));
+		for i in 0..digests.len() {
+			for j in 0..i {
+				assert_ne!(digests[i], digests[j]);
+			}
+		}
+	}
+
+	#[test]
+	fn test_xof_extends_digest() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let message = random_elements(&mut rng, 20);
+		let sponge = VisionSponge::<12>::new().chain_absorb(&message);
+
+		let mut output = vec![BinaryField32b::ZERO; 50];
+		sponge.clone().finalize_xof().squeeze(&mut output);
+		assert_eq!(output[..VISION_DIGEST_SIZE], sponge.clone().finalize());
+
+		let mut reader = sponge.finalize_xof();
+		let mut parts = vec![BinaryField32b::ZERO; 50];
+		for part in parts.chunks_mut(5) {
+			reader.squeeze(part);
+		}
+		assert_eq!(parts, output);
+	}
+
+	#[test]
+	fn test_rate_changes_digest() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let message = random_elements(&mut rng, 20);
+		assert_ne!(VisionSponge::<16>::hash(&message), VisionSponge::<8>::hash(&message));
+		assert_eq!(VisionSponge::<8>::capacity(), 16);
+	}
+
+	#[test]
+	fn test_compression_truncates_permutation() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let left: [BinaryField32b; VISION_DIGEST_SIZE] =
+			array::from_fn(|_| BinaryField32b::new(rng.gen()));
+		let right: [BinaryField32b; VISION_DIGEST_SIZE] =
+			array::from_fn(|_| BinaryField32b::new(rng.gen()));
+
+		let mut state = [AESTowerField32b::ZERO; WIDTH];
+		for (state, &input) in state.iter_mut().zip(left.iter().chain(&right)) {
+			*state = input.into();
+		}
+		Vision32bPermutation::default().permute_mut(&mut state);
+
+		let digest = VisionCompression::default().compress([left, right]);
+		assert_eq!(digest, array::from_fn(|i| BinaryField32b::from(state[i])));
+		assert_ne!(digest, VisionCompression::default().compress([right, left]));
+	}
+
+	#[test]
+	fn test_byte_compression_matches_elements() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let left: [BinaryField32b; VISION_DIGEST_SIZE] =
+			array::from_fn(|_| BinaryField32b::new(rng.gen()));
+		let right: [BinaryField32b; VISION_DIGEST_SIZE] =
+			array::from_fn(|_| BinaryField32b::new(rng.gen()));
+		assert_eq!(digest_to_elements(&elements_to_digest(left)), left);
+		assert_eq!(
+			VisionByteCompression::default()
+				.compress([elements_to_digest(left), elements_to_digest(right)]),
+			elements_to_digest(VisionCompression::default().compress([left, right]))
+		);
+	}
+}
@@ -53,6 +53,35 @@ pub(crate) trait IndexedParallelIteratorInner: ParallelIteratorInner {
 	{
 		Iterator::step_by(self, step)
 	}
+
+	#[inline]
+	fn chunks(self, chunk_size: usize) -> impl IndexedParallelIteratorInner<Item = Vec<Self::Item>>
+	where
+		Self: Sized,
+	{
+		assert!(chunk_size != 0, "chunk_size must not be zero");
+		Chunks {
+			iter: self,
+			chunk_size,
+		}
+	}
+}
+
+/// An iterator over the items of an iterator in vectors of `chunk_size` items, the last one of
+/// which may be shorter.
+pub(crate) struct Chunks<I> {
+	iter: I,
+	chunk_size: usize,
+}
+
+impl<I: Iterator> Iterator for Chunks<I> {
+	type Item = Vec<I::Item>;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let chunk = self.iter.by_ref().take(self.chunk_size).collect::<Vec<_>>();
+		(!chunk.is_empty()).then_some(chunk)
+	}
 }
 
 // Implement `IndexedParallelIteratorInner` for different `std::iter::Iterator` types.
@@ -70,6 +99,7 @@ impl<I: IndexedParallelIteratorInner> IndexedParallelIteratorInner for std::iter
 impl<I: Iterator, R, F: Fn(I::Item) -> R> IndexedParallelIteratorInner for std::iter::Map<I, F> {}
 impl<T> IndexedParallelIteratorInner for std::vec::IntoIter<T> {}
 impl<T, const N: usize> IndexedParallelIteratorInner for std::array::IntoIter<T, N> {}
+impl<I: IndexedParallelIteratorInner> IndexedParallelIteratorInner for Chunks<I> {}
 
 #[allow(private_bounds)]
 pub trait IndexedParallelIterator: ParallelIterator {
@@ -131,6 +161,17 @@ pub trait IndexedParallelIterator: ParallelIterator {
 			step,
 		))
 	}
+
+	#[inline]
+	fn chunks(self, chunk_size: usize) -> impl IndexedParallelIterator<Item = Vec<Self::Item>>
+	where
+		Self: Sized,
+	{
+		ParallelWrapper::new(IndexedParallelIteratorInner::chunks(
+			IndexedParallelIterator::into_inner(self),
+			chunk_size,
+		))
+	}
 }
 
 impl<I: IndexedParallelIteratorInner> IndexedParallelIterator for ParallelWrapper<I> {
@@ -171,6 +212,14 @@ mod tests {
 		assert_eq!(result, vec![1, 3, 5, 7, 9]);
 	}
 
+	#[test]
+	fn check_chunks() {
+		let a = &[1, 2, 3, 4, 5];
+
+		let result = a.into_par_iter().chunks(2).collect::<Vec<_>>();
+		assert_eq!(result, vec![vec![1, 2], vec![3, 4], vec![5]]);
+	}
+
 	#[test]
 	fn check_map() {
 		let a = &[1, 2, 3];
@@ -1,11 +1,11 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::iter::repeat_with;
+use std::{fs::File, io::Write, iter::repeat_with};
 
 use binius_field::{
 	arch::OptimalUnderlier, as_packed_field::PackedType, AESTowerField128b, AESTowerField32b,
 	BinaryField128b, BinaryField128bPolyval, BinaryField32b, ByteSlicedAES32x128b, PackedExtension,
-	TowerField,
+	PackedField, TowerField,
 };
 use binius_ntt::{AdditiveNTT, SingleThreadedNTT};
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
@@ -38,10 +38,59 @@ fn bench_large_transform<F: TowerField, PE: PackedExtension<F>>(c: &mut Criterio
 			group.bench_function(BenchmarkId::new("multithread/precompute", &params), |b| {
 				b.iter(|| ntt.forward_transform_ext(&mut data, 0, log_batch_size, log_dim));
 			});
+
+			let ntt = SingleThreadedNTT::<F>::new(log_dim)
+				.unwrap()
+				.precompute_twiddles()
+				.four_step();
+			group.bench_function(BenchmarkId::new("four-step/precompute", &params), |b| {
+				b.iter(|| ntt.forward_transform_ext(&mut data, 0, log_batch_size, log_dim));
+			});
 		}
 	}
 }
 
+fn bench_mmap(c: &mut Criterion) {
+	type P = PackedType<OptimalUnderlier, BinaryField32b>;
+
+	let mut group = c.benchmark_group("NTT");
+	for log_dim in [20, 24] {
+		let log_batch_size = 2;
+		let data_len = 1 << (log_dim + log_batch_size - P::LOG_WIDTH);
+		let mut rng = thread_rng();
+		let data = repeat_with(|| P::random(&mut rng))
+			.take(data_len)
+			.collect::<Vec<_>>();
+
+		let path = std::env::temp_dir().join(format!("binius_ntt_bench_{log_dim}"));
+		let mut file = File::options()
+			.read(true)
+			.write(true)
+			.create(true)
+			.truncate(true)
+			.open(&path)
+			.unwrap();
+		file.write_all(bytemuck::cast_slice(&data)).unwrap();
+
+		let params = format!("field=BinaryField32b/log_dim={log_dim}/log_b={log_batch_size}");
+		group.throughput(Throughput::Bytes((data_len * size_of::<P>()) as u64));
+
+		let ntt = SingleThreadedNTT::<BinaryField32b>::new(log_dim)
+			.unwrap()
+			.precompute_twiddles()
+			.four_step();
+		group.bench_function(BenchmarkId::new("four-step/mmap", &params), |b| {
+			// SAFETY: the file is private to this benchmark and only accessed through `file`.
+			b.iter(|| unsafe {
+				ntt.forward_transform_mmap::<P>(&file, 0, log_batch_size, log_dim)
+			});
+		});
+
+		drop(file);
+		std::fs::remove_file(&path).unwrap();
+	}
+}
+
 // We are ignoring the transposition associated with byte slicing
 fn bench_byte_sliced(c: &mut Criterion) {
 	bench_large_transform::<AESTowerField32b, ByteSlicedAES32x128b>(
@@ -70,6 +119,6 @@ fn bench_packed128b(c: &mut Criterion) {
 criterion_group! {
 	name = large_transform;
 	config = Criterion::default().sample_size(10);
-	targets = bench_packed128b, bench_byte_sliced
+	targets = bench_packed128b, bench_byte_sliced, bench_mmap
 }
 criterion_main!(large_transform);
@@ -22,6 +22,10 @@ pub enum Error {
 	BatchTooLarge,
 	#[error("odd interpolation length mismatch, expected to be exactly {expected_len}")]
 	OddInterpolateIncorrectLength { expected_len: usize },
+	#[error("file length must be a multiple of the packed element size")]
+	UnalignedFileLength,
+	#[error("I/O error: {0}")]
+	Io(#[from] std::io::Error),
 	#[error("math error: {0}")]
 	MathError(#[from] binius_math::Error),
 }
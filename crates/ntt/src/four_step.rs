@@ -0,0 +1,500 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::fs::File;
+
+use binius_field::{square_transpose, BinaryField, PackedField};
+use binius_math::BinarySubspace;
+use binius_maybe_rayon::prelude::*;
+use binius_utils::{bail, checked_arithmetics::log2_ceil_usize};
+use bytemuck::Pod;
+use memmap2::MmapMut;
+
+use super::{
+	error::Error,
+	single_threaded::{self, check_batch_transform_inputs_and_params},
+	strided_array::StridedArray2DViewMut,
+	twiddle::TwiddleAccess,
+	AdditiveNTT, SingleThreadedNTT,
+};
+use crate::twiddle::OnTheFlyTwiddleAccess;
+
+/// The default size of the blocks processed by [`FourStepNTT`], chosen to fit in a typical L2
+/// cache.
+pub const DEFAULT_LOG_BLOCK_BYTES: usize = 18;
+
+/// Implementation of `AdditiveNTT` for transforms much larger than the CPU cache.
+///
+/// The transform of size $2^n$ is decomposed in the manner of Bailey's four-step FFT. The data is
+/// viewed as a row-major matrix with $2^h$ rows. The NTT rounds that butterfly elements in
+/// different rows are performed first as a batch of column transforms, and the remaining rounds
+/// as independent transforms of the rows, each over its own coset of the evaluation domain. The
+/// column transforms are performed on blocks of adjacent columns, which are transposed into a
+/// contiguous buffer with [`square_transpose`] so that every column can be transformed on its
+/// own. The decomposition is applied recursively to both the row transforms and the column
+/// transforms until they fit in a block of the configured size, so the memory access pattern is
+/// cache-friendly regardless of the cache hierarchy.
+///
+/// The blocked access pattern also makes the transform suitable for data that does not fit in
+/// memory, see [`Self::forward_transform_mmap`].
+#[derive(Debug)]
+pub struct FourStepNTT<F: BinaryField, TA: TwiddleAccess<F> = OnTheFlyTwiddleAccess<F, Vec<F>>> {
+	single_threaded: SingleThreadedNTT<F, TA>,
+	log_block_bytes: usize,
+}
+
+impl<F: BinaryField, TA: TwiddleAccess<F> + Sync> SingleThreadedNTT<F, TA> {
+	/// Returns four-step NTT implementation which uses the default block size.
+	pub const fn four_step(self) -> FourStepNTT<F, TA> {
+		self.four_step_with_block_size(DEFAULT_LOG_BLOCK_BYTES)
+	}
+
+	/// Returns four-step NTT implementation which processes blocks of at most
+	/// `1 << log_block_bytes` bytes.
+	pub const fn four_step_with_block_size(self, log_block_bytes: usize) -> FourStepNTT<F, TA> {
+		FourStepNTT {
+			single_threaded: self,
+			log_block_bytes,
+		}
+	}
+}
+
+impl<F, TA> FourStepNTT<F, TA>
+where
+	F: BinaryField,
+	TA: TwiddleAccess<F> + Sync,
+{
+	/// Forward transformation on a batch of inputs stored in a file, which is memory-mapped.
+	///
+	/// The file contents are interpreted as a slice of packed field elements in the same layout as
+	/// the `data` argument of [`AdditiveNTT::forward_transform`], and transformed in place. Only
+	/// the blocks being processed need to be resident in memory, which allows transforming data
+	/// larger than the available RAM.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::UnalignedFileLength`] if the file length is not a multiple of the size of `P`.
+	/// * [`Error::Io`] if the file cannot be mapped or flushed.
+	///
+	/// ## Safety
+	///
+	/// The file is mapped into memory and accessed as a mutable slice. The caller must ensure
+	/// that the file is not modified or truncated, by this or any other process, until the call
+	/// returns. See [`MmapMut::map_mut`].
+	pub unsafe fn forward_transform_mmap<P: PackedField<Scalar = F> + Pod>(
+		&self,
+		file: &File,
+		coset: u32,
+		log_batch_size: usize,
+		log_n: usize,
+	) -> Result<(), Error> {
+		// SAFETY: the caller guarantees that the file is not modified for the duration of the call.
+		unsafe {
+			with_mapped_file(file, |data: &mut [P]| {
+				self.forward_transform(data, coset, log_batch_size, log_n)
+			})
+		}
+	}
+
+	/// Inverse transformation on a batch of inputs stored in a file, which is memory-mapped.
+	///
+	/// See [`Self::forward_transform_mmap`] for the file layout.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::UnalignedFileLength`] if the file length is not a multiple of the size of `P`.
+	/// * [`Error::Io`] if the file cannot be mapped or flushed.
+	///
+	/// ## Safety
+	///
+	/// Same as for [`Self::forward_transform_mmap`].
+	pub unsafe fn inverse_transform_mmap<P: PackedField<Scalar = F> + Pod>(
+		&self,
+		file: &File,
+		coset: u32,
+		log_batch_size: usize,
+		log_n: usize,
+	) -> Result<(), Error> {
+		// SAFETY: the caller guarantees that the file is not modified for the duration of the call.
+		unsafe {
+			with_mapped_file(file, |data: &mut [P]| {
+				self.inverse_transform(data, coset, log_batch_size, log_n)
+			})
+		}
+	}
+
+	fn log_block_len<P: PackedField>(&self) -> usize {
+		self.log_block_bytes
+			.saturating_sub(log2_ceil_usize(size_of::<P>()))
+	}
+}
+
+impl<F, TA> AdditiveNTT<F> for FourStepNTT<F, TA>
+where
+	F: BinaryField,
+	TA: TwiddleAccess<F> + Sync,
+{
+	fn log_domain_size(&self) -> usize {
+		self.single_threaded.log_domain_size()
+	}
+
+	fn subspace(&self, i: usize) -> BinarySubspace<F> {
+		self.single_threaded.subspace(i)
+	}
+
+	fn get_subspace_eval(&self, i: usize, j: usize) -> F {
+		self.single_threaded.get_subspace_eval(i, j)
+	}
+
+	fn forward_transform<P: PackedField<Scalar = F>>(
+		&self,
+		data: &mut [P],
+		coset: u32,
+		log_batch_size: usize,
+		log_n: usize,
+	) -> Result<(), Error> {
+		if data.len() > 1 {
+			check_batch_transform_inputs_and_params(
+				self.log_domain_size(),
+				data,
+				coset,
+				log_batch_size,
+				log_n,
+			)?;
+		}
+		forward_transform(
+			self.log_domain_size(),
+			self.single_threaded.twiddles(),
+			data,
+			coset,
+			log_batch_size,
+			log_n,
+			self.log_block_len::<P>(),
+		)
+	}
+
+	fn inverse_transform<P: PackedField<Scalar = F>>(
+		&self,
+		data: &mut [P],
+		coset: u32,
+		log_batch_size: usize,
+		log_n: usize,
+	) -> Result<(), Error> {
+		if data.len() > 1 {
+			check_batch_transform_inputs_and_params(
+				self.log_domain_size(),
+				data,
+				coset,
+				log_batch_size,
+				log_n,
+			)?;
+		}
+		inverse_transform(
+			self.log_domain_size(),
+			self.single_threaded.twiddles(),
+			data,
+			coset,
+			log_batch_size,
+			log_n,
+			self.log_block_len::<P>(),
+		)
+	}
+}
+
+/// Returns the base-2 logarithm of the number of matrix rows, or `None` if the transform should
+/// be performed directly.
+fn split_log_height<P: PackedField>(
+	data: &[P],
+	log_batch_size: usize,
+	log_n: usize,
+	log_block_len: usize,
+) -> Option<usize> {
+	// Cutoff is the stage of the NTT where each the butterfly units are contained within
+	// packed base field elements. Only the rounds above the cutoff can be split.
+	let cutoff = P::LOG_WIDTH.saturating_sub(log_batch_size);
+	let n_split_rounds = log_n.saturating_sub(cutoff);
+	let log_height = n_split_rounds / 2;
+
+	// Columns are transposed into whole packed elements, so there must be at least as many rows
+	// as scalars in a packed element.
+	(data.len() > 1 << log_block_len && log_height >= P::LOG_WIDTH.max(1)).then_some(log_height)
+}
+
+fn forward_transform<F: BinaryField, P: PackedField<Scalar = F>>(
+	log_domain_size: usize,
+	s_evals: &[impl TwiddleAccess<F> + Sync],
+	data: &mut [P],
+	coset: u32,
+	log_batch_size: usize,
+	log_n: usize,
+	log_block_len: usize,
+) -> Result<(), Error> {
+	let Some(log_height) = split_log_height(data, log_batch_size, log_n, log_block_len) else {
+		return single_threaded::forward_transform(
+			log_domain_size,
+			s_evals,
+			data,
+			coset,
+			log_batch_size,
+			log_n,
+		);
+	};
+
+	let log_width = data.len().ilog2() as usize - log_height;
+	let log_row_n = log_n - log_height;
+
+	// Perform the rounds across rows as transforms of blocks of columns.
+	let log_block_width = log_block_len.saturating_sub(log_height).min(log_width);
+	let matrix = StridedArray2DViewMut::without_stride(data, 1 << log_height, 1 << log_width)
+		.expect("dimensions are correct");
+	matrix
+		.into_par_strides(1 << log_block_width)
+		.try_for_each(|mut stride| {
+			let mut columns = transpose_columns(&stride);
+			columns
+				.chunks_exact_mut(1 << (log_height - P::LOG_WIDTH))
+				.try_for_each(|column| {
+					forward_transform(
+						log_domain_size - log_row_n,
+						&s_evals[log_row_n..],
+						column,
+						coset,
+						0,
+						log_height,
+						log_block_len,
+					)
+				})?;
+			untranspose_columns(&mut columns, &mut stride);
+			Ok::<_, Error>(())
+		})?;
+
+	// Perform the remaining rounds as independent transforms of the rows.
+	data.par_chunks_mut(1 << log_width)
+		.enumerate()
+		.try_for_each(|(row, chunk)| {
+			forward_transform(
+				log_domain_size,
+				&s_evals[..log_row_n],
+				chunk,
+				coset << log_height | row as u32,
+				log_batch_size,
+				log_row_n,
+				log_block_len,
+			)
+		})
+}
+
+fn inverse_transform<F: BinaryField, P: PackedField<Scalar = F>>(
+	log_domain_size: usize,
+	s_evals: &[impl TwiddleAccess<F> + Sync],
+	data: &mut [P],
+	coset: u32,
+	log_batch_size: usize,
+	log_n: usize,
+	log_block_len: usize,
+) -> Result<(), Error> {
+	let Some(log_height) = split_log_height(data, log_batch_size, log_n, log_block_len) else {
+		return single_threaded::inverse_transform(
+			log_domain_size,
+			s_evals,
+			data,
+			coset,
+			log_batch_size,
+			log_n,
+		);
+	};
+
+	let log_width = data.len().ilog2() as usize - log_height;
+	let log_row_n = log_n - log_height;
+
+	// The inverse transform undoes the steps of the forward transform in reverse order.
+	data.par_chunks_mut(1 << log_width)
+		.enumerate()
+		.try_for_each(|(row, chunk)| {
+			inverse_transform(
+				log_domain_size,
+				&s_evals[..log_row_n],
+				chunk,
+				coset << log_height | row as u32,
+				log_batch_size,
+				log_row_n,
+				log_block_len,
+			)
+		})?;
+
+	let log_block_width = log_block_len.saturating_sub(log_height).min(log_width);
+	let matrix = StridedArray2DViewMut::without_stride(data, 1 << log_height, 1 << log_width)
+		.expect("dimensions are correct");
+	matrix
+		.into_par_strides(1 << log_block_width)
+		.try_for_each(|mut stride| {
+			let mut columns = transpose_columns(&stride);
+			columns
+				.chunks_exact_mut(1 << (log_height - P::LOG_WIDTH))
+				.try_for_each(|column| {
+					inverse_transform(
+						log_domain_size - log_row_n,
+						&s_evals[log_row_n..],
+						column,
+						coset,
+						0,
+						log_height,
+						log_block_len,
+					)
+				})?;
+			untranspose_columns(&mut columns, &mut stride);
+			Ok::<_, Error>(())
+		})
+}
+
+/// Copies a vertical slice of the matrix into a buffer in which every scalar column is
+/// contiguous, in the order of the scalar columns of the slice.
+///
+/// The packed elements are first permuted so that, for every column of packed elements, the
+/// $W \times W$ scalar squares formed by $W$ consecutive rows are the squares transposed by
+/// [`square_transpose`], where $W$ is the packing width. The slice must have at least $W$ rows.
+fn transpose_columns<P: PackedField>(stride: &StridedArray2DViewMut<P>) -> Vec<P> {
+	let height = stride.height();
+	let log_squares = height.ilog2() as usize - P::LOG_WIDTH;
+
+	let mut columns = Vec::with_capacity(height * stride.width());
+	for j in 0..stride.width() {
+		for r in 0..P::WIDTH {
+			for k in 0..1 << log_squares {
+				columns.push(stride[(k << P::LOG_WIDTH | r, j)]);
+			}
+		}
+	}
+
+	for column in columns.chunks_exact_mut(height) {
+		square_transpose(P::LOG_WIDTH, column).expect("column length is a multiple of the width");
+	}
+	columns
+}
+
+/// Inverse of [`transpose_columns`], copying the columns back into the vertical slice.
+fn untranspose_columns<P: PackedField>(columns: &mut [P], stride: &mut StridedArray2DViewMut<P>) {
+	let height = stride.height();
+	let log_squares = height.ilog2() as usize - P::LOG_WIDTH;
+
+	for column in columns.chunks_exact_mut(height) {
+		square_transpose(P::LOG_WIDTH, column).expect("column length is a multiple of the width");
+	}
+
+	let mut columns = columns.iter();
+	for j in 0..stride.width() {
+		for r in 0..P::WIDTH {
+			for k in 0..1 << log_squares {
+				stride[(k << P::LOG_WIDTH | r, j)] = *columns.next().expect("lengths match");
+			}
+		}
+	}
+}
+
+/// Maps the file into memory and runs `transform` on its contents.
+///
+/// ## Safety
+///
+/// The file must not be modified or truncated until the function returns.
+unsafe fn with_mapped_file<P: Pod>(
+	file: &File,
+	transform: impl FnOnce(&mut [P]) -> Result<(), Error>,
+) -> Result<(), Error> {
+	// SAFETY: the mapping does not outlive this function, and the caller guarantees that the
+	// file is not modified through other mappings or handles while it exists. The mapping is
+	// page-aligned, so casting it to `P` only depends on the file length, which is checked.
+	let mut mmap = unsafe { MmapMut::map_mut(file)? };
+	let Ok(data) = bytemuck::try_cast_slice_mut::<u8, P>(&mut mmap) else {
+		bail!(Error::UnalignedFileLength);
+	};
+	transform(data)?;
+	mmap.flush()?;
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use std::io::{Read, Seek, Write};
+
+	use binius_field::{BinaryField32b, PackedBinaryField4x32b};
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+
+	#[test]
+	fn test_mmap_transform_matches_single_threaded() {
+		type P = PackedBinaryField4x32b;
+
+		let log_n = 10;
+		let log_batch_size = 1;
+		let ntt = SingleThreadedNTT::<BinaryField32b>::new(log_n + 2)
+			.unwrap()
+			.four_step_with_block_size(8);
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let data = (0..1 << (log_n + log_batch_size - P::LOG_WIDTH))
+			.map(|_| P::random(&mut rng))
+			.collect::<Vec<_>>();
+
+		let path = std::env::temp_dir().join(format!("binius_ntt_mmap_{}", std::process::id()));
+		let mut file = File::options()
+			.read(true)
+			.write(true)
+			.create(true)
+			.truncate(true)
+			.open(&path)
+			.unwrap();
+		file.write_all(bytemuck::cast_slice(&data)).unwrap();
+
+		// SAFETY: the file is private to this test and only accessed through `file`.
+		unsafe { ntt.forward_transform_mmap::<P>(&file, 3, log_batch_size, log_n) }.unwrap();
+
+		let mut expected = data.clone();
+		SingleThreadedNTT::<BinaryField32b>::new(log_n + 2)
+			.unwrap()
+			.forward_transform(&mut expected, 3, log_batch_size, log_n)
+			.unwrap();
+
+		let mut bytes = Vec::new();
+		file.rewind().unwrap();
+		file.read_to_end(&mut bytes).unwrap();
+		assert_eq!(bytemuck::cast_slice::<u8, P>(&bytes), expected);
+
+		// SAFETY: the file is private to this test and only accessed through `file`.
+		unsafe { ntt.inverse_transform_mmap::<P>(&file, 3, log_batch_size, log_n) }.unwrap();
+
+		bytes.clear();
+		file.rewind().unwrap();
+		file.read_to_end(&mut bytes).unwrap();
+		assert_eq!(bytemuck::cast_slice::<u8, P>(&bytes), data);
+
+		std::fs::remove_file(&path).unwrap();
+	}
+
+	#[test]
+	fn test_mmap_transform_unaligned_file_length() {
+		type P = PackedBinaryField4x32b;
+
+		let ntt = SingleThreadedNTT::<BinaryField32b>::new(8)
+			.unwrap()
+			.four_step();
+
+		let path =
+			std::env::temp_dir().join(format!("binius_ntt_mmap_unaligned_{}", std::process::id()));
+		let mut file = File::options()
+			.read(true)
+			.write(true)
+			.create(true)
+			.truncate(true)
+			.open(&path)
+			.unwrap();
+		file.write_all(&[0u8; 17]).unwrap();
+
+		assert!(matches!(
+			// SAFETY: the file is private to this test and only accessed through `file`.
+			unsafe { ntt.forward_transform_mmap::<P>(&file, 0, 0, 2) },
+			Err(Error::UnalignedFileLength)
+		));
+
+		std::fs::remove_file(&path).unwrap();
+	}
+}
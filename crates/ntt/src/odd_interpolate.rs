@@ -19,10 +19,44 @@ impl<F: BinaryField> OddInterpolate<F> {
 	where
 		TA: TwiddleAccess<F>,
 	{
-		// TODO: This constructor should accept an `impl AdditiveNTT` instead of an
-		// `impl TwiddleAccess`. It can use `AdditiveNTT::get_subspace_eval` instead of the twiddle
-		// accessors directly. `AdditiveNTT` is a more public interface.
-		let vandermonde = novel_vandermonde(d, ell, twiddle_access)?;
+		let log_required_domain_size = log2_ceil_usize(d) + ell;
+		if twiddle_access.len() < log_required_domain_size {
+			bail!(Error::DomainTooSmall {
+				log_required_domain_size
+			});
+		}
+
+		Self::with_subspace_evals(d, ell, |j, i| {
+			let twiddle_access_j = &twiddle_access[j];
+			assert!(twiddle_access_j.log_n() >= log_required_domain_size - 1 - j);
+			twiddle_access_j.get(i)
+		})
+	}
+
+	/// Create a new odd interpolator into novel polynomial basis for domains of size $d \times 2^{\ell}$.
+	/// Like [`Self::new`], but seeds the "Vandermonde" matrix with the subspace polynomial evaluations
+	/// of an [`AdditiveNTT`] instead of its raw twiddle factors.
+	/// Time complexity is $\mathcal{O}(d^3).$
+	pub fn with_ntt<NTT>(d: usize, ell: usize, ntt: &NTT) -> Result<Self, Error>
+	where
+		NTT: AdditiveNTT<F>,
+	{
+		let log_required_domain_size = log2_ceil_usize(d) + ell;
+		if ntt.log_domain_size() < log_required_domain_size {
+			bail!(Error::DomainTooSmall {
+				log_required_domain_size
+			});
+		}
+
+		Self::with_subspace_evals(d, ell, |j, i| ntt.get_subspace_eval(j, i))
+	}
+
+	fn with_subspace_evals(
+		d: usize,
+		ell: usize,
+		subspace_eval: impl Fn(usize, usize) -> F,
+	) -> Result<Self, Error> {
+		let vandermonde = novel_vandermonde(d, ell, subspace_eval);
 
 		let mut vandermonde_inverse = Matrix::zeros(d, d);
 		vandermonde.inverse_into(&mut vandermonde_inverse)?;
@@ -91,13 +125,20 @@ impl<F: BinaryField> OddInterpolate<F> {
 /// Compute the Vandermonde matrix: $X^{(\ell)}_i(w^{\ell}_j)$, where $w^{\ell}_j$ is the $j^{\text{th}}$ element of the field
 /// with respect to the $\beta^{(\ell)}_i$ in little Endian order. The matrix has dimensions $d\times d$.
 /// The key trick is that $\widehat{W}^{(\ell)}_i(\beta^{\ell}_j) = $\widehat{W}_{i+\ell}(\beta_{j+\ell})$.
-fn novel_vandermonde<F, TA>(d: usize, ell: usize, twiddle_access: &[TA]) -> Result<Matrix<F>, Error>
+///
+/// `subspace_eval(j, i)` must return the $i^{\text{th}}$ evaluation of the $j^{\text{th}}$ normalized
+/// subspace polynomial, as in [`AdditiveNTT::get_subspace_eval`]. The caller is responsible for
+/// checking that the domain is large enough.
+fn novel_vandermonde<F>(
+	d: usize,
+	ell: usize,
+	subspace_eval: impl Fn(usize, usize) -> F,
+) -> Matrix<F>
 where
 	F: BinaryField,
-	TA: TwiddleAccess<F>,
 {
 	if d == 0 {
-		return Ok(Matrix::zeros(0, 0));
+		return Matrix::zeros(0, 0);
 	}
 
 	let log_d = log2_ceil_usize(d);
@@ -108,18 +149,9 @@ where
 	// $X_0$ is the function "1".
 	(0..d).for_each(|j| x_ell[(j, 0)] = F::ONE);
 
-	let log_required_domain_size = log_d + ell;
-	if twiddle_access.len() < log_required_domain_size {
-		bail!(Error::DomainTooSmall {
-			log_required_domain_size
-		});
-	}
-
-	for (j, twiddle_access_j_plus_ell) in twiddle_access[ell..ell + log_d].iter().enumerate() {
-		assert!(twiddle_access_j_plus_ell.log_n() >= log_d - 1 - j);
-
+	for j in 0..log_d {
 		for i in 0..d {
-			x_ell[(i, 1 << j)] = twiddle_access_j_plus_ell.get(i >> (j + 1))
+			x_ell[(i, 1 << j)] = subspace_eval(j + ell, i >> (j + 1))
 				+ if (i >> j) & 1 == 1 { F::ONE } else { F::ZERO };
 		}
 
@@ -131,7 +163,7 @@ where
 		}
 	}
 
-	Ok(x_ell)
+	x_ell
 }
 
 #[cfg(test)]
@@ -167,12 +199,19 @@ mod tests {
 				ntt.forward_transform(&mut ntt_evals, 0, 0, next_log_n)
 					.unwrap();
 
-				let odd_interpolate = OddInterpolate::new(d, ell, &ntt.s_evals).unwrap();
-				odd_interpolate
+				let mut with_twiddles = ntt_evals.clone();
+				OddInterpolate::new(d, ell, &ntt.s_evals)
+					.unwrap()
+					.inverse_transform(&ntt, &mut with_twiddles[..d << ell])
+					.unwrap();
+
+				OddInterpolate::with_ntt(d, ell, &ntt)
+					.unwrap()
 					.inverse_transform(&ntt, &mut ntt_evals[..d << ell])
 					.unwrap();
 
 				assert_eq!(expected_novel, &ntt_evals[..d << ell]);
+				assert_eq!(expected_novel, &with_twiddles[..d << ell]);
 			}
 		}
 	}
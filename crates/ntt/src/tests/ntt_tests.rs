@@ -87,6 +87,14 @@ fn check_roundtrip_all_ntts<P>(
 	let dynamic_dispatch_ntt = DynamicDispatchNTT::SingleThreaded(
 		SingleThreadedNTT::<P::Scalar>::new(log_domain_size).unwrap(),
 	);
+	// Use the smallest block size to exercise the recursive decomposition on small inputs.
+	let four_step_ntt = SingleThreadedNTT::<P::Scalar>::new(log_domain_size)
+		.unwrap()
+		.four_step_with_block_size(0);
+	let four_step_precompute_ntt = SingleThreadedNTT::<P::Scalar>::new(log_domain_size)
+		.unwrap()
+		.precompute_twiddles()
+		.four_step_with_block_size(0);
 
 	let mut rng = StdRng::seed_from_u64(0);
 	let data = (0..1u128 << log_data_size)
@@ -143,6 +151,22 @@ fn check_roundtrip_all_ntts<P>(
 				log_batch_size,
 				log_n,
 			);
+			check_roundtrip_with_reference(
+				&simple_ntt,
+				&four_step_ntt,
+				&data,
+				cosets.clone(),
+				log_batch_size,
+				log_n,
+			);
+			check_roundtrip_with_reference(
+				&simple_ntt,
+				&four_step_precompute_ntt,
+				&data,
+				cosets.clone(),
+				log_batch_size,
+				log_n,
+			);
 		}
 	}
 }
@@ -0,0 +1,409 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::BinaryField;
+use binius_utils::{bail, checked_arithmetics::log2_ceil_usize};
+
+use crate::{additive_ntt::AdditiveNTT, error::Error, odd_interpolate::OddInterpolate};
+
+/// Fast arithmetic on univariate polynomials represented in the novel polynomial basis of an
+/// additive NTT.
+///
+/// The novel polynomial basis $X_0, \ldots, X_{2^\ell - 1}$ is defined with respect to the
+/// normalized subspace polynomials $\hat{W}_0, \ldots, \hat{W}_{\ell-1}$ of the NTT, where
+/// $X_j = \prod_{i} \hat{W}_i^{j_i}$ for the binary digits $j_i$ of $j$. See [LCH14] and [DP24]
+/// Section 2.3.
+///
+/// The evaluation domain of an NTT is the sequence of elements of the subspace $S^{(0)}$ in
+/// little-endian binary counting order with respect to its basis. All operations supported here
+/// are restricted to polynomials of degree less than $2^\ell$, where $\ell$ is the log size of
+/// the NTT domain.
+///
+/// [LCH14]: <https://arxiv.org/abs/1404.3458>
+/// [DP24]: <https://eprint.iacr.org/2024/504>
+#[derive(Debug)]
+pub struct NovelPolyArithmetic<'a, F: BinaryField, NTT> {
+	ntt: &'a NTT,
+	/// The monomial coefficients of the normalized subspace polynomials. The polynomials are
+	/// linearized, so the `t`-th entry of `subspace_polys[i]` is the coefficient of $X^{2^t}$ in
+	/// $\hat{W}_i$.
+	subspace_polys: Vec<Vec<F>>,
+}
+
+impl<'a, F, NTT> NovelPolyArithmetic<'a, F, NTT>
+where
+	F: BinaryField,
+	NTT: AdditiveNTT<F>,
+{
+	/// Precomputes the monomial representations of the subspace polynomials of the NTT.
+	///
+	/// Time complexity is $\mathcal{O}(\ell^2)$.
+	pub fn new(ntt: &'a NTT) -> Self {
+		let log_domain_size = ntt.log_domain_size();
+		let mut subspace_polys = Vec::<Vec<F>>::with_capacity(log_domain_size);
+		if log_domain_size > 0 {
+			// The basis of the evaluation domain begins with 1, hence $\hat{W}_0(X) = X$.
+			subspace_polys.push(vec![F::ONE]);
+		}
+		for i in 1..log_domain_size {
+			// $\hat{W}_i(X) = \hat{W}_{i-1}(X) (\hat{W}_{i-1}(X) + 1) / c$, normalized so that
+			// $\hat{W}_i(\beta_i) = 1$. In characteristic 2, squaring a linearized polynomial
+			// squares its coefficients and doubles the exponents.
+			let normalizer = ntt.get_subspace_eval(i - 1, 1);
+			let normalizer_inv = (normalizer.square() + normalizer)
+				.invert()
+				.expect("subspace polynomial does not vanish on the next basis element");
+
+			let prev = &subspace_polys[i - 1];
+			let mut next = vec![F::ZERO; i + 1];
+			for (t, &coeff) in prev.iter().enumerate() {
+				next[t] += coeff;
+				next[t + 1] += coeff.square();
+			}
+			for coeff in &mut next {
+				*coeff *= normalizer_inv;
+			}
+			subspace_polys.push(next);
+		}
+
+		Self {
+			ntt,
+			subspace_polys,
+		}
+	}
+
+	/// The underlying additive NTT.
+	pub const fn ntt(&self) -> &NTT {
+		self.ntt
+	}
+
+	/// Multiplies two polynomials given in the novel basis.
+	///
+	/// The result is in the novel basis and has length `lhs.len() + rhs.len() - 1`, or is empty
+	/// if either operand is empty. The product is computed with forward and inverse NTTs over a
+	/// domain large enough to determine it.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::DomainTooSmall`] if the product has degree at least the NTT domain size.
+	pub fn mul(&self, lhs: &[F], rhs: &[F]) -> Result<Vec<F>, Error> {
+		if lhs.is_empty() || rhs.is_empty() {
+			return Ok(Vec::new());
+		}
+
+		let len = lhs.len() + rhs.len() - 1;
+		let log_n = self.check_log_len(len)?;
+
+		let mut lhs_evals = lhs.to_vec();
+		lhs_evals.resize(1 << log_n, F::ZERO);
+		self.ntt.forward_transform(&mut lhs_evals, 0, 0, log_n)?;
+
+		let mut rhs_evals = rhs.to_vec();
+		rhs_evals.resize(1 << log_n, F::ZERO);
+		self.ntt.forward_transform(&mut rhs_evals, 0, 0, log_n)?;
+
+		for (lhs_eval, rhs_eval) in lhs_evals.iter_mut().zip(&rhs_evals) {
+			*lhs_eval *= *rhs_eval;
+		}
+
+		self.ntt.inverse_transform(&mut lhs_evals, 0, 0, log_n)?;
+		lhs_evals.truncate(len);
+		Ok(lhs_evals)
+	}
+
+	/// Evaluates a polynomial given in the novel basis at the first `n` points of the NTT domain.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::DomainTooSmall`] if either `n` or the number of coefficients exceeds the NTT
+	///   domain size.
+	pub fn evaluate_on_domain(&self, coeffs: &[F], n: usize) -> Result<Vec<F>, Error> {
+		let log_n = self.check_log_len(coeffs.len().max(n))?;
+
+		let mut evals = coeffs.to_vec();
+		evals.resize(1 << log_n, F::ZERO);
+		self.ntt.forward_transform(&mut evals, 0, 0, log_n)?;
+		evals.truncate(n);
+		Ok(evals)
+	}
+
+	/// Interpolates the novel basis coefficients of the unique polynomial of degree less than
+	/// `values.len()` that takes the given values on the first `values.len()` points of the NTT
+	/// domain.
+	///
+	/// The domain size need not be a power of two. Writing it as $d \times 2^k$ with $d$ odd, the
+	/// time complexity is $\mathcal{O}(d^3 + d^2 2^k + k 2^k)$ (see [`OddInterpolate`]).
+	///
+	/// ## Throws
+	///
+	/// * [`Error::DomainTooSmall`] if the number of values exceeds the NTT domain size.
+	pub fn interpolate_on_domain(&self, values: &[F]) -> Result<Vec<F>, Error> {
+		if values.is_empty() {
+			return Ok(Vec::new());
+		}
+
+		self.check_log_len(values.len())?;
+
+		let ell = values.len().trailing_zeros() as usize;
+		let odd_interpolate = OddInterpolate::with_ntt(values.len() >> ell, ell, self.ntt)?;
+
+		let mut coeffs = values.to_vec();
+		odd_interpolate.inverse_transform(self.ntt, &mut coeffs)?;
+		Ok(coeffs)
+	}
+
+	/// Evaluates a polynomial given in the novel basis at an arbitrary point.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::DomainTooSmall`] if the number of coefficients exceeds the NTT domain size.
+	pub fn evaluate(&self, coeffs: &[F], point: F) -> Result<F, Error> {
+		let log_n = self.check_log_len(coeffs.len())?;
+
+		// The novel basis polynomials are products of subspace polynomial powers, so the
+		// evaluation folds like a multilinear polynomial in the subspace polynomial evaluations.
+		let mut folded = coeffs.to_vec();
+		folded.resize(1 << log_n, F::ZERO);
+		for i in (0..log_n).rev() {
+			let subspace_eval = evaluate_linearized(&self.subspace_polys[i], point);
+			let (lo, hi) = folded.split_at_mut(1 << i);
+			for (lo_j, &hi_j) in lo.iter_mut().zip(hi.iter()) {
+				*lo_j += subspace_eval * hi_j;
+			}
+			folded.truncate(1 << i);
+		}
+		Ok(folded.first().copied().unwrap_or(F::ZERO))
+	}
+
+	/// Converts a polynomial from the novel basis to the monomial basis.
+	///
+	/// The coefficient vector length is preserved. Time complexity is
+	/// $\mathcal{O}(k^2 2^k)$ for $2^k$ coefficients.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::DomainTooSmall`] if the number of coefficients exceeds the NTT domain size.
+	pub fn novel_to_monomial(&self, coeffs: &[F]) -> Result<Vec<F>, Error> {
+		let log_n = self.check_log_len(coeffs.len())?;
+
+		let mut result = coeffs.to_vec();
+		result.resize(1 << log_n, F::ZERO);
+		self.novel_to_monomial_inplace(&mut result, log_n);
+		result.truncate(coeffs.len());
+		Ok(result)
+	}
+
+	/// Converts a polynomial from the monomial basis to the novel basis.
+	///
+	/// This is the inverse of [`Self::novel_to_monomial`].
+	///
+	/// ## Throws
+	///
+	/// * [`Error::DomainTooSmall`] if the number of coefficients exceeds the NTT domain size.
+	pub fn monomial_to_novel(&self, coeffs: &[F]) -> Result<Vec<F>, Error> {
+		let log_n = self.check_log_len(coeffs.len())?;
+
+		let mut result = coeffs.to_vec();
+		result.resize(1 << log_n, F::ZERO);
+		self.monomial_to_novel_inplace(&mut result, log_n);
+		result.truncate(coeffs.len());
+		Ok(result)
+	}
+
+	/// Recursively computes $A(X) + \hat{W}_{k-1}(X) B(X)$, where $A$ and $B$ are given by the
+	/// lower and upper halves of `data` in the novel basis.
+	fn novel_to_monomial_inplace(&self, data: &mut [F], log_n: usize) {
+		if log_n == 0 {
+			return;
+		}
+
+		let half = 1 << (log_n - 1);
+		let (lo, hi) = data.split_at_mut(half);
+		self.novel_to_monomial_inplace(lo, log_n - 1);
+		self.novel_to_monomial_inplace(hi, log_n - 1);
+
+		// Multiply the upper half by the sparse subspace polynomial, undoing the steps of the long
+		// division in `monomial_to_novel_inplace` in reverse order.
+		let (&lead, rest) = self.subspace_polys[log_n - 1]
+			.split_last()
+			.expect("subspace polynomials are non-zero");
+		for i in half..1 << log_n {
+			let quotient = data[i];
+			for (t, &coeff) in rest.iter().enumerate() {
+				data[i - half + (1 << t)] += coeff * quotient;
+			}
+			data[i] = lead * quotient;
+		}
+	}
+
+	/// Recursively divides `data` in the monomial basis by $\hat{W}_{k-1}(X)$, storing the
+	/// remainder in the lower half and the quotient in the upper half before converting both.
+	fn monomial_to_novel_inplace(&self, data: &mut [F], log_n: usize) {
+		if log_n == 0 {
+			return;
+		}
+
+		let half = 1 << (log_n - 1);
+		let (&lead, rest) = self.subspace_polys[log_n - 1]
+			.split_last()
+			.expect("subspace polynomials are non-zero");
+		let lead_inv = lead.invert().expect("leading coefficient is non-zero");
+		for i in (half..1 << log_n).rev() {
+			let quotient = data[i] * lead_inv;
+			for (t, &coeff) in rest.iter().enumerate() {
+				data[i - half + (1 << t)] -= coeff * quotient;
+			}
+			data[i] = quotient;
+		}
+
+		let (lo, hi) = data.split_at_mut(half);
+		self.monomial_to_novel_inplace(lo, log_n - 1);
+		self.monomial_to_novel_inplace(hi, log_n - 1);
+	}
+
+	fn check_log_len(&self, len: usize) -> Result<usize, Error> {
+		let log_required_domain_size = log2_ceil_usize(len);
+		if log_required_domain_size > self.ntt.log_domain_size() {
+			bail!(Error::DomainTooSmall {
+				log_required_domain_size
+			});
+		}
+		Ok(log_required_domain_size)
+	}
+}
+
+/// Evaluates the linearized polynomial $\sum_t c_t X^{2^t}$ at `point`.
+fn evaluate_linearized<F: BinaryField>(coeffs: &[F], point: F) -> F {
+	let mut power = point;
+	let mut result = F::ZERO;
+	for &coeff in coeffs {
+		result += coeff * power;
+		power = power.square();
+	}
+	result
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use binius_field::{BinaryField32b, Field};
+	use binius_math::evaluate_univariate;
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+	use crate::single_threaded::SingleThreadedNTT;
+
+	type F = BinaryField32b;
+
+	fn random_poly(len: usize, rng: &mut StdRng) -> Vec<F> {
+		repeat_with(|| F::random(&mut *rng)).take(len).collect()
+	}
+
+	fn mul_naive(lhs: &[F], rhs: &[F]) -> Vec<F> {
+		if lhs.is_empty() || rhs.is_empty() {
+			return Vec::new();
+		}
+
+		let mut product = vec![F::ZERO; lhs.len() + rhs.len() - 1];
+		for (i, &lhs_i) in lhs.iter().enumerate() {
+			for (j, &rhs_j) in rhs.iter().enumerate() {
+				product[i + j] += lhs_i * rhs_j;
+			}
+		}
+		product
+	}
+
+	#[test]
+	fn test_basis_conversion_matches_ntt() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let ntt = SingleThreadedNTT::<F>::new(8).unwrap();
+		let arithmetic = NovelPolyArithmetic::new(&ntt);
+		let domain = ntt.subspace(0);
+
+		for log_n in 0..=6 {
+			let novel = random_poly(1 << log_n, &mut rng);
+			let monomial = arithmetic.novel_to_monomial(&novel).unwrap();
+
+			let mut evals = novel.clone();
+			ntt.forward_transform(&mut evals, 0, 0, log_n).unwrap();
+			for (index, &eval) in evals.iter().enumerate() {
+				assert_eq!(evaluate_univariate(&monomial, domain.get(index)), eval);
+			}
+
+			assert_eq!(arithmetic.monomial_to_novel(&monomial).unwrap(), novel);
+		}
+	}
+
+	#[test]
+	fn test_basis_conversion_non_power_of_two() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let ntt = SingleThreadedNTT::<F>::new(8).unwrap();
+		let arithmetic = NovelPolyArithmetic::new(&ntt);
+
+		for len in [0, 3, 5, 13, 100] {
+			let monomial = random_poly(len, &mut rng);
+			let novel = arithmetic.monomial_to_novel(&monomial).unwrap();
+			assert_eq!(novel.len(), len);
+			assert_eq!(arithmetic.novel_to_monomial(&novel).unwrap(), monomial);
+		}
+	}
+
+	#[test]
+	fn test_mul_matches_naive() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let ntt = SingleThreadedNTT::<F>::new(8).unwrap();
+		let arithmetic = NovelPolyArithmetic::new(&ntt);
+
+		for (lhs_len, rhs_len) in [(0, 5), (1, 1), (3, 4), (16, 16), (17, 50), (128, 129)] {
+			let lhs = random_poly(lhs_len, &mut rng);
+			let rhs = random_poly(rhs_len, &mut rng);
+
+			let product = arithmetic.mul(&lhs, &rhs).unwrap();
+			let expected = mul_naive(
+				&arithmetic.novel_to_monomial(&lhs).unwrap(),
+				&arithmetic.novel_to_monomial(&rhs).unwrap(),
+			);
+			assert_eq!(arithmetic.novel_to_monomial(&product).unwrap(), expected);
+		}
+	}
+
+	#[test]
+	fn test_mul_domain_too_small() {
+		let ntt = SingleThreadedNTT::<F>::new(4).unwrap();
+		let arithmetic = NovelPolyArithmetic::new(&ntt);
+
+		let poly = vec![F::ONE; 9];
+		assert!(matches!(
+			arithmetic.mul(&poly, &poly),
+			Err(Error::DomainTooSmall {
+				log_required_domain_size: 5
+			})
+		));
+	}
+
+	#[test]
+	fn test_evaluate_and_interpolate() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let ntt = SingleThreadedNTT::<F>::new(8).unwrap();
+		let arithmetic = NovelPolyArithmetic::new(&ntt);
+		let domain = ntt.subspace(0);
+
+		for len in [1, 2, 3, 6, 12, 40] {
+			let novel = random_poly(len, &mut rng);
+			let monomial = arithmetic.novel_to_monomial(&novel).unwrap();
+
+			let point = F::random(&mut rng);
+			assert_eq!(
+				arithmetic.evaluate(&novel, point).unwrap(),
+				evaluate_univariate(&monomial, point)
+			);
+
+			let evals = arithmetic.evaluate_on_domain(&novel, len + 3).unwrap();
+			for (index, &eval) in evals.iter().enumerate() {
+				assert_eq!(evaluate_univariate(&monomial, domain.get(index)), eval);
+			}
+
+			assert_eq!(arithmetic.interpolate_on_domain(&evals[..len]).unwrap(), novel);
+		}
+	}
+}